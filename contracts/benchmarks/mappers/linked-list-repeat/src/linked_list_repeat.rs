@@ -34,6 +34,7 @@ pub trait LinkedListRepeat: benchmark_common::BenchmarkCommon {
     }
 
     #[view]
+    #[paginated_view(benchPaginated)]
     #[storage_mapper("benchmark")]
     fn bench(&self) -> LinkedListMapper<ManagedBuffer>;
 
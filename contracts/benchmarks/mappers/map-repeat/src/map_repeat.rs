@@ -31,6 +31,7 @@ pub trait MapRepeat: benchmark_common::BenchmarkCommon {
         }
     }
 
+    #[paginated_view(benchPaginated)]
     #[storage_mapper("benchmark")]
     fn bench(&self) -> MapMapper<ManagedBuffer, ManagedBuffer>;
 
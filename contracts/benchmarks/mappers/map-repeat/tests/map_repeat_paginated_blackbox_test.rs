@@ -0,0 +1,103 @@
+use multiversx_sc::{
+    codec::multi_types::{MultiValue2, OptionalValue},
+    types::{ManagedBuffer, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    scenario_model::{Account, ScCallStep, ScQueryStep, SetStateStep, TxExpect},
+    ContractInfo, ScenarioWorld,
+};
+
+use map_repeat::ProxyTrait as _;
+
+const OWNER_ADDRESS_EXPR: &str = "address:owner";
+const MAP_REPEAT_ADDRESS_EXPR: &str = "sc:map-repeat";
+const MAP_REPEAT_PATH_EXPR: &str = "mxsc:output/map-repeat.mxsc.json";
+
+type MapRepeatContract = ContractInfo<map_repeat::Proxy<StaticApi>>;
+
+type Entry = MultiValue2<ManagedBuffer<StaticApi>, ManagedBuffer<StaticApi>>;
+type Page = MultiValue2<Option<ManagedBuffer<StaticApi>>, MultiValueEncoded<StaticApi, Entry>>;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/benchmarks/mappers/map-repeat");
+
+    blockchain.register_contract(MAP_REPEAT_PATH_EXPR, map_repeat::ContractBuilder);
+    blockchain
+}
+
+fn setup(num_entries: usize) -> (ScenarioWorld, MapRepeatContract) {
+    let mut world = world();
+    let code = world.code_expression(MAP_REPEAT_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(MAP_REPEAT_ADDRESS_EXPR, Account::new().nonce(1).code(code)),
+    );
+
+    let mut contract = MapRepeatContract::new(MAP_REPEAT_ADDRESS_EXPR);
+    world.sc_call(
+        ScCallStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .call(contract.add(
+                num_entries,
+                ManagedBuffer::from("key"),
+                ManagedBuffer::from("value"),
+            )),
+    );
+    (world, contract)
+}
+
+fn key(index: usize) -> ManagedBuffer<StaticApi> {
+    let mut key = ManagedBuffer::from("key");
+    key.append_u32_be(index as u32);
+    key
+}
+
+fn query_page(
+    world: &mut ScenarioWorld,
+    contract: &mut MapRepeatContract,
+    page_size: usize,
+    cursor: Option<ManagedBuffer<StaticApi>>,
+) -> (
+    Option<ManagedBuffer<StaticApi>>,
+    Vec<ManagedBuffer<StaticApi>>,
+) {
+    let page: Page =
+        world.quick_query(contract.bench_paginated(page_size, OptionalValue::from(cursor)));
+    let (next_cursor, entries) = page.into_tuple();
+    let keys = entries
+        .into_iter()
+        .map(|entry| {
+            let (key, value) = entry.into_tuple();
+            assert_eq!(value, ManagedBuffer::from("value"));
+            key
+        })
+        .collect();
+    (next_cursor, keys)
+}
+
+#[test]
+fn map_repeat_paginated_view_test() {
+    let (mut world, mut contract) = setup(5);
+
+    let (next_cursor, keys) = query_page(&mut world, &mut contract, 3, None);
+    assert_eq!(next_cursor, Some(key(3)));
+    assert_eq!(keys, vec![key(0), key(1), key(2)]);
+
+    let (next_cursor, keys) = query_page(&mut world, &mut contract, 3, next_cursor);
+    assert_eq!(next_cursor, None);
+    assert_eq!(keys, vec![key(3), key(4)]);
+}
+
+#[test]
+fn map_repeat_paginated_view_unknown_key_test() {
+    let (mut world, mut contract) = setup(5);
+
+    world.sc_query(
+        ScQueryStep::new()
+            .call(contract.bench_paginated(2usize, OptionalValue::Some(key(5))))
+            .expect(TxExpect::user_error("str:invalid cursor")),
+    );
+}
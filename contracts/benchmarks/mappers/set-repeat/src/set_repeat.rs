@@ -31,6 +31,7 @@ pub trait SetRepeat: benchmark_common::BenchmarkCommon {
     }
 
     #[view]
+    #[paginated_view(benchPaginated)]
     #[storage_mapper("benchmark")]
     fn bench(&self) -> SetMapper<ManagedBuffer>;
 
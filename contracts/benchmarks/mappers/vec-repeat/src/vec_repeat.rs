@@ -32,6 +32,7 @@ pub trait VecRepeat: benchmark_common::BenchmarkCommon {
     }
 
     #[view]
+    #[paginated_view(benchPaginated)]
     #[storage_mapper("benchmark")]
     fn bench(&self) -> VecMapper<ManagedBuffer>;
 
@@ -60,4 +61,17 @@ pub trait VecRepeat: benchmark_common::BenchmarkCommon {
     #[view]
     #[storage_mapper("bench_struct")]
     fn bench_struct(&self) -> VecMapper<ExampleStruct<Self::Api>>;
+
+    #[endpoint]
+    fn add_for_caller(&self, num_repeats: usize, value: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        let mut bench = self.user_bench(&caller);
+        for i in 0..num_repeats {
+            bench.push(&self.append_index(&value, i));
+        }
+    }
+
+    #[paginated_view(userBenchPaginated)]
+    #[storage_mapper("user_bench")]
+    fn user_bench(&self, user: &ManagedAddress) -> VecMapper<ManagedBuffer>;
 }
@@ -0,0 +1,139 @@
+use multiversx_sc::{
+    codec::multi_types::{MultiValue2, OptionalValue},
+    types::{ManagedAddress, ManagedBuffer, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    scenario_model::{Account, AddressValue, ScCallStep, ScQueryStep, SetStateStep, TxExpect},
+    ContractInfo, ScenarioWorld,
+};
+
+use vec_repeat::ProxyTrait as _;
+
+const OWNER_ADDRESS_EXPR: &str = "address:owner";
+const USER_ADDRESS_EXPR: &str = "address:user";
+const VEC_REPEAT_ADDRESS_EXPR: &str = "sc:vec-repeat";
+const VEC_REPEAT_PATH_EXPR: &str = "mxsc:output/vec-repeat.mxsc.json";
+
+type VecRepeatContract = ContractInfo<vec_repeat::Proxy<StaticApi>>;
+
+type Page = MultiValue2<Option<usize>, MultiValueEncoded<StaticApi, ManagedBuffer<StaticApi>>>;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/benchmarks/mappers/vec-repeat");
+
+    blockchain.register_contract(VEC_REPEAT_PATH_EXPR, vec_repeat::ContractBuilder);
+    blockchain
+}
+
+fn setup(num_items: usize) -> (ScenarioWorld, VecRepeatContract) {
+    let mut world = world();
+    let code = world.code_expression(VEC_REPEAT_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(USER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(VEC_REPEAT_ADDRESS_EXPR, Account::new().nonce(1).code(code)),
+    );
+
+    let mut contract = VecRepeatContract::new(VEC_REPEAT_ADDRESS_EXPR);
+    world.sc_call(
+        ScCallStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .call(contract.add(num_items, ManagedBuffer::from("item"))),
+    );
+    (world, contract)
+}
+
+fn item(index: usize) -> ManagedBuffer<StaticApi> {
+    let mut item = ManagedBuffer::from("item");
+    item.append_u32_be(index as u32);
+    item
+}
+
+fn query_page(
+    world: &mut ScenarioWorld,
+    contract: &mut VecRepeatContract,
+    page_size: usize,
+    cursor: Option<usize>,
+) -> (Option<usize>, Vec<ManagedBuffer<StaticApi>>) {
+    let page: Page =
+        world.quick_query(contract.bench_paginated(page_size, OptionalValue::from(cursor)));
+    let (next_cursor, items) = page.into_tuple();
+    (next_cursor, items.to_vec().into_vec())
+}
+
+#[test]
+fn vec_repeat_paginated_view_test() {
+    let (mut world, mut contract) = setup(5);
+
+    let (next_cursor, items) = query_page(&mut world, &mut contract, 2, None);
+    assert_eq!(next_cursor, Some(3));
+    assert_eq!(items, vec![item(0), item(1)]);
+
+    let (next_cursor, items) = query_page(&mut world, &mut contract, 2, next_cursor);
+    assert_eq!(next_cursor, Some(5));
+    assert_eq!(items, vec![item(2), item(3)]);
+
+    let (next_cursor, items) = query_page(&mut world, &mut contract, 2, next_cursor);
+    assert_eq!(next_cursor, None);
+    assert_eq!(items, vec![item(4)]);
+
+    // a page that fits all items
+    let (next_cursor, items) = query_page(&mut world, &mut contract, 10, None);
+    assert_eq!(next_cursor, None);
+    assert_eq!(items.len(), 5);
+}
+
+#[test]
+fn vec_repeat_paginated_view_empty_test() {
+    let (mut world, mut contract) = setup(0);
+
+    let (next_cursor, items) = query_page(&mut world, &mut contract, 2, None);
+    assert_eq!(next_cursor, None);
+    assert!(items.is_empty());
+}
+
+#[test]
+fn vec_repeat_paginated_view_invalid_cursor_test() {
+    let (mut world, mut contract) = setup(5);
+
+    world.sc_query(
+        ScQueryStep::new()
+            .call(contract.bench_paginated(2usize, OptionalValue::Some(6usize)))
+            .expect(TxExpect::user_error("str:invalid cursor")),
+    );
+}
+
+#[test]
+fn vec_repeat_paginated_view_with_reference_key_arg_test() {
+    let (mut world, mut contract) = setup(5);
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER_ADDRESS_EXPR)
+            .call(contract.add_for_caller(3usize, ManagedBuffer::from("item"))),
+    );
+
+    // the view takes the `&ManagedAddress` key argument of the mapper
+    let user = AddressValue::from(USER_ADDRESS_EXPR).to_address();
+    let page: Page = world.quick_query(contract.user_bench_paginated(
+        ManagedAddress::from(&user),
+        2usize,
+        OptionalValue::<usize>::None,
+    ));
+    let (next_cursor, items) = page.into_tuple();
+    assert_eq!(next_cursor, Some(3));
+    assert_eq!(items.to_vec().into_vec(), vec![item(0), item(1)]);
+
+    // other users have no items
+    let owner = AddressValue::from(OWNER_ADDRESS_EXPR).to_address();
+    let page: Page = world.quick_query(contract.user_bench_paginated(
+        ManagedAddress::from(&owner),
+        2usize,
+        OptionalValue::<usize>::None,
+    ));
+    let (next_cursor, items) = page.into_tuple();
+    assert_eq!(next_cursor, None);
+    assert!(items.is_empty());
+}
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                            8
+// Endpoints:                           11
 // Async Callback (empty):               1
-// Total number of exported functions:  10
+// Total number of exported functions:  13
 
 #![no_std]
 #![allow(internal_features)]
@@ -24,10 +24,13 @@ multiversx_sc_wasm_adapter::endpoints! {
         count => count
         remove => remove
         bench => bench
+        benchPaginated => bench_paginated
         add_struct => add_struct
         count_struct => count_struct
         remove_struct => remove_struct
         bench_struct => bench_struct
+        add_for_caller => add_for_caller
+        userBenchPaginated => user_bench_paginated
     )
 }
 
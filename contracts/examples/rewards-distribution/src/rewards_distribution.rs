@@ -23,7 +23,7 @@ pub struct ComputedBracket<M: ManagedTypeApi> {
     pub nft_reward_percent: BigUint<M>,
 }
 
-#[derive(NestedEncode, NestedDecode)]
+#[derive(NestedEncode, NestedDecode, TypeAbi)]
 pub struct RaffleProgress<M: ManagedTypeApi> {
     pub raffle_id: u64,
     pub ticket_position: u64,
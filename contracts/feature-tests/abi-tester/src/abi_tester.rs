@@ -119,6 +119,25 @@ pub trait AbiTester {
     #[storage_mapper("sample_storage_mapper")]
     fn sample_storage_mapper(&self) -> SingleValueMapper<OnlyShowsUpAsNestedInSingleValueMapper>;
 
+    /// Storage keys with arguments also show up in the ABI.
+    #[storage_mapper("sample_map_mapper")]
+    fn sample_map_mapper(&self, address: &ManagedAddress) -> MapMapper<u32, OnlyShowsUpInStorage>;
+
+    /// Nested mappers list the key type, followed by the value types of the nested mapper.
+    #[storage_mapper("sample_nested_mapper")]
+    fn sample_nested_mapper(&self) -> MapStorageMapper<ManagedAddress, SetMapper<u64>>;
+
+    #[storage_get("sample_value")]
+    fn get_sample_value(&self, index: u32) -> BigUint;
+
+    #[storage_set("sample_value")]
+    fn set_sample_value(&self, index: u32, value: &BigUint);
+
+    /// Stored types without an ABI description can be left out of the ABI.
+    #[storage_mapper("skipped_storage")]
+    #[skip_storage_abi]
+    fn skipped_storage(&self) -> SingleValueMapper<NeverShowsUpInAbi>;
+
     #[view]
     fn item_for_vec(&self) -> Vec<OnlyShowsUpAsNestedInVec> {
         Vec::new()
@@ -6,6 +6,12 @@ pub struct OnlyShowsUpInConstructor {
     pub something: (),
 }
 
+/// Tests that the ABI generator also fetches types that only appear in storage.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpInStorage {
+    pub something: u8,
+}
+
 /// Tests that the ABI generator also fetches types that only appear as fields.
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
 pub struct OnlyShowsUpAsNested01;
@@ -83,3 +89,7 @@ pub struct OnlyShowsUpAsNestedInSlice;
 /// Tests that the ABI generator also fetches types that only appear as fields.
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
 pub struct OnlyShowsUpAsNestedInOption;
+
+/// Has no ABI description, so it can only be stored by methods left out of the storage ABI.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct NeverShowsUpInAbi;
@@ -22,6 +22,7 @@ pub mod small_num_overflow_test_ops;
 pub mod storage_direct_load;
 pub mod storage_direct_store;
 pub mod storage_mapper_address_to_id;
+pub mod storage_mapper_avl_tree;
 pub mod storage_mapper_fungible_token;
 pub mod storage_mapper_get_at_address;
 pub mod storage_mapper_linked_list;
@@ -60,6 +61,7 @@ pub trait BasicFeatures:
     + storage_direct_load::StorageLoadFeatures
     + storage_direct_store::StorageStoreFeatures
     + storage_mapper_address_to_id::AddressToIdMapperFeatures
+    + storage_mapper_avl_tree::AvlTreeMapperFeatures
     + storage_mapper_linked_list::LinkedListMapperFeatures
     + storage_mapper_queue::QueueMapperFeatures
     + storage_mapper_map::MapMapperFeatures
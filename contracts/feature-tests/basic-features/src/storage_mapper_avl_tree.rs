@@ -0,0 +1,59 @@
+multiversx_sc::imports!();
+
+/// Storage mapper test.
+#[multiversx_sc::module]
+pub trait AvlTreeMapperFeatures {
+    #[view]
+    #[storage_mapper("avl_tree_mapper")]
+    fn avl_tree_mapper(&self) -> AvlTreeMapper<u64>;
+
+    #[endpoint]
+    fn avl_tree_mapper_insert(&self, item: u64) -> bool {
+        let mut avl_tree_mapper = self.avl_tree_mapper();
+        avl_tree_mapper.insert(item)
+    }
+
+    #[endpoint]
+    fn avl_tree_mapper_insert_range(&self, start: u64, end: u64) {
+        let mut avl_tree_mapper = self.avl_tree_mapper();
+        for item in start..end {
+            avl_tree_mapper.insert(item);
+        }
+    }
+
+    #[endpoint]
+    fn avl_tree_mapper_remove(&self, item: u64) -> bool {
+        let mut avl_tree_mapper = self.avl_tree_mapper();
+        avl_tree_mapper.remove(&item)
+    }
+
+    #[endpoint]
+    fn avl_tree_mapper_contains(&self, item: u64) -> bool {
+        self.avl_tree_mapper().contains(&item)
+    }
+
+    #[endpoint]
+    fn avl_tree_mapper_lower_bound(&self, item: u64) -> Option<u64> {
+        self.avl_tree_mapper().lower_bound(&item)
+    }
+
+    #[endpoint]
+    fn avl_tree_mapper_rank(&self, item: u64) -> usize {
+        self.avl_tree_mapper().rank(&item)
+    }
+
+    #[endpoint]
+    fn avl_tree_mapper_nth(&self, index: usize) -> Option<u64> {
+        self.avl_tree_mapper().nth(index)
+    }
+
+    #[endpoint]
+    fn avl_tree_mapper_height(&self) -> u32 {
+        self.avl_tree_mapper().height()
+    }
+
+    #[endpoint]
+    fn avl_tree_mapper_range(&self, start: u64, end: u64) -> MultiValueEncoded<u64> {
+        self.avl_tree_mapper().range(start..end).collect()
+    }
+}
@@ -0,0 +1,225 @@
+use multiversx_sc::{
+    codec::{CodecFrom, TopEncodeMulti},
+    types::ContractCall,
+};
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    multiversx_chain_vm::schedule::{GasSchedule, GasSection},
+    scenario_model::{Account, ScCallStep, SetStateStep},
+    ContractInfo, ScenarioWorld,
+};
+
+use basic_features::storage_mapper_avl_tree::ProxyTrait;
+
+const USER_ADDRESS_EXPR: &str = "address:user";
+const BASIC_FEATURES_ADDRESS_EXPR: &str = "sc:basic-features";
+const BASIC_FEATURES_PATH_EXPR: &str = "mxsc:output/basic-features.mxsc.json";
+
+const GAS_LIMIT: u64 = 100_000_000;
+// only storage access costs gas, a write costing more than all the reads of a call,
+// so that the gas used tells both how many reads and how many writes a call makes
+const STORAGE_READ_COST: u64 = 1;
+const STORAGE_WRITE_COST: u64 = 1_000;
+// the tree info is decoded with a default, which first loads its length, then its contents
+const INFO_READS: u64 = 2;
+
+type BasicFeatures = ContractInfo<basic_features::Proxy<StaticApi>>;
+
+#[derive(Debug)]
+struct StorageAccessCount {
+    reads: u64,
+    writes: u64,
+}
+
+/// The height of an AVL tree with n nodes is less than 1.4405 * log2(n + 2) - 0.3277.
+fn max_avl_height(len: u64) -> u64 {
+    (1.4405 * ((len + 2) as f64).log2() - 0.3277) as u64
+}
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+
+    blockchain.register_contract(BASIC_FEATURES_PATH_EXPR, basic_features::ContractBuilder);
+
+    blockchain
+}
+
+fn setup() -> (ScenarioWorld, BasicFeatures) {
+    let mut world = world();
+    let mut gas_schedule = GasSchedule::default();
+    for (section, key, cost) in [
+        (GasSection::BaseOpsApiCost, "StorageLoad", STORAGE_READ_COST),
+        (
+            GasSection::BaseOpsApiCost,
+            "StorageStore",
+            STORAGE_WRITE_COST,
+        ),
+        (
+            GasSection::ManagedBufferApiCost,
+            "MBufferStorageLoad",
+            STORAGE_READ_COST,
+        ),
+        (
+            GasSection::ManagedBufferApiCost,
+            "MBufferStorageStore",
+            STORAGE_WRITE_COST,
+        ),
+    ] {
+        gas_schedule.set_cost(section, key, cost);
+    }
+    world.set_gas_schedule(gas_schedule);
+
+    let basic_features_code = world.code_expression(BASIC_FEATURES_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(USER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(
+                BASIC_FEATURES_ADDRESS_EXPR,
+                Account::new().nonce(1).code(basic_features_code),
+            ),
+    );
+    (world, BasicFeatures::new(BASIC_FEATURES_ADDRESS_EXPR))
+}
+
+fn call_counting_storage_access<CC>(
+    world: &mut ScenarioWorld,
+    contract_call: CC,
+) -> (CC::OriginalResult, StorageAccessCount)
+where
+    CC: ContractCall<StaticApi>,
+    CC::OriginalResult: TopEncodeMulti + CodecFrom<CC::OriginalResult>,
+{
+    let mut sc_call = ScCallStep::new()
+        .from(USER_ADDRESS_EXPR)
+        .gas_limit(GAS_LIMIT)
+        .call(contract_call);
+    world.sc_call(&mut sc_call);
+    let result = sc_call.result().unwrap();
+    let gas = sc_call.response().gas;
+    let count = StorageAccessCount {
+        reads: gas % STORAGE_WRITE_COST / STORAGE_READ_COST,
+        writes: gas / STORAGE_WRITE_COST,
+    };
+    (result, count)
+}
+
+fn insert_checking_storage_access(
+    world: &mut ScenarioWorld,
+    basic_features: &mut BasicFeatures,
+    len: &mut u64,
+    value: u64,
+) {
+    let height = max_avl_height(*len + 1);
+    let (inserted, count) =
+        call_counting_storage_access(world, basic_features.avl_tree_mapper_insert(value));
+    assert!(inserted);
+    assert!(
+        count.reads <= INFO_READS + height + 2,
+        "insert {value}: {count:?}"
+    );
+    assert!(count.writes <= height + 4, "insert {value}: {count:?}");
+    *len += 1;
+}
+
+/// Sorted inserts would degenerate an unbalanced tree into a list, the AVL tree only grows logarithmically.
+#[test]
+fn avl_tree_mapper_insert_storage_access_test() {
+    let (mut world, mut basic_features) = setup();
+    let mut len = 0;
+    for value in 0..256u64 {
+        insert_checking_storage_access(&mut world, &mut basic_features, &mut len, value);
+    }
+    for value in (256..512u64).rev() {
+        insert_checking_storage_access(&mut world, &mut basic_features, &mut len, value);
+    }
+
+    let (height, _) =
+        call_counting_storage_access(&mut world, basic_features.avl_tree_mapper_height());
+    assert!(height as u64 <= max_avl_height(len));
+
+    // duplicates only walk down the tree, without writing anything
+    let (inserted, count) =
+        call_counting_storage_access(&mut world, basic_features.avl_tree_mapper_insert(100u64));
+    assert!(!inserted);
+    assert!(count.reads <= INFO_READS + max_avl_height(len));
+    assert_eq!(count.writes, 0);
+}
+
+#[test]
+fn avl_tree_mapper_query_storage_access_test() {
+    let (mut world, mut basic_features) = setup();
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER_ADDRESS_EXPR)
+            .gas_limit(GAS_LIMIT)
+            .call(basic_features.avl_tree_mapper_insert_range(0u64, 512u64)),
+    );
+    let max_reads = INFO_READS + max_avl_height(512);
+
+    for query in (0..520u64).step_by(13) {
+        let (contains, count) = call_counting_storage_access(
+            &mut world,
+            basic_features.avl_tree_mapper_contains(query),
+        );
+        assert_eq!(contains, query < 512);
+        assert!(count.reads <= max_reads, "contains {query}: {count:?}");
+        assert_eq!(count.writes, 0);
+
+        let (lower_bound, count) = call_counting_storage_access(
+            &mut world,
+            basic_features.avl_tree_mapper_lower_bound(query),
+        );
+        assert_eq!(lower_bound, (query < 512).then_some(query));
+        assert!(count.reads <= max_reads, "lower bound {query}: {count:?}");
+        assert_eq!(count.writes, 0);
+
+        let (rank, count) =
+            call_counting_storage_access(&mut world, basic_features.avl_tree_mapper_rank(query));
+        assert_eq!(rank as u64, query.min(512));
+        assert!(count.reads <= max_reads, "rank {query}: {count:?}");
+        assert_eq!(count.writes, 0);
+
+        let (nth, count) = call_counting_storage_access(
+            &mut world,
+            basic_features.avl_tree_mapper_nth(query as usize),
+        );
+        assert_eq!(nth, (query < 512).then_some(query));
+        assert!(count.reads <= max_reads, "nth {query}: {count:?}");
+        assert_eq!(count.writes, 0);
+    }
+}
+
+/// Removing can rebalance at every level, each time loading and saving up to 2 extra nodes.
+#[test]
+fn avl_tree_mapper_remove_storage_access_test() {
+    let (mut world, mut basic_features) = setup();
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER_ADDRESS_EXPR)
+            .gas_limit(GAS_LIMIT)
+            .call(basic_features.avl_tree_mapper_insert_range(0u64, 512u64)),
+    );
+
+    // 211 is coprime with 512, so this removes every value, in a scattered order
+    let mut len = 512u64;
+    for i in 0..512u64 {
+        let value = i * 211 % 512;
+        let height = max_avl_height(len);
+        let (removed, count) =
+            call_counting_storage_access(&mut world, basic_features.avl_tree_mapper_remove(value));
+        assert!(removed);
+        assert!(
+            count.reads <= INFO_READS + 3 * height,
+            "remove {value}: {count:?}"
+        );
+        assert!(count.writes <= 3 * height + 2, "remove {value}: {count:?}");
+        len -= 1;
+    }
+
+    let (removed, count) =
+        call_counting_storage_access(&mut world, basic_features.avl_tree_mapper_remove(5u64));
+    assert!(!removed);
+    assert_eq!(count.reads, INFO_READS);
+    assert_eq!(count.writes, 0);
+}
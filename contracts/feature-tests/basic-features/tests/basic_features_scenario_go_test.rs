@@ -44,6 +44,12 @@ fn big_uint_to_u_64_go() {
     world().run("scenarios/big_uint_to_u64.scen.json");
 }
 
+#[test]
+#[ignore = "block hash and state root hash are not configurable in scenario-go"]
+fn block_hash_go() {
+    world().run("scenarios/block_hash.scen.json");
+}
+
 #[test]
 fn block_info_go() {
     world().run("scenarios/block_info.scen.json");
@@ -365,6 +371,11 @@ fn storage_mapper_address_to_id_go() {
     world().run("scenarios/storage_mapper_address_to_id.scen.json");
 }
 
+#[test]
+fn storage_mapper_avl_tree_go() {
+    world().run("scenarios/storage_mapper_avl_tree.scen.json");
+}
+
 #[test]
 #[ignore = "currently not supported. TODO: investigate"]
 fn storage_mapper_fungible_token_go() {
@@ -56,6 +56,11 @@ fn big_uint_to_u_64_rs() {
     world().run("scenarios/big_uint_to_u64.scen.json");
 }
 
+#[test]
+fn block_hash_rs() {
+    world().run("scenarios/block_hash.scen.json");
+}
+
 #[test]
 fn block_info_rs() {
     world().run("scenarios/block_info.scen.json");
@@ -72,7 +77,6 @@ fn count_ones_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_elliptic_curves_rs() {
     world().run("scenarios/crypto_elliptic_curves.scen.json");
 }
@@ -83,7 +87,6 @@ fn crypto_keccak_256_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_ripemd_160_rs() {
     world().run("scenarios/crypto_ripemd160.scen.json");
 }
@@ -94,7 +97,6 @@ fn crypto_sha_256_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_verify_bls_rs() {
     world().run("scenarios/crypto_verify_bls.scen.json");
 }
@@ -105,7 +107,6 @@ fn crypto_verify_ed_25519_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_verify_secp_256_k_1_rs() {
     world().run("scenarios/crypto_verify_secp256k1.scen.json");
 }
@@ -383,6 +384,11 @@ fn storage_mapper_address_to_id_rs() {
     world().run("scenarios/storage_mapper_address_to_id.scen.json");
 }
 
+#[test]
+fn storage_mapper_avl_tree_rs() {
+    world().run("scenarios/storage_mapper_avl_tree.scen.json");
+}
+
 #[test]
 #[ignore]
 fn storage_mapper_fungible_token_rs() {
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                          384
+// Endpoints:                          395
 // Async Callback:                       1
-// Total number of exported functions: 386
+// Total number of exported functions: 397
 
 #![no_std]
 #![allow(internal_features)]
@@ -121,6 +121,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         get_prev_block_round => get_prev_block_round
         get_prev_block_epoch => get_prev_block_epoch
         get_prev_block_random_seed => get_prev_block_random_seed
+        get_block_hash => get_block_hash
         get_caller => get_caller
         get_owner_address => get_owner_address
         get_shard_of_address => get_shard_of_address
@@ -265,6 +266,16 @@ multiversx_sc_wasm_adapter::endpoints! {
         address_to_id_mapper_get_id_or_insert => address_to_id_mapper_get_id_or_insert
         address_to_id_mapper_remove_by_id => address_to_id_mapper_remove_by_id
         address_to_id_mapper_remove_by_address => address_to_id_mapper_remove_by_address
+        avl_tree_mapper => avl_tree_mapper
+        avl_tree_mapper_insert => avl_tree_mapper_insert
+        avl_tree_mapper_insert_range => avl_tree_mapper_insert_range
+        avl_tree_mapper_remove => avl_tree_mapper_remove
+        avl_tree_mapper_contains => avl_tree_mapper_contains
+        avl_tree_mapper_lower_bound => avl_tree_mapper_lower_bound
+        avl_tree_mapper_rank => avl_tree_mapper_rank
+        avl_tree_mapper_nth => avl_tree_mapper_nth
+        avl_tree_mapper_height => avl_tree_mapper_height
+        avl_tree_mapper_range => avl_tree_mapper_range
         getListMapper => list_mapper
         listMapperPushBack => list_mapper_push_back
         listMapperPushFront => list_mapper_push_front
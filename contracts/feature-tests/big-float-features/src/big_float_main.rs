@@ -6,6 +6,7 @@ pub mod big_float_methods;
 pub mod big_float_methods_wrapped;
 pub mod big_float_operators;
 pub mod big_float_operators_wrapped;
+pub mod managed_decimal_features;
 
 #[multiversx_sc::contract]
 pub trait BigFloatFeatures:
@@ -13,6 +14,7 @@ pub trait BigFloatFeatures:
     + big_float_operators::BigFloatOperators
     + big_float_methods_wrapped::BigFloatWrappedMethods
     + big_float_operators_wrapped::BigFloatWrappedOperators
+    + managed_decimal_features::ManagedDecimalFeatures
 {
     #[init]
     fn init(&self) {}
@@ -0,0 +1,37 @@
+multiversx_sc::imports!();
+
+/// Checks ManagedDecimal arithmetic, with both compile-time and runtime numbers of decimals.
+#[multiversx_sc::module]
+pub trait ManagedDecimalFeatures {
+    #[endpoint]
+    fn managed_decimal_addition(
+        &self,
+        first: ManagedDecimal<ConstDecimals<2>>,
+        second: ManagedDecimal<ConstDecimals<2>>,
+    ) -> ManagedDecimal<ConstDecimals<2>> {
+        first + second
+    }
+
+    #[endpoint]
+    fn managed_decimal_ln(
+        &self,
+        x: ManagedDecimal<ConstDecimals<9>>,
+    ) -> ManagedDecimal<ConstDecimals<9>> {
+        x.ln()
+            .unwrap_or_else(|| sc_panic!("ln argument must be at least 1"))
+    }
+
+    /// Continuously compounded interest: principal * e^(rate * periods), rounded down.
+    #[endpoint]
+    fn managed_decimal_continuous_interest(
+        &self,
+        principal: BigUint,
+        rate: ManagedDecimal<NumDecimals>,
+        periods: u32,
+    ) -> BigUint {
+        let periods: ManagedDecimal<ConstDecimals<0>> = BigUint::from(periods).into();
+        let growth = (rate * periods).exp();
+        let principal = ManagedDecimal::from_integer(principal, growth.scale());
+        (principal * growth).trunc()
+    }
+}
@@ -48,3 +48,8 @@ fn big_float_operator_checks_go() {
 fn big_float_operators_go() {
     world().run("scenarios/big_float_operators.scen.json");
 }
+
+#[test]
+fn managed_decimal_go() {
+    world().run("scenarios/managed_decimal.scen.json");
+}
@@ -58,3 +58,8 @@ fn big_float_operator_checks_rs() {
 fn big_float_operators_rs() {
     world().run("scenarios/big_float_operators.scen.json");
 }
+
+#[test]
+fn managed_decimal_rs() {
+    world().run("scenarios/managed_decimal.scen.json");
+}
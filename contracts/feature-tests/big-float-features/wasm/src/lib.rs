@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           70
+// Endpoints:                           73
 // Async Callback (empty):               1
-// Total number of exported functions:  72
+// Total number of exported functions:  75
 
 #![no_std]
 #![allow(internal_features)]
@@ -90,6 +90,9 @@ multiversx_sc_wasm_adapter::endpoints! {
         mul_assign_big_float_ref_wrapped => mul_assign_big_float_ref_wrapped
         div_assign_big_float_wrapped => div_assign_big_float_wrapped
         div_assign_big_float_ref_wrapped => div_assign_big_float_ref_wrapped
+        managed_decimal_addition => managed_decimal_addition
+        managed_decimal_ln => managed_decimal_ln
+        managed_decimal_continuous_interest => managed_decimal_continuous_interest
     )
 }
 
@@ -20,6 +20,14 @@ fn world() -> ScenarioWorld {
         "mxsc:forwarder-raw/output/forwarder-raw.mxsc.json",
         forwarder_raw::ContractBuilder,
     );
+    let forwarder_raw_sc_config = meta::multi_contract_config::<forwarder_raw::AbiProvider>(
+        &blockchain.current_dir().join("forwarder-raw"),
+    );
+    blockchain.register_contract_variant(
+        "mxsc:forwarder-raw/output/forwarder-raw-init-async-call.mxsc.json",
+        forwarder_raw::ContractBuilder,
+        forwarder_raw_sc_config.find_contract("forwarder-raw-init-async-call"),
+    );
     blockchain.register_contract(
         "mxsc:promises-features/output/promises-features.mxsc.json",
         promises_features::ContractBuilder,
@@ -133,7 +141,7 @@ fn forw_raw_direct_multi_esdt_rs() {
 }
 
 #[test]
-#[ignore = "not yet supported"]
+#[ignore = "the Rust VM allows async calls from constructors"]
 fn forw_raw_init_async_rs() {
     world().run("scenarios/forw_raw_init_async.scen.json");
 }
@@ -166,23 +174,25 @@ fn forw_raw_sync_egld_rs() {
 }
 
 #[test]
-#[ignore]
 fn forw_raw_sync_readonly_rs() {
     world().run("scenarios/forw_raw_sync_readonly.scen.json");
 }
 
 #[test]
-#[ignore]
 fn forw_raw_sync_same_context_rs() {
     world().run("scenarios/forw_raw_sync_same_context.scen.json");
 }
 
 #[test]
-#[ignore]
 fn forw_raw_sync_same_context_egld_rs() {
     world().run("scenarios/forw_raw_sync_same_context_egld.scen.json");
 }
 
+#[test]
+fn forw_raw_upgrade_async_callback_rs() {
+    world().run("scenarios/forw_raw_upgrade_async_callback.scen.json");
+}
+
 #[test]
 fn forw_raw_transf_exec_accept_egld_rs() {
     world().run("scenarios/forw_raw_transf_exec_accept_egld.scen.json");
@@ -0,0 +1,351 @@
+use multiversx_sc::{
+    codec::multi_types::MultiValue5,
+    types::{
+        BigUint, EgldOrEsdtTokenIdentifier, ManagedBuffer, MultiValueEncoded, MultiValueManagedVec,
+    },
+};
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    multiversx_chain_vm::schedule::{GasSchedule, GasSection},
+    scenario_model::{Account, CheckAccount, CheckStateStep, ScCallStep, SetStateStep},
+    ContractInfo, ScenarioWorld,
+};
+
+use forwarder::call_async::{CallbackData, ProxyTrait};
+
+const USER_ADDRESS_EXPR: &str = "address:user";
+// the last address byte decides the shard: 'd' is in shard 0, '_' in shard 1
+const FORWARDER_SHARD_0_ADDRESS_EXPR: &str = "sc:forwarder-second-shard";
+const FORWARDER_SHARD_1_ADDRESS_EXPR: &str = "sc:forwarder";
+const FORWARDER_PATH_EXPR: &str = "mxsc:forwarder/output/forwarder.mxsc.json";
+const VAULT_ADDRESS_EXPR: &str = "sc:vault";
+const VAULT_PATH_EXPR: &str = "mxsc:vault/output/vault.mxsc.json";
+const TOKEN_ID_EXPR: &str = "str:TOKEN-123456";
+
+const USER_BALANCE: u64 = 100_000_000;
+const GAS_LIMIT: u64 = 10_000_000;
+const GAS_PRICE: u64 = 2;
+// 4 storage writes: the callback closure, its removal in the callback, then the callback data and its length
+const ASYNC_CALL_GAS_USED: u64 = 400_000;
+
+type ForwarderContract = ContractInfo<forwarder::Proxy<StaticApi>>;
+type VaultContract = ContractInfo<vault::Proxy<StaticApi>>;
+
+type CallbackDataResult = MultiValue5<
+    ManagedBuffer<StaticApi>,
+    EgldOrEsdtTokenIdentifier<StaticApi>,
+    u64,
+    BigUint<StaticApi>,
+    MultiValueManagedVec<StaticApi, ManagedBuffer<StaticApi>>,
+>;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+
+    blockchain.register_contract(FORWARDER_PATH_EXPR, forwarder::ContractBuilder);
+    blockchain.register_contract(VAULT_PATH_EXPR, vault::ContractBuilder);
+
+    blockchain
+}
+
+fn setup_sharded_world(forwarder_address_expr: &str) -> ScenarioWorld {
+    let mut world = world();
+    world.enable_sharding(3);
+
+    let forwarder_code = world.code_expression(FORWARDER_PATH_EXPR);
+    let vault_code = world.code_expression(VAULT_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(USER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(
+                forwarder_address_expr,
+                Account::new().nonce(1).code(forwarder_code),
+            )
+            .put_account(
+                VAULT_ADDRESS_EXPR,
+                Account::new().nonce(1).code(vault_code).balance("1000"),
+            ),
+    );
+    world
+}
+
+/// Storage writes cost gas, so that the callback of the async call, which saves its data, has a cost.
+fn enable_gas_metering(world: &mut ScenarioWorld) {
+    let mut gas_schedule = GasSchedule::default();
+    gas_schedule.set_cost(
+        GasSection::ManagedBufferApiCost,
+        "MBufferStorageStore",
+        100_000,
+    );
+    world.set_gas_schedule(gas_schedule);
+    world.set_state_step(SetStateStep::new().put_account(
+        USER_ADDRESS_EXPR,
+        Account::new().nonce(1).balance(USER_BALANCE),
+    ));
+}
+
+fn retrieve_funds_async_paying_gas(
+    world: &mut ScenarioWorld,
+    forwarder: &mut ForwarderContract,
+) -> u64 {
+    let vault = VaultContract::new(VAULT_ADDRESS_EXPR);
+    let mut sc_call = ScCallStep::new()
+        .from(USER_ADDRESS_EXPR)
+        .gas_limit(GAS_LIMIT)
+        .gas_price(GAS_PRICE)
+        .call(forwarder.forward_async_retrieve_funds(
+            vault.to_address(),
+            EgldOrEsdtTokenIdentifier::egld(),
+            0u64,
+            100u64,
+        ));
+    world.sc_call(&mut sc_call);
+    sc_call.response().gas
+}
+
+fn check_user_balance(world: &mut ScenarioWorld, balance: u64) {
+    world.check_state_step(CheckStateStep::new().put_account(
+        USER_ADDRESS_EXPR,
+        CheckAccount::new().balance(balance.to_string().as_str()),
+    ));
+}
+
+fn retrieve_funds_async(world: &mut ScenarioWorld, forwarder: &mut ForwarderContract) {
+    let vault = VaultContract::new(VAULT_ADDRESS_EXPR);
+    world.sc_call(ScCallStep::new().from(USER_ADDRESS_EXPR).call(
+        forwarder.forward_async_retrieve_funds(
+            vault.to_address(),
+            EgldOrEsdtTokenIdentifier::egld(),
+            0u64,
+            100u64,
+        ),
+    ));
+}
+
+fn check_balances(
+    world: &mut ScenarioWorld,
+    forwarder_address_expr: &str,
+    forwarder_balance: &str,
+    vault_balance: &str,
+) {
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                forwarder_address_expr,
+                CheckAccount::new().balance(forwarder_balance),
+            )
+            .put_account(
+                VAULT_ADDRESS_EXPR,
+                CheckAccount::new().balance(vault_balance),
+            ),
+    );
+}
+
+fn check_esdt_balances(
+    world: &mut ScenarioWorld,
+    forwarder_address_expr: &str,
+    forwarder_balance: &str,
+    vault_balance: &str,
+) {
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                forwarder_address_expr,
+                CheckAccount::new().esdt_balance(TOKEN_ID_EXPR, forwarder_balance),
+            )
+            .put_account(
+                VAULT_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(TOKEN_ID_EXPR, vault_balance),
+            ),
+    );
+}
+
+fn num_callbacks_executed(world: &mut ScenarioWorld, forwarder: &mut ForwarderContract) -> usize {
+    let callback_data: MultiValueEncoded<StaticApi, CallbackData<StaticApi>> =
+        world.quick_query(forwarder.callback_data());
+    callback_data.len()
+}
+
+fn check_callback_executed(world: &mut ScenarioWorld, forwarder: &mut ForwarderContract) {
+    assert_eq!(num_callbacks_executed(world, forwarder), 1);
+    let callback_data: CallbackDataResult =
+        world.quick_query(forwarder.callback_data_at_index(1usize));
+    let (callback_name, token_identifier, token_nonce, token_amount, _) =
+        callback_data.into_tuple();
+    assert_eq!(
+        callback_name,
+        ManagedBuffer::from("retrieve_funds_callback")
+    );
+    assert!(token_identifier.is_egld());
+    assert_eq!(token_nonce, 0);
+    assert_eq!(token_amount, BigUint::from(100u32));
+}
+
+#[test]
+fn cross_shard_async_call_test() {
+    let mut world = setup_sharded_world(FORWARDER_SHARD_0_ADDRESS_EXPR);
+    let mut forwarder = ForwarderContract::new(FORWARDER_SHARD_0_ADDRESS_EXPR);
+
+    // the async call leaves the forwarder shard, nothing happens in the vault yet
+    retrieve_funds_async(&mut world, &mut forwarder);
+    check_balances(&mut world, FORWARDER_SHARD_0_ADDRESS_EXPR, "0", "1000");
+    assert_eq!(num_callbacks_executed(&mut world, &mut forwarder), 0);
+    assert!(world.has_pending_cross_shard_calls());
+
+    // the vault executes the call, the callback leaves for the forwarder shard
+    let responses = world.step_round();
+    assert_eq!(responses.len(), 1);
+    assert!(responses[0].is_success());
+    check_balances(&mut world, FORWARDER_SHARD_0_ADDRESS_EXPR, "100", "900");
+    assert_eq!(num_callbacks_executed(&mut world, &mut forwarder), 0);
+    assert!(world.has_pending_cross_shard_calls());
+
+    // the callback executes one block later
+    let responses = world.step_round();
+    assert_eq!(responses.len(), 1);
+    assert!(responses[0].is_success());
+    check_callback_executed(&mut world, &mut forwarder);
+    assert!(!world.has_pending_cross_shard_calls());
+    assert!(world.step_round().is_empty());
+}
+
+#[test]
+fn cross_shard_async_call_value_test() {
+    let mut world = setup_sharded_world(FORWARDER_SHARD_0_ADDRESS_EXPR);
+    let mut forwarder = ForwarderContract::new(FORWARDER_SHARD_0_ADDRESS_EXPR);
+    let vault = VaultContract::new(VAULT_ADDRESS_EXPR);
+    world.set_state_step(
+        SetStateStep::new().put_account(USER_ADDRESS_EXPR, Account::new().nonce(1).balance("100")),
+    );
+
+    // the call value leaves the forwarder when the call is sent, not when it arrives
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER_ADDRESS_EXPR)
+            .egld_value("100")
+            .call(forwarder.forward_async_accept_funds(vault.to_address())),
+    );
+    check_balances(&mut world, FORWARDER_SHARD_0_ADDRESS_EXPR, "0", "1000");
+
+    let responses = world.step_rounds_until_idle();
+    assert_eq!(responses.len(), 2);
+    assert!(responses.iter().all(|response| response.is_success()));
+    check_balances(&mut world, FORWARDER_SHARD_0_ADDRESS_EXPR, "0", "1100");
+}
+
+#[test]
+fn cross_shard_async_call_esdt_value_test() {
+    let mut world = setup_sharded_world(FORWARDER_SHARD_0_ADDRESS_EXPR);
+    let mut forwarder = ForwarderContract::new(FORWARDER_SHARD_0_ADDRESS_EXPR);
+    let vault = VaultContract::new(VAULT_ADDRESS_EXPR);
+    world.set_state_step(SetStateStep::new().put_account(
+        USER_ADDRESS_EXPR,
+        Account::new().nonce(1).esdt_balance(TOKEN_ID_EXPR, "100"),
+    ));
+
+    // ESDT transfers also leave the forwarder when the call is sent
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER_ADDRESS_EXPR)
+            .esdt_transfer(TOKEN_ID_EXPR, 0, "100")
+            .call(forwarder.forward_async_accept_funds(vault.to_address())),
+    );
+    check_esdt_balances(&mut world, FORWARDER_SHARD_0_ADDRESS_EXPR, "0", "0");
+
+    let responses = world.step_rounds_until_idle();
+    assert!(responses.iter().all(|response| response.is_success()));
+    check_esdt_balances(&mut world, FORWARDER_SHARD_0_ADDRESS_EXPR, "0", "100");
+}
+
+#[test]
+fn cross_shard_failed_async_call_value_test() {
+    let mut world = setup_sharded_world(FORWARDER_SHARD_0_ADDRESS_EXPR);
+    let mut forwarder = ForwarderContract::new(FORWARDER_SHARD_0_ADDRESS_EXPR);
+    let other_forwarder = ForwarderContract::new(FORWARDER_SHARD_1_ADDRESS_EXPR);
+    let forwarder_code = world.code_expression(FORWARDER_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(USER_ADDRESS_EXPR, Account::new().nonce(1).balance("100"))
+            .put_account(
+                FORWARDER_SHARD_1_ADDRESS_EXPR,
+                Account::new().nonce(1).code(forwarder_code),
+            ),
+    );
+
+    // the other forwarder has no `accept_funds` endpoint, so the call fails at its destination
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER_ADDRESS_EXPR)
+            .egld_value("100")
+            .call(forwarder.forward_async_accept_funds(other_forwarder.to_address())),
+    );
+    check_balances(&mut world, FORWARDER_SHARD_0_ADDRESS_EXPR, "0", "1000");
+
+    let responses = world.step_round();
+    assert_eq!(responses.len(), 1);
+    assert!(!responses[0].is_success());
+    // the call value travels back with the callback
+    check_balances(&mut world, FORWARDER_SHARD_0_ADDRESS_EXPR, "0", "1000");
+    world.check_state_step(CheckStateStep::new().put_account(
+        FORWARDER_SHARD_1_ADDRESS_EXPR,
+        CheckAccount::new().balance("0"),
+    ));
+
+    let responses = world.step_round();
+    assert_eq!(responses.len(), 1);
+    check_balances(&mut world, FORWARDER_SHARD_0_ADDRESS_EXPR, "100", "1000");
+    assert!(!world.has_pending_cross_shard_calls());
+}
+
+#[test]
+fn cross_shard_step_until_idle_test() {
+    let mut world = setup_sharded_world(FORWARDER_SHARD_0_ADDRESS_EXPR);
+    let mut forwarder = ForwarderContract::new(FORWARDER_SHARD_0_ADDRESS_EXPR);
+
+    retrieve_funds_async(&mut world, &mut forwarder);
+    assert_eq!(world.step_rounds_until_idle().len(), 2);
+    check_balances(&mut world, FORWARDER_SHARD_0_ADDRESS_EXPR, "100", "900");
+    check_callback_executed(&mut world, &mut forwarder);
+}
+
+#[test]
+fn same_shard_async_call_test() {
+    let mut world = setup_sharded_world(FORWARDER_SHARD_1_ADDRESS_EXPR);
+    let mut forwarder = ForwarderContract::new(FORWARDER_SHARD_1_ADDRESS_EXPR);
+
+    // within the same shard, the call and callback complete in the same transaction
+    retrieve_funds_async(&mut world, &mut forwarder);
+    assert!(!world.has_pending_cross_shard_calls());
+    check_balances(&mut world, FORWARDER_SHARD_1_ADDRESS_EXPR, "100", "900");
+    check_callback_executed(&mut world, &mut forwarder);
+}
+
+#[test]
+fn same_shard_async_call_gas_test() {
+    let mut world = setup_sharded_world(FORWARDER_SHARD_1_ADDRESS_EXPR);
+    let mut forwarder = ForwarderContract::new(FORWARDER_SHARD_1_ADDRESS_EXPR);
+    enable_gas_metering(&mut world);
+
+    // the async call and the callback are paid for as part of the transaction
+    let gas_used = retrieve_funds_async_paying_gas(&mut world, &mut forwarder);
+    assert_eq!(gas_used, ASYNC_CALL_GAS_USED);
+    check_user_balance(&mut world, USER_BALANCE - ASYNC_CALL_GAS_USED * GAS_PRICE);
+    check_callback_executed(&mut world, &mut forwarder);
+}
+
+#[test]
+fn cross_shard_async_call_gas_test() {
+    let mut world = setup_sharded_world(FORWARDER_SHARD_0_ADDRESS_EXPR);
+    let mut forwarder = ForwarderContract::new(FORWARDER_SHARD_0_ADDRESS_EXPR);
+    enable_gas_metering(&mut world);
+
+    // the gas left is forwarded with the async call, it stays paid for until the callback is done
+    let gas_used = retrieve_funds_async_paying_gas(&mut world, &mut forwarder);
+    assert_eq!(gas_used, GAS_LIMIT);
+    check_user_balance(&mut world, USER_BALANCE - GAS_LIMIT * GAS_PRICE);
+
+    world.step_rounds_until_idle();
+    check_callback_executed(&mut world, &mut forwarder);
+    check_user_balance(&mut world, USER_BALANCE - ASYNC_CALL_GAS_USED * GAS_PRICE);
+}
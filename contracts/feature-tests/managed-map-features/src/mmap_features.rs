@@ -46,4 +46,38 @@ pub trait ManagedMapFeatures {
         let get_value = map.get(get_key);
         (removed_value, get_value).into()
     }
+
+    fn create_encoded_map(&self) -> ManagedMapEncoded<ManagedBuffer, ManagedBuffer> {
+        let mut map = ManagedMapEncoded::new();
+        let num_entries = self.get_num_entries();
+        for index in 0..num_entries {
+            map.put(&self.get_key(index), &self.get_value(index));
+        }
+        map
+    }
+
+    #[view]
+    fn mm_encoded_remove_iter(
+        &self,
+        remove_key: &ManagedBuffer,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer, ManagedBuffer>> {
+        let mut map = self.create_encoded_map();
+        let _ = map.remove(remove_key);
+        map.iter().map(MultiValue2::from).collect()
+    }
+
+    /// Adds up the amounts of each token, in the order in which the tokens first appear.
+    #[view]
+    fn mm_encoded_aggregate(
+        &self,
+        payments: MultiValueEncoded<MultiValue2<TokenIdentifier, BigUint>>,
+    ) -> MultiValueEncoded<MultiValue2<TokenIdentifier, BigUint>> {
+        let mut totals: ManagedMapEncoded<TokenIdentifier, BigUint> = ManagedMapEncoded::new();
+        for payment in payments {
+            let (token_id, amount) = payment.into_tuple();
+            let total = totals.get(&token_id).unwrap_or_default() + amount;
+            totals.put(&token_id, &total);
+        }
+        totals.iter().map(MultiValue2::from).collect()
+    }
 }
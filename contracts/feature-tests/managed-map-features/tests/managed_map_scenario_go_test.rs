@@ -4,6 +4,12 @@ fn world() -> ScenarioWorld {
     ScenarioWorld::vm_go()
 }
 
+#[test]
+#[ignore]
+fn mmap_encoded_go() {
+    world().run("scenarios/mmap_encoded.scen.json");
+}
+
 #[test]
 #[ignore]
 fn mmap_get_go() {
@@ -10,6 +10,11 @@ fn world() -> ScenarioWorld {
     blockchain
 }
 
+#[test]
+fn mmap_encoded_rs() {
+    world().run("scenarios/mmap_encoded.scen.json");
+}
+
 #[test]
 fn mmap_get_rs() {
     world().run("scenarios/mmap_get.scen.json");
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                            3
+// Endpoints:                            5
 // Async Callback (empty):               1
-// Total number of exported functions:   5
+// Total number of exported functions:   7
 
 #![no_std]
 #![allow(internal_features)]
@@ -23,6 +23,8 @@ multiversx_sc_wasm_adapter::endpoints! {
         mm_get => mm_get
         mm_contains => mm_contains
         mm_remove_get => mm_remove_get
+        mm_encoded_remove_iter => mm_encoded_remove_iter
+        mm_encoded_aggregate => mm_encoded_aggregate
     )
 }
 
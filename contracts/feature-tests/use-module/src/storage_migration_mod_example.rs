@@ -0,0 +1,59 @@
+multiversx_sc::imports!();
+
+use multiversx_sc_modules::{
+    ongoing_operation::{self, CONTINUE_OP, DEFAULT_MIN_GAS_TO_SAVE_PROGRESS, STOP_OP},
+    storage_migration::{self, StorageVersion},
+};
+
+const CURRENT_STORAGE_VERSION: StorageVersion = 2;
+
+/// Example of a module migrating its storage on upgrade.
+///
+/// - version 1 moves the fee to a new storage key;
+/// - version 2 converts all prices from whole units to cents, in batches.
+#[multiversx_sc::module]
+pub trait StorageMigrationModExample:
+    ongoing_operation::OngoingOperationModule + storage_migration::StorageMigrationModule
+{
+    #[upgrade]
+    fn upgrade_with_storage_migration(&self) -> OperationCompletionStatus {
+        self.migrate_example_storage()
+    }
+
+    /// Resumes a migration interrupted for lack of gas.
+    #[endpoint(migrateStorage)]
+    fn migrate_example_storage(&self) -> OperationCompletionStatus {
+        self.run_storage_migrations(CURRENT_STORAGE_VERSION, |version| match version {
+            1 => {
+                let fee = self.legacy_fee().take();
+                self.fee().set(fee);
+                OperationCompletionStatus::Completed
+            },
+            2 => {
+                self.run_batched_migration(DEFAULT_MIN_GAS_TO_SAVE_PROGRESS, |index: &mut usize| {
+                    if *index == self.prices().len() {
+                        return STOP_OP;
+                    }
+
+                    *index += 1;
+                    let price_in_cents = self.prices().get(*index) * 100u32;
+                    self.prices().set(*index, &price_in_cents);
+
+                    CONTINUE_OP
+                })
+            },
+            _ => sc_panic!("unknown storage version"),
+        })
+    }
+
+    #[storage_mapper("migration_example:fee")]
+    fn legacy_fee(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getFee)]
+    #[storage_mapper("migration_example:feeAmount")]
+    fn fee(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getPrices)]
+    #[storage_mapper("migration_example:prices")]
+    fn prices(&self) -> VecMapper<BigUint>;
+}
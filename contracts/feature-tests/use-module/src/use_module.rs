@@ -12,6 +12,7 @@ mod only_admin_derived_mod;
 mod only_admin_mod;
 mod only_owner_derived_mod;
 mod only_owner_mod;
+mod storage_migration_mod_example;
 pub mod token_merge_mod_impl;
 
 multiversx_sc::imports!();
@@ -39,6 +40,7 @@ pub trait UseModule:
     + only_admin_mod::OnlyAdminTestModule
     + only_admin_derived_mod::OnlyAdminDerivedTestModule
     + ongoing_operation_mod_example::OngoingOperationModExample
+    + storage_migration_mod_example::StorageMigrationModExample
     + token_merge_mod_impl::TokenMergeModImpl
     + multiversx_sc_modules::claim_developer_rewards::ClaimDeveloperRewardsModule
     + multiversx_sc_modules::dns::DnsModule
@@ -54,6 +56,7 @@ pub trait UseModule:
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::only_admin::OnlyAdminModule
     + multiversx_sc_modules::ongoing_operation::OngoingOperationModule
+    + multiversx_sc_modules::storage_migration::StorageMigrationModule
 {
     /// Validates that the "featureName" feature is on.
     /// Uses the `feature_guard!` macro.
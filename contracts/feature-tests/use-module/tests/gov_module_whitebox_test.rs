@@ -197,6 +197,18 @@ fn test_change_gov_config() {
         },
     );
 
+    // snapshot votes are not accepted for deposit-based proposals
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(THIRD_USER_ADDRESS_EXPR).no_expect(),
+        |sc| {
+            sc.vote_with_snapshot(proposal_id, VoteType::UpVote);
+        },
+        |r| {
+            r.assert_user_error("Proposal does not accept snapshot votes");
+        },
+    );
+
     // try execute before queue
     world.whitebox_call_check(
         &use_module_whitebox,
@@ -594,6 +606,130 @@ fn test_gov_cancel_defeated_proposal() {
     );
 }
 
+fn record_voting_power(world: &mut ScenarioWorld, user_address_expr: &str, voting_power: u64) {
+    let use_module_whitebox =
+        WhiteboxContract::new(USE_MODULE_ADDRESS_EXPR, use_module::contract_obj);
+    let user = address_expr_to_address(user_address_expr);
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| {
+            sc.record_voting_power(&managed_address!(&user), managed_biguint!(voting_power));
+        },
+    );
+}
+
+#[test]
+fn test_vote_with_snapshot() {
+    let mut world = setup();
+    let use_module_whitebox =
+        WhiteboxContract::new(USE_MODULE_ADDRESS_EXPR, use_module::contract_obj);
+
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| {
+            sc.enable_snapshot_voting();
+        },
+    );
+
+    world.set_state_step(SetStateStep::new().block_nonce(5));
+    record_voting_power(&mut world, FIRST_USER_ADDRESS_EXPR, 1_000);
+    record_voting_power(&mut world, SECOND_USER_ADDRESS_EXPR, 600);
+
+    let mut current_block_nonce = 10;
+    world.set_state_step(SetStateStep::new().block_nonce(current_block_nonce));
+    let proposal_id = propose(
+        &mut world,
+        &address_expr_to_address(FIRST_USER_ADDRESS_EXPR),
+        500,
+        &address_expr_to_address(USE_MODULE_ADDRESS_EXPR),
+        b"changeQuorum",
+        vec![1_000u64.to_be_bytes().to_vec()],
+    );
+
+    // after the proposal was created, the first user moves all their voting power to the third user
+    world.set_state_step(SetStateStep::new().block_nonce(current_block_nonce + 1));
+    record_voting_power(&mut world, FIRST_USER_ADDRESS_EXPR, 0);
+    record_voting_power(&mut world, THIRD_USER_ADDRESS_EXPR, 1_000);
+
+    current_block_nonce += VOTING_DELAY_BLOCKS;
+    world.set_state_step(SetStateStep::new().block_nonce(current_block_nonce));
+
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(FIRST_USER_ADDRESS_EXPR),
+        |sc| {
+            sc.vote_with_snapshot(proposal_id, VoteType::UpVote);
+        },
+    );
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(SECOND_USER_ADDRESS_EXPR),
+        |sc| {
+            sc.vote_with_snapshot(proposal_id, VoteType::DownVote);
+        },
+    );
+
+    // the moved voting power cannot be used a second time, neither through a snapshot, nor through a deposit
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(THIRD_USER_ADDRESS_EXPR).no_expect(),
+        |sc| {
+            sc.vote_with_snapshot(proposal_id, VoteType::UpVote);
+        },
+        |r| {
+            r.assert_user_error("No voting power at proposal start block");
+        },
+    );
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new()
+            .from(THIRD_USER_ADDRESS_EXPR)
+            .esdt_transfer(GOV_TOKEN_ID, 0, "1000")
+            .no_expect(),
+        |sc| {
+            sc.vote(proposal_id, VoteType::UpVote);
+        },
+        |r| {
+            r.assert_user_error("Proposal only accepts snapshot votes");
+        },
+    );
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(FIRST_USER_ADDRESS_EXPR).no_expect(),
+        |sc| {
+            sc.vote_with_snapshot(proposal_id, VoteType::UpVote);
+        },
+        |r| {
+            r.assert_user_error("Already voted for this proposal");
+        },
+    );
+
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        let proposal_votes = sc.proposal_votes(proposal_id).get();
+        assert_eq!(proposal_votes.up_votes, managed_biguint!(1_000));
+        assert_eq!(proposal_votes.down_votes, managed_biguint!(600));
+
+        let first_user = managed_address!(&address_expr_to_address(FIRST_USER_ADDRESS_EXPR));
+        assert_eq!(
+            sc.get_voting_power_at(first_user.clone(), 4),
+            managed_biguint!(0)
+        );
+        assert_eq!(
+            sc.get_voting_power_at(first_user.clone(), 10),
+            managed_biguint!(1_000)
+        );
+        assert_eq!(sc.get_voting_power_at(first_user, 11), managed_biguint!(0));
+    });
+
+    // no tokens were deposited for voting
+    world.check_state_step(CheckStateStep::new().put_account(
+        SECOND_USER_ADDRESS_EXPR,
+        CheckAccount::new().esdt_balance(GOV_TOKEN_ID_EXPR, "1000"),
+    ));
+}
+
 fn address_expr_to_address(address_expr: &str) -> Address {
     AddressValue::from(address_expr).to_address()
 }
@@ -0,0 +1,169 @@
+use multiversx_sc_scenario::{
+    multiversx_chain_vm::schedule::{GasSchedule, GasSection},
+    scenario_model::*,
+    *,
+};
+
+const USE_MODULE_PATH_EXPR: &str = "mxsc:output/use-module.mxsc.json";
+const OWNER_ADDRESS_EXPR: &str = "address:owner";
+const USE_MODULE_ADDRESS_EXPR: &str = "sc:use_module";
+
+const VERSION_KEY: &str = "str:storage_migration:version";
+const LEGACY_FEE_KEY: &str = "str:migration_example:fee";
+const FEE_KEY: &str = "str:migration_example:feeAmount";
+const PRICES_LEN_KEY: &str = "str:migration_example:prices.len";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/use-module");
+
+    blockchain.register_contract(USE_MODULE_PATH_EXPR, use_module::ContractBuilder);
+    blockchain
+}
+
+fn price_key(index: usize) -> String {
+    format!("str:migration_example:prices.item|u32:{index}")
+}
+
+/// Sets up the contract with the storage layout from before the first migration.
+fn set_legacy_state(world: &mut ScenarioWorld, prices: &[u64]) {
+    let code = world.code_expression(USE_MODULE_PATH_EXPR);
+    let mut contract = Account::new().code(code).owner(OWNER_ADDRESS_EXPR);
+    contract.storage.insert(LEGACY_FEE_KEY.into(), "5".into());
+    contract.storage.insert(
+        PRICES_LEN_KEY.into(),
+        prices.len().to_string().as_str().into(),
+    );
+    for (i, price) in prices.iter().enumerate() {
+        contract.storage.insert(
+            price_key(i + 1).as_str().into(),
+            price.to_string().as_str().into(),
+        );
+    }
+
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(USE_MODULE_ADDRESS_EXPR, contract),
+    );
+}
+
+fn upgrade_step(world: &mut ScenarioWorld) -> ScCallStep {
+    let code = world.code_expression(USE_MODULE_PATH_EXPR);
+    ScCallStep::new()
+        .from(OWNER_ADDRESS_EXPR)
+        .to(USE_MODULE_ADDRESS_EXPR)
+        .function("upgradeContract")
+        .argument(&code)
+        .argument("0x0502") // codeMetadata
+}
+
+#[test]
+fn storage_migration_on_upgrade() {
+    let mut world = world();
+    set_legacy_state(&mut world, &[1, 2, 3]);
+
+    let (price_1, price_2, price_3) = (price_key(1), price_key(2), price_key(3));
+    world.check_storage_migration(
+        USE_MODULE_ADDRESS_EXPR,
+        &[
+            (LEGACY_FEE_KEY, "5"),
+            (PRICES_LEN_KEY, "3"),
+            (&price_1, "1"),
+            (&price_2, "2"),
+            (&price_3, "3"),
+        ],
+        |world| {
+            let upgrade = upgrade_step(world).expect(TxExpect::ok().result("str:completed"));
+            world.sc_call(upgrade);
+        },
+        &[
+            (VERSION_KEY, "2"),
+            (FEE_KEY, "5"),
+            (PRICES_LEN_KEY, "3"),
+            (&price_1, "100"),
+            (&price_2, "200"),
+            (&price_3, "300"),
+        ],
+    );
+
+    // already up to date, nothing happens
+    world.sc_call(
+        ScCallStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .to(USE_MODULE_ADDRESS_EXPR)
+            .function("migrateStorage")
+            .expect(TxExpect::ok().result("str:completed")),
+    );
+    world.check_storage_layout(
+        USE_MODULE_ADDRESS_EXPR,
+        &[
+            (VERSION_KEY, "2"),
+            (FEE_KEY, "5"),
+            (PRICES_LEN_KEY, "3"),
+            (&price_1, "100"),
+            (&price_2, "200"),
+            (&price_3, "300"),
+        ],
+    );
+}
+
+#[test]
+fn storage_migration_resumed_after_running_out_of_gas() {
+    let mut world = world();
+    let mut gas_schedule = GasSchedule::default();
+    gas_schedule.set_cost(
+        GasSection::ManagedBufferApiCost,
+        "MBufferStorageLoad",
+        100_000,
+    );
+    gas_schedule.set_cost(
+        GasSection::ManagedBufferApiCost,
+        "MBufferStorageStore",
+        100_000,
+    );
+    world.set_gas_schedule(gas_schedule);
+
+    let prices: Vec<u64> = (1..=20).collect();
+    set_legacy_state(&mut world, &prices);
+
+    // the fee gets migrated, but the gas runs out while converting the prices
+    let upgrade = upgrade_step(&mut world)
+        .gas_limit("3,000,000")
+        .expect(TxExpect::ok().result("str:interrupted"));
+    world.sc_call(upgrade);
+
+    // only some of the prices got converted, the rest are still in the old format
+    let mut partially_migrated = CheckAccount::new()
+        .check_storage(VERSION_KEY, "1")
+        .check_storage(LEGACY_FEE_KEY, "")
+        .check_storage(FEE_KEY, "5")
+        .check_storage(&price_key(1), "100")
+        .check_storage(&price_key(20), "20");
+    if let CheckStorage::Equal(details) = &mut partially_migrated.storage {
+        details.other_storages_allowed = true;
+    }
+    world.check_state_step(
+        CheckStateStep::new().put_account(USE_MODULE_ADDRESS_EXPR, partially_migrated),
+    );
+
+    world.sc_call(
+        ScCallStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .to(USE_MODULE_ADDRESS_EXPR)
+            .function("migrateStorage")
+            .gas_limit("100,000,000")
+            .expect(TxExpect::ok().result("str:completed")),
+    );
+
+    let price_keys: Vec<String> = (1..=20).map(price_key).collect();
+    let price_values: Vec<String> = prices
+        .iter()
+        .map(|price| (price * 100).to_string())
+        .collect();
+    let mut layout = vec![(VERSION_KEY, "2"), (FEE_KEY, "5"), (PRICES_LEN_KEY, "20")];
+    for (key, value) in price_keys.iter().zip(price_values.iter()) {
+        layout.push((key, value));
+    }
+    world.check_storage_layout(USE_MODULE_ADDRESS_EXPR, &layout);
+}
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           63
+// Endpoints:                           70
 // Async Callback:                       1
-// Total number of exported functions:  65
+// Total number of exported functions:  72
 
 #![no_std]
 #![allow(internal_features)]
@@ -32,6 +32,10 @@ multiversx_sc_wasm_adapter::endpoints! {
         only_admin_mod_endpoint => only_admin_mod_endpoint
         call_derived_not_admin_only => call_derived_not_admin_only
         countTo100 => count_to_100
+        upgrade => upgrade_with_storage_migration
+        migrateStorage => migrate_example_storage
+        getFee => fee
+        getPrices => prices
         mergeTokens => merge_tokens_endpoint
         mergeTokensCustomAttributes => merge_tokens_custom_attributes_endpoint
         splitTokens => split_tokens_endpoint
@@ -44,10 +48,12 @@ multiversx_sc_wasm_adapter::endpoints! {
         withdrawGovernanceTokens => claim_deposited_tokens
         propose => propose
         vote => vote
+        voteWithSnapshot => vote_with_snapshot
         queue => queue
         execute => execute
         cancel => cancel
         getProposalStatus => get_proposal_status
+        getVotingPowerAt => get_voting_power_at
         getProposer => get_proposer
         getProposalDescription => get_proposal_description
         getProposalActions => get_proposal_actions
@@ -83,6 +89,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         addAdmin => add_admin
         removeAdmin => remove_admin
         getAdmins => admins
+        getStorageVersion => storage_version
     )
 }
 
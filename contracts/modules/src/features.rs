@@ -37,7 +37,7 @@ pub trait FeaturesModule {
 
 multiversx_sc::derive_imports!();
 
-#[derive(TopEncode)]
+#[derive(TopEncode, TypeAbi)]
 pub struct FeatureName<M>(ManagedBuffer<M>)
 where
     M: ManagedTypeApi;
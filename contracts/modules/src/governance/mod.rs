@@ -11,6 +11,9 @@ const MIN_AMOUNT_PER_DEPOSIT: u64 = 1;
 pub const ALREADY_VOTED_ERR_MSG: &[u8] = b"Already voted for this proposal";
 pub const MIN_FEES_REACHED: &[u8] = b"Propose already reached min threshold for fees";
 pub const MIN_AMOUNT_NOT_REACHED: &[u8] = b"Minimum amount not reached";
+pub const NO_VOTING_POWER_ERR_MSG: &[u8] = b"No voting power at proposal start block";
+pub const SNAPSHOT_VOTES_ONLY_ERR_MSG: &[u8] = b"Proposal only accepts snapshot votes";
+pub const DEPOSIT_VOTES_ONLY_ERR_MSG: &[u8] = b"Proposal does not accept snapshot votes";
 
 #[multiversx_sc::module]
 pub trait GovernanceModule:
@@ -170,6 +173,8 @@ pub trait GovernanceModule:
 
         let current_block = self.blockchain().get_block_nonce();
         self.proposal_start_block(proposal_id).set(current_block);
+        self.proposal_snapshot_voting(proposal_id)
+            .set(self.snapshot_voting_enabled().get());
 
         self.proposal_created_event(proposal_id, &proposer, current_block, &proposal);
 
@@ -178,48 +183,42 @@ pub trait GovernanceModule:
 
     /// Vote on a proposal by depositing any amount of governance tokens
     /// These tokens will be locked until the proposal is executed or cancelled.
+    /// Not available for proposals created while snapshot voting was enabled.
     #[payable("*")]
     #[endpoint]
     fn vote(&self, proposal_id: usize, vote: VoteType) {
         self.require_caller_not_self();
+        require!(
+            !self.proposal_snapshot_voting(proposal_id).get(),
+            SNAPSHOT_VOTES_ONLY_ERR_MSG
+        );
 
         let payment = self.require_payment_token_governance_token();
+        let voter = self.blockchain().get_caller();
+        self.cast_vote(&voter, proposal_id, vote, &payment.amount);
+    }
+
+    /// Vote on a proposal with the voting power the caller had at the end of the block the proposal was created in,
+    /// as recorded by `record_voting_power`. No tokens are deposited.
+    ///
+    /// Since the voting power is taken from the past, moving tokens to another account
+    /// after the proposal was created does not allow voting twice with them.
+    /// Only available for proposals created while snapshot voting was enabled.
+    #[endpoint(voteWithSnapshot)]
+    fn vote_with_snapshot(&self, proposal_id: usize, vote: VoteType) {
+        self.require_caller_not_self();
         self.require_valid_proposal_id(proposal_id);
         require!(
-            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active,
-            "Proposal is not active"
+            self.proposal_snapshot_voting(proposal_id).get(),
+            DEPOSIT_VOTES_ONLY_ERR_MSG
         );
 
         let voter = self.blockchain().get_caller();
-        let new_user = self.user_voted_proposals(&voter).insert(proposal_id);
-        require!(new_user, ALREADY_VOTED_ERR_MSG);
+        let snapshot_block = self.proposal_start_block(proposal_id).get();
+        let voting_power = self.get_voting_power_at(voter.clone(), snapshot_block);
+        require!(voting_power != 0, NO_VOTING_POWER_ERR_MSG);
 
-        match vote {
-            VoteType::UpVote => {
-                self.proposal_votes(proposal_id).update(|total_votes| {
-                    total_votes.up_votes += &payment.amount.clone();
-                });
-                self.up_vote_cast_event(&voter, proposal_id, &payment.amount);
-            },
-            VoteType::DownVote => {
-                self.proposal_votes(proposal_id).update(|total_votes| {
-                    total_votes.down_votes += &payment.amount.clone();
-                });
-                self.down_vote_cast_event(&voter, proposal_id, &payment.amount);
-            },
-            VoteType::DownVetoVote => {
-                self.proposal_votes(proposal_id).update(|total_votes| {
-                    total_votes.down_veto_votes += &payment.amount.clone();
-                });
-                self.down_veto_vote_cast_event(&voter, proposal_id, &payment.amount);
-            },
-            VoteType::AbstainVote => {
-                self.proposal_votes(proposal_id).update(|total_votes| {
-                    total_votes.abstain_votes += &payment.amount.clone();
-                });
-                self.abstain_vote_cast_event(&voter, proposal_id, &payment.amount);
-            },
-        }
+        self.cast_vote(&voter, proposal_id, vote, &voting_power);
     }
 
     /// Queue a proposal for execution.
@@ -376,6 +375,14 @@ pub trait GovernanceModule:
         }
     }
 
+    /// The voting power of a user at the end of the given block.
+    #[view(getVotingPowerAt)]
+    fn get_voting_power_at(&self, user: ManagedAddress, block_nonce: u64) -> BigUint {
+        self.voting_power_checkpoints(&user)
+            .upper_lookup(block_nonce)
+            .unwrap_or_default()
+    }
+
     #[view(getProposer)]
     fn get_proposer(&self, proposal_id: usize) -> OptionalValue<ManagedAddress> {
         if !self.proposal_exists(proposal_id) {
@@ -415,6 +422,65 @@ pub trait GovernanceModule:
 
     // private
 
+    /// Proposals created from now on can only be voted on with `voteWithSnapshot`.
+    /// Deposit votes are rejected for them, so that the same voting power cannot be counted twice.
+    fn enable_snapshot_voting(&self) {
+        self.snapshot_voting_enabled().set(true);
+    }
+
+    /// Records the new voting power of a user, starting from the current block.
+    /// It is up to the contract using the module to call this whenever the voting power of a user changes,
+    /// e.g. when they stake or unstake governance tokens.
+    fn record_voting_power(&self, user: &ManagedAddress, voting_power: BigUint) {
+        let current_block = self.blockchain().get_block_nonce();
+        self.voting_power_checkpoints(user)
+            .push(current_block, voting_power);
+    }
+
+    fn cast_vote(
+        &self,
+        voter: &ManagedAddress,
+        proposal_id: usize,
+        vote: VoteType,
+        voting_power: &BigUint,
+    ) {
+        self.require_valid_proposal_id(proposal_id);
+        require!(
+            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active,
+            "Proposal is not active"
+        );
+
+        let new_user = self.user_voted_proposals(voter).insert(proposal_id);
+        require!(new_user, ALREADY_VOTED_ERR_MSG);
+
+        match vote {
+            VoteType::UpVote => {
+                self.proposal_votes(proposal_id).update(|total_votes| {
+                    total_votes.up_votes += voting_power;
+                });
+                self.up_vote_cast_event(voter, proposal_id, voting_power);
+            },
+            VoteType::DownVote => {
+                self.proposal_votes(proposal_id).update(|total_votes| {
+                    total_votes.down_votes += voting_power;
+                });
+                self.down_vote_cast_event(voter, proposal_id, voting_power);
+            },
+            VoteType::DownVetoVote => {
+                self.proposal_votes(proposal_id).update(|total_votes| {
+                    total_votes.down_veto_votes += voting_power;
+                });
+                self.down_veto_vote_cast_event(voter, proposal_id, voting_power);
+            },
+            VoteType::AbstainVote => {
+                self.proposal_votes(proposal_id).update(|total_votes| {
+                    total_votes.abstain_votes += voting_power;
+                });
+                self.abstain_vote_cast_event(voter, proposal_id, voting_power);
+            },
+        }
+    }
+
     fn refund_payments(&self, proposal_id: ProposalId) {
         let payments = self.proposals().get(proposal_id).fees;
 
@@ -487,6 +553,7 @@ pub trait GovernanceModule:
         self.proposals().clear_entry(proposal_id);
         self.proposal_start_block(proposal_id).clear();
         self.proposal_queue_block(proposal_id).clear();
+        self.proposal_snapshot_voting(proposal_id).clear();
 
         self.total_votes(proposal_id).clear();
         self.total_downvotes(proposal_id).clear();
@@ -507,6 +574,17 @@ pub trait GovernanceModule:
     #[storage_mapper("governance:userVotedProposals")]
     fn user_voted_proposals(&self, user: &ManagedAddress) -> UnorderedSetMapper<ProposalId>;
 
+    #[storage_mapper("governance:snapshotVotingEnabled")]
+    fn snapshot_voting_enabled(&self) -> SingleValueMapper<bool>;
+
+    /// Set at proposal creation, from `snapshot_voting_enabled`.
+    #[storage_mapper("governance:proposalSnapshotVoting")]
+    fn proposal_snapshot_voting(&self, proposal_id: usize) -> SingleValueMapper<bool>;
+
+    /// Voting power history, used by `voteWithSnapshot`.
+    #[storage_mapper("governance:votingPowerCheckpoints")]
+    fn voting_power_checkpoints(&self, user: &ManagedAddress) -> CheckpointMapper<BigUint>;
+
     #[view(getProposalVotes)]
     #[storage_mapper("proposalVotes")]
     fn proposal_votes(
@@ -0,0 +1,120 @@
+multiversx_sc::imports!();
+
+use crate::ongoing_operation::{self, LoopOp};
+
+/// Version of the storage layout. Version 0 is the layout before the first migration.
+pub type StorageVersion = u32;
+
+/// Keeps track of the storage layout version, and brings the storage up to date on upgrade.
+///
+/// Migration `n` takes the storage from version `n - 1` to version `n`.
+/// Migrations run in order, and each of them runs only once.
+///
+/// Large mappers can be migrated in batches, with `run_batched_migration`,
+/// across several transactions. The progress is kept by the `OngoingOperationModule`,
+/// so no other ongoing operation should be started while a migration is interrupted.
+#[multiversx_sc::module]
+pub trait StorageMigrationModule: ongoing_operation::OngoingOperationModule {
+    /// Sets the storage version of a freshly deployed contract, which needs no migration.
+    /// Meant to be called from `#[init]`.
+    fn init_storage_version(&self, version: StorageVersion) {
+        self.storage_version().set(version);
+    }
+
+    /// Runs the migrations needed to bring the storage up to `target_version`.
+    ///
+    /// `migrate` receives the version to migrate to, and performs that single migration.
+    ///
+    /// If a migration gets interrupted, the storage version stays at the previous version,
+    /// so the next call resumes from the interrupted migration.
+    /// Meant to be called from `#[upgrade]`, and from an endpoint that resumes interrupted migrations.
+    ///
+    /// # Usage example
+    /// ```
+    /// # use multiversx_sc::types::OperationCompletionStatus;
+    /// # use multiversx_sc_modules::ongoing_operation::{
+    /// #     self, CONTINUE_OP, DEFAULT_MIN_GAS_TO_SAVE_PROGRESS, STOP_OP,
+    /// # };
+    /// # use multiversx_sc_modules::storage_migration;
+    /// # pub trait ExampleContract: multiversx_sc::contract_base::ContractBase + ongoing_operation::OngoingOperationModule + storage_migration::StorageMigrationModule
+    /// # {
+    /// fn migrate(&self) -> OperationCompletionStatus {
+    ///     self.run_storage_migrations(2, |version| match version {
+    ///         1 => {
+    ///             // e.g. move a value to a new storage key
+    ///             OperationCompletionStatus::Completed
+    ///         },
+    ///         _ => self.run_batched_migration(DEFAULT_MIN_GAS_TO_SAVE_PROGRESS, |index: &mut usize| {
+    ///             if *index == 1000 {
+    ///                 return STOP_OP;
+    ///             }
+    ///
+    ///             // e.g. convert the item at `index` of a large mapper
+    ///             *index += 1;
+    ///
+    ///             CONTINUE_OP
+    ///         }),
+    ///     })
+    /// }
+    /// # }
+    /// ```
+    fn run_storage_migrations<F>(
+        &self,
+        target_version: StorageVersion,
+        mut migrate: F,
+    ) -> OperationCompletionStatus
+    where
+        F: FnMut(StorageVersion) -> OperationCompletionStatus,
+    {
+        let mut version = self.storage_version().get();
+        require!(
+            version <= target_version,
+            "storage version is newer than the contract code"
+        );
+
+        while version < target_version {
+            let next_version = version + 1;
+            if migrate(next_version) == OperationCompletionStatus::InterruptedBeforeOutOfGas {
+                return OperationCompletionStatus::InterruptedBeforeOutOfGas;
+            }
+
+            self.storage_version().set(next_version);
+            self.storage_migrated_event(next_version);
+            version = next_version;
+        }
+
+        OperationCompletionStatus::Completed
+    }
+
+    /// Runs a migration step by step, for as long as there is gas left.
+    ///
+    /// The progress is loaded before the first step, and saved if the migration gets interrupted,
+    /// so that the next call picks up where the previous one left off.
+    /// It starts out as the default value of its type.
+    fn run_batched_migration<T, Step>(
+        &self,
+        min_gas_to_save_progress: u64,
+        mut step: Step,
+    ) -> OperationCompletionStatus
+    where
+        T: TopEncode + TopDecode + Default,
+        Step: FnMut(&mut T) -> LoopOp,
+    {
+        let mut progress = self.load_operation::<T>();
+        let run_result =
+            self.run_while_it_has_gas(min_gas_to_save_progress, || step(&mut progress));
+
+        if run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas {
+            self.save_progress(&progress);
+        }
+
+        run_result
+    }
+
+    #[event("storageMigrated")]
+    fn storage_migrated_event(&self, #[indexed] version: StorageVersion);
+
+    #[view(getStorageVersion)]
+    #[storage_mapper("storage_migration:version")]
+    fn storage_version(&self) -> SingleValueMapper<StorageVersion>;
+}
@@ -13,6 +13,7 @@ pub struct ContractAbi {
     pub endpoints: Vec<EndpointAbi>,
     pub promise_callbacks: Vec<EndpointAbi>,
     pub events: Vec<EventAbi>,
+    pub storage: Vec<StorageAbi>,
     pub esdt_attributes: Vec<EsdtAttributeAbi>,
     pub has_callback: bool,
     pub type_descriptions: TypeDescriptionContainerImpl,
@@ -29,6 +30,7 @@ impl ContractAbi {
             endpoints: Vec::new(),
             promise_callbacks: Vec::new(),
             events: Vec::new(),
+            storage: Vec::new(),
             esdt_attributes: Vec::new(),
             has_callback,
             type_descriptions: TypeDescriptionContainerImpl::new(),
@@ -40,6 +42,9 @@ impl ContractAbi {
             .extend_from_slice(other.constructors.as_slice());
         self.endpoints.extend_from_slice(other.endpoints.as_slice());
         self.events.extend_from_slice(other.events.as_slice());
+        for storage_abi in other.storage {
+            self.add_storage(storage_abi);
+        }
         self.promise_callbacks
             .extend_from_slice(other.promise_callbacks.as_slice());
         self.has_callback |= other.has_callback;
@@ -48,6 +53,17 @@ impl ContractAbi {
             .extend_from_slice(other.esdt_attributes.as_slice());
     }
 
+    /// Adds a storage entry, unless one with the same key is already listed.
+    pub fn add_storage(&mut self, storage_abi: StorageAbi) {
+        if !self
+            .storage
+            .iter()
+            .any(|existing| existing.has_same_key(&storage_abi))
+        {
+            self.storage.push(storage_abi);
+        }
+    }
+
     /// A type can provide more than 1 type descripions.
     /// For instance, a struct can also provide the descriptions of its fields.
     pub fn add_type_descriptions<T: TypeAbi>(&mut self) {
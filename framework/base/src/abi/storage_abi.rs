@@ -0,0 +1,85 @@
+use super::*;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// The layout of `#[storage_get]` and `#[storage_set]` values, which is the same as that of a `SingleValueMapper`.
+pub const SINGLE_VALUE_MAPPER_NAME: &str = "SingleValueMapper";
+
+/// Describes how a storage mapper lays out its data, so that it can be listed in the contract ABI.
+pub trait StorageMapperAbi {
+    /// The name of the mapper, e.g. `VecMapper`.
+    fn mapper_name() -> TypeName;
+
+    /// The types chosen by the contract for the data the mapper saves, e.g. the item type of a `VecMapper`.
+    ///
+    /// Mappers with a fixed layout, like `UserMapper`, have none.
+    fn value_type_names() -> Vec<TypeName> {
+        Vec::new()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(_accumulator: &mut TDC) {}
+}
+
+#[derive(Clone, Debug)]
+pub struct StorageKeyArgAbi {
+    pub arg_name: String,
+    pub type_name: TypeName,
+}
+
+/// A storage entry listed in the ABI. Storage methods annotated with `#[skip_storage_abi]` are not listed.
+#[derive(Clone, Debug)]
+pub struct StorageAbi {
+    pub docs: Vec<String>,
+    pub key: String,
+    pub mapper: String,
+    pub key_args: Vec<StorageKeyArgAbi>,
+    pub value_types: Vec<TypeName>,
+}
+
+impl StorageAbi {
+    /// Used in code generation.
+    pub fn new(docs: &[&str], key: &str) -> Self {
+        StorageAbi {
+            docs: docs.iter().map(|s| s.to_string()).collect(),
+            key: key.to_string(),
+            mapper: String::new(),
+            key_args: Vec::new(),
+            value_types: Vec::new(),
+        }
+    }
+
+    /// Used in code generation.
+    pub fn add_key_arg<T: TypeAbi>(&mut self, arg_name: &str) {
+        self.key_args.push(StorageKeyArgAbi {
+            arg_name: arg_name.to_string(),
+            type_name: T::type_name(),
+        });
+    }
+
+    /// Used in code generation, for `#[storage_mapper]`.
+    pub fn set_mapper<M: StorageMapperAbi>(&mut self) {
+        self.mapper = M::mapper_name();
+        self.value_types = M::value_type_names();
+    }
+
+    /// Used in code generation, for `#[storage_get]` and `#[storage_set]`.
+    pub fn set_single_value<T: TypeAbi>(&mut self) {
+        self.mapper = SINGLE_VALUE_MAPPER_NAME.to_string();
+        self.value_types = alloc::vec![T::type_name()];
+    }
+
+    /// Storage getters and setters often come in pairs, with the same key and layout.
+    ///
+    /// Key argument names are not relevant, only their types.
+    pub fn has_same_key(&self, other: &StorageAbi) -> bool {
+        self.key == other.key
+            && self.key_args.len() == other.key_args.len()
+            && self
+                .key_args
+                .iter()
+                .zip(other.key_args.iter())
+                .all(|(a, b)| a.type_name == b.type_name)
+    }
+}
@@ -1,7 +1,11 @@
 use super::{HandleTypeInfo, ManagedTypeApi, ManagedTypeApiImpl, RawHandle};
-use crate::types::{
-    heap::{Address, Box, H256},
-    EsdtLocalRoleFlags,
+use crate::{
+    api::ErrorApiImpl,
+    err_msg,
+    types::{
+        heap::{Address, Box, H256},
+        EsdtLocalRoleFlags,
+    },
 };
 
 pub trait BlockchainApi: ManagedTypeApi {
@@ -81,6 +85,11 @@ pub trait BlockchainApiImpl: ManagedTypeApiImpl {
 
     fn load_block_random_seed_managed(&self, dest: Self::ManagedBufferHandle);
 
+    /// Signals an error by default, for the APIs that cannot provide block hashes.
+    fn load_block_hash_managed(&self, _nonce: u64, _dest: Self::ManagedBufferHandle) {
+        Self::error_api_impl().signal_error(err_msg::BLOCK_HASH_NOT_SUPPORTED);
+    }
+
     fn get_prev_block_timestamp(&self) -> u64;
 
     fn get_prev_block_nonce(&self) -> u64;
@@ -31,4 +31,22 @@ pub trait ManagedMapApiImpl: HandleTypeInfo {
         map_handle: Self::ManagedMapHandle,
         key_handle: Self::ManagedBufferHandle,
     ) -> bool;
+
+    /// Number of keys, not counting the ones with empty values.
+    ///
+    /// Not all VMs provide it yet, so it is only used with the `managed-map-iteration` feature.
+    fn mm_len(&self, _map_handle: Self::ManagedMapHandle) -> usize {
+        unimplemented!("managed map length is not supported")
+    }
+
+    /// Writes all keys to a `ManagedVec<ManagedBuffer>`, in ascending byte order.
+    ///
+    /// Not all VMs provide it yet, so it is only used with the `managed-map-iteration` feature.
+    fn mm_keys(
+        &self,
+        _map_handle: Self::ManagedMapHandle,
+        _out_keys_handle: Self::ManagedBufferHandle,
+    ) {
+        unimplemented!("managed map keys are not supported")
+    }
 }
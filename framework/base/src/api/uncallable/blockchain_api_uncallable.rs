@@ -71,6 +71,10 @@ impl BlockchainApiImpl for UncallableApi {
         unreachable!()
     }
 
+    fn load_block_hash_managed(&self, _nonce: u64, _dest: Self::ManagedBufferHandle) {
+        unreachable!()
+    }
+
     fn get_prev_block_timestamp(&self) -> u64 {
         unreachable!()
     }
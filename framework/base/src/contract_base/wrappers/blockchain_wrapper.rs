@@ -233,6 +233,16 @@ where
         ManagedByteArray::from_handle(handle)
     }
 
+    /// The hash of the block with the given nonce.
+    ///
+    /// Blocks that do not exist yet have no hash, all bytes are zero in that case.
+    #[inline]
+    pub fn get_block_hash(&self, nonce: u64) -> ManagedByteArray<A, 32> {
+        let handle: A::ManagedBufferHandle = use_raw_handle(A::static_var_api_impl().next_handle());
+        A::blockchain_api_impl().load_block_hash_managed(nonce, handle.clone());
+        ManagedByteArray::from_handle(handle)
+    }
+
     #[inline]
     pub fn get_prev_block_timestamp(&self) -> u64 {
         A::blockchain_api_impl().get_prev_block_timestamp()
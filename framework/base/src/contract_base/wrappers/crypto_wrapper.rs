@@ -5,7 +5,10 @@ use crate::{
         use_raw_handle, CryptoApi, CryptoApiImpl, StaticVarApiImpl, KECCAK256_RESULT_LEN,
         SHA256_RESULT_LEN,
     },
-    types::{ManagedBuffer, ManagedByteArray, ManagedType, MessageHashType},
+    types::{
+        Keccak256MerkleHasher, ManagedBuffer, ManagedByteArray, ManagedType, MerkleProofVerifier,
+        MessageHashType, Sha256MerkleHasher,
+    },
 };
 
 #[derive(Default)]
@@ -56,6 +59,16 @@ where
         ManagedByteArray::from_handle(new_handle)
     }
 
+    /// Verifies Merkle proofs for trees built with sha256.
+    pub fn merkle_sha256(&self) -> MerkleProofVerifier<A, Sha256MerkleHasher> {
+        MerkleProofVerifier::new()
+    }
+
+    /// Verifies Merkle proofs for trees built with keccak256.
+    pub fn merkle_keccak256(&self) -> MerkleProofVerifier<A, Keccak256MerkleHasher> {
+        MerkleProofVerifier::new()
+    }
+
     pub fn verify_bls(
         &self,
         key: &ManagedBuffer<A>,
@@ -39,6 +39,8 @@ pub const VALUE_EXCEEDS_SLICE: &[u8] = b"value exceeds target slice";
 pub const CAST_TO_I64_ERROR: &[u8] = b"cast to i64 error";
 pub const BIG_UINT_EXCEEDS_SLICE: &[u8] = b"big uint as_bytes exceed target slice";
 pub const BIG_UINT_SUB_NEGATIVE: &[u8] = b"cannot subtract because result would be negative";
+pub const MANAGED_DECIMAL_NEGATIVE: &str = "managed decimal cannot be negative";
+pub const MANAGED_DECIMAL_SCALE_MISMATCH: &[u8] = b"managed decimals have different scales";
 
 pub const DESERIALIZATION_INVALID_BYTE: &str = "call data deserialization error: not a valid byte";
 pub const DESERIALIZATION_NOT_32_BYTES: &str =
@@ -57,6 +59,8 @@ pub static ONLY_USER_ACCOUNT_CALLER: &[u8] = b"Endpoint can only be called by us
 pub const STORAGE_NOT_I64: &[u8] = b"storage not i64";
 pub const STORAGE_NOT_32_BYTES: &[u8] = b"32 bytes of data expected in storage at key";
 
+pub const BLOCK_HASH_NOT_SUPPORTED: &[u8] = b"block hash not supported";
+
 /// An additional non-VM status, meant just to signal an error in the debugger infrastructure of in the tests.
 pub const DEBUG_API_ERR_STATUS: u64 = 100;
 pub const DEBUG_API_ERR_HANDLE_STALE: &str =
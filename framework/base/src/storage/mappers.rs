@@ -1,10 +1,15 @@
 mod address_to_id_mapper;
+mod avl_tree_mapper;
 mod bi_di_mapper;
+mod checkpoint_mapper;
 mod linked_list_mapper;
 mod map_mapper;
 mod map_storage_mapper;
 mod mapper;
+mod merkle_tree_mapper;
 mod ordered_binary_tree_mapper;
+mod pagination;
+mod priority_queue_mapper;
 mod queue_mapper;
 mod set_mapper;
 mod single_value_mapper;
@@ -16,14 +21,21 @@ mod vec_mapper;
 mod whitelist_mapper;
 
 pub use address_to_id_mapper::{AddressId, AddressToIdMapper, NULL_ID};
+pub use avl_tree_mapper::{AvlTreeMapper, AvlTreeMapperInfo, AvlTreeNode};
 pub use bi_di_mapper::BiDiMapper;
+pub use checkpoint_mapper::{Checkpoint, CheckpointMapper};
 pub use linked_list_mapper::{LinkedListMapper, LinkedListNode};
 pub use map_mapper::MapMapper;
 pub use map_storage_mapper::MapStorageMapper;
 pub use mapper::{StorageClearable, StorageMapper};
+pub use merkle_tree_mapper::{MerkleTreeMapper, MERKLE_TREE_MAX_DEPTH};
 pub use ordered_binary_tree_mapper::{
     NodeId, OrderedBinaryTreeMapper, OrderedBinaryTreeNode, NULL_NODE_ID,
 };
+pub use pagination::{PaginatedStorageMapper, StorageMapperPage};
+pub use priority_queue_mapper::{
+    MaxFirst, MinFirst, PriorityQueueEntryId, PriorityQueueMapper, PriorityQueueOrder,
+};
 pub use queue_mapper::QueueMapper;
 pub use set_mapper::SetMapper;
 pub use single_value_mapper::{SingleValue, SingleValueMapper};
@@ -5,6 +5,7 @@ use super::{
     StorageMapper,
 };
 use crate::{
+    abi::{StorageMapperAbi, TypeName},
     api::{ErrorApiImpl, StorageMapperApi},
     storage::{storage_clear, storage_set, StorageKey},
     types::{ManagedAddress, ManagedType},
@@ -181,3 +182,12 @@ where
         storage_clear(self.id_to_address_key(id).as_ref());
     }
 }
+
+impl<SA> StorageMapperAbi for AddressToIdMapper<SA>
+where
+    SA: StorageMapperApi,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("AddressToIdMapper")
+    }
+}
@@ -0,0 +1,799 @@
+use core::{
+    cmp::Ordering,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+};
+
+use super::{
+    set_mapper::{CurrentStorage, StorageAddress},
+    NodeId, StorageClearable, StorageMapper, NULL_NODE_ID,
+};
+use crate::{
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
+    api::StorageMapperApi,
+    codec::{
+        self,
+        derive::{TopDecode, TopDecodeOrDefault, TopEncode, TopEncodeOrDefault},
+        multi_encode_iter_or_handle_err, CodecFrom, DecodeDefault, EncodeDefault,
+        EncodeErrorHandler, NestedDecode, NestedEncode, TopDecode, TopEncode, TopEncodeMulti,
+        TopEncodeMultiOutput,
+    },
+    storage::{storage_set, StorageKey},
+    types::{ManagedAddress, ManagedType, ManagedVec, MultiValueEncoded},
+};
+
+const INFO_IDENTIFIER: &[u8] = b".info";
+const NODE_IDENTIFIER: &[u8] = b".node";
+
+// https://en.wikipedia.org/wiki/AVL_tree
+
+/// A tree node. Besides the links, it keeps the height and size of both its subtrees,
+/// so that rebalancing and rank queries never need to load the siblings on the search path.
+#[derive(TopEncode, TopDecode, Clone, PartialEq, Debug)]
+pub struct AvlTreeNode<T: NestedEncode + NestedDecode> {
+    pub left_id: NodeId,
+    pub right_id: NodeId,
+    pub left_height: u32,
+    pub right_height: u32,
+    pub left_size: u64,
+    pub right_size: u64,
+    pub value: T,
+}
+
+impl<T> AvlTreeNode<T>
+where
+    T: NestedEncode + NestedDecode,
+{
+    fn new_leaf(value: T) -> Self {
+        AvlTreeNode {
+            left_id: NULL_NODE_ID,
+            right_id: NULL_NODE_ID,
+            left_height: 0,
+            right_height: 0,
+            left_size: 0,
+            right_size: 0,
+            value,
+        }
+    }
+
+    pub fn height(&self) -> u32 {
+        core::cmp::max(self.left_height, self.right_height) + 1
+    }
+
+    pub fn size(&self) -> u64 {
+        self.left_size + self.right_size + 1
+    }
+
+    fn set_left(&mut self, subtree: Subtree) {
+        self.left_id = subtree.root_id;
+        self.left_height = subtree.height;
+        self.left_size = subtree.size;
+    }
+
+    fn set_right(&mut self, subtree: Subtree) {
+        self.right_id = subtree.root_id;
+        self.right_height = subtree.height;
+        self.right_size = subtree.size;
+    }
+
+    fn left_subtree(&self) -> Subtree {
+        Subtree {
+            root_id: self.left_id,
+            height: self.left_height,
+            size: self.left_size,
+        }
+    }
+
+    fn right_subtree(&self) -> Subtree {
+        Subtree {
+            root_id: self.right_id,
+            height: self.right_height,
+            size: self.right_size,
+        }
+    }
+}
+
+#[derive(TopEncodeOrDefault, TopDecodeOrDefault, PartialEq, Eq, Clone, Copy)]
+pub struct AvlTreeMapperInfo {
+    pub root_id: NodeId,
+    pub len: u64,
+    pub last_id: NodeId,
+}
+
+impl EncodeDefault for AvlTreeMapperInfo {
+    fn is_default(&self) -> bool {
+        self.root_id == NULL_NODE_ID && self.last_id == 0
+    }
+}
+
+impl DecodeDefault for AvlTreeMapperInfo {
+    fn default() -> Self {
+        Self {
+            root_id: NULL_NODE_ID,
+            len: 0,
+            last_id: 0,
+        }
+    }
+}
+
+impl AvlTreeMapperInfo {
+    pub fn generate_new_node_id(&mut self) -> NodeId {
+        self.last_id += 1;
+        self.last_id
+    }
+}
+
+/// What a parent needs to know about a child subtree.
+#[derive(Clone, Copy)]
+struct Subtree {
+    root_id: NodeId,
+    height: u32,
+    size: u64,
+}
+
+impl Subtree {
+    const EMPTY: Subtree = Subtree {
+        root_id: NULL_NODE_ID,
+        height: 0,
+        size: 0,
+    };
+
+    fn of<T: NestedEncode + NestedDecode>(node_id: NodeId, node: &AvlTreeNode<T>) -> Self {
+        Subtree {
+            root_id: node_id,
+            height: node.height(),
+            size: node.size(),
+        }
+    }
+}
+
+/// An ordered set, kept as an AVL tree in storage.
+///
+/// Unlike the `OrderedBinaryTreeMapper`, the tree stays balanced regardless of the insertion order,
+/// so its height never exceeds `1.44 * log2(n + 2)`. Each node is stored under a single key,
+/// together with the heights and sizes of its subtrees, which means that:
+/// - lookups, bounds, `rank` and `nth` load at most one node per level;
+/// - `insert` loads and saves at most one node per level, plus at most 2 more for the single rebalancing it performs;
+/// - `remove` loads and saves at most 3 nodes per level, since it can rebalance at every level.
+pub struct AvlTreeMapper<SA, T, A = CurrentStorage>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + Ord + Clone + 'static,
+{
+    _phantom_api: PhantomData<SA>,
+    address: A,
+    base_key: StorageKey<SA>,
+    _phantom_item: PhantomData<T>,
+}
+
+impl<SA, T> StorageMapper<SA> for AvlTreeMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Ord + Clone,
+{
+    fn new(base_key: StorageKey<SA>) -> Self {
+        AvlTreeMapper {
+            _phantom_api: PhantomData,
+            address: CurrentStorage,
+            base_key,
+            _phantom_item: PhantomData,
+        }
+    }
+}
+
+impl<SA, T> StorageClearable for AvlTreeMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Ord + Clone,
+{
+    fn clear(&mut self) {
+        let info = self.get_info();
+        let mut stack = ManagedVec::<SA, NodeId>::new();
+        if info.root_id != NULL_NODE_ID {
+            stack.push(info.root_id);
+        }
+        while let Some(node_id) = pop_last(&mut stack) {
+            let node = self.get_node(node_id);
+            if node.left_id != NULL_NODE_ID {
+                stack.push(node.left_id);
+            }
+            if node.right_id != NULL_NODE_ID {
+                stack.push(node.right_id);
+            }
+            self.clear_node(node_id);
+        }
+        self.set_info(AvlTreeMapperInfo::default());
+    }
+}
+
+impl<SA, T> AvlTreeMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Ord + Clone,
+{
+    fn set_info(&mut self, info: AvlTreeMapperInfo) {
+        storage_set(self.build_name_key(INFO_IDENTIFIER).as_ref(), &info);
+    }
+
+    fn set_node(&mut self, node_id: NodeId, node: &AvlTreeNode<T>) {
+        storage_set(self.build_node_key(node_id).as_ref(), node);
+    }
+
+    fn clear_node(&mut self, node_id: NodeId) {
+        storage_set(self.build_node_key(node_id).as_ref(), &codec::Empty);
+    }
+
+    /// Adds a value to the tree.
+    ///
+    /// Returns `false` if the value was already present, in which case nothing is written.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn insert(&mut self, value: T) -> bool {
+        let mut info = self.get_info();
+        let Some(new_root) = self.insert_into(info.root_id, value, &mut info) else {
+            return false;
+        };
+        info.root_id = new_root.root_id;
+        info.len += 1;
+        self.set_info(info);
+        true
+    }
+
+    /// Removes a value from the tree.
+    ///
+    /// Returns `false` if the value was not present, in which case nothing is written.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn remove(&mut self, value: &T) -> bool {
+        let mut info = self.get_info();
+        let Some(new_root) = self.remove_from(info.root_id, value) else {
+            return false;
+        };
+        info.root_id = new_root.root_id;
+        info.len -= 1;
+        self.set_info(info);
+        true
+    }
+
+    fn insert_into(
+        &mut self,
+        node_id: NodeId,
+        value: T,
+        info: &mut AvlTreeMapperInfo,
+    ) -> Option<Subtree> {
+        if node_id == NULL_NODE_ID {
+            let new_node_id = info.generate_new_node_id();
+            let new_node = AvlTreeNode::new_leaf(value);
+            self.set_node(new_node_id, &new_node);
+            return Some(Subtree::of(new_node_id, &new_node));
+        }
+
+        let mut node = self.get_node(node_id);
+        match value.cmp(&node.value) {
+            Ordering::Equal => return None,
+            Ordering::Less => {
+                let new_left = self.insert_into(node.left_id, value, info)?;
+                node.set_left(new_left);
+            },
+            Ordering::Greater => {
+                let new_right = self.insert_into(node.right_id, value, info)?;
+                node.set_right(new_right);
+            },
+        }
+        Some(self.rebalance_and_save(node_id, node))
+    }
+
+    fn remove_from(&mut self, node_id: NodeId, value: &T) -> Option<Subtree> {
+        if node_id == NULL_NODE_ID {
+            return None;
+        }
+
+        let mut node = self.get_node(node_id);
+        match value.cmp(&node.value) {
+            Ordering::Less => {
+                let new_left = self.remove_from(node.left_id, value)?;
+                node.set_left(new_left);
+                Some(self.rebalance_and_save(node_id, node))
+            },
+            Ordering::Greater => {
+                let new_right = self.remove_from(node.right_id, value)?;
+                node.set_right(new_right);
+                Some(self.rebalance_and_save(node_id, node))
+            },
+            Ordering::Equal => {
+                self.clear_node(node_id);
+                if node.left_id == NULL_NODE_ID {
+                    return Some(node.right_subtree());
+                }
+                if node.right_id == NULL_NODE_ID {
+                    return Some(node.left_subtree());
+                }
+
+                // the successor takes the place of the removed node
+                let (successor_id, mut successor, new_right) = self.remove_min(node.right_id);
+                successor.set_left(node.left_subtree());
+                successor.set_right(new_right);
+                Some(self.rebalance_and_save(successor_id, successor))
+            },
+        }
+    }
+
+    /// Detaches the smallest node of a subtree, without clearing it from storage.
+    ///
+    /// Returns the detached node, along with what is left of the subtree.
+    fn remove_min(&mut self, node_id: NodeId) -> (NodeId, AvlTreeNode<T>, Subtree) {
+        let mut node = self.get_node(node_id);
+        if node.left_id == NULL_NODE_ID {
+            let rest = node.right_subtree();
+            return (node_id, node, rest);
+        }
+
+        let (min_id, min_node, new_left) = self.remove_min(node.left_id);
+        node.set_left(new_left);
+        (min_id, min_node, self.rebalance_and_save(node_id, node))
+    }
+
+    /// Restores the AVL property of a subtree whose children are balanced,
+    /// and whose heights differ by at most 2. Saves all the nodes that changed.
+    fn rebalance_and_save(&mut self, node_id: NodeId, node: AvlTreeNode<T>) -> Subtree {
+        let (new_root_id, new_root) = if node.left_height > node.right_height + 1 {
+            let mut left_id = node.left_id;
+            let mut left = self.get_node(left_id);
+            if left.right_height > left.left_height {
+                let left_right_id = left.right_id;
+                let left_right = self.get_node(left_right_id);
+                (left_id, left) = self.rotate_left(left_id, left, left_right_id, left_right);
+            }
+            self.rotate_right(node_id, node, left_id, left)
+        } else if node.right_height > node.left_height + 1 {
+            let mut right_id = node.right_id;
+            let mut right = self.get_node(right_id);
+            if right.left_height > right.right_height {
+                let right_left_id = right.left_id;
+                let right_left = self.get_node(right_left_id);
+                (right_id, right) = self.rotate_right(right_id, right, right_left_id, right_left);
+            }
+            self.rotate_left(node_id, node, right_id, right)
+        } else {
+            (node_id, node)
+        };
+
+        self.set_node(new_root_id, &new_root);
+        Subtree::of(new_root_id, &new_root)
+    }
+
+    /// The left child becomes the root of the subtree. Only saves the demoted node.
+    fn rotate_right(
+        &mut self,
+        node_id: NodeId,
+        mut node: AvlTreeNode<T>,
+        left_id: NodeId,
+        mut left: AvlTreeNode<T>,
+    ) -> (NodeId, AvlTreeNode<T>) {
+        node.set_left(left.right_subtree());
+        self.set_node(node_id, &node);
+        left.set_right(Subtree::of(node_id, &node));
+        (left_id, left)
+    }
+
+    /// The right child becomes the root of the subtree. Only saves the demoted node.
+    fn rotate_left(
+        &mut self,
+        node_id: NodeId,
+        mut node: AvlTreeNode<T>,
+        right_id: NodeId,
+        mut right: AvlTreeNode<T>,
+    ) -> (NodeId, AvlTreeNode<T>) {
+        node.set_right(right.left_subtree());
+        self.set_node(node_id, &node);
+        right.set_left(Subtree::of(node_id, &node));
+        (right_id, right)
+    }
+}
+
+impl<SA, T> AvlTreeMapper<SA, T, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Ord + Clone,
+{
+    pub fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        AvlTreeMapper {
+            _phantom_api: PhantomData,
+            address,
+            base_key,
+            _phantom_item: PhantomData,
+        }
+    }
+}
+
+impl<SA, A, T> AvlTreeMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + Ord + Clone,
+{
+    fn build_node_key(&self, node_id: NodeId) -> StorageKey<SA> {
+        let mut node_key = self.base_key.clone();
+        node_key.append_bytes(NODE_IDENTIFIER);
+        node_key.append_item(&node_id);
+        node_key
+    }
+
+    fn build_name_key(&self, name: &[u8]) -> StorageKey<SA> {
+        let mut name_key = self.base_key.clone();
+        name_key.append_bytes(name);
+        name_key
+    }
+
+    fn get_info(&self) -> AvlTreeMapperInfo {
+        self.address
+            .address_storage_get(self.build_name_key(INFO_IDENTIFIER).as_ref())
+    }
+
+    pub fn get_node(&self, node_id: NodeId) -> AvlTreeNode<T> {
+        self.address
+            .address_storage_get(self.build_node_key(node_id).as_ref())
+    }
+
+    pub fn get_root(&self) -> Option<AvlTreeNode<T>> {
+        self.get_node_option(self.get_info().root_id)
+    }
+
+    fn get_node_option(&self, node_id: NodeId) -> Option<AvlTreeNode<T>> {
+        if node_id == NULL_NODE_ID {
+            return None;
+        }
+        Some(self.get_node(node_id))
+    }
+
+    /// Returns `true` if the tree is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn is_empty(&self) -> bool {
+        self.get_info().len == 0
+    }
+
+    /// Returns the number of elements in the tree.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn len(&self) -> usize {
+        self.get_info().len as usize
+    }
+
+    /// The number of levels of the tree, 0 if empty.
+    pub fn height(&self) -> u32 {
+        self.get_root()
+            .map(|root| root.height())
+            .unwrap_or_default()
+    }
+
+    /// This operation should compute in *O*(log n) time.
+    pub fn contains(&self, value: &T) -> bool {
+        let mut opt_node = self.get_root();
+        while let Some(node) = opt_node {
+            opt_node = match value.cmp(&node.value) {
+                Ordering::Equal => return true,
+                Ordering::Less => self.get_node_option(node.left_id),
+                Ordering::Greater => self.get_node_option(node.right_id),
+            };
+        }
+        false
+    }
+
+    /// The smallest element, `None` if the tree is empty.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn first(&self) -> Option<T> {
+        let mut node = self.get_root()?;
+        while node.left_id != NULL_NODE_ID {
+            node = self.get_node(node.left_id);
+        }
+        Some(node.value)
+    }
+
+    /// The largest element, `None` if the tree is empty.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn last(&self) -> Option<T> {
+        let mut node = self.get_root()?;
+        while node.right_id != NULL_NODE_ID {
+            node = self.get_node(node.right_id);
+        }
+        Some(node.value)
+    }
+
+    /// The smallest element greater than or equal to the given value.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn lower_bound(&self, value: &T) -> Option<T> {
+        self.first_matching(|node_value| node_value >= value)
+    }
+
+    /// The smallest element strictly greater than the given value.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn upper_bound(&self, value: &T) -> Option<T> {
+        self.first_matching(|node_value| node_value > value)
+    }
+
+    /// Finds the smallest element satisfying a predicate that is monotonic with respect to the ordering.
+    fn first_matching<F>(&self, predicate: F) -> Option<T>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut result = None;
+        let mut opt_node = self.get_root();
+        while let Some(node) = opt_node {
+            if predicate(&node.value) {
+                opt_node = self.get_node_option(node.left_id);
+                result = Some(node.value);
+            } else {
+                opt_node = self.get_node_option(node.right_id);
+            }
+        }
+        result
+    }
+
+    /// The number of elements strictly smaller than the given value,
+    /// i.e. its position in the tree, if present.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn rank(&self, value: &T) -> usize {
+        let mut rank = 0u64;
+        let mut opt_node = self.get_root();
+        while let Some(node) = opt_node {
+            match value.cmp(&node.value) {
+                Ordering::Less => {
+                    opt_node = self.get_node_option(node.left_id);
+                },
+                Ordering::Equal => {
+                    rank += node.left_size;
+                    break;
+                },
+                Ordering::Greater => {
+                    rank += node.left_size + 1;
+                    opt_node = self.get_node_option(node.right_id);
+                },
+            }
+        }
+        rank as usize
+    }
+
+    /// The element at the given position, in ascending order, starting from 0.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn nth(&self, index: usize) -> Option<T> {
+        let mut index = index as u64;
+        let mut opt_node = self.get_root();
+        while let Some(node) = opt_node {
+            match index.cmp(&node.left_size) {
+                Ordering::Less => {
+                    opt_node = self.get_node_option(node.left_id);
+                },
+                Ordering::Equal => return Some(node.value),
+                Ordering::Greater => {
+                    index -= node.left_size + 1;
+                    opt_node = self.get_node_option(node.right_id);
+                },
+            }
+        }
+        None
+    }
+
+    /// Iterates over all elements, in ascending order.
+    pub fn iter(&self) -> Iter<SA, A, T> {
+        self.range(..)
+    }
+
+    /// Iterates over the elements in the given range, in ascending order.
+    ///
+    /// Creating the iterator loads at most one node per level,
+    /// after which each step loads on average 2 nodes.
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> Iter<SA, A, T> {
+        Iter::new(
+            self,
+            range.start_bound().cloned(),
+            range.end_bound().cloned(),
+        )
+    }
+
+    /// Checks the ordering, the balance, as well as the heights and sizes cached in each node.
+    /// Used for unit testing.
+    ///
+    /// This operation should compute in *O*(n) time.
+    pub fn check_internal_consistency(&self) -> bool {
+        let info = self.get_info();
+        match self.check_subtree(info.root_id, None, None) {
+            Some(subtree) => subtree.size == info.len && info.root_id <= info.last_id,
+            None => false,
+        }
+    }
+
+    /// The bounds are exclusive, `None` means unbounded.
+    fn check_subtree(
+        &self,
+        node_id: NodeId,
+        lower: Option<&T>,
+        upper: Option<&T>,
+    ) -> Option<Subtree> {
+        if node_id == NULL_NODE_ID {
+            return Some(Subtree::EMPTY);
+        }
+
+        let node = self.get_node(node_id);
+        if matches!(lower, Some(lower) if &node.value <= lower) {
+            return None;
+        }
+        if matches!(upper, Some(upper) if &node.value >= upper) {
+            return None;
+        }
+
+        let left = self.check_subtree(node.left_id, lower, Some(&node.value))?;
+        let right = self.check_subtree(node.right_id, Some(&node.value), upper)?;
+        let cached_correctly = left.height == node.left_height
+            && left.size == node.left_size
+            && right.height == node.right_height
+            && right.size == node.right_size;
+        let balanced = node.left_height.abs_diff(node.right_height) <= 1;
+        if !cached_correctly || !balanced {
+            return None;
+        }
+        Some(Subtree::of(node_id, &node))
+    }
+}
+
+fn pop_last<SA: StorageMapperApi>(stack: &mut ManagedVec<SA, NodeId>) -> Option<NodeId> {
+    let last_index = stack.len().checked_sub(1)?;
+    Some(stack.take(last_index))
+}
+
+impl<'a, SA, A, T> IntoIterator for &'a AvlTreeMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + Ord + Clone,
+{
+    type Item = T;
+
+    type IntoIter = Iter<'a, SA, A, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An ascending iterator over the elements of an `AvlTreeMapper`, or of a range of them.
+///
+/// This `struct` is created by [`AvlTreeMapper::iter()`] and [`AvlTreeMapper::range()`].
+/// It keeps the ids of the nodes whose left subtree was visited, but not the node itself.
+pub struct Iter<'a, SA, A, T>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + Ord + Clone + 'static,
+{
+    tree: &'a AvlTreeMapper<SA, T, A>,
+    stack: ManagedVec<SA, NodeId>,
+    end: Bound<T>,
+}
+
+impl<'a, SA, A, T> Iter<'a, SA, A, T>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + Ord + Clone,
+{
+    fn new(tree: &'a AvlTreeMapper<SA, T, A>, start: Bound<T>, end: Bound<T>) -> Self {
+        let mut stack = ManagedVec::new();
+        let mut node_id = tree.get_info().root_id;
+        while node_id != NULL_NODE_ID {
+            let node = tree.get_node(node_id);
+            let after_start = match &start {
+                Bound::Included(start) => &node.value >= start,
+                Bound::Excluded(start) => &node.value > start,
+                Bound::Unbounded => true,
+            };
+            if after_start {
+                stack.push(node_id);
+                node_id = node.left_id;
+            } else {
+                node_id = node.right_id;
+            }
+        }
+
+        Iter { tree, stack, end }
+    }
+
+    fn push_left_branch(&mut self, mut node_id: NodeId) {
+        while node_id != NULL_NODE_ID {
+            self.stack.push(node_id);
+            node_id = self.tree.get_node(node_id).left_id;
+        }
+    }
+}
+
+impl<'a, SA, A, T> Iterator for Iter<'a, SA, A, T>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + Ord + Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let node_id = pop_last(&mut self.stack)?;
+        let node = self.tree.get_node(node_id);
+        let before_end = match &self.end {
+            Bound::Included(end) => &node.value <= end,
+            Bound::Excluded(end) => &node.value < end,
+            Bound::Unbounded => true,
+        };
+        if !before_end {
+            self.stack.clear();
+            return None;
+        }
+
+        self.push_left_branch(node.right_id);
+        Some(node.value)
+    }
+}
+
+/// Behaves like a MultiResultVec when an endpoint result.
+impl<SA, T> TopEncodeMulti for AvlTreeMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + TopEncode + Ord + Clone,
+{
+    fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        multi_encode_iter_or_handle_err(self.iter(), output, h)
+    }
+}
+
+impl<SA, T> CodecFrom<AvlTreeMapper<SA, T, CurrentStorage>> for MultiValueEncoded<SA, T>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + TopEncode + TopDecode + Ord + Clone,
+{
+}
+
+/// Behaves like a MultiResultVec when an endpoint result.
+impl<SA, T> TypeAbi for AvlTreeMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + TopEncode + Ord + Clone + TypeAbi,
+{
+    fn type_name() -> TypeName {
+        crate::abi::type_name_variadic::<T>()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+
+    fn is_variadic() -> bool {
+        true
+    }
+}
+
+impl<SA, T> StorageMapperAbi for AvlTreeMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Ord + Clone + TypeAbi,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("AvlTreeMapper")
+    }
+
+    fn value_type_names() -> alloc::vec::Vec<TypeName> {
+        alloc::vec![T::type_name()]
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
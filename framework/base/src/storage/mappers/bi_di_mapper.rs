@@ -10,10 +10,10 @@ use crate::{
 
 use super::{
     set_mapper::{CurrentStorage, StorageAddress},
-    unordered_set_mapper, StorageMapper, UnorderedSetMapper,
+    unordered_set_mapper, StorageClearable, StorageMapper, UnorderedSetMapper,
 };
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
     storage::{storage_set, StorageKey},
     storage_clear,
@@ -65,6 +65,24 @@ where
     }
 }
 
+impl<SA, K, V> StorageClearable for BiDiMapper<SA, K, V, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static + Default + PartialEq,
+    V: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static + Default + PartialEq,
+{
+    fn clear(&mut self) {
+        for id in self.id_set_mapper.iter() {
+            self.clear_value_by_id(&id);
+        }
+        for value in self.value_set_mapper.iter() {
+            self.clear_id_by_value(&value);
+        }
+        self.id_set_mapper.clear();
+        self.value_set_mapper.clear();
+    }
+}
+
 impl<SA, K, V> BiDiMapper<SA, K, V, ManagedAddress<SA>>
 where
     SA: StorageMapperApi,
@@ -339,3 +357,37 @@ where
         true
     }
 }
+
+impl<SA, K, V> StorageMapperAbi for BiDiMapper<SA, K, V, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    K: TopEncode
+        + TopDecode
+        + NestedEncode
+        + NestedDecode
+        + 'static
+        + Default
+        + PartialEq
+        + TypeAbi,
+    V: TopEncode
+        + TopDecode
+        + NestedEncode
+        + NestedDecode
+        + 'static
+        + Default
+        + PartialEq
+        + TypeAbi,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("BiDiMapper")
+    }
+
+    fn value_type_names() -> alloc::vec::Vec<TypeName> {
+        alloc::vec![K::type_name(), V::type_name()]
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        K::provide_type_descriptions(accumulator);
+        V::provide_type_descriptions(accumulator);
+    }
+}
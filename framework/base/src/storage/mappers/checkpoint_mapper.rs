@@ -0,0 +1,302 @@
+use core::marker::PhantomData;
+
+pub use super::vec_mapper::Iter;
+use super::{
+    set_mapper::{CurrentStorage, StorageAddress},
+    StorageClearable, StorageMapper, VecMapper,
+};
+use crate::{
+    abi::{
+        StorageMapperAbi, StructFieldDescription, TypeAbi, TypeContents, TypeDescription,
+        TypeDescriptionContainer, TypeName,
+    },
+    api::{ErrorApiImpl, StorageMapperApi},
+    codec::{
+        self,
+        derive::{NestedDecode, NestedEncode, TopDecode, TopEncode},
+        multi_encode_iter_or_handle_err, CodecFrom, EncodeErrorHandler, NestedDecode, NestedEncode,
+        TopEncodeMulti, TopEncodeMultiOutput,
+    },
+    storage::StorageKey,
+    types::{ManagedAddress, MultiValueEncoded},
+};
+
+static CHECKPOINT_IN_THE_PAST_ERR_MSG: &[u8] = b"checkpoint timepoint in the past";
+
+/// A value, together with the moment from which it applies.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct Checkpoint<T: NestedEncode + NestedDecode> {
+    pub timepoint: u64,
+    pub value: T,
+}
+
+impl<T> TypeAbi for Checkpoint<T>
+where
+    T: NestedEncode + NestedDecode + TypeAbi,
+{
+    fn type_name() -> TypeName {
+        let mut repr = TypeName::from("Checkpoint<");
+        repr.push_str(T::type_name().as_str());
+        repr.push('>');
+        repr
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        let type_name = Self::type_name();
+        if !accumulator.contains_type(&type_name) {
+            accumulator.reserve_type_name(type_name.clone());
+            let field_descriptions = alloc::vec![
+                StructFieldDescription::new(&[], "timepoint", u64::type_name()),
+                StructFieldDescription::new(&[], "value", T::type_name()),
+            ];
+            T::provide_type_descriptions(accumulator);
+            accumulator.insert(
+                type_name.clone(),
+                TypeDescription::new(
+                    &[" A value, together with the moment from which it applies."],
+                    type_name,
+                    TypeContents::Struct(field_descriptions),
+                ),
+            );
+        }
+    }
+}
+
+/// Keeps the history of a value, as a list of checkpoints sorted by timepoint,
+/// so that it can also answer what the value was at any moment in the past.
+///
+/// Timepoints are only required to never decrease. They are usually block nonces, but epochs or timestamps also work,
+/// as long as the same kind is used for all the checkpoints of a mapper.
+///
+/// Historical lookups perform a binary search, so they only load a logarithmic number of checkpoints.
+pub struct CheckpointMapper<SA, T, A = CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Clone + 'static,
+{
+    _phantom_api: PhantomData<SA>,
+    checkpoints: VecMapper<SA, Checkpoint<T>, A>,
+}
+
+impl<SA, T> StorageMapper<SA> for CheckpointMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Clone,
+{
+    fn new(base_key: StorageKey<SA>) -> Self {
+        CheckpointMapper {
+            _phantom_api: PhantomData,
+            checkpoints: VecMapper::new(base_key),
+        }
+    }
+}
+
+impl<SA, T> CheckpointMapper<SA, T, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Clone,
+{
+    pub fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        CheckpointMapper {
+            _phantom_api: PhantomData,
+            checkpoints: VecMapper::new_from_address(address, base_key),
+        }
+    }
+}
+
+impl<SA, T> StorageClearable for CheckpointMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Clone,
+{
+    fn clear(&mut self) {
+        self.checkpoints.clear();
+    }
+}
+
+impl<SA, T, A> CheckpointMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + Clone,
+{
+    /// Number of checkpoints.
+    pub fn len(&self) -> usize {
+        self.checkpoints.len()
+    }
+
+    /// True if no value was ever recorded.
+    pub fn is_empty(&self) -> bool {
+        self.checkpoints.is_empty()
+    }
+
+    /// Get the checkpoint at index. Indexes start from 1, in chronological order.
+    /// Index must be valid (1 <= index <= count).
+    pub fn get(&self, index: usize) -> Checkpoint<T> {
+        self.checkpoints.get(index)
+    }
+
+    /// The most recent checkpoint, if any.
+    pub fn latest_checkpoint(&self) -> Option<Checkpoint<T>> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        Some(self.checkpoints.get_unchecked(len))
+    }
+
+    /// The current value, if any was recorded.
+    pub fn latest(&self) -> Option<T> {
+        self.latest_checkpoint().map(|checkpoint| checkpoint.value)
+    }
+
+    /// The value that applied at the given timepoint,
+    /// i.e. the one from the last checkpoint at or before it.
+    ///
+    /// Returns `None` if the first checkpoint comes after the timepoint.
+    pub fn upper_lookup(&self, timepoint: u64) -> Option<T> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+
+        // lookups close to the present are the most common, so the latest checkpoint is checked first
+        let latest = self.checkpoints.get_unchecked(len);
+        if latest.timepoint <= timepoint {
+            return Some(latest.value);
+        }
+
+        // invariant: all checkpoints before `low` are at or before the timepoint,
+        // all checkpoints from `high` onwards are after it
+        let mut low = 1;
+        let mut high = len;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.checkpoints.get_unchecked(mid).timepoint <= timepoint {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        if low == 1 {
+            None
+        } else {
+            Some(self.checkpoints.get_unchecked(low - 1).value)
+        }
+    }
+
+    /// Provides a forward iterator over all checkpoints, in chronological order.
+    pub fn iter(&self) -> Iter<SA, Checkpoint<T>, A> {
+        self.checkpoints.iter()
+    }
+}
+
+impl<SA, T> CheckpointMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Clone,
+{
+    /// Records a new value, starting from the given timepoint.
+    ///
+    /// The timepoint cannot be before that of the latest checkpoint.
+    /// If it is the same, the latest checkpoint gets overwritten,
+    /// so there is at most one checkpoint per timepoint.
+    ///
+    /// Returns the previous value, if any.
+    pub fn push(&mut self, timepoint: u64, value: T) -> Option<T> {
+        let len = self.len();
+        if len == 0 {
+            self.checkpoints.push(&Checkpoint { timepoint, value });
+            return None;
+        }
+
+        let latest = self.checkpoints.get_unchecked(len);
+        if timepoint < latest.timepoint {
+            SA::error_api_impl().signal_error(CHECKPOINT_IN_THE_PAST_ERR_MSG);
+        }
+
+        let new_checkpoint = Checkpoint { timepoint, value };
+        if timepoint == latest.timepoint {
+            self.checkpoints.set(len, &new_checkpoint);
+        } else {
+            self.checkpoints.push(&new_checkpoint);
+        }
+        Some(latest.value)
+    }
+}
+
+impl<'a, SA, T, A> IntoIterator for &'a CheckpointMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + Clone + 'static,
+{
+    type Item = Checkpoint<T>;
+
+    type IntoIter = Iter<'a, SA, Checkpoint<T>, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Behaves like a MultiResultVec of checkpoints when an endpoint result.
+impl<SA, T> TopEncodeMulti for CheckpointMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Clone,
+{
+    fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        multi_encode_iter_or_handle_err(self.iter(), output, h)
+    }
+}
+
+impl<SA, T> CodecFrom<CheckpointMapper<SA, T, CurrentStorage>>
+    for MultiValueEncoded<SA, Checkpoint<T>>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Clone,
+{
+}
+
+/// Behaves like a MultiResultVec of checkpoints when an endpoint result.
+impl<SA, T> TypeAbi for CheckpointMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Clone + TypeAbi,
+{
+    fn type_name() -> TypeName {
+        crate::abi::type_name_variadic::<Checkpoint<T>>()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        Checkpoint::<T>::provide_type_descriptions(accumulator);
+    }
+
+    fn is_variadic() -> bool {
+        true
+    }
+}
+
+impl<SA, T> StorageMapperAbi for CheckpointMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + Clone + TypeAbi,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("CheckpointMapper")
+    }
+
+    fn value_type_names() -> alloc::vec::Vec<TypeName> {
+        alloc::vec![T::type_name()]
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
@@ -1,12 +1,13 @@
 use core::marker::PhantomData;
 
 use super::{
+    pagination::INVALID_CURSOR_ERR_MSG,
     set_mapper::{CurrentStorage, StorageAddress},
-    StorageClearable, StorageMapper,
+    PaginatedStorageMapper, StorageClearable, StorageMapper, StorageMapperPage,
 };
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
-    api::StorageMapperApi,
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
+    api::{ErrorApiImpl, StorageMapperApi},
     codec::{
         self,
         derive::{
@@ -595,6 +596,48 @@ where
     }
 }
 
+impl<SA, T, A> PaginatedStorageMapper<SA> for LinkedListMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + Clone,
+{
+    /// The id of the first node on the page.
+    type Cursor = u32;
+
+    type Item = T;
+
+    fn load_page(&self, cursor: Option<u32>, page_size: usize) -> StorageMapperPage<SA, u32, T> {
+        let mut node_id = match cursor {
+            Some(node_id) => {
+                if node_id == NULL_ENTRY || self.is_empty_node(node_id) {
+                    SA::error_api_impl().signal_error(INVALID_CURSOR_ERR_MSG);
+                }
+                node_id
+            },
+            None => self.get_info().front,
+        };
+
+        let mut items = MultiValueEncoded::new();
+        let mut num_items = 0;
+        while node_id != NULL_ENTRY && num_items < page_size {
+            let node = self.get_node(node_id);
+            node_id = node.next_id;
+            items.push(node.value);
+            num_items += 1;
+        }
+
+        StorageMapperPage {
+            items,
+            next_cursor: if node_id != NULL_ENTRY {
+                Some(node_id)
+            } else {
+                None
+            },
+        }
+    }
+}
+
 impl<SA, T> TopEncodeMulti for LinkedListMapper<SA, T>
 where
     SA: StorageMapperApi,
@@ -637,3 +680,21 @@ where
         true
     }
 }
+
+impl<SA, T> StorageMapperAbi for LinkedListMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + Clone + TypeAbi,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("LinkedListMapper")
+    }
+
+    fn value_type_names() -> Vec<TypeName> {
+        alloc::vec![T::type_name()]
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
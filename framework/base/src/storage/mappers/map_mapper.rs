@@ -2,10 +2,10 @@ use core::marker::PhantomData;
 
 use super::{
     set_mapper::{self, CurrentStorage, StorageAddress},
-    SetMapper, StorageClearable, StorageMapper,
+    PaginatedStorageMapper, SetMapper, StorageClearable, StorageMapper, StorageMapperPage,
 };
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
     codec::{
         multi_encode_iter_or_handle_err, multi_types::MultiValue2, CodecFrom, EncodeErrorHandler,
@@ -518,6 +518,38 @@ where
     }
 }
 
+impl<SA, A, K, V> PaginatedStorageMapper<SA> for MapMapper<SA, K, V, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode,
+    V: TopEncode + TopDecode,
+{
+    /// The first key on the page.
+    type Cursor = K;
+
+    type Item = MultiValue2<K, V>;
+
+    fn load_page(
+        &self,
+        cursor: Option<K>,
+        page_size: usize,
+    ) -> StorageMapperPage<SA, K, Self::Item> {
+        let mut keys = self.keys_set.iter_from_cursor(cursor);
+        StorageMapperPage {
+            items: keys
+                .by_ref()
+                .take(page_size)
+                .map(|key| {
+                    let value = self.get_mapped_value(&key);
+                    (key, value).into()
+                })
+                .collect(),
+            next_cursor: keys.next(),
+        }
+    }
+}
+
 /// Behaves like a MultiResultVec<MultiValue2<K, V>> when an endpoint result.
 impl<SA, K, V> TopEncodeMulti for MapMapper<SA, K, V, CurrentStorage>
 where
@@ -564,3 +596,23 @@ where
         true
     }
 }
+
+impl<SA, K, V> StorageMapperAbi for MapMapper<SA, K, V, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + TypeAbi,
+    V: TopEncode + TopDecode + TypeAbi,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("MapMapper")
+    }
+
+    fn value_type_names() -> alloc::vec::Vec<TypeName> {
+        alloc::vec![K::type_name(), V::type_name()]
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        K::provide_type_descriptions(accumulator);
+        V::provide_type_descriptions(accumulator);
+    }
+}
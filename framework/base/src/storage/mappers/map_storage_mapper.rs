@@ -5,6 +5,7 @@ use super::{
     SetMapper, StorageClearable, StorageMapper,
 };
 use crate::{
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
     codec::{NestedDecode, NestedEncode, TopDecode, TopEncode},
     storage::{self, StorageKey},
@@ -14,6 +15,14 @@ use crate::{
 const MAPPED_STORAGE_VALUE_IDENTIFIER: &[u8] = b".storage";
 type Keys<'a, SA, A, T> = set_mapper::Iter<'a, SA, A, T>;
 
+/// A map from keys to nested storage mappers, e.g. `MapStorageMapper<ManagedAddress, SetMapper<u64>>`.
+///
+/// Unlike a mapper with key arguments (`fn ids(&self, user: &ManagedAddress) -> SetMapper<u64>`),
+/// it keeps track of its keys, so all the nested mappers can be iterated over.
+/// Removing a key clears its nested mapper, so the nested mapper type needs to be `StorageClearable`.
+///
+/// Each nested mapper lives under the base key, followed by `.storage` and the encoded key.
+/// Nested mappers can themselves be `MapStorageMapper`s.
 pub struct MapStorageMapper<SA, K, V, A = CurrentStorage>
 where
     SA: StorageMapperApi,
@@ -72,7 +81,7 @@ where
         self.keys_set.insert(k)
     }
 
-    /// Removes the entry from the map.
+    /// Removes the entry from the map, clearing the whole nested mapper.
     ///
     /// If the entry was removed, `true` is returned.
     ///
@@ -454,3 +463,26 @@ where
         self.map.remove(&self.key);
     }
 }
+
+/// The nested mapper is part of the name, its value types follow the key type.
+impl<SA, K, V> StorageMapperAbi for MapStorageMapper<SA, K, V, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + TypeAbi,
+    V: StorageMapper<SA> + StorageClearable + StorageMapperAbi,
+{
+    fn mapper_name() -> TypeName {
+        alloc::format!("MapStorageMapper<{}>", V::mapper_name())
+    }
+
+    fn value_type_names() -> alloc::vec::Vec<TypeName> {
+        let mut value_type_names = alloc::vec![K::type_name()];
+        value_type_names.extend(V::value_type_names());
+        value_type_names
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        K::provide_type_descriptions(accumulator);
+        V::provide_type_descriptions(accumulator);
+    }
+}
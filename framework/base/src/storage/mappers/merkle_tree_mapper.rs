@@ -0,0 +1,287 @@
+use core::marker::PhantomData;
+
+use super::{
+    set_mapper::{CurrentStorage, StorageAddress},
+    StorageClearable, StorageMapper,
+};
+use crate::{
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
+    api::{CryptoApi, ErrorApiImpl, StorageMapperApi},
+    codec::{CodecFrom, EncodeErrorHandler, TopEncodeMulti, TopEncodeMultiOutput},
+    storage::{storage_clear, storage_set, StorageKey},
+    types::{
+        ManagedAddress, ManagedType, ManagedVec, MerkleHash, MerkleHasher, MerkleProofVerifier,
+    },
+};
+
+const DEPTH_SUFFIX: &[u8] = b".depth";
+const LEN_SUFFIX: &[u8] = b".len";
+const ROOT_SUFFIX: &[u8] = b".root";
+const FILLED_SUBTREE_SUFFIX: &[u8] = b".filled";
+const ZERO_SUFFIX: &[u8] = b".zero";
+
+/// Leaf indexes are u64, but deeper trees would not be practical anyway.
+pub const MERKLE_TREE_MAX_DEPTH: u8 = 32;
+
+static ALREADY_INITIALIZED_ERR_MSG: &[u8] = b"Merkle tree already initialized";
+static NOT_INITIALIZED_ERR_MSG: &[u8] = b"Merkle tree not initialized";
+static INVALID_DEPTH_ERR_MSG: &[u8] = b"invalid Merkle tree depth";
+static TREE_FULL_ERR_MSG: &[u8] = b"Merkle tree is full";
+
+/// An append-only Merkle tree of fixed depth, which keeps its root up to date on every insert.
+///
+/// Only the rightmost filled subtree of each level is kept in storage (besides the root and the leaf count),
+/// so an insert loads and saves at most one node per level. The leaves themselves are not stored,
+/// whoever needs to build proofs has to collect them off-chain (e.g. from events).
+///
+/// Empty leaves are all-zero hashes. Proofs are indexed: at each level, the leaf index decides
+/// whether the sibling is on the left or on the right, see `MerkleProofVerifier::verify_indexed_proof`.
+pub struct MerkleTreeMapper<SA, H, A = CurrentStorage>
+where
+    SA: StorageMapperApi + CryptoApi,
+    H: MerkleHasher,
+    A: StorageAddress<SA>,
+{
+    _phantom_hasher: PhantomData<H>,
+    address: A,
+    base_key: StorageKey<SA>,
+}
+
+impl<SA, H> StorageMapper<SA> for MerkleTreeMapper<SA, H, CurrentStorage>
+where
+    SA: StorageMapperApi + CryptoApi,
+    H: MerkleHasher,
+{
+    fn new(base_key: StorageKey<SA>) -> Self {
+        MerkleTreeMapper {
+            _phantom_hasher: PhantomData,
+            address: CurrentStorage,
+            base_key,
+        }
+    }
+}
+
+impl<SA, H> MerkleTreeMapper<SA, H, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi + CryptoApi,
+    H: MerkleHasher,
+{
+    pub fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        MerkleTreeMapper {
+            _phantom_hasher: PhantomData,
+            address,
+            base_key,
+        }
+    }
+}
+
+impl<SA, H, A> MerkleTreeMapper<SA, H, A>
+where
+    SA: StorageMapperApi + CryptoApi,
+    H: MerkleHasher,
+    A: StorageAddress<SA>,
+{
+    fn build_key(&self, suffix: &[u8]) -> StorageKey<SA> {
+        let mut key = self.base_key.clone();
+        key.append_bytes(suffix);
+        key
+    }
+
+    fn build_level_key(&self, suffix: &[u8], level: u8) -> StorageKey<SA> {
+        let mut key = self.build_key(suffix);
+        key.append_item(&level);
+        key
+    }
+
+    /// Number of levels below the root. The tree has room for 2^depth leaves.
+    ///
+    /// Zero means that the tree was not initialized yet.
+    pub fn depth(&self) -> u8 {
+        self.address
+            .address_storage_get(self.build_key(DEPTH_SUFFIX).as_ref())
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.depth() != 0
+    }
+
+    /// Number of leaves inserted so far.
+    pub fn len(&self) -> u64 {
+        self.address
+            .address_storage_get(self.build_key(LEN_SUFFIX).as_ref())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Maximum number of leaves.
+    pub fn capacity(&self) -> u64 {
+        1u64 << self.depth()
+    }
+
+    /// The root of the tree, with all the leaves not yet inserted considered empty.
+    pub fn root(&self) -> MerkleHash<SA> {
+        self.address
+            .address_storage_get(self.build_key(ROOT_SUFFIX).as_ref())
+    }
+
+    /// The root of a subtree of the given height, with only empty leaves.
+    fn zero_hash(&self, level: u8) -> MerkleHash<SA> {
+        self.address
+            .address_storage_get(self.build_level_key(ZERO_SUFFIX, level).as_ref())
+    }
+
+    /// The root of the last subtree of the given height whose leaves are all inserted,
+    /// only relevant while it is the left child of its parent.
+    fn filled_subtree(&self, level: u8) -> MerkleHash<SA> {
+        self.address
+            .address_storage_get(self.build_level_key(FILLED_SUBTREE_SUFFIX, level).as_ref())
+    }
+
+    /// Checks that the leaf with the given index is part of the current tree.
+    pub fn verify_proof(
+        &self,
+        leaf: &MerkleHash<SA>,
+        leaf_index: u64,
+        proof: &ManagedVec<SA, MerkleHash<SA>>,
+    ) -> bool {
+        proof.len() == self.depth() as usize
+            && leaf_index < self.len()
+            && MerkleProofVerifier::<SA, H>::new().verify_indexed_proof(
+                &self.root(),
+                leaf,
+                leaf_index,
+                proof,
+            )
+    }
+}
+
+impl<SA, H> MerkleTreeMapper<SA, H, CurrentStorage>
+where
+    SA: StorageMapperApi + CryptoApi,
+    H: MerkleHasher,
+{
+    /// Sets the depth of the tree. Can only be called once.
+    pub fn init(&self, depth: u8) {
+        if self.is_initialized() {
+            SA::error_api_impl().signal_error(ALREADY_INITIALIZED_ERR_MSG);
+        }
+        if depth == 0 || depth > MERKLE_TREE_MAX_DEPTH {
+            SA::error_api_impl().signal_error(INVALID_DEPTH_ERR_MSG);
+        }
+
+        let verifier = MerkleProofVerifier::<SA, H>::new();
+        let mut zero_hash = MerkleHash::<SA>::default();
+        for level in 0..depth {
+            storage_set(
+                self.build_level_key(ZERO_SUFFIX, level).as_ref(),
+                &zero_hash,
+            );
+            zero_hash = verifier.hash_pair(&zero_hash, &zero_hash);
+        }
+
+        storage_set(self.build_key(DEPTH_SUFFIX).as_ref(), &depth);
+        storage_set(self.build_key(ROOT_SUFFIX).as_ref(), &zero_hash);
+    }
+
+    /// Appends a leaf and updates the root.
+    ///
+    /// Returns the index of the new leaf, needed to verify it later.
+    pub fn insert(&mut self, leaf: &MerkleHash<SA>) -> u64 {
+        let depth = self.depth();
+        if depth == 0 {
+            SA::error_api_impl().signal_error(NOT_INITIALIZED_ERR_MSG);
+        }
+        let leaf_index = self.len();
+        if leaf_index >= self.capacity() {
+            SA::error_api_impl().signal_error(TREE_FULL_ERR_MSG);
+        }
+
+        let verifier = MerkleProofVerifier::<SA, H>::new();
+        let mut current_hash = leaf.clone();
+        let mut index = leaf_index;
+        for level in 0..depth {
+            current_hash = if index & 1 == 0 {
+                // the right sibling is still empty, and the current node becomes the filled subtree of its level
+                storage_set(
+                    self.build_level_key(FILLED_SUBTREE_SUFFIX, level).as_ref(),
+                    &current_hash,
+                );
+                verifier.hash_pair(&current_hash, &self.zero_hash(level))
+            } else {
+                verifier.hash_pair(&self.filled_subtree(level), &current_hash)
+            };
+            index >>= 1;
+        }
+
+        storage_set(self.build_key(ROOT_SUFFIX).as_ref(), &current_hash);
+        storage_set(self.build_key(LEN_SUFFIX).as_ref(), &(leaf_index + 1));
+        leaf_index
+    }
+}
+
+impl<SA, H> StorageClearable for MerkleTreeMapper<SA, H, CurrentStorage>
+where
+    SA: StorageMapperApi + CryptoApi,
+    H: MerkleHasher,
+{
+    /// Removes everything, including the depth, so the tree can be initialized again.
+    fn clear(&mut self) {
+        let depth = self.depth();
+        for level in 0..depth {
+            storage_clear(self.build_level_key(ZERO_SUFFIX, level).as_ref());
+            storage_clear(self.build_level_key(FILLED_SUBTREE_SUFFIX, level).as_ref());
+        }
+        storage_clear(self.build_key(DEPTH_SUFFIX).as_ref());
+        storage_clear(self.build_key(LEN_SUFFIX).as_ref());
+        storage_clear(self.build_key(ROOT_SUFFIX).as_ref());
+    }
+}
+
+/// Behaves like the root when an endpoint result.
+impl<SA, H> TopEncodeMulti for MerkleTreeMapper<SA, H, CurrentStorage>
+where
+    SA: StorageMapperApi + CryptoApi,
+    H: MerkleHasher,
+{
+    fn multi_encode_or_handle_err<O, EH>(&self, output: &mut O, h: EH) -> Result<(), EH::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        EH: EncodeErrorHandler,
+    {
+        output.push_single_value(&self.root(), h)
+    }
+}
+
+impl<SA, H> CodecFrom<MerkleTreeMapper<SA, H, CurrentStorage>> for MerkleHash<SA>
+where
+    SA: StorageMapperApi + CryptoApi,
+    H: MerkleHasher,
+{
+}
+
+/// Behaves like the root when an endpoint result.
+impl<SA, H> TypeAbi for MerkleTreeMapper<SA, H, CurrentStorage>
+where
+    SA: StorageMapperApi + CryptoApi,
+    H: MerkleHasher,
+{
+    fn type_name() -> TypeName {
+        MerkleHash::<SA>::type_name()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        MerkleHash::<SA>::provide_type_descriptions(accumulator)
+    }
+}
+
+impl<SA, H> StorageMapperAbi for MerkleTreeMapper<SA, H, CurrentStorage>
+where
+    SA: StorageMapperApi + CryptoApi,
+    H: MerkleHasher,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("MerkleTreeMapper")
+    }
+}
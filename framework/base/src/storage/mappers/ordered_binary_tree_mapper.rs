@@ -3,6 +3,7 @@ use core::marker::PhantomData;
 use codec::Empty;
 
 use crate::{
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
     imports::{ErrorApiImpl, ManagedType},
     storage::StorageKey,
@@ -470,3 +471,21 @@ where
         storage_set(key.as_ref(), &Empty);
     }
 }
+
+impl<SA, T> StorageMapperAbi for OrderedBinaryTreeMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + PartialOrd + PartialEq + Clone + TypeAbi + 'static,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("OrderedBinaryTreeMapper")
+    }
+
+    fn value_type_names() -> alloc::vec::Vec<TypeName> {
+        alloc::vec![T::type_name()]
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{
+    api::ManagedTypeApi,
+    codec::{multi_types::MultiValue2, TopEncodeMulti},
+    types::MultiValueEncoded,
+};
+
+pub(super) static INVALID_CURSOR_ERR_MSG: &[u8] = b"invalid cursor";
+
+/// Storage mappers that can be read one page at a time,
+/// so that views over large collections stay within the query gas limit.
+///
+/// Generated automatically as an endpoint by the `#[paginated_view]` annotation.
+pub trait PaginatedStorageMapper<M>
+where
+    M: ManagedTypeApi,
+{
+    /// Points to the first item of a page.
+    ///
+    /// Cursors are only valid as long as the item they point to is still in the collection.
+    /// Loading a page from an invalid cursor signals an error.
+    type Cursor;
+
+    type Item: TopEncodeMulti;
+
+    /// Loads at most `page_size` items, starting with the one the cursor points to,
+    /// or with the first one, if there is no cursor.
+    fn load_page(
+        &self,
+        cursor: Option<Self::Cursor>,
+        page_size: usize,
+    ) -> StorageMapperPage<M, Self::Cursor, Self::Item>;
+}
+
+/// A page of items, loaded from a storage mapper.
+///
+/// The items are kept encoded in managed memory, the same way they are returned from an endpoint.
+pub struct StorageMapperPage<M, C, T>
+where
+    M: ManagedTypeApi,
+{
+    pub items: MultiValueEncoded<M, T>,
+
+    /// Points to the first item of the next page, `None` if this was the last page.
+    pub next_cursor: Option<C>,
+}
+
+impl<M, C, T> StorageMapperPage<M, C, T>
+where
+    M: ManagedTypeApi,
+{
+    /// Endpoint result form. The next cursor comes first, since the items are variadic.
+    pub fn into_multi_value(self) -> MultiValue2<Option<C>, MultiValueEncoded<M, T>> {
+        (self.next_cursor, self.items).into()
+    }
+}
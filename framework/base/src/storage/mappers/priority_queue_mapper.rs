@@ -0,0 +1,564 @@
+use core::marker::PhantomData;
+
+use super::{
+    set_mapper::{CurrentStorage, StorageAddress},
+    StorageClearable, StorageMapper,
+};
+use crate::{
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
+    api::StorageMapperApi,
+    codec::{
+        self,
+        derive::{TopDecodeOrDefault, TopEncodeOrDefault},
+        multi_encode_iter_or_handle_err, CodecFrom, DecodeDefault, EncodeDefault,
+        EncodeErrorHandler, TopDecode, TopEncode, TopEncodeMulti, TopEncodeMultiOutput,
+    },
+    storage::{storage_clear, storage_set, StorageKey},
+    types::{ManagedAddress, ManagedType, MultiValueEncoded},
+};
+
+const INFO_IDENTIFIER: &[u8] = b".info";
+const SLOT_IDENTIFIER: &[u8] = b".slot";
+const POSITION_IDENTIFIER: &[u8] = b".pos";
+const VALUE_IDENTIFIER: &[u8] = b".value";
+
+/// Identifies an entry for as long as it is in the queue, regardless of where the heap moves it.
+pub type PriorityQueueEntryId = u32;
+
+/// Decides which of two items leaves the queue first.
+pub trait PriorityQueueOrder<T>: 'static {
+    /// True if `a` should be popped before `b`.
+    fn has_priority(a: &T, b: &T) -> bool;
+}
+
+/// The smallest item is popped first. The default.
+pub struct MinFirst;
+
+impl<T: Ord> PriorityQueueOrder<T> for MinFirst {
+    fn has_priority(a: &T, b: &T) -> bool {
+        a < b
+    }
+}
+
+/// The largest item is popped first.
+pub struct MaxFirst;
+
+impl<T: Ord> PriorityQueueOrder<T> for MaxFirst {
+    fn has_priority(a: &T, b: &T) -> bool {
+        a > b
+    }
+}
+
+#[derive(TopEncodeOrDefault, TopDecodeOrDefault, PartialEq, Eq, Clone, Copy)]
+pub struct PriorityQueueMapperInfo {
+    pub len: u32,
+    pub last_id: PriorityQueueEntryId,
+}
+
+impl EncodeDefault for PriorityQueueMapperInfo {
+    fn is_default(&self) -> bool {
+        self.len == 0 && self.last_id == 0
+    }
+}
+
+impl DecodeDefault for PriorityQueueMapperInfo {
+    fn default() -> Self {
+        Self { len: 0, last_id: 0 }
+    }
+}
+
+/// A priority queue, organized as a binary heap.
+///
+/// The heap is an array of entry ids, saved one slot per key, starting from slot 1.
+/// The values are saved by entry id, so they never move; only the ids do.
+/// Each entry also keeps track of its current slot, so that it can be removed by id.
+///
+/// Push, pop and remove all load and save a logarithmic number of slots.
+/// Items with the same priority are popped in the order they were pushed.
+pub struct PriorityQueueMapper<SA, T, O = MinFirst, A = CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + 'static,
+    O: PriorityQueueOrder<T>,
+    A: StorageAddress<SA>,
+{
+    _phantom_api: PhantomData<SA>,
+    _phantom_item: PhantomData<T>,
+    _phantom_order: PhantomData<O>,
+    address: A,
+    base_key: StorageKey<SA>,
+}
+
+impl<SA, T, O> StorageMapper<SA> for PriorityQueueMapper<SA, T, O, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+    O: PriorityQueueOrder<T>,
+{
+    fn new(base_key: StorageKey<SA>) -> Self {
+        PriorityQueueMapper {
+            _phantom_api: PhantomData,
+            _phantom_item: PhantomData,
+            _phantom_order: PhantomData,
+            address: CurrentStorage,
+            base_key,
+        }
+    }
+}
+
+impl<SA, T, O> PriorityQueueMapper<SA, T, O, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+    O: PriorityQueueOrder<T>,
+{
+    pub fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        PriorityQueueMapper {
+            _phantom_api: PhantomData,
+            _phantom_item: PhantomData,
+            _phantom_order: PhantomData,
+            address,
+            base_key,
+        }
+    }
+}
+
+impl<SA, T, O> StorageClearable for PriorityQueueMapper<SA, T, O, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+    O: PriorityQueueOrder<T>,
+{
+    /// Removes all entries. Entry ids are not reused afterwards.
+    fn clear(&mut self) {
+        let mut info = self.get_info();
+        for slot in 1..=info.len {
+            let id = self.get_slot(slot);
+            storage_clear(self.build_id_key(VALUE_IDENTIFIER, id).as_ref());
+            storage_clear(self.build_id_key(POSITION_IDENTIFIER, id).as_ref());
+            storage_clear(self.build_id_key(SLOT_IDENTIFIER, slot).as_ref());
+        }
+        info.len = 0;
+        self.set_info(info);
+    }
+}
+
+impl<SA, T, O, A> PriorityQueueMapper<SA, T, O, A>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+    O: PriorityQueueOrder<T>,
+    A: StorageAddress<SA>,
+{
+    fn build_name_key(&self, name: &[u8]) -> StorageKey<SA> {
+        let mut key = self.base_key.clone();
+        key.append_bytes(name);
+        key
+    }
+
+    /// Used for both entry ids and slots.
+    fn build_id_key(&self, name: &[u8], id: u32) -> StorageKey<SA> {
+        let mut key = self.base_key.clone();
+        key.append_bytes(name);
+        key.append_item(&id);
+        key
+    }
+
+    fn get_info(&self) -> PriorityQueueMapperInfo {
+        self.address
+            .address_storage_get(self.build_name_key(INFO_IDENTIFIER).as_ref())
+    }
+
+    fn get_slot(&self, slot: u32) -> PriorityQueueEntryId {
+        self.address
+            .address_storage_get(self.build_id_key(SLOT_IDENTIFIER, slot).as_ref())
+    }
+
+    fn get_position(&self, id: PriorityQueueEntryId) -> u32 {
+        self.address
+            .address_storage_get(self.build_id_key(POSITION_IDENTIFIER, id).as_ref())
+    }
+
+    fn get_value(&self, id: PriorityQueueEntryId) -> T {
+        self.address
+            .address_storage_get(self.build_id_key(VALUE_IDENTIFIER, id).as_ref())
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.get_info().len as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.get_info().len == 0
+    }
+
+    /// The id of the entry that would be popped next.
+    pub fn peek_id(&self) -> Option<PriorityQueueEntryId> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(self.get_slot(1))
+    }
+
+    /// The item that would be popped next.
+    pub fn peek(&self) -> Option<T> {
+        self.peek_id().map(|id| self.get_value(id))
+    }
+
+    /// True if the entry is still in the queue.
+    pub fn contains_id(&self, id: PriorityQueueEntryId) -> bool {
+        self.get_position(id) != 0
+    }
+
+    /// The item of an entry, if it is still in the queue.
+    pub fn get(&self, id: PriorityQueueEntryId) -> Option<T> {
+        if !self.contains_id(id) {
+            return None;
+        }
+        Some(self.get_value(id))
+    }
+
+    /// Iterates over the entries in heap order, i.e. in the order they are saved in storage.
+    /// Only the first one is guaranteed to be in priority order.
+    pub fn iter(&self) -> Iter<SA, T, O, A> {
+        Iter::new(self)
+    }
+
+    /// Iterates over the entry ids in heap order.
+    pub fn iter_ids(&self) -> IdIter<SA, T, O, A> {
+        IdIter::new(self)
+    }
+
+    /// Checks the heap property and the position of every entry. Loads every entry, meant for testing.
+    pub fn check_internal_consistency(&self) -> bool {
+        let len = self.get_info().len;
+        for slot in 1..=len {
+            let id = self.get_slot(slot);
+            if id == 0 || self.get_position(id) != slot {
+                return false;
+            }
+            if slot > 1 {
+                let parent_id = self.get_slot(slot / 2);
+                if self.entry_has_priority(
+                    id,
+                    &self.get_value(id),
+                    parent_id,
+                    &self.get_value(parent_id),
+                ) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Ties are broken by id, so that equal items leave in the order they came in.
+    fn entry_has_priority(
+        &self,
+        a_id: PriorityQueueEntryId,
+        a: &T,
+        b_id: PriorityQueueEntryId,
+        b: &T,
+    ) -> bool {
+        O::has_priority(a, b) || (!O::has_priority(b, a) && a_id < b_id)
+    }
+}
+
+impl<SA, T, O> PriorityQueueMapper<SA, T, O, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+    O: PriorityQueueOrder<T>,
+{
+    fn set_info(&mut self, info: PriorityQueueMapperInfo) {
+        storage_set(self.build_name_key(INFO_IDENTIFIER).as_ref(), &info);
+    }
+
+    /// Places the entry in the slot, and lets it know where it is.
+    fn place(&mut self, slot: u32, id: PriorityQueueEntryId) {
+        storage_set(self.build_id_key(SLOT_IDENTIFIER, slot).as_ref(), &id);
+        storage_set(self.build_id_key(POSITION_IDENTIFIER, id).as_ref(), &slot);
+    }
+
+    /// Moves the entry up from the given slot, until its parent has priority over it.
+    /// Parents are moved down one level along the way.
+    ///
+    /// Returns true if the entry moved.
+    fn sift_up(&mut self, mut slot: u32, id: PriorityQueueEntryId, value: &T) -> bool {
+        let start_slot = slot;
+        while slot > 1 {
+            let parent_slot = slot / 2;
+            let parent_id = self.get_slot(parent_slot);
+            let parent_value = self.get_value(parent_id);
+            if !self.entry_has_priority(id, value, parent_id, &parent_value) {
+                break;
+            }
+            self.place(slot, parent_id);
+            slot = parent_slot;
+        }
+        if slot != start_slot {
+            self.place(slot, id);
+        }
+        slot != start_slot
+    }
+
+    /// Moves the entry down from the given slot, until it has priority over its children.
+    /// The child with priority is moved up one level each time.
+    fn sift_down(&mut self, mut slot: u32, len: u32, id: PriorityQueueEntryId, value: &T) {
+        loop {
+            let left_slot = slot * 2;
+            if left_slot > len {
+                break;
+            }
+
+            let mut child_slot = left_slot;
+            let mut child_id = self.get_slot(left_slot);
+            let mut child_value = self.get_value(child_id);
+            let right_slot = left_slot + 1;
+            if right_slot <= len {
+                let right_id = self.get_slot(right_slot);
+                let right_value = self.get_value(right_id);
+                if self.entry_has_priority(right_id, &right_value, child_id, &child_value) {
+                    child_slot = right_slot;
+                    child_id = right_id;
+                    child_value = right_value;
+                }
+            }
+
+            if !self.entry_has_priority(child_id, &child_value, id, value) {
+                break;
+            }
+            self.place(slot, child_id);
+            slot = child_slot;
+        }
+        self.place(slot, id);
+    }
+
+    /// Adds an item to the queue.
+    ///
+    /// Returns the id of the new entry, which can be used to remove it later.
+    pub fn push(&mut self, item: T) -> PriorityQueueEntryId {
+        let mut info = self.get_info();
+        info.last_id += 1;
+        info.len += 1;
+        let id = info.last_id;
+        self.set_info(info);
+
+        storage_set(self.build_id_key(VALUE_IDENTIFIER, id).as_ref(), &item);
+        if !self.sift_up(info.len, id, &item) {
+            self.place(info.len, id);
+        }
+        id
+    }
+
+    /// Removes and returns the item with the highest priority.
+    pub fn pop(&mut self) -> Option<T> {
+        let id = self.peek_id()?;
+        self.remove(id)
+    }
+
+    /// Removes an entry, wherever it is in the heap.
+    ///
+    /// Returns its item, or `None` if it was not in the queue.
+    pub fn remove(&mut self, id: PriorityQueueEntryId) -> Option<T> {
+        let slot = self.get_position(id);
+        if slot == 0 {
+            return None;
+        }
+        let value = self.get_value(id);
+
+        let mut info = self.get_info();
+        let last_slot = info.len;
+        info.len -= 1;
+        self.set_info(info);
+
+        storage_clear(self.build_id_key(VALUE_IDENTIFIER, id).as_ref());
+        storage_clear(self.build_id_key(POSITION_IDENTIFIER, id).as_ref());
+
+        let last_id = self.get_slot(last_slot);
+        storage_clear(self.build_id_key(SLOT_IDENTIFIER, last_slot).as_ref());
+        if slot != last_slot {
+            // the last entry fills the gap, then moves either up or down
+            let last_value = self.get_value(last_id);
+            if !self.sift_up(slot, last_id, &last_value) {
+                self.sift_down(slot, info.len, last_id, &last_value);
+            }
+        }
+
+        Some(value)
+    }
+}
+
+impl<'a, SA, T, O, A> IntoIterator for &'a PriorityQueueMapper<SA, T, O, A>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + 'static,
+    O: PriorityQueueOrder<T>,
+    A: StorageAddress<SA>,
+{
+    type Item = T;
+
+    type IntoIter = Iter<'a, SA, T, O, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterates over the entry ids of a `PriorityQueueMapper`, in heap order.
+pub struct IdIter<'a, SA, T, O, A>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + 'static,
+    O: PriorityQueueOrder<T>,
+    A: StorageAddress<SA>,
+{
+    slot: u32,
+    len: u32,
+    queue: &'a PriorityQueueMapper<SA, T, O, A>,
+}
+
+impl<'a, SA, T, O, A> IdIter<'a, SA, T, O, A>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + 'static,
+    O: PriorityQueueOrder<T>,
+    A: StorageAddress<SA>,
+{
+    fn new(queue: &'a PriorityQueueMapper<SA, T, O, A>) -> Self {
+        IdIter {
+            slot: 1,
+            len: queue.get_info().len,
+            queue,
+        }
+    }
+}
+
+impl<'a, SA, T, O, A> Iterator for IdIter<'a, SA, T, O, A>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + 'static,
+    O: PriorityQueueOrder<T>,
+    A: StorageAddress<SA>,
+{
+    type Item = PriorityQueueEntryId;
+
+    #[inline]
+    fn next(&mut self) -> Option<PriorityQueueEntryId> {
+        if self.slot > self.len {
+            return None;
+        }
+        let id = self.queue.get_slot(self.slot);
+        self.slot += 1;
+        Some(id)
+    }
+}
+
+/// Iterates over the items of a `PriorityQueueMapper`, in heap order.
+pub struct Iter<'a, SA, T, O, A>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + 'static,
+    O: PriorityQueueOrder<T>,
+    A: StorageAddress<SA>,
+{
+    ids: IdIter<'a, SA, T, O, A>,
+}
+
+impl<'a, SA, T, O, A> Iter<'a, SA, T, O, A>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + 'static,
+    O: PriorityQueueOrder<T>,
+    A: StorageAddress<SA>,
+{
+    fn new(queue: &'a PriorityQueueMapper<SA, T, O, A>) -> Self {
+        Iter {
+            ids: IdIter::new(queue),
+        }
+    }
+}
+
+impl<'a, SA, T, O, A> Iterator for Iter<'a, SA, T, O, A>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + 'static,
+    O: PriorityQueueOrder<T>,
+    A: StorageAddress<SA>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        let id = self.ids.next()?;
+        Some(self.ids.queue.get_value(id))
+    }
+}
+
+/// Behaves like a MultiResultVec when an endpoint result, in heap order.
+impl<SA, T, O> TopEncodeMulti for PriorityQueueMapper<SA, T, O, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+    O: PriorityQueueOrder<T>,
+{
+    fn multi_encode_or_handle_err<OUT, H>(
+        &self,
+        output: &mut OUT,
+        h: H,
+    ) -> Result<(), H::HandledErr>
+    where
+        OUT: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        multi_encode_iter_or_handle_err(self.iter(), output, h)
+    }
+}
+
+impl<SA, T, O> CodecFrom<PriorityQueueMapper<SA, T, O, CurrentStorage>> for MultiValueEncoded<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+    O: PriorityQueueOrder<T>,
+{
+}
+
+/// Behaves like a MultiResultVec when an endpoint result.
+impl<SA, T, O> TypeAbi for PriorityQueueMapper<SA, T, O, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + TypeAbi,
+    O: PriorityQueueOrder<T>,
+{
+    fn type_name() -> TypeName {
+        crate::abi::type_name_variadic::<T>()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+
+    fn is_variadic() -> bool {
+        true
+    }
+}
+
+impl<SA, T, O> StorageMapperAbi for PriorityQueueMapper<SA, T, O, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + TypeAbi,
+    O: PriorityQueueOrder<T>,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("PriorityQueueMapper")
+    }
+
+    fn value_type_names() -> alloc::vec::Vec<TypeName> {
+        alloc::vec![T::type_name()]
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
@@ -3,10 +3,13 @@ use core::marker::PhantomData;
 use storage_get_from_address::storage_get_len_from_address;
 
 pub use super::queue_mapper::Iter;
-use super::{QueueMapper, StorageClearable, StorageMapper};
+use super::{
+    pagination::INVALID_CURSOR_ERR_MSG, PaginatedStorageMapper, QueueMapper, StorageClearable,
+    StorageMapper, StorageMapperPage,
+};
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
-    api::StorageMapperApi,
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
+    api::{ErrorApiImpl, StorageMapperApi},
     codec::{
         self, multi_encode_iter_or_handle_err, CodecFrom, EncodeErrorHandler, NestedDecode,
         NestedEncode, TopDecode, TopEncode, TopEncodeMulti, TopEncodeMultiOutput,
@@ -191,6 +194,19 @@ where
         self.queue_mapper.iter_from_node_id(node_id)
     }
 
+    /// Iterates starting with the page cursor, or from the start, if there is none.
+    pub(super) fn iter_from_cursor(&self, cursor: Option<T>) -> Iter<SA, A, T> {
+        let Some(value) = cursor else {
+            return self.iter();
+        };
+
+        let node_id = self.get_node_id(&value);
+        if node_id == NULL_ENTRY {
+            SA::error_api_impl().signal_error(INVALID_CURSOR_ERR_MSG);
+        }
+        self.queue_mapper.iter_from_node_id(node_id)
+    }
+
     fn get_node_id(&self, value: &T) -> u32 {
         self.address.address_storage_get(
             self.build_named_value_key(NODE_ID_IDENTIFIER, value)
@@ -279,6 +295,26 @@ where
     }
 }
 
+impl<SA, T, A> PaginatedStorageMapper<SA> for SetMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode,
+{
+    /// The first value on the page.
+    type Cursor = T;
+
+    type Item = T;
+
+    fn load_page(&self, cursor: Option<T>, page_size: usize) -> StorageMapperPage<SA, T, T> {
+        let mut iter = self.iter_from_cursor(cursor);
+        StorageMapperPage {
+            items: iter.by_ref().take(page_size).collect(),
+            next_cursor: iter.next(),
+        }
+    }
+}
+
 /// Behaves like a MultiResultVec when an endpoint result.
 impl<SA, T> TopEncodeMulti for SetMapper<SA, T, CurrentStorage>
 where
@@ -319,3 +355,21 @@ where
         true
     }
 }
+
+impl<SA, T> StorageMapperAbi for SetMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + TypeAbi,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("SetMapper")
+    }
+
+    fn value_type_names() -> alloc::vec::Vec<TypeName> {
+        alloc::vec![T::type_name()]
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
@@ -2,10 +2,10 @@ use core::{borrow::Borrow, marker::PhantomData};
 
 use super::{
     set_mapper::{CurrentStorage, StorageAddress},
-    StorageMapper,
+    StorageClearable, StorageMapper,
 };
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
     codec::{
         multi_types::PlaceholderOutput, CodecFrom, CodecFromSelf, DecodeErrorHandler,
@@ -45,6 +45,16 @@ where
     }
 }
 
+impl<SA, T> StorageClearable for SingleValueMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+    fn clear(&mut self) {
+        SingleValueMapper::clear(self);
+    }
+}
+
 impl<SA, T> SingleValueMapper<SA, T, ManagedAddress<SA>>
 where
     SA: StorageMapperApi,
@@ -230,3 +240,21 @@ where
         T::provide_type_descriptions(accumulator)
     }
 }
+
+impl<SA, T> StorageMapperAbi for SingleValueMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + TypeAbi,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("SingleValueMapper")
+    }
+
+    fn value_type_names() -> alloc::vec::Vec<TypeName> {
+        alloc::vec![T::type_name()]
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
@@ -11,7 +11,7 @@ use super::{
     TokenMapperState,
 };
 use crate::{
-    abi::TypeName,
+    abi::{StorageMapperAbi, TypeName},
     api::{CallTypeApi, StorageMapperApi},
     contract_base::{BlockchainWrapper, SendWrapper},
     esdt::{ESDTSystemSmartContractProxy, FungibleTokenProperties},
@@ -286,3 +286,12 @@ where
         false
     }
 }
+
+impl<SA> StorageMapperAbi for FungibleTokenMapper<SA>
+where
+    SA: StorageMapperApi + CallTypeApi,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("FungibleTokenMapper")
+    }
+}
@@ -12,7 +12,7 @@ use super::{
     TokenMapperState,
 };
 use crate::{
-    abi::{TypeAbi, TypeName},
+    abi::{StorageMapperAbi, TypeAbi, TypeName},
     api::{CallTypeApi, ErrorApiImpl, StorageMapperApi},
     contract_base::{BlockchainWrapper, SendWrapper},
     esdt::{
@@ -416,3 +416,12 @@ where
         false
     }
 }
+
+impl<SA> StorageMapperAbi for NonFungibleTokenMapper<SA>
+where
+    SA: StorageMapperApi + CallTypeApi,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("NonFungibleTokenMapper")
+    }
+}
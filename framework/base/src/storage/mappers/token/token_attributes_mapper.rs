@@ -4,6 +4,7 @@ use crate::codec::{NestedDecode, NestedEncode, TopDecode, TopEncode};
 
 use super::super::StorageMapper;
 use crate::{
+    abi::{StorageMapperAbi, TypeName},
     api::{ErrorApiImpl, ManagedTypeApi, StorageMapperApi},
     storage::{storage_clear, storage_get, storage_get_len, storage_set, StorageKey},
     types::{ManagedType, TokenIdentifier},
@@ -341,3 +342,12 @@ where
         );
     }
 }
+
+impl<SA> StorageMapperAbi for TokenAttributesMapper<SA>
+where
+    SA: StorageMapperApi,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("TokenAttributesMapper")
+    }
+}
@@ -8,10 +8,10 @@ use crate::{
 
 use super::{
     set_mapper::{CurrentStorage, StorageAddress},
-    StorageMapper, VecMapper,
+    StorageClearable, StorageMapper, VecMapper,
 };
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::{ErrorApiImpl, StorageMapperApi},
     storage::StorageKey,
     storage_set,
@@ -47,6 +47,16 @@ where
     }
 }
 
+impl<SA> StorageClearable for UniqueIdMapper<SA, CurrentStorage>
+where
+    SA: StorageMapperApi,
+{
+    /// Empties the mapper, the length can then be initialized again.
+    fn clear(&mut self) {
+        self.vec_mapper.clear();
+    }
+}
+
 impl<SA> UniqueIdMapper<SA, ManagedAddress<SA>>
 where
     SA: StorageMapperApi,
@@ -235,3 +245,12 @@ where
         true
     }
 }
+
+impl<SA> StorageMapperAbi for UniqueIdMapper<SA, CurrentStorage>
+where
+    SA: StorageMapperApi,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("UniqueIdMapper")
+    }
+}
@@ -3,10 +3,10 @@ use core::marker::PhantomData;
 pub use super::vec_mapper::Iter;
 use super::{
     set_mapper::{CurrentStorage, StorageAddress},
-    StorageClearable, StorageMapper, VecMapper,
+    PaginatedStorageMapper, StorageClearable, StorageMapper, StorageMapperPage, VecMapper,
 };
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
     codec::{
         multi_encode_iter_or_handle_err, CodecFrom, EncodeErrorHandler, NestedDecode, NestedEncode,
@@ -210,6 +210,27 @@ where
     }
 }
 
+impl<SA, T, A> PaginatedStorageMapper<SA> for UnorderedSetMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode,
+{
+    /// The index of the first item on the page.
+    /// Since removing items moves the last item in their place, it is best not to remove items while paginating.
+    type Cursor = usize;
+
+    type Item = T;
+
+    fn load_page(
+        &self,
+        cursor: Option<usize>,
+        page_size: usize,
+    ) -> StorageMapperPage<SA, usize, T> {
+        self.vec_mapper.load_page(cursor, page_size)
+    }
+}
+
 /// Behaves like a MultiResultVec when an endpoint result.
 impl<SA, T> TopEncodeMulti for UnorderedSetMapper<SA, T>
 where
@@ -250,3 +271,21 @@ where
         true
     }
 }
+
+impl<SA, T> StorageMapperAbi for UnorderedSetMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + TypeAbi,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("UnorderedSetMapper")
+    }
+
+    fn value_type_names() -> alloc::vec::Vec<TypeName> {
+        alloc::vec![T::type_name()]
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
@@ -10,7 +10,7 @@ use super::{
     StorageMapper,
 };
 use crate::{
-    abi::{TypeAbi, TypeName},
+    abi::{StorageMapperAbi, TypeAbi, TypeName},
     api::StorageMapperApi,
     storage::{storage_set, StorageKey},
     types::{ManagedAddress, ManagedType, ManagedVec, MultiValueEncoded},
@@ -237,3 +237,12 @@ where
         true
     }
 }
+
+impl<SA> StorageMapperAbi for UserMapper<SA>
+where
+    SA: StorageMapperApi,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("UserMapper")
+    }
+}
@@ -1,9 +1,10 @@
 use super::{
+    pagination::INVALID_CURSOR_ERR_MSG,
     set_mapper::{CurrentStorage, StorageAddress},
-    StorageClearable, StorageMapper,
+    PaginatedStorageMapper, StorageClearable, StorageMapper, StorageMapperPage,
 };
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::{ErrorApiImpl, StorageMapperApi},
     codec::{
         multi_encode_iter_or_handle_err, CodecFrom, EncodeErrorHandler, TopDecode, TopEncode,
@@ -329,6 +330,47 @@ where
     }
 }
 
+impl<SA, T, A> PaginatedStorageMapper<SA> for VecMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: TopEncode + TopDecode,
+{
+    /// The index of the first item on the page.
+    type Cursor = usize;
+
+    type Item = T;
+
+    fn load_page(
+        &self,
+        cursor: Option<usize>,
+        page_size: usize,
+    ) -> StorageMapperPage<SA, usize, T> {
+        let len = self.len();
+        let start_index = match cursor {
+            Some(index) => {
+                if index == 0 || index > len {
+                    SA::error_api_impl().signal_error(INVALID_CURSOR_ERR_MSG);
+                }
+                index
+            },
+            None => 1,
+        };
+        let end_index = core::cmp::min(start_index.saturating_add(page_size), len + 1);
+
+        StorageMapperPage {
+            items: (start_index..end_index)
+                .map(|index| self.get_unchecked(index))
+                .collect(),
+            next_cursor: if end_index <= len {
+                Some(end_index)
+            } else {
+                None
+            },
+        }
+    }
+}
+
 /// Behaves like a MultiResultVec when an endpoint result.
 impl<SA, T> TopEncodeMulti for VecMapper<SA, T, CurrentStorage>
 where
@@ -369,3 +411,21 @@ where
         true
     }
 }
+
+impl<SA, T> StorageMapperAbi for VecMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + TypeAbi,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("VecMapper")
+    }
+
+    fn value_type_names() -> alloc::vec::Vec<TypeName> {
+        alloc::vec![T::type_name()]
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
@@ -7,6 +7,7 @@ use super::{
     SingleValueMapper, StorageMapper,
 };
 use crate::{
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::{ErrorApiImpl, StorageMapperApi},
     codec::NestedEncode,
     storage::StorageKey,
@@ -116,3 +117,21 @@ where
         FlagMapper::<SA, CurrentStorage>::new(key)
     }
 }
+
+impl<SA, T> StorageMapperAbi for WhitelistMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + TypeAbi + 'static,
+{
+    fn mapper_name() -> TypeName {
+        TypeName::from("WhitelistMapper")
+    }
+
+    fn value_type_names() -> alloc::vec::Vec<TypeName> {
+        alloc::vec![T::type_name()]
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
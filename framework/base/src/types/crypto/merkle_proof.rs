@@ -0,0 +1,151 @@
+use core::marker::PhantomData;
+
+use crate::{
+    api::CryptoApi,
+    contract_base::CryptoWrapper,
+    types::{ManagedBuffer, ManagedByteArray, ManagedVec},
+};
+
+/// Both supported hash functions produce 32 bytes.
+pub const MERKLE_HASH_LEN: usize = 32;
+
+/// A Merkle tree node, leaf or root.
+pub type MerkleHash<M> = ManagedByteArray<M, MERKLE_HASH_LEN>;
+
+/// The hash function used to build a Merkle tree.
+pub trait MerkleHasher: 'static {
+    fn hash<A: CryptoApi>(data: &ManagedBuffer<A>) -> MerkleHash<A>;
+}
+
+pub struct Sha256MerkleHasher;
+
+impl MerkleHasher for Sha256MerkleHasher {
+    fn hash<A: CryptoApi>(data: &ManagedBuffer<A>) -> MerkleHash<A> {
+        CryptoWrapper::<A>::new().sha256(data)
+    }
+}
+
+pub struct Keccak256MerkleHasher;
+
+impl MerkleHasher for Keccak256MerkleHasher {
+    fn hash<A: CryptoApi>(data: &ManagedBuffer<A>) -> MerkleHash<A> {
+        CryptoWrapper::<A>::new().keccak256(data)
+    }
+}
+
+/// Verifies Merkle proofs, i.e. that a leaf belongs to the tree with a given root.
+///
+/// A proof is the list of sibling hashes on the path from the leaf up to the root. Two flavors are supported:
+/// - sorted-pair proofs, where each pair of siblings is sorted before hashing, so the proof needs no positions
+///   (the scheme used by OpenZeppelin's `MerkleProof`, and most airdrop tooling);
+/// - indexed proofs, where the leaf index decides at each level whether the sibling is on the left or on the right
+///   (the scheme used by `MerkleTreeMapper`).
+pub struct MerkleProofVerifier<A, H>
+where
+    A: CryptoApi,
+    H: MerkleHasher,
+{
+    _phantom: PhantomData<(A, H)>,
+}
+
+impl<A, H> Default for MerkleProofVerifier<A, H>
+where
+    A: CryptoApi,
+    H: MerkleHasher,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A, H> MerkleProofVerifier<A, H>
+where
+    A: CryptoApi,
+    H: MerkleHasher,
+{
+    pub fn new() -> Self {
+        MerkleProofVerifier {
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Hashes the concatenation of the two nodes.
+    pub fn hash_pair(&self, left: &MerkleHash<A>, right: &MerkleHash<A>) -> MerkleHash<A> {
+        let mut concatenated = left.as_managed_buffer().clone();
+        concatenated.append(right.as_managed_buffer());
+        H::hash(&concatenated)
+    }
+
+    /// Hashes the concatenation of the two nodes, the lesser one first.
+    pub fn hash_sorted_pair(&self, a: &MerkleHash<A>, b: &MerkleHash<A>) -> MerkleHash<A> {
+        if a.to_byte_array() <= b.to_byte_array() {
+            self.hash_pair(a, b)
+        } else {
+            self.hash_pair(b, a)
+        }
+    }
+
+    /// Rebuilds the root from a leaf and its sorted-pair proof.
+    pub fn process_sorted_proof(
+        &self,
+        leaf: &MerkleHash<A>,
+        proof: &ManagedVec<A, MerkleHash<A>>,
+    ) -> MerkleHash<A> {
+        let mut computed_hash = leaf.clone();
+        for sibling in proof.iter() {
+            computed_hash = self.hash_sorted_pair(&computed_hash, &sibling);
+        }
+        computed_hash
+    }
+
+    /// Checks a sorted-pair proof against the root.
+    pub fn verify_sorted_proof(
+        &self,
+        root: &MerkleHash<A>,
+        leaf: &MerkleHash<A>,
+        proof: &ManagedVec<A, MerkleHash<A>>,
+    ) -> bool {
+        &self.process_sorted_proof(leaf, proof) == root
+    }
+
+    /// Rebuilds the root from a leaf, its index among the leaves and its indexed proof.
+    ///
+    /// At each level, the current node is on the right if the corresponding bit of the index is set.
+    /// Returns `None` if the index does not fit in a tree as deep as the proof is long.
+    pub fn process_indexed_proof(
+        &self,
+        leaf: &MerkleHash<A>,
+        leaf_index: u64,
+        proof: &ManagedVec<A, MerkleHash<A>>,
+    ) -> Option<MerkleHash<A>> {
+        if proof.len() < 64 && leaf_index >> proof.len() != 0 {
+            return None;
+        }
+
+        let mut computed_hash = leaf.clone();
+        let mut index = leaf_index;
+        for sibling in proof.iter() {
+            computed_hash = if index & 1 == 0 {
+                self.hash_pair(&computed_hash, &sibling)
+            } else {
+                self.hash_pair(&sibling, &computed_hash)
+            };
+            index >>= 1;
+        }
+        Some(computed_hash)
+    }
+
+    /// Checks an indexed proof against the root.
+    pub fn verify_indexed_proof(
+        &self,
+        root: &MerkleHash<A>,
+        leaf: &MerkleHash<A>,
+        leaf_index: u64,
+        proof: &ManagedVec<A, MerkleHash<A>>,
+    ) -> bool {
+        match self.process_indexed_proof(leaf, leaf_index, proof) {
+            Some(computed_root) => &computed_root == root,
+            None => false,
+        }
+    }
+}
@@ -1,3 +1,5 @@
+mod merkle_proof;
 mod message_hash_type;
 
+pub use merkle_proof::*;
 pub use message_hash_type::MessageHashType;
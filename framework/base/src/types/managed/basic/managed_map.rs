@@ -73,3 +73,26 @@ impl<M: ManagedTypeApi> ManagedMap<M> {
         M::managed_type_impl().mm_contains(self.handle.clone(), key.handle.clone())
     }
 }
+
+/// Length and key listing, which rely on VM hooks that the Go VM does not provide yet.
+///
+/// Contracts using them only deploy on VMs that have the `managedMapLen` and `managedMapKeys` hooks.
+#[cfg(feature = "managed-map-iteration")]
+impl<M: ManagedTypeApi> ManagedMap<M> {
+    /// Number of keys. Keys with empty values are considered missing, so they are not counted.
+    pub fn len(&self) -> usize {
+        M::managed_type_impl().mm_len(self.handle.clone())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// All keys with non-empty values, in ascending byte order.
+    pub fn keys(&self) -> crate::types::ManagedVec<M, ManagedBuffer<M>> {
+        let new_handle: M::ManagedBufferHandle =
+            use_raw_handle(M::static_var_api_impl().next_handle());
+        M::managed_type_impl().mm_keys(self.handle.clone(), new_handle.clone());
+        crate::types::ManagedVec::from_handle(new_handle)
+    }
+}
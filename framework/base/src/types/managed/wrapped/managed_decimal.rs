@@ -0,0 +1,347 @@
+use crate::{
+    abi::{TypeAbi, TypeName},
+    api::ManagedTypeApi,
+    codec::{
+        CodecFromSelf, DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
+        NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
+        TopEncodeOutput,
+    },
+    types::{BigFloat, BigUint},
+};
+
+use alloc::string::ToString;
+
+/// The number of decimals of a `ManagedDecimal`, when only known at runtime.
+pub type NumDecimals = usize;
+
+/// Provides the number of decimals of a `ManagedDecimal`,
+/// either as a compile-time constant (`ConstDecimals`) or as a runtime value (`NumDecimals`).
+pub trait Decimals: Clone {
+    fn num_decimals(&self) -> NumDecimals;
+
+    /// 10 to the power of the number of decimals.
+    fn scaling_factor<M: ManagedTypeApi>(&self) -> BigUint<M> {
+        BigUint::from(10u32).pow(self.num_decimals() as u32)
+    }
+}
+
+impl Decimals for NumDecimals {
+    #[inline]
+    fn num_decimals(&self) -> NumDecimals {
+        *self
+    }
+}
+
+/// Number of decimals fixed at compile time, e.g. `ConstDecimals<18>`.
+///
+/// It takes up no space and is not serialized.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ConstDecimals<const DECIMALS: NumDecimals>;
+
+impl<const DECIMALS: NumDecimals> Decimals for ConstDecimals<DECIMALS> {
+    #[inline]
+    fn num_decimals(&self) -> NumDecimals {
+        DECIMALS
+    }
+}
+
+/// Decides what happens to the digits that do not fit in the number of decimals of the result.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Discards the extra digits.
+    #[default]
+    Down,
+
+    /// Rounds up whenever the extra digits are not all zero.
+    Up,
+
+    /// Rounds to the nearest value, halfway cases are rounded up.
+    HalfUp,
+
+    /// Rounds to the nearest value, halfway cases are rounded to the even neighbour.
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Divides, rounding the result according to the rounding mode.
+    pub(crate) fn div<M: ManagedTypeApi>(
+        self,
+        numerator: BigUint<M>,
+        denominator: &BigUint<M>,
+    ) -> BigUint<M> {
+        let quotient = &numerator / denominator;
+        if self == RoundingMode::Down {
+            return quotient;
+        }
+
+        let remainder = numerator % denominator;
+        let round_up = match self {
+            RoundingMode::Down => false,
+            RoundingMode::Up => remainder > 0u32,
+            RoundingMode::HalfUp => remainder * 2u32 >= *denominator,
+            RoundingMode::HalfEven => match (remainder * 2u32).cmp(denominator) {
+                core::cmp::Ordering::Less => false,
+                core::cmp::Ordering::Equal => &quotient % 2u32 == 1u32,
+                core::cmp::Ordering::Greater => true,
+            },
+        };
+        if round_up {
+            quotient + 1u32
+        } else {
+            quotient
+        }
+    }
+}
+
+/// Unsigned fixed-point decimal number, with an explicit number of decimals.
+///
+/// The value is kept as a `BigUint` number of units, each unit being 10^-decimals.
+/// For example, 1.5 with 18 decimals is kept as 1_500_000_000_000_000_000.
+///
+/// The number of decimals is either fixed by the type (`ConstDecimals<18>`),
+/// or chosen at runtime (`NumDecimals`), in which case it is also serialized.
+#[derive(Clone)]
+pub struct ManagedDecimal<M: ManagedTypeApi, D: Decimals> {
+    data: BigUint<M>,
+    decimals: D,
+}
+
+impl<M: ManagedTypeApi, D: Decimals> ManagedDecimal<M, D> {
+    /// Creates a decimal from a number of units, e.g. 1_500 units with 3 decimals are 1.5.
+    #[inline]
+    pub fn from_raw_units(data: BigUint<M>, decimals: D) -> Self {
+        ManagedDecimal { data, decimals }
+    }
+
+    /// Creates a decimal with no fractional part, e.g. 2 becomes 2.000 with 3 decimals.
+    pub fn from_integer(value: BigUint<M>, decimals: D) -> Self {
+        let data = value * decimals.scaling_factor();
+        ManagedDecimal { data, decimals }
+    }
+
+    #[inline]
+    pub fn raw_units(&self) -> &BigUint<M> {
+        &self.data
+    }
+
+    #[inline]
+    pub fn into_raw_units(self) -> BigUint<M> {
+        self.data
+    }
+
+    #[inline]
+    pub fn decimals(&self) -> &D {
+        &self.decimals
+    }
+
+    /// The number of decimals.
+    #[inline]
+    pub fn scale(&self) -> NumDecimals {
+        self.decimals.num_decimals()
+    }
+
+    #[inline]
+    pub fn scaling_factor(&self) -> BigUint<M> {
+        self.decimals.scaling_factor()
+    }
+
+    /// The integer part, i.e. the value with its decimals discarded.
+    pub fn trunc(&self) -> BigUint<M> {
+        &self.data / &self.scaling_factor()
+    }
+
+    /// Converts to a different number of decimals, rounding if some digits are lost.
+    pub fn rescale<T: Decimals>(
+        &self,
+        scale_to: T,
+        rounding: RoundingMode,
+    ) -> ManagedDecimal<M, T> {
+        let from_scale = self.scale();
+        let to_scale = scale_to.num_decimals();
+        let data = match from_scale.cmp(&to_scale) {
+            core::cmp::Ordering::Equal => self.data.clone(),
+            core::cmp::Ordering::Less => {
+                let factor = BigUint::<M>::from(10u32).pow((to_scale - from_scale) as u32);
+                &self.data * &factor
+            },
+            core::cmp::Ordering::Greater => {
+                let factor = BigUint::<M>::from(10u32).pow((from_scale - to_scale) as u32);
+                rounding.div(self.data.clone(), &factor)
+            },
+        };
+        ManagedDecimal::from_raw_units(data, scale_to)
+    }
+
+    /// Same value, but with the number of decimals only known at runtime.
+    pub fn into_var_decimals(self) -> ManagedDecimal<M, NumDecimals> {
+        let decimals = self.scale();
+        ManagedDecimal::from_raw_units(self.data, decimals)
+    }
+
+    /// Creates a decimal from a `BigFloat`, discarding the digits beyond the number of decimals.
+    ///
+    /// Signals an error if the value is negative.
+    pub fn from_big_float(value: &BigFloat<M>, decimals: D) -> Self {
+        let denominator = BigFloat::from_big_uint(&decimals.scaling_factor());
+        let data = value
+            .to_fixed_point(&denominator)
+            .into_big_uint()
+            .unwrap_or_sc_panic(crate::err_msg::MANAGED_DECIMAL_NEGATIVE);
+        ManagedDecimal { data, decimals }
+    }
+
+    pub fn to_big_float(&self) -> BigFloat<M> {
+        BigFloat::from_big_uint(&self.data) / BigFloat::from_big_uint(&self.scaling_factor())
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> From<BigUint<M>>
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    /// Interprets the value as an integer, e.g. 2 becomes 2.000 with 3 decimals.
+    fn from(value: BigUint<M>) -> Self {
+        Self::from_integer(value, ConstDecimals)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> TopEncode
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    #[inline]
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.data.top_encode_or_handle_err(output, h)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> TopDecode
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(ManagedDecimal::from_raw_units(
+            BigUint::top_decode_or_handle_err(input, h)?,
+            ConstDecimals,
+        ))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> NestedEncode
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    #[inline]
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.data.dep_encode_or_handle_err(dest, h)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> NestedDecode
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(ManagedDecimal::from_raw_units(
+            BigUint::dep_decode_or_handle_err(input, h)?,
+            ConstDecimals,
+        ))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> CodecFromSelf
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> TypeAbi
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    fn type_name() -> TypeName {
+        let mut repr = TypeName::from("ManagedDecimal<");
+        repr.push_str(DECIMALS.to_string().as_str());
+        repr.push('>');
+        repr
+    }
+}
+
+/// Serialized as the units, followed by the number of decimals.
+impl<M: ManagedTypeApi> TopEncode for ManagedDecimal<M, NumDecimals> {
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        let mut nested_buffer = output.start_nested_encode();
+        self.dep_encode_or_handle_err(&mut nested_buffer, h)?;
+        output.finalize_nested_encode(nested_buffer);
+        Ok(())
+    }
+}
+
+impl<M: ManagedTypeApi> TopDecode for ManagedDecimal<M, NumDecimals> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let mut nested_buffer = input.into_nested_buffer();
+        let result = Self::dep_decode_or_handle_err(&mut nested_buffer, h)?;
+        if !nested_buffer.is_depleted() {
+            return Err(h.handle_error(DecodeError::INPUT_TOO_LONG));
+        }
+        Ok(result)
+    }
+}
+
+impl<M: ManagedTypeApi> NestedEncode for ManagedDecimal<M, NumDecimals> {
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.data.dep_encode_or_handle_err(dest, h)?;
+        self.decimals.dep_encode_or_handle_err(dest, h)
+    }
+}
+
+impl<M: ManagedTypeApi> NestedDecode for ManagedDecimal<M, NumDecimals> {
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(ManagedDecimal::from_raw_units(
+            BigUint::dep_decode_or_handle_err(input, h)?,
+            NumDecimals::dep_decode_or_handle_err(input, h)?,
+        ))
+    }
+}
+
+impl<M: ManagedTypeApi> CodecFromSelf for ManagedDecimal<M, NumDecimals> {}
+
+impl<M: ManagedTypeApi> TypeAbi for ManagedDecimal<M, NumDecimals> {
+    fn type_name() -> TypeName {
+        TypeName::from("ManagedDecimal<usize>")
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> core::fmt::Debug for ManagedDecimal<M, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ManagedDecimal")
+            .field("data", &self.data)
+            .field("decimals", &self.scale())
+            .finish()
+    }
+}
@@ -0,0 +1,54 @@
+use core::cmp::Ordering;
+
+use crate::{
+    api::ManagedTypeApi,
+    types::{BigUint, Decimals, ManagedDecimal},
+};
+
+/// Compares the values, regardless of the number of decimals, e.g. 1.5 == 1.50.
+fn cmp_values<M, D1, D2>(a: &ManagedDecimal<M, D1>, b: &ManagedDecimal<M, D2>) -> Ordering
+where
+    M: ManagedTypeApi,
+    D1: Decimals,
+    D2: Decimals,
+{
+    let (a_scale, b_scale) = (a.scale(), b.scale());
+    match a_scale.cmp(&b_scale) {
+        Ordering::Equal => a.raw_units().cmp(b.raw_units()),
+        Ordering::Less => {
+            let factor = BigUint::<M>::from(10u32).pow((b_scale - a_scale) as u32);
+            (a.raw_units() * &factor).cmp(b.raw_units())
+        },
+        Ordering::Greater => {
+            let factor = BigUint::<M>::from(10u32).pow((a_scale - b_scale) as u32);
+            a.raw_units().cmp(&(b.raw_units() * &factor))
+        },
+    }
+}
+
+impl<M: ManagedTypeApi, D1: Decimals, D2: Decimals> PartialEq<ManagedDecimal<M, D2>>
+    for ManagedDecimal<M, D1>
+{
+    #[inline]
+    fn eq(&self, other: &ManagedDecimal<M, D2>) -> bool {
+        cmp_values(self, other).is_eq()
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> Eq for ManagedDecimal<M, D> {}
+
+impl<M: ManagedTypeApi, D1: Decimals, D2: Decimals> PartialOrd<ManagedDecimal<M, D2>>
+    for ManagedDecimal<M, D1>
+{
+    #[inline]
+    fn partial_cmp(&self, other: &ManagedDecimal<M, D2>) -> Option<Ordering> {
+        Some(cmp_values(self, other))
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> Ord for ManagedDecimal<M, D> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_values(self, other)
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{
+    api::{ErrorApiImpl, ManagedTypeApi},
+    err_msg,
+    types::{BigUint, Decimals, ManagedDecimal, RoundingMode},
+};
+
+/// Extra decimals used in intermediate results, so that rounding errors do not reach the final result.
+const GUARD_DECIMALS: u32 = 9;
+
+fn pow10<M: ManagedTypeApi>(exp: u32) -> BigUint<M> {
+    BigUint::from(10u32).pow(exp)
+}
+
+fn num_digits(value: u32) -> u32 {
+    value.checked_ilog10().unwrap_or(0) + 1
+}
+
+/// Fixed-point multiplication, where `one` is the representation of 1.
+fn fixed_mul<M: ManagedTypeApi>(a: &BigUint<M>, b: &BigUint<M>, one: &BigUint<M>) -> BigUint<M> {
+    a * b / one
+}
+
+/// e^x, for 0 <= x < 1, by Taylor series.
+fn exp_series<M: ManagedTypeApi>(x: &BigUint<M>, one: &BigUint<M>) -> BigUint<M> {
+    let mut sum = one.clone();
+    let mut term = one.clone();
+    let mut k = 1u32;
+    loop {
+        term = fixed_mul(&term, x, one) / k;
+        if term == 0u32 {
+            return sum;
+        }
+        sum += &term;
+        k += 1;
+    }
+}
+
+/// atanh(z) = z + z^3/3 + z^5/5 + ..., for 0 <= z <= 1/3.
+fn atanh_series<M: ManagedTypeApi>(z: &BigUint<M>, one: &BigUint<M>) -> BigUint<M> {
+    let z_squared = fixed_mul(z, z, one);
+    let mut sum = z.clone();
+    let mut power = z.clone();
+    let mut divisor = 3u32;
+    loop {
+        power = fixed_mul(&power, &z_squared, one);
+        let term = &power / divisor;
+        if term == 0u32 {
+            return sum;
+        }
+        sum += &term;
+        divisor += 2;
+    }
+}
+
+/// ln(y) = 2 * atanh((y - 1) / (y + 1)), for 1 <= y < 2.
+fn ln_series<M: ManagedTypeApi>(y: &BigUint<M>, one: &BigUint<M>) -> BigUint<M> {
+    let z = (y - one) * one / &(y + one);
+    atanh_series(&z, one) * 2u32
+}
+
+impl<M: ManagedTypeApi, D: Decimals> ManagedDecimal<M, D> {
+    /// Approximates e^self, rounded to the nearest value with the same number of decimals.
+    ///
+    /// Signals an error if the integer part of the argument does not fit in a `u32`.
+    pub fn exp(&self) -> Self {
+        let integer_part = self
+            .trunc()
+            .to_u64()
+            .and_then(|value| u32::try_from(value).ok())
+            .unwrap_or_else(|| M::error_api_impl().signal_error(err_msg::ARG_OUT_OF_RANGE));
+
+        // e^n = e^1 multiplied n times, so the error of e^1 grows with n
+        let guard_decimals = GUARD_DECIMALS + num_digits(integer_part);
+        let guard_factor = pow10::<M>(guard_decimals);
+        let one = pow10::<M>(self.scale() as u32 + guard_decimals);
+
+        let fractional_part = (self.raw_units() % &self.scaling_factor()) * &guard_factor;
+        let mut result = exp_series(&fractional_part, &one);
+
+        // e^n by exponentiation by squaring
+        let mut base = exp_series(&one, &one);
+        let mut exp = integer_part;
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = fixed_mul(&result, &base, &one);
+            }
+            exp /= 2;
+            if exp > 0 {
+                base = fixed_mul(&base, &base, &one);
+            }
+        }
+
+        let data = RoundingMode::HalfUp.div(result, &guard_factor);
+        ManagedDecimal::from_raw_units(data, self.decimals().clone())
+    }
+
+    /// Approximates the natural logarithm, rounded to the nearest value with the same number of decimals.
+    ///
+    /// Only defined for values of at least 1, since the result cannot be negative. Returns `None` otherwise.
+    pub fn ln(&self) -> Option<Self> {
+        let integer_part = self.trunc();
+        if integer_part == 0u32 {
+            return None;
+        }
+
+        // self = 2^k * y, with 1 <= y < 2, so ln(self) = k * ln(2) + ln(y)
+        let k = integer_part.log2();
+        let guard_decimals = GUARD_DECIMALS + num_digits(k);
+        let guard_factor = pow10::<M>(guard_decimals);
+        let one = pow10::<M>(self.scale() as u32 + guard_decimals);
+
+        let x = self.raw_units() * &guard_factor;
+        let y = x / &BigUint::from(2u32).pow(k);
+
+        let ln_2 = ln_series(&(&one * 2u32), &one);
+        let result = ln_2 * k + ln_series(&y, &one);
+
+        let data = RoundingMode::HalfUp.div(result, &guard_factor);
+        Some(ManagedDecimal::from_raw_units(
+            data,
+            self.decimals().clone(),
+        ))
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{
+    api::{ErrorApiImpl, ManagedTypeApi},
+    err_msg,
+    types::{Decimals, ManagedDecimal, RoundingMode},
+};
+use core::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+
+impl<M: ManagedTypeApi, D: Decimals> ManagedDecimal<M, D> {
+    fn require_same_scale<D2: Decimals>(&self, other: &ManagedDecimal<M, D2>) {
+        if self.scale() != other.scale() {
+            M::error_api_impl().signal_error(err_msg::MANAGED_DECIMAL_SCALE_MISMATCH);
+        }
+    }
+
+    /// Multiplies, keeping the number of decimals of `self`.
+    pub fn mul_with_rounding<D2: Decimals>(
+        &self,
+        other: &ManagedDecimal<M, D2>,
+        rounding: RoundingMode,
+    ) -> Self {
+        let data = rounding.div(
+            self.raw_units() * other.raw_units(),
+            &other.scaling_factor(),
+        );
+        ManagedDecimal::from_raw_units(data, self.decimals().clone())
+    }
+
+    /// Divides, keeping the number of decimals of `self`.
+    ///
+    /// Signals an error when dividing by zero.
+    pub fn div_with_rounding<D2: Decimals>(
+        &self,
+        other: &ManagedDecimal<M, D2>,
+        rounding: RoundingMode,
+    ) -> Self {
+        let data = rounding.div(
+            self.raw_units() * &other.scaling_factor(),
+            other.raw_units(),
+        );
+        ManagedDecimal::from_raw_units(data, self.decimals().clone())
+    }
+}
+
+/// Both operands need to have the same number of decimals, otherwise an error is signalled.
+impl<M: ManagedTypeApi, D: Decimals> Add for ManagedDecimal<M, D> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += &other;
+        self
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> AddAssign<&ManagedDecimal<M, D>> for ManagedDecimal<M, D> {
+    fn add_assign(&mut self, other: &ManagedDecimal<M, D>) {
+        self.require_same_scale(other);
+        let data = self.raw_units() + other.raw_units();
+        *self = ManagedDecimal::from_raw_units(data, self.decimals().clone());
+    }
+}
+
+/// Both operands need to have the same number of decimals, otherwise an error is signalled.
+///
+/// Just like for `BigUint`, an error is also signalled if the result would be negative.
+impl<M: ManagedTypeApi, D: Decimals> Sub for ManagedDecimal<M, D> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        self -= &other;
+        self
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> SubAssign<&ManagedDecimal<M, D>> for ManagedDecimal<M, D> {
+    fn sub_assign(&mut self, other: &ManagedDecimal<M, D>) {
+        self.require_same_scale(other);
+        let data = self.raw_units() - other.raw_units();
+        *self = ManagedDecimal::from_raw_units(data, self.decimals().clone());
+    }
+}
+
+/// Keeps the number of decimals of the left operand, rounding down.
+impl<M: ManagedTypeApi, D1: Decimals, D2: Decimals> Mul<ManagedDecimal<M, D2>>
+    for ManagedDecimal<M, D1>
+{
+    type Output = Self;
+
+    fn mul(self, other: ManagedDecimal<M, D2>) -> Self {
+        self.mul_with_rounding(&other, RoundingMode::Down)
+    }
+}
+
+/// Keeps the number of decimals of the left operand, rounding down.
+impl<M: ManagedTypeApi, D1: Decimals, D2: Decimals> Div<ManagedDecimal<M, D2>>
+    for ManagedDecimal<M, D1>
+{
+    type Output = Self;
+
+    fn div(self, other: ManagedDecimal<M, D2>) -> Self {
+        self.div_with_rounding(&other, RoundingMode::Down)
+    }
+}
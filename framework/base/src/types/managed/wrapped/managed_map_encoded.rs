@@ -0,0 +1,145 @@
+use core::marker::PhantomData;
+
+use crate::{
+    api::ManagedTypeApi,
+    codec::{TopDecode, TopEncode},
+    contract_base::ManagedSerializer,
+    types::{ManagedBuffer, ManagedMap, ManagedVec},
+};
+
+/// A `ManagedMap` with typed keys and values, which also keeps track of its keys, so it can be iterated.
+///
+/// Keys and values are top-encoded before being handed over to the VM.
+///
+/// The VM managed map cannot list its keys, so they are also kept in a `ManagedVec`, in insertion order,
+/// with a second managed map pointing from each key to its position in the list.
+/// All operations take constant time, but removing a key moves the last key in its place.
+///
+/// The VM considers keys with empty values missing, so values that top-encode as empty (e.g. zero)
+/// would get lost in a plain `ManagedMap`. Presence is decided by the positions instead,
+/// which are 1-based, so never empty.
+pub struct ManagedMapEncoded<M, K, V>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode,
+    V: TopEncode + TopDecode,
+{
+    values: ManagedMap<M>,
+    positions: ManagedMap<M>,
+    keys: ManagedVec<M, ManagedBuffer<M>>,
+    _phantom: PhantomData<(K, V)>,
+}
+
+impl<M, K, V> ManagedMapEncoded<M, K, V>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode,
+    V: TopEncode + TopDecode,
+{
+    pub fn new() -> Self {
+        ManagedMapEncoded {
+            values: ManagedMap::new(),
+            positions: ManagedMap::new(),
+            keys: ManagedVec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn encode<T: TopEncode>(item: &T) -> ManagedBuffer<M> {
+        ManagedSerializer::<M>::new().top_encode_to_managed_buffer(item)
+    }
+
+    fn decode<T: TopDecode>(buffer: &ManagedBuffer<M>) -> T {
+        ManagedSerializer::<M>::new().top_decode_from_managed_buffer(buffer)
+    }
+
+    /// Number of keys.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.positions.contains(&Self::encode(key))
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        let encoded_key = Self::encode(key);
+        if !self.positions.contains(&encoded_key) {
+            return None;
+        }
+        Some(Self::decode(&self.values.get(&encoded_key)))
+    }
+
+    /// Sets the value of a key, adding the key if missing.
+    pub fn put(&mut self, key: &K, value: &V) {
+        let encoded_key = Self::encode(key);
+        if !self.positions.contains(&encoded_key) {
+            self.keys.push(encoded_key.clone());
+            self.positions
+                .put(&encoded_key, &Self::encode(&self.keys.len()));
+        }
+        self.values.put(&encoded_key, &Self::encode(value));
+    }
+
+    /// Removes a key, returning its value if it was present.
+    ///
+    /// The last key takes the place of the removed one in the iteration order.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let encoded_key = Self::encode(key);
+        if !self.positions.contains(&encoded_key) {
+            return None;
+        }
+        let encoded_value = self.values.remove(&encoded_key);
+
+        let position: usize = Self::decode(&self.positions.remove(&encoded_key));
+        let last_position = self.keys.len();
+        if position != last_position {
+            let last_key = self.keys.get(last_position - 1).clone_value();
+            self.positions.put(&last_key, &Self::encode(&position));
+            let _ = self.keys.set(position - 1, &last_key);
+        }
+        self.keys.remove(last_position - 1);
+
+        Some(Self::decode(&encoded_value))
+    }
+
+    /// Iterates over the keys, in insertion order, as long as none were removed.
+    pub fn keys(&self) -> impl Iterator<Item = K> + '_ {
+        self.keys
+            .iter()
+            .map(|encoded_key| Self::decode(&encoded_key))
+    }
+
+    /// Iterates over the values, in the same order as the keys.
+    pub fn values(&self) -> impl Iterator<Item = V> + '_ {
+        self.keys
+            .iter()
+            .map(|encoded_key| Self::decode(&self.values.get(&encoded_key)))
+    }
+
+    /// Iterates over the key-value pairs, in the same order as the keys.
+    pub fn iter(&self) -> impl Iterator<Item = (K, V)> + '_ {
+        self.keys.iter().map(|encoded_key| {
+            (
+                Self::decode(&encoded_key),
+                Self::decode(&self.values.get(&encoded_key)),
+            )
+        })
+    }
+}
+
+impl<M, K, V> Default for ManagedMapEncoded<M, K, V>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode,
+    V: TopEncode + TopDecode,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
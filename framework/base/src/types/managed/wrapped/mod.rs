@@ -7,6 +7,11 @@ mod esdt_token_payment;
 mod managed_address;
 mod managed_buffer_cached_builder;
 mod managed_byte_array;
+mod managed_decimal;
+mod managed_decimal_cmp;
+mod managed_decimal_math;
+mod managed_decimal_operators;
+mod managed_map_encoded;
 mod managed_option;
 mod managed_ref;
 mod managed_vec;
@@ -28,6 +33,8 @@ pub use managed_address::ManagedAddress;
 pub use managed_buffer_cached_builder::ManagedBufferCachedBuilder;
 pub(crate) use managed_byte_array::ManagedBufferSizeContext;
 pub use managed_byte_array::ManagedByteArray;
+pub use managed_decimal::{ConstDecimals, Decimals, ManagedDecimal, NumDecimals, RoundingMode};
+pub use managed_map_encoded::ManagedMapEncoded;
 pub use managed_option::ManagedOption;
 pub use managed_ref::ManagedRef;
 pub use managed_vec::ManagedVec;
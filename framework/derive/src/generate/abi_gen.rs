@@ -1,7 +1,7 @@
 use super::util::*;
 use crate::model::{
-    AutoImpl, ContractTrait, EndpointMutabilityMetadata, EndpointTypeMetadata, Method, MethodImpl,
-    PublicRole,
+    AutoImpl, ContractTrait, EndpointMutabilityMetadata, EndpointTypeMetadata, Method,
+    MethodArgument, MethodImpl, PublicRole,
 };
 
 fn generate_endpoint_snippet(
@@ -171,6 +171,93 @@ fn generate_event_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenSt
         .collect()
 }
 
+fn generate_storage_snippet(
+    m: &Method,
+    identifier: &str,
+    key_args: &[MethodArgument],
+    value_snippet: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let storage_docs = &m.docs;
+    let key_arg_snippets: Vec<proc_macro2::TokenStream> = key_args
+        .iter()
+        .map(|arg| {
+            let mut arg_type = arg.ty.clone();
+            clear_all_type_lifetimes(&mut arg_type);
+            let arg_name = &arg.pat;
+            let arg_name_str = quote! { #arg_name }.to_string();
+            quote! {
+                storage_abi.add_key_arg::<#arg_type>(#arg_name_str);
+                contract_abi.add_type_descriptions::<#arg_type>();
+            }
+        })
+        .collect();
+
+    quote! {
+        let mut storage_abi = multiversx_sc::abi::StorageAbi::new(
+            &[ #(#storage_docs),* ],
+            #identifier,
+        );
+        #(#key_arg_snippets)*
+        #value_snippet
+    }
+}
+
+fn return_type_without_lifetimes(m: &Method) -> syn::Type {
+    match &m.return_type {
+        syn::ReturnType::Default => panic!("storage getters and mappers should return some value"),
+        syn::ReturnType::Type(_, ty) => {
+            let mut res_type = *ty.clone();
+            clear_all_type_lifetimes(&mut res_type);
+            res_type
+        },
+    }
+}
+
+fn generate_storage_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
+    contract
+        .methods
+        .iter()
+        .filter(|m| !m.skip_storage_abi)
+        .filter_map(|m| {
+            let storage_def = match &m.implementation {
+                MethodImpl::Generated(AutoImpl::StorageGetter { identifier }) => {
+                    let value_type = return_type_without_lifetimes(m);
+                    let value_snippet = quote! {
+                        storage_abi.set_single_value::<#value_type>();
+                        contract_abi.add_type_descriptions::<#value_type>();
+                    };
+                    generate_storage_snippet(m, identifier, &m.method_args, value_snippet)
+                },
+                MethodImpl::Generated(AutoImpl::StorageSetter { identifier }) => {
+                    let (value_arg, key_args) = m.method_args.split_last()?;
+                    let mut value_type = value_arg.ty.clone();
+                    clear_all_type_lifetimes(&mut value_type);
+                    let value_snippet = quote! {
+                        storage_abi.set_single_value::<#value_type>();
+                        contract_abi.add_type_descriptions::<#value_type>();
+                    };
+                    generate_storage_snippet(m, identifier, key_args, value_snippet)
+                },
+                MethodImpl::Generated(AutoImpl::StorageMapper { identifier }) => {
+                    let mapper_type = return_type_without_lifetimes(m);
+                    let value_snippet = quote! {
+                        storage_abi.set_mapper::<#mapper_type>();
+                        <#mapper_type as multiversx_sc::abi::StorageMapperAbi>::provide_type_descriptions(
+                            &mut contract_abi.type_descriptions,
+                        );
+                    };
+                    generate_storage_snippet(m, identifier, &m.method_args, value_snippet)
+                },
+                _ => return None,
+            };
+            Some(quote! {
+                #storage_def
+                contract_abi.add_storage(storage_abi);
+            })
+        })
+        .collect()
+}
+
 fn has_callback(contract: &ContractTrait) -> bool {
     contract.methods.iter().any(|m| {
         matches!(
@@ -217,6 +304,7 @@ fn generate_abi_method_body(
     let contract_name = &contract.trait_name.to_string();
     let endpoint_snippets = generate_endpoint_snippets(contract);
     let event_snippets = generate_event_snippets(contract);
+    let storage_snippets = generate_storage_snippets(contract);
     let has_callbacks = has_callback(contract);
     let supertrait_snippets: Vec<proc_macro2::TokenStream> = if is_contract_main {
         generate_supertrait_snippets(contract)
@@ -245,6 +333,7 @@ fn generate_abi_method_body(
         );
         #(#endpoint_snippets)*
         #(#event_snippets)*
+        #(#storage_snippets)*
         #(#supertrait_snippets)*
         #(#esdt_attributes)*
         contract_abi
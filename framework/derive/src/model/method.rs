@@ -43,6 +43,8 @@ pub struct Method {
     pub label_names: Vec<String>,
     pub return_type: syn::ReturnType,
     pub implementation: MethodImpl,
+    /// Storage methods annotated with `#[skip_storage_abi]` are left out of the ABI, together with their types.
+    pub skip_storage_abi: bool,
 }
 
 impl Method {
@@ -11,6 +11,7 @@ pub(super) static ATTR_PAYMENT_MULTI: &str = "payment_multi";
 pub(super) static ATTR_INIT: &str = "init";
 pub(super) static ATTR_VIEW: &str = "view";
 pub(super) static ATTR_EXTERNAL_VIEW: &str = "external_view";
+pub(super) static ATTR_PAGINATED_VIEW: &str = "paginated_view";
 pub(super) static ATTR_EVENT: &str = "event";
 pub(super) static ATTR_EVENT_INDEXED: &str = "indexed";
 pub(super) static ATTR_ENDPOINT: &str = "endpoint";
@@ -23,6 +24,7 @@ pub(super) static ATTR_STORAGE_SET: &str = "storage_set";
 pub(super) static ATTR_STORAGE_MAPPER: &str = "storage_mapper";
 pub(super) static ATTR_STORAGE_IS_EMPTY: &str = "storage_is_empty";
 pub(super) static ATTR_STORAGE_CLEAR: &str = "storage_clear";
+pub(super) static ATTR_SKIP_STORAGE_ABI: &str = "skip_storage_abi";
 pub(super) static ATTR_PROXY: &str = "proxy";
 pub(super) static ATTR_LABEL: &str = "label";
 pub(super) static ATTR_ALLOW_MULTIPLE_VAR_ARGS: &str = "allow_multiple_var_args";
@@ -88,6 +88,24 @@ impl ExternalViewAttribute {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct PaginatedViewAttribute {
+    pub view_name: syn::Ident,
+}
+
+impl PaginatedViewAttribute {
+    pub fn parse(attr: &syn::Attribute) -> Option<PaginatedViewAttribute> {
+        match is_attr_with_one_opt_token_tree_arg(attr, ATTR_PAGINATED_VIEW) {
+            None => None,
+            Some(Some(proc_macro2::TokenTree::Ident(ident))) => {
+                Some(PaginatedViewAttribute { view_name: ident })
+            },
+            Some(None) => panic!("paginated view name required"),
+            _ => panic!("unexpected paginated view argument tokens"),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct CallbackAttribute {
     pub callback_name: Option<syn::Ident>,
@@ -1,5 +1,9 @@
 use super::{attr_names::*, util::*};
 
+pub fn is_skip_storage_abi(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_SKIP_STORAGE_ABI)
+}
+
 pub struct StorageGetAttribute {
     pub identifier: String,
 }
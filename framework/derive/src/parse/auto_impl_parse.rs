@@ -93,3 +93,12 @@ pub fn process_storage_clear_attribute(attr: &syn::Attribute, method: &mut Metho
         })
         .is_some()
 }
+
+pub fn process_skip_storage_abi_attribute(attr: &syn::Attribute, method: &mut Method) -> bool {
+    if is_skip_storage_abi(attr) {
+        method.skip_storage_abi = true;
+        true
+    } else {
+        false
+    }
+}
@@ -1,5 +1,6 @@
 use super::{
-    attributes::extract_doc, method_parse::process_method, parse_util::validate_attribute_args,
+    attributes::extract_doc, method_parse::process_method,
+    paginated_view_parse::split_paginated_view, parse_util::validate_attribute_args,
     supertrait_parse::parse_supertrait,
 };
 use crate::{
@@ -33,10 +34,11 @@ pub fn parse_contract_trait(
     let methods: Vec<Method> = contract_trait
         .items
         .iter()
-        .map(|itm| match itm {
-            syn::TraitItem::Fn(m) => process_method(m, &trait_attributes),
+        .flat_map(|itm| match itm {
+            syn::TraitItem::Fn(m) => split_paginated_view(m),
             _ => panic!("Only methods allowed in contract traits"),
         })
+        .map(|m| process_method(&m, &trait_attributes))
         .collect();
 
     ContractTrait {
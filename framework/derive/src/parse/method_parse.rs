@@ -1,11 +1,14 @@
-use crate::model::{Method, MethodImpl, MethodPayableMetadata, PublicRole, TraitProperties};
+use crate::model::{
+    AutoImpl, Method, MethodImpl, MethodPayableMetadata, PublicRole, TraitProperties,
+};
 
 use super::{
     attributes::extract_doc,
     auto_impl_parse::{
-        process_event_attribute, process_proxy_attribute, process_storage_clear_attribute,
-        process_storage_get_attribute, process_storage_is_empty_attribute,
-        process_storage_mapper_attribute, process_storage_set_attribute,
+        process_event_attribute, process_proxy_attribute, process_skip_storage_abi_attribute,
+        process_storage_clear_attribute, process_storage_get_attribute,
+        process_storage_is_empty_attribute, process_storage_mapper_attribute,
+        process_storage_set_attribute,
     },
     extract_method_args, process_allow_multiple_var_args_attribute, process_callback_attribute,
     process_callback_raw_attribute, process_endpoint_attribute, process_external_view_attribute,
@@ -59,6 +62,7 @@ pub fn process_method(m: &syn::TraitItemFn, trait_attributes: &TraitProperties)
         label_names: Vec::new(),
         return_type: m.sig.output.clone(),
         implementation,
+        skip_storage_abi: false,
     };
 
     process_attributes_second_pass(
@@ -129,6 +133,7 @@ fn process_attribute_second_pass(
         || process_storage_mapper_attribute(attr, method)
         || process_storage_is_empty_attribute(attr, method)
         || process_storage_clear_attribute(attr, method)
+        || process_skip_storage_abi_attribute(attr, method)
         || process_output_names_attribute(attr, method)
         || process_label_names_attribute(attr, method)
 }
@@ -141,5 +146,18 @@ fn validate_method(method: &Method) {
         ) || method.label_names.is_empty(),
         "Labels can only be placed on endpoints, constructors, and promises callbacks. Method '{}' is neither.",
         &method.name.to_string()
-    )
+    );
+    assert!(
+        !method.skip_storage_abi
+            || matches!(
+                method.implementation,
+                MethodImpl::Generated(
+                    AutoImpl::StorageGetter { .. }
+                        | AutoImpl::StorageSetter { .. }
+                        | AutoImpl::StorageMapper { .. }
+                )
+            ),
+        "`#[skip_storage_abi]` can only be placed on `#[storage_get]`, `#[storage_set]` and `#[storage_mapper]` methods. Method '{}' is neither.",
+        method.name
+    );
 }
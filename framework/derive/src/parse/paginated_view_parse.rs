@@ -0,0 +1,85 @@
+use super::attributes::{PaginatedViewAttribute, StorageMapperAttribute};
+
+/// Removes the `#[paginated_view]` annotation from a storage mapper method
+/// and, if it was present, appends the view method it stands for.
+///
+/// The generated view takes the same arguments as the storage mapper method,
+/// plus a page size and an optional cursor,
+/// and returns the cursor of the next page, followed by the items on the page.
+pub fn split_paginated_view(m: &syn::TraitItemFn) -> Vec<syn::TraitItemFn> {
+    let mut opt_view_name = None;
+    let mut mapper_method = m.clone();
+    mapper_method
+        .attrs
+        .retain(|attr| match PaginatedViewAttribute::parse(attr) {
+            Some(paginated_view) => {
+                assert!(
+                    opt_view_name.is_none(),
+                    "only one `#[paginated_view]` allowed per method"
+                );
+                opt_view_name = Some(paginated_view.view_name);
+                false
+            },
+            None => true,
+        });
+
+    let Some(view_name) = opt_view_name else {
+        return vec![mapper_method];
+    };
+    let paginated_view = generate_paginated_view_method(&mapper_method, view_name);
+    vec![mapper_method, paginated_view]
+}
+
+fn generate_paginated_view_method(
+    mapper_method: &syn::TraitItemFn,
+    view_name: syn::Ident,
+) -> syn::TraitItemFn {
+    let mapper_method_name = &mapper_method.sig.ident;
+    assert!(
+        mapper_method
+            .attrs
+            .iter()
+            .any(|attr| StorageMapperAttribute::parse(attr).is_some()),
+        "`#[paginated_view]` can only be used on `#[storage_mapper]` methods, `{mapper_method_name}` is not one"
+    );
+    let syn::ReturnType::Type(_, mapper_type) = &mapper_method.sig.output else {
+        panic!("storage mapper method `{mapper_method_name}` must return a storage mapper")
+    };
+
+    let mapper_args: Vec<&syn::PatType> = mapper_method
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            syn::FnArg::Typed(pat_type) => Some(pat_type),
+            syn::FnArg::Receiver(_) => None,
+        })
+        .collect();
+    let mapper_arg_names = mapper_args.iter().map(|pat_type| &pat_type.pat);
+
+    let method_name = format_ident!("{}_paginated", mapper_method_name);
+    let doc = format!(" Paginated view over the `{mapper_method_name}` storage mapper.");
+    let paginated = quote! { <#mapper_type as multiversx_sc::storage::mappers::PaginatedStorageMapper<Self::Api>> };
+    syn::parse_quote! {
+        #[doc = #doc]
+        #[view(#view_name)]
+        #[output_name("next_cursor")]
+        #[output_name("items")]
+        fn #method_name(
+            &self,
+            #(#mapper_args,)*
+            page_size: usize,
+            cursor: multiversx_sc::codec::multi_types::OptionalValue<#paginated::Cursor>,
+        ) -> multiversx_sc::codec::multi_types::MultiValue2<
+            Option<#paginated::Cursor>,
+            multiversx_sc::types::MultiValueEncoded<Self::Api, #paginated::Item>,
+        > {
+            multiversx_sc::storage::mappers::PaginatedStorageMapper::load_page(
+                &self.#mapper_method_name(#(#mapper_arg_names),*),
+                cursor.into_option(),
+                page_size,
+            )
+            .into_multi_value()
+        }
+    }
+}
@@ -73,6 +73,8 @@ fn add_managed_types(substitutions: &mut SubstitutionsMap) {
     add_managed_type(substitutions, &quote!(ManagedAddress));
     add_managed_type(substitutions, &quote!(ManagedBufferCachedBuilder));
     add_managed_type_with_generics(substitutions, &quote!(ManagedByteArray));
+    add_managed_type_with_generics(substitutions, &quote!(ManagedMapEncoded));
+    add_managed_type_with_generics(substitutions, &quote!(ManagedDecimal));
     add_managed_type_with_generics(substitutions, &quote!(ManagedOption));
     add_managed_type_with_generics(substitutions, &quote!(ManagedRef));
     add_managed_type_with_generics(substitutions, &quote!(ManagedVecOwnedIterator));
@@ -110,10 +112,14 @@ fn add_storage_mappers(substitutions: &mut SubstitutionsMap) {
     add_storage_mapper_single_generic_arg(substitutions, &quote!(UniqueIdMapper));
     add_storage_mapper_single_generic_arg(substitutions, &quote!(UserMapper));
 
+    add_storage_mapper(substitutions, &quote!(AvlTreeMapper));
     add_storage_mapper(substitutions, &quote!(BiDiMapper));
+    add_storage_mapper(substitutions, &quote!(CheckpointMapper));
     add_storage_mapper(substitutions, &quote!(LinkedListMapper));
     add_storage_mapper(substitutions, &quote!(MapMapper));
     add_storage_mapper(substitutions, &quote!(MapStorageMapper));
+    add_storage_mapper(substitutions, &quote!(MerkleTreeMapper));
+    add_storage_mapper(substitutions, &quote!(PriorityQueueMapper));
     add_storage_mapper(substitutions, &quote!(SetMapper));
     add_storage_mapper(substitutions, &quote!(UnorderedSetMapper));
     add_storage_mapper(substitutions, &quote!(SingleValueMapper));
@@ -191,6 +191,7 @@ fn build_contract_abi(builder: ContractVariantBuilder, original_abi: &ContractAb
         endpoints,
         promise_callbacks,
         events: original_abi.events.clone(),
+        storage: original_abi.storage.clone(),
         has_callback,
         type_descriptions: original_abi.type_descriptions.clone(),
         esdt_attributes: original_abi.esdt_attributes.clone(),
@@ -3,6 +3,8 @@ use multiversx_sc::{
     types::{Address, EsdtLocalRoleFlags, H256},
 };
 
+use multiversx_chain_vm::executor::MemPtr;
+
 use crate::api::{i32_to_bool, VMHooksApi, VMHooksApiBackend};
 
 impl<VHB: VMHooksApiBackend> BlockchainApi for VMHooksApi<VHB> {
@@ -73,8 +75,9 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
         });
     }
 
-    fn load_state_root_hash_managed(&self, _dest: Self::ManagedBufferHandle) {
-        panic!("state root hash not implemented")
+    fn load_state_root_hash_managed(&self, dest: Self::ManagedBufferHandle) {
+        self.assert_live_handle(&dest);
+        self.with_vm_hooks(|vh| vh.managed_get_state_root_hash(dest.get_raw_handle_unchecked()));
     }
 
     fn get_tx_hash_legacy(&self) -> H256 {
@@ -111,6 +114,14 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
         self.with_vm_hooks(|vh| vh.managed_get_block_random_seed(dest.get_raw_handle_unchecked()));
     }
 
+    fn load_block_hash_managed(&self, nonce: u64, dest: Self::ManagedBufferHandle) {
+        self.assert_live_handle(&dest);
+        let mut block_hash = [0u8; 32];
+        let _ = self
+            .with_vm_hooks(|vh| vh.get_block_hash(nonce as i64, block_hash.as_mut_ptr() as MemPtr));
+        self.mb_overwrite(dest, &block_hash);
+    }
+
     fn get_prev_block_timestamp(&self) -> u64 {
         self.with_vm_hooks(|vh| vh.get_prev_block_timestamp()) as u64
     }
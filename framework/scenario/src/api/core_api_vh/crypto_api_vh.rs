@@ -42,19 +42,31 @@ impl<VHB: VMHooksApiBackend> CryptoApiImpl for VMHooksApi<VHB> {
 
     fn ripemd160_managed(
         &self,
-        _dest: Self::ManagedBufferHandle,
-        _data_handle: Self::ManagedBufferHandle,
+        dest: Self::ManagedBufferHandle,
+        data_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ripemd160 not implemented yet!")
+        self.with_vm_hooks_ctx_2(&dest, &data_handle, |vh| {
+            vh.managed_ripemd160(
+                data_handle.get_raw_handle_unchecked(),
+                dest.get_raw_handle_unchecked(),
+            )
+        });
     }
 
     fn verify_bls_managed(
         &self,
-        _key: Self::ManagedBufferHandle,
-        _message: Self::ManagedBufferHandle,
-        _signature: Self::ManagedBufferHandle,
+        key: Self::ManagedBufferHandle,
+        message: Self::ManagedBufferHandle,
+        signature: Self::ManagedBufferHandle,
     ) -> bool {
-        panic!("verify_bls not implemented yet!")
+        let result = self.with_vm_hooks_ctx_3(&key, &message, &signature, |vh| {
+            vh.managed_verify_bls(
+                key.get_raw_handle_unchecked(),
+                message.get_raw_handle_unchecked(),
+                signature.get_raw_handle_unchecked(),
+            )
+        });
+        result == 0
     }
 
     fn verify_ed25519_managed(
@@ -74,29 +86,50 @@ impl<VHB: VMHooksApiBackend> CryptoApiImpl for VMHooksApi<VHB> {
 
     fn verify_secp256k1_managed(
         &self,
-        _key: Self::ManagedBufferHandle,
-        _message: Self::ManagedBufferHandle,
-        _signature: Self::ManagedBufferHandle,
+        key: Self::ManagedBufferHandle,
+        message: Self::ManagedBufferHandle,
+        signature: Self::ManagedBufferHandle,
     ) -> bool {
-        panic!("verify_secp256k1 not implemented yet!")
+        let result = self.with_vm_hooks_ctx_3(&key, &message, &signature, |vh| {
+            vh.managed_verify_secp256k1(
+                key.get_raw_handle_unchecked(),
+                message.get_raw_handle_unchecked(),
+                signature.get_raw_handle_unchecked(),
+            )
+        });
+        result == 0
     }
 
     fn verify_custom_secp256k1_managed(
         &self,
-        _key: Self::ManagedBufferHandle,
-        _message: Self::ManagedBufferHandle,
-        _signature: Self::ManagedBufferHandle,
-        _hash_type: MessageHashType,
+        key: Self::ManagedBufferHandle,
+        message: Self::ManagedBufferHandle,
+        signature: Self::ManagedBufferHandle,
+        hash_type: MessageHashType,
     ) -> bool {
-        panic!("verify_custom_secp256k1 not implemented yet!")
+        let result = self.with_vm_hooks_ctx_3(&key, &message, &signature, |vh| {
+            vh.managed_verify_custom_secp256k1(
+                key.get_raw_handle_unchecked(),
+                message.get_raw_handle_unchecked(),
+                signature.get_raw_handle_unchecked(),
+                hash_type.as_u8() as i32,
+            )
+        });
+        result == 0
     }
 
     fn encode_secp256k1_der_signature_managed(
         &self,
-        _r: Self::ManagedBufferHandle,
-        _s: Self::ManagedBufferHandle,
-        _dest: Self::ManagedBufferHandle,
+        r: Self::ManagedBufferHandle,
+        s: Self::ManagedBufferHandle,
+        dest: Self::ManagedBufferHandle,
     ) {
-        panic!("encode_secp256k1_signature not implemented yet!")
+        self.with_vm_hooks_ctx_3(&r, &s, &dest, |vh| {
+            vh.managed_encode_secp256k1_der_signature(
+                r.get_raw_handle_unchecked(),
+                s.get_raw_handle_unchecked(),
+                dest.get_raw_handle_unchecked(),
+            )
+        });
     }
 }
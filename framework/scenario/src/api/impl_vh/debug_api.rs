@@ -31,9 +31,7 @@ impl VMHooksApiBackend for DebugApiBackend {
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
-        let wrapper = DebugApiVMHooksHandler::new(handle.context);
-        let dispatcher = VMHooksDispatcher::new(Box::new(wrapper));
-        f(&dispatcher)
+        Self::with_vm_hooks_dispatcher_ctx_1(handle, |dispatcher| f(dispatcher))
     }
 
     fn with_vm_hooks_ctx_2<R, F>(handle1: Self::HandleType, handle2: Self::HandleType, f: F) -> R
@@ -58,6 +56,27 @@ impl VMHooksApiBackend for DebugApiBackend {
         Self::with_vm_hooks_ctx_1(handle1, f)
     }
 
+    fn with_vm_hooks_dispatcher_ctx_1<R, F>(handle: Self::HandleType, f: F) -> R
+    where
+        F: FnOnce(&VMHooksDispatcher) -> R,
+    {
+        let wrapper = DebugApiVMHooksHandler::new(handle.context);
+        let dispatcher = VMHooksDispatcher::new(Box::new(wrapper));
+        f(&dispatcher)
+    }
+
+    fn with_vm_hooks_dispatcher_ctx_2<R, F>(
+        handle1: Self::HandleType,
+        handle2: Self::HandleType,
+        f: F,
+    ) -> R
+    where
+        F: FnOnce(&VMHooksDispatcher) -> R,
+    {
+        assert_handles_on_same_context(&handle1, &handle2);
+        Self::with_vm_hooks_dispatcher_ctx_1(handle1, f)
+    }
+
     fn assert_live_handle(handle: &Self::HandleType) {
         if !handle.is_on_current_context() {
             debugger_panic(
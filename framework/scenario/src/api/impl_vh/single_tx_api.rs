@@ -3,7 +3,7 @@ use std::sync::Mutex;
 use multiversx_chain_vm::{
     executor::VMHooks,
     types::VMAddress,
-    vm_hooks::{SingleTxApiData, SingleTxApiVMHooksHandler, VMHooksDispatcher},
+    vm_hooks::{SingleTxApiData, SingleTxApiVMHooksHandler, StorageAccessCount, VMHooksDispatcher},
     world_mock::AccountData,
 };
 use multiversx_sc::api::RawHandle;
@@ -18,6 +18,17 @@ thread_local! {
     static SINGLE_TX_API_STATIC_CELL: StaticVarData = StaticVarData::default();
 }
 
+fn with_single_tx_dispatcher<R, F>(f: F) -> R
+where
+    F: FnOnce(&VMHooksDispatcher) -> R,
+{
+    SINGLE_TX_API_VH_CELL.with(|cell| {
+        let handler = cell.lock().unwrap().clone();
+        let dispatcher = VMHooksDispatcher::new(Box::new(handler));
+        f(&dispatcher)
+    })
+}
+
 #[derive(Clone)]
 pub struct SingleTxApiBackend;
 
@@ -28,11 +39,14 @@ impl VMHooksApiBackend for SingleTxApiBackend {
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
-        SINGLE_TX_API_VH_CELL.with(|cell| {
-            let handler = cell.lock().unwrap().clone();
-            let dispatcher = VMHooksDispatcher::new(Box::new(handler));
-            f(&dispatcher)
-        })
+        with_single_tx_dispatcher(|dispatcher| f(dispatcher))
+    }
+
+    fn with_vm_hooks_dispatcher_ctx_1<R, F>(_handle: Self::HandleType, f: F) -> R
+    where
+        F: FnOnce(&VMHooksDispatcher) -> R,
+    {
+        with_single_tx_dispatcher(f)
     }
 
     fn with_static_data<R, F>(f: F) -> R
@@ -69,6 +83,15 @@ impl SingleTxApi {
     {
         Self::with_global(|data| data.with_account_mut(&VMAddress::zero(), f))
     }
+
+    /// Storage reads and writes performed since the start, or since the last reset.
+    pub fn storage_access_count() -> StorageAccessCount {
+        Self::with_global(|data| data.storage_access_counter.count())
+    }
+
+    pub fn reset_storage_access_count() {
+        Self::with_global(|data| data.storage_access_counter.reset())
+    }
 }
 
 impl std::fmt::Debug for SingleTxApi {
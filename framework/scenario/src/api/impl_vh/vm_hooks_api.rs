@@ -4,7 +4,10 @@ use super::VMHooksApiBackend;
 
 use std::marker::PhantomData;
 
-use multiversx_chain_vm::executor::{MemPtr, VMHooks};
+use multiversx_chain_vm::{
+    executor::{MemPtr, VMHooks},
+    vm_hooks::VMHooksDispatcher,
+};
 use multiversx_sc::api::{HandleTypeInfo, ManagedBufferApiImpl};
 
 #[derive(Clone, Debug)]
@@ -62,6 +65,27 @@ impl<VHB: VMHooksApiBackend> VMHooksApi<VHB> {
         VHB::with_vm_hooks_ctx_3(handle1.clone(), handle2.clone(), handle3.clone(), f)
     }
 
+    /// Works with the full debugger VM hooks, given by the context of 1 handle.
+    pub fn with_vm_hooks_dispatcher_ctx_1<R, F>(&self, handle: &VHB::HandleType, f: F) -> R
+    where
+        F: FnOnce(&VMHooksDispatcher) -> R,
+    {
+        VHB::with_vm_hooks_dispatcher_ctx_1(handle.clone(), f)
+    }
+
+    /// Works with the full debugger VM hooks, given by the context of 2 handles.
+    pub fn with_vm_hooks_dispatcher_ctx_2<R, F>(
+        &self,
+        handle1: &VHB::HandleType,
+        handle2: &VHB::HandleType,
+        f: F,
+    ) -> R
+    where
+        F: FnOnce(&VMHooksDispatcher) -> R,
+    {
+        VHB::with_vm_hooks_dispatcher_ctx_2(handle1.clone(), handle2.clone(), f)
+    }
+
     /// Checks that the handle refers to the current active context (if possible).
     ///
     /// This is to prevent working with handles pointing to the wrong context, when debugging.
@@ -1,4 +1,4 @@
-use multiversx_chain_vm::executor::VMHooks;
+use multiversx_chain_vm::{executor::VMHooks, vm_hooks::VMHooksDispatcher};
 use multiversx_sc::api::HandleConstraints;
 
 use crate::debug_executor::StaticVarData;
@@ -38,6 +38,23 @@ pub trait VMHooksApiBackend: Clone + Send + Sync + 'static {
         Self::with_vm_hooks(f)
     }
 
+    /// Like `with_vm_hooks_ctx_1`, but also gives access to the debugger hooks
+    /// that are not part of the executor `VMHooks` interface.
+    fn with_vm_hooks_dispatcher_ctx_1<R, F>(handle: Self::HandleType, f: F) -> R
+    where
+        F: FnOnce(&VMHooksDispatcher) -> R;
+
+    fn with_vm_hooks_dispatcher_ctx_2<R, F>(
+        handle1: Self::HandleType,
+        _handle2: Self::HandleType,
+        f: F,
+    ) -> R
+    where
+        F: FnOnce(&VMHooksDispatcher) -> R,
+    {
+        Self::with_vm_hooks_dispatcher_ctx_1(handle1, f)
+    }
+
     fn assert_live_handle(_handle: &Self::HandleType) {
         // by default, no check
     }
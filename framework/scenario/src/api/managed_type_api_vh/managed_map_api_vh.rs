@@ -65,4 +65,23 @@ impl<VHB: VMHooksApiBackend> ManagedMapApiImpl for VMHooksApi<VHB> {
             )
         }))
     }
+
+    fn mm_len(&self, map_handle: Self::ManagedMapHandle) -> usize {
+        self.with_vm_hooks_dispatcher_ctx_1(&map_handle, |vh| {
+            vh.managed_map_len(map_handle.get_raw_handle_unchecked())
+        }) as usize
+    }
+
+    fn mm_keys(
+        &self,
+        map_handle: Self::ManagedMapHandle,
+        out_keys_handle: Self::ManagedBufferHandle,
+    ) {
+        self.with_vm_hooks_dispatcher_ctx_2(&map_handle, &out_keys_handle, |vh| {
+            vh.managed_map_keys(
+                map_handle.get_raw_handle_unchecked(),
+                out_keys_handle.get_raw_handle_unchecked(),
+            )
+        });
+    }
 }
@@ -1,5 +1,8 @@
 use multiversx_chain_scenario_format::interpret_trait::InterpretableFrom;
-use multiversx_chain_vm::world_mock::BlockchainState;
+use multiversx_chain_vm::{
+    schedule::GasSchedule,
+    world_mock::{BlockchainState, ShardCoordinator},
+};
 use multiversx_sc_meta::cmd::contract::sc_config::ContractVariant;
 
 use crate::{
@@ -11,7 +14,7 @@ use crate::{
     },
     scenario::{run_trace::ScenarioTrace, run_vm::ScenarioVMRunner},
     scenario_format::{interpret_trait::InterpreterContext, value_interpreter::interpret_string},
-    scenario_model::BytesValue,
+    scenario_model::{BytesValue, TxResponse},
     vm_go_tool::run_mx_scenario_go,
 };
 use multiversx_sc_meta::find_workspace::find_current_workspace;
@@ -19,6 +22,9 @@ use std::path::{Path, PathBuf};
 
 use super::debugger_backend::DebuggerBackend;
 
+/// Guards against contracts that keep calling each other across shards indefinitely.
+const MAX_ROUNDS_UNTIL_IDLE: u64 = 100;
+
 /// A facade for contracts tests.
 ///
 /// Contains all the context needed to execute scenarios involving contracts.
@@ -52,6 +58,21 @@ impl ScenarioWorld {
         }
     }
 
+    /// Runs the compiled contracts referenced in scenarios (via `file:` or `mxsc:`) in-process,
+    /// using a WebAssembly interpreter written in Rust.
+    ///
+    /// Contracts registered via `register_contract` are only used where the compiled code is missing.
+    #[cfg(feature = "wasm-executor")]
+    pub fn wasm_executor() -> Self {
+        ScenarioWorld {
+            current_dir: std::env::current_dir().unwrap(),
+            backend: Backend::Debugger(DebuggerBackend {
+                vm_runner: ScenarioVMRunner::new_wasm_executor(),
+                trace: None,
+            }),
+        }
+    }
+
     /// Backwards compatibility only.
     pub fn new() -> Self {
         Self::debugger()
@@ -113,6 +134,71 @@ impl ScenarioWorld {
         self
     }
 
+    /// Enables gas metering in the debugger, with the costs from the given gas schedule.
+    ///
+    /// Gas used is then reported in tx responses and checked against the `gas` expectations.
+    pub fn set_gas_schedule(&mut self, gas_schedule: GasSchedule) -> &mut Self {
+        self.get_mut_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .set_gas_schedule(Some(gas_schedule))
+            .unwrap();
+        self
+    }
+
+    /// Assigns accounts to shards based on their address, in the debugger.
+    ///
+    /// Async calls and promises to contracts in other shards are no longer executed within the transaction,
+    /// they wait for the next round instead, see `step_round`. Their callbacks arrive one round later still.
+    pub fn enable_sharding(&mut self, num_shards: u32) -> &mut Self {
+        self.get_mut_state().shard_coordinator = Some(ShardCoordinator::new(num_shards));
+        self
+    }
+
+    /// Moves the debugger to the next block, and executes the cross-shard calls queued so far.
+    ///
+    /// Returns the responses of the calls and callbacks executed, in order.
+    pub fn step_round(&mut self) -> Vec<TxResponse> {
+        let blockchain_mock = &mut self.get_mut_debugger_backend().vm_runner.blockchain_mock;
+        blockchain_mock
+            .vm
+            .execute_next_round(&mut blockchain_mock.state)
+            .into_iter()
+            .map(TxResponse::from_tx_result)
+            .collect()
+    }
+
+    /// Returns the responses of all the calls and callbacks executed in these rounds, in order.
+    pub fn step_rounds(&mut self, num_rounds: u64) -> Vec<TxResponse> {
+        let mut responses = Vec::new();
+        for _ in 0..num_rounds {
+            responses.extend(self.step_round());
+        }
+        responses
+    }
+
+    /// Steps rounds until all cross-shard calls and their callbacks have completed.
+    ///
+    /// Returns the responses of all the calls and callbacks executed meanwhile, in order.
+    pub fn step_rounds_until_idle(&mut self) -> Vec<TxResponse> {
+        let mut responses = Vec::new();
+        let mut num_rounds = 0;
+        while self.has_pending_cross_shard_calls() {
+            assert!(
+                num_rounds < MAX_ROUNDS_UNTIL_IDLE,
+                "cross-shard calls still pending after {MAX_ROUNDS_UNTIL_IDLE} rounds"
+            );
+            responses.extend(self.step_round());
+            num_rounds += 1;
+        }
+        responses
+    }
+
+    pub fn has_pending_cross_shard_calls(&self) -> bool {
+        !self.get_state().cross_shard_calls.is_empty()
+    }
+
     /// Tells the tests where the crate lies relative to the workspace.
     /// This ensures that the paths are set correctly, including in debug mode.
     pub fn set_current_dir_from_workspace(&mut self, relative_path: &str) -> &mut Self {
@@ -209,6 +209,42 @@ impl ScenarioWorld {
         self
     }
 
+    /// Checks that the storage of an account consists of exactly the given key-value pairs.
+    ///
+    /// Keys and values are scenario expressions, e.g. `("str:version", "2")`.
+    pub fn check_storage_layout(
+        &mut self,
+        address_expr: &str,
+        layout: &[(&str, &str)],
+    ) -> &mut Self {
+        let mut account = CheckAccount::new();
+        account.storage = CheckStorage::Equal(CheckStorageDetails::default());
+        for (key, value) in layout {
+            account = account.check_storage(key, value);
+        }
+
+        self.check_state_step(CheckStateStep::new().put_account(address_expr, account))
+    }
+
+    /// Checks the storage layout of a contract before and after a storage migration,
+    /// e.g. an upgrade, or a series of calls that resume an interrupted migration.
+    ///
+    /// Both layouts need to match exactly, see `check_storage_layout`.
+    pub fn check_storage_migration<F>(
+        &mut self,
+        address_expr: &str,
+        before: &[(&str, &str)],
+        migration: F,
+        after: &[(&str, &str)],
+    ) -> &mut Self
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.check_storage_layout(address_expr, before);
+        migration(self);
+        self.check_storage_layout(address_expr, after)
+    }
+
     /// Adds a dump state step, then executes it.
     pub fn dump_state_step(&mut self) -> &mut Self {
         self.run_dump_state_step();
@@ -12,6 +12,8 @@ pub struct BlockInfo {
     pub block_round: Option<U64Value>,
     pub block_epoch: Option<U64Value>,
     pub block_random_seed: Option<BytesValue>,
+    pub block_hash: Option<BytesValue>,
+    pub state_root_hash: Option<BytesValue>,
 }
 
 impl InterpretableFrom<BlockInfoRaw> for BlockInfo {
@@ -32,6 +34,12 @@ impl InterpretableFrom<BlockInfoRaw> for BlockInfo {
             block_random_seed: from
                 .block_random_seed
                 .map(|v| BytesValue::interpret_from(v, context)),
+            block_hash: from
+                .block_hash
+                .map(|v| BytesValue::interpret_from(v, context)),
+            state_root_hash: from
+                .state_root_hash
+                .map(|v| BytesValue::interpret_from(v, context)),
         }
     }
 }
@@ -44,6 +52,8 @@ impl IntoRaw<BlockInfoRaw> for BlockInfo {
             block_round: self.block_round.map(|value| value.original),
             block_epoch: self.block_epoch.map(|value| value.original),
             block_random_seed: self.block_random_seed.map(|value| value.original),
+            block_hash: self.block_hash.map(|value| value.original),
+            state_root_hash: self.state_root_hash.map(|value| value.original),
         }
     }
 }
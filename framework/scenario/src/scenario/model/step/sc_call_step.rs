@@ -132,6 +132,14 @@ impl ScCallStep {
         self
     }
 
+    pub fn gas_price<V>(mut self, value: V) -> Self
+    where
+        U64Value: From<V>,
+    {
+        self.tx.gas_price = U64Value::from(value);
+        self
+    }
+
     /// Sets following fields based on the smart contract proxy:
     /// - "to"
     /// - "function"
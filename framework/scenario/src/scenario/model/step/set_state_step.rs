@@ -116,6 +116,30 @@ impl SetStateStep {
         self
     }
 
+    pub fn block_hash<B>(mut self, block_hash_expr: B) -> Self
+    where
+        BytesValue: From<B>,
+    {
+        let block_hash = BytesValue::from(block_hash_expr);
+
+        let mut block_info = self.current_block_info.unwrap_or_default();
+        block_info.block_hash = Some(block_hash);
+        self.current_block_info = Box::new(Some(block_info));
+        self
+    }
+
+    pub fn state_root_hash<B>(mut self, state_root_hash_expr: B) -> Self
+    where
+        BytesValue: From<B>,
+    {
+        let state_root_hash = BytesValue::from(state_root_hash_expr);
+
+        let mut block_info = self.current_block_info.unwrap_or_default();
+        block_info.state_root_hash = Some(state_root_hash);
+        self.current_block_info = Box::new(Some(block_info));
+        self
+    }
+
     pub fn prev_block_epoch<N>(mut self, block_epoch_expr: N) -> Self
     where
         U64Value: From<N>,
@@ -175,4 +199,28 @@ impl SetStateStep {
         self.previous_block_info = Box::new(Some(block_info));
         self
     }
+
+    pub fn prev_block_hash<B>(mut self, block_hash_expr: B) -> Self
+    where
+        BytesValue: From<B>,
+    {
+        let block_hash = BytesValue::from(block_hash_expr);
+
+        let mut block_info = self.previous_block_info.unwrap_or_default();
+        block_info.block_hash = Some(block_hash);
+        self.previous_block_info = Box::new(Some(block_info));
+        self
+    }
+
+    pub fn prev_state_root_hash<B>(mut self, state_root_hash_expr: B) -> Self
+    where
+        BytesValue: From<B>,
+    {
+        let state_root_hash = BytesValue::from(state_root_hash_expr);
+
+        let mut block_info = self.previous_block_info.unwrap_or_default();
+        block_info.state_root_hash = Some(state_root_hash);
+        self.previous_block_info = Box::new(Some(block_info));
+        self
+    }
 }
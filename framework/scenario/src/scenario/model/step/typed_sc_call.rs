@@ -89,6 +89,14 @@ impl<OriginalResult> TypedScCall<OriginalResult> {
         self
     }
 
+    pub fn gas_price<V>(mut self, value: V) -> Self
+    where
+        U64Value: From<V>,
+    {
+        self.sc_call_step = self.sc_call_step.gas_price(value);
+        self
+    }
+
     /// Adds a custom expect section to the tx.
     pub fn expect(mut self, expect: TxExpect) -> Self {
         self.sc_call_step = self.sc_call_step.expect(expect);
@@ -46,6 +46,7 @@ impl TxResponse {
                 status: tx_result.result_status,
                 message: tx_result.result_message,
             },
+            gas: tx_result.gas_used,
             ..Default::default()
         }
     }
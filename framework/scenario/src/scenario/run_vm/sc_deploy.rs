@@ -8,7 +8,7 @@ use multiversx_chain_vm::{
     types::VMCodeMetadata,
 };
 
-use super::{check_tx_output, tx_input_util::generate_tx_hash, ScenarioVMRunner};
+use super::{check_tx_gas, check_tx_output, tx_input_util::generate_tx_hash, ScenarioVMRunner};
 
 impl ScenarioVMRunner {
     /// Adds a SC deploy step, as specified in the `step` argument, then executes it.
@@ -32,17 +32,13 @@ impl ScenarioVMRunner {
     {
         let tx_input = tx_input_from_deploy(sc_deploy_step);
         let contract_code = &sc_deploy_step.tx.contract_code.value;
-        let (new_address, tx_result) = self.blockchain_mock.vm.sc_create(
+        let (new_address, tx_result) = self.blockchain_mock.vm.sc_create_with_async_and_callback(
             tx_input,
             contract_code,
             VMCodeMetadata::from(sc_deploy_step.tx.code_metadata.bits()),
             &mut self.blockchain_mock.state,
             f,
         );
-        assert!(
-            tx_result.pending_calls.no_calls(),
-            "Async calls from constructors are currently not supported"
-        );
         (new_address.as_array().into(), tx_result)
     }
 
@@ -57,6 +53,9 @@ impl ScenarioVMRunner {
         let (new_address, tx_result) = self.perform_sc_deploy_lambda(sc_deploy_step, f);
         if let Some(tx_expect) = &sc_deploy_step.expect {
             check_tx_output(&sc_deploy_step.id, tx_expect, &tx_result);
+            if self.blockchain_mock.vm.is_gas_metering_enabled() {
+                check_tx_gas(&sc_deploy_step.id, tx_expect, &tx_result);
+            }
         }
         (new_address, tx_result)
     }
@@ -4,7 +4,7 @@ use multiversx_chain_vm::{
     tx_mock::{TxInput, TxResult},
 };
 
-use super::{check_tx_output, tx_input_util::generate_tx_hash, ScenarioVMRunner};
+use super::{check_tx_gas, check_tx_output, tx_input_util::generate_tx_hash, ScenarioVMRunner};
 
 impl ScenarioVMRunner {
     /// Adds a SC query step, as specified in the `sc_query_step` argument, then executes it.
@@ -41,6 +41,9 @@ impl ScenarioVMRunner {
         let tx_result = self.perform_sc_query_lambda(step, f);
         if let Some(tx_expect) = &step.expect {
             check_tx_output(&step.id, tx_expect, &tx_result);
+            if self.blockchain_mock.vm.is_gas_metering_enabled() {
+                check_tx_gas(&step.id, tx_expect, &tx_result);
+            }
         }
         tx_result
     }
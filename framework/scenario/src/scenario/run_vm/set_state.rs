@@ -1,4 +1,4 @@
-use crate::scenario::model::SetStateStep;
+use crate::scenario::model::{BytesValue, SetStateStep};
 
 use multiversx_chain_vm::{
     types::{VMAddress, VMCodeMetadata},
@@ -173,6 +173,7 @@ fn convert_scenario_esdt_instance_to_world_mock(
                 .map(|attributes| attributes.value.clone())
                 .unwrap_or_default(),
         },
+        frozen: false,
     }
 }
 
@@ -184,7 +185,7 @@ fn update_block_info(
         block_info.block_timestamp = u64_value.value;
     }
     if let Some(u64_value) = &scenario_block_info.block_nonce {
-        block_info.block_nonce = u64_value.value;
+        block_info.advance_to_nonce(u64_value.value);
     }
     if let Some(u64_value) = &scenario_block_info.block_epoch {
         block_info.block_epoch = u64_value.value;
@@ -205,4 +206,24 @@ fn update_block_info(
         seed[..].copy_from_slice(val.as_slice());
         block_info.block_random_seed = Box::from(seed);
     }
+    if let Some(bytes_value) = &scenario_block_info.block_hash {
+        block_info.block_hash = Box::from(hash_from_bytes_value(bytes_value, "block hash"));
+    }
+    if let Some(bytes_value) = &scenario_block_info.state_root_hash {
+        block_info.state_root_hash =
+            Box::from(hash_from_bytes_value(bytes_value, "state root hash"));
+    }
+}
+
+fn hash_from_bytes_value(bytes_value: &BytesValue, name: &str) -> [u8; 32] {
+    let val = &bytes_value.value;
+
+    assert!(
+        val.len() == 32,
+        "{name} input value must be exactly 32 bytes long"
+    );
+
+    let mut hash = [0u8; 32];
+    hash[..].copy_from_slice(val.as_slice());
+    hash
 }
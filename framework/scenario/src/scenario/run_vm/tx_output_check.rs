@@ -65,6 +65,17 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
     }
 }
 
+/// Gas is only checked when gas metering is enabled in the VM.
+pub fn check_tx_gas(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult) {
+    assert!(
+        tx_expect.gas.check(tx_result.gas_used),
+        "gas used mismatch. Tx id: '{}'. Want: {}. Have: {}.",
+        tx_id,
+        tx_expect.gas,
+        tx_result.gas_used,
+    );
+}
+
 fn scenario_check(
     actual_log: &TxLog,
     expected_log: &crate::scenario::model::CheckLog,
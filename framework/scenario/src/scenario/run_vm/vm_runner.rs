@@ -4,6 +4,9 @@ use crate::{
     scenario::{model::*, ScenarioRunner},
 };
 
+#[cfg(feature = "wasm-executor")]
+use crate::multiversx_chain_vm::wasm_executor::WasmiExecutor;
+
 /// Wraps calls to the blockchain mock,
 /// while implementing the StepRunner interface.
 #[derive(Default, Debug)]
@@ -21,6 +24,20 @@ impl ScenarioVMRunner {
             blockchain_mock,
         }
     }
+
+    /// Executes contract code that is WebAssembly in the wasmi interpreter.
+    ///
+    /// Contracts registered in the contract map are still used for all other contract code.
+    #[cfg(feature = "wasm-executor")]
+    pub fn new_wasm_executor() -> Self {
+        let contract_map_ref = ContractMapRef::new();
+        let executor = WasmiExecutor::new(Box::new(contract_map_ref.clone()));
+        let blockchain_mock = BlockchainMock::new(Box::new(executor));
+        ScenarioVMRunner {
+            contract_map_ref,
+            blockchain_mock,
+        }
+    }
 }
 
 impl ScenarioRunner for ScenarioVMRunner {
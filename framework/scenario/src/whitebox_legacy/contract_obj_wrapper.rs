@@ -504,6 +504,16 @@ impl BlockchainStateWrapper {
             .set_state_step(SetStateStep::new().block_random_seed(block_random_seed.as_slice()));
     }
 
+    pub fn set_block_hash(&mut self, block_hash: &[u8; 32]) {
+        self.world
+            .set_state_step(SetStateStep::new().block_hash(block_hash.as_slice()));
+    }
+
+    pub fn set_state_root_hash(&mut self, state_root_hash: &[u8; 32]) {
+        self.world
+            .set_state_step(SetStateStep::new().state_root_hash(state_root_hash.as_slice()));
+    }
+
     pub fn set_block_round(&mut self, block_round: u64) {
         self.world
             .set_state_step(SetStateStep::new().block_round(block_round));
@@ -0,0 +1,45 @@
+use multiversx_sc::types::{BigUint, EllipticCurve, ManagedBuffer};
+use multiversx_sc_scenario::api::StaticApi;
+
+#[test]
+fn test_elliptic_curve_values() {
+    let ec = EllipticCurve::<StaticApi>::from_bitsize(256).unwrap();
+    let (_, _, _, x_base_point, y_base_point, size_in_bits) = ec.get_values();
+    assert_eq!(size_in_bits, 256);
+    assert_eq!(ec.get_curve_length(), 256);
+    assert_eq!(ec.get_priv_key_byte_length(), 32);
+    assert!(ec.is_on_curve(x_base_point, y_base_point));
+}
+
+#[test]
+fn test_elliptic_curve_add_marshal() {
+    let ec = EllipticCurve::<StaticApi>::from_name(&ManagedBuffer::from("p256"));
+    let (_, _, _, x_base_point, y_base_point, _) = ec.get_values();
+    let (x, y) = ec.add(
+        x_base_point.clone(),
+        y_base_point.clone(),
+        x_base_point.clone(),
+        y_base_point.clone(),
+    );
+    assert_eq!(
+        (x.clone(), y.clone()),
+        ec.double(x_base_point, y_base_point)
+    );
+
+    let marshalled = ec.marshal(x.clone(), y.clone());
+    assert_eq!(marshalled.len(), 65);
+    assert_eq!(ec.unmarshal(&marshalled), (x.clone(), y.clone()));
+
+    let compressed = ec.marshal_compressed(x.clone(), y.clone());
+    assert_eq!(compressed.len(), 33);
+    assert_eq!(ec.unmarshal_compressed(&compressed), (x, y));
+}
+
+#[test]
+fn test_elliptic_curve_scalar_base_mult() {
+    let ec = EllipticCurve::<StaticApi>::from_name_str("p224");
+    let (x, _) = ec.scalar_base_mult(&ManagedBuffer::from(&[1u8][..]));
+    let (_, _, _, x_base_point, _, _) = ec.get_values();
+    assert_eq!(x, x_base_point);
+    assert_ne!(x, BigUint::zero());
+}
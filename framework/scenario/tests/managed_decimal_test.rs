@@ -0,0 +1,177 @@
+use multiversx_sc::{
+    abi::TypeAbi,
+    codec::test_util::{check_dep_encode_decode, check_top_encode_decode},
+    types::{BigFloat, BigUint, ConstDecimals, ManagedDecimal, NumDecimals, RoundingMode},
+};
+use multiversx_sc_scenario::api::StaticApi;
+
+type Decimal6 = ManagedDecimal<StaticApi, ConstDecimals<6>>;
+
+fn dec6(raw_units: u64) -> Decimal6 {
+    ManagedDecimal::from_raw_units(BigUint::from(raw_units), ConstDecimals)
+}
+
+fn dec(raw_units: u64, decimals: NumDecimals) -> ManagedDecimal<StaticApi, NumDecimals> {
+    ManagedDecimal::from_raw_units(BigUint::from(raw_units), decimals)
+}
+
+#[test]
+fn test_managed_decimal_basics() {
+    let x = Decimal6::from(BigUint::from(3u32));
+    assert_eq!(x.raw_units(), &BigUint::from(3_000_000u32));
+    assert_eq!(x.scale(), 6);
+
+    let y = dec6(1_750_000);
+    assert_eq!(y.trunc(), BigUint::from(1u32));
+    assert_eq!(x.clone() + y.clone(), dec6(4_750_000));
+    assert_eq!(x - y, dec6(1_250_000));
+
+    // values are compared regardless of the number of decimals
+    assert_eq!(dec(15, 1), dec6(1_500_000));
+    assert!(dec(16, 1) > dec6(1_599_999));
+    assert!(dec(1, 0) < dec(1_000_001, 6));
+}
+
+#[test]
+fn test_managed_decimal_mul_div() {
+    let amount = dec6(2_500_000);
+    let rate = dec(5, 2);
+    assert_eq!(amount.clone() * rate.clone(), dec6(125_000));
+    assert_eq!(amount / rate, dec6(50_000_000));
+
+    // 1 / 3 = 0.333333|33...
+    let one = dec6(1_000_000);
+    let three = dec6(3_000_000);
+    assert_eq!(one.clone() / three.clone(), dec6(333_333));
+    assert_eq!(
+        one.div_with_rounding(&three, RoundingMode::Up),
+        dec6(333_334)
+    );
+
+    // 2 / 3 = 0.666666|66...
+    let two = dec6(2_000_000);
+    assert_eq!(
+        two.div_with_rounding(&three, RoundingMode::Down),
+        dec6(666_666)
+    );
+    assert_eq!(
+        two.div_with_rounding(&three, RoundingMode::HalfUp),
+        dec6(666_667)
+    );
+
+    // 0.000005 * 0.5 = 0.000002|5
+    let tiny = dec6(5);
+    let half = dec(5, 1);
+    assert_eq!(tiny.mul_with_rounding(&half, RoundingMode::Down), dec6(2));
+    assert_eq!(tiny.mul_with_rounding(&half, RoundingMode::HalfUp), dec6(3));
+    assert_eq!(
+        tiny.mul_with_rounding(&half, RoundingMode::HalfEven),
+        dec6(2)
+    );
+    assert_eq!(
+        dec6(7).mul_with_rounding(&half, RoundingMode::HalfEven),
+        dec6(4)
+    );
+}
+
+#[test]
+fn test_managed_decimal_rescale() {
+    let x = dec(123_456, 3);
+    let up = x.rescale(ConstDecimals::<6>, RoundingMode::Down);
+    assert_eq!(up.raw_units(), &BigUint::from(123_456_000u32));
+
+    assert_eq!(
+        x.rescale(1usize, RoundingMode::Down).raw_units(),
+        &BigUint::from(1234u32)
+    );
+    assert_eq!(
+        x.rescale(1usize, RoundingMode::HalfUp).raw_units(),
+        &BigUint::from(1235u32)
+    );
+    assert_eq!(
+        x.rescale(0usize, RoundingMode::Up).raw_units(),
+        &BigUint::from(124u32)
+    );
+
+    let var = up.into_var_decimals();
+    assert_eq!(var.scale(), 6);
+    assert_eq!(var, x);
+}
+
+#[test]
+fn test_managed_decimal_big_float() {
+    let x = dec6(2_500_000);
+    let big_float = x.to_big_float();
+    assert_eq!(big_float, BigFloat::from_frac(5, 2));
+    assert_eq!(
+        ManagedDecimal::from_big_float(&BigFloat::from_frac(1, 3), 4usize),
+        dec(3333, 4)
+    );
+}
+
+#[test]
+fn test_managed_decimal_exp_ln() {
+    type Decimal18 = ManagedDecimal<StaticApi, ConstDecimals<18>>;
+    let from_str = |raw_units: &str| -> Decimal18 {
+        ManagedDecimal::from_raw_units(
+            BigUint::from(raw_units.parse::<u128>().unwrap()),
+            ConstDecimals,
+        )
+    };
+
+    let zero = from_str("0");
+    let one = from_str("1000000000000000000");
+    assert_eq!(zero.exp(), one);
+    assert_eq!(one.ln(), Some(zero.clone()));
+
+    assert_eq!(one.exp(), from_str("2718281828459045235"));
+    assert_eq!(
+        from_str("500000000000000000").exp(),
+        from_str("1648721270700128147")
+    );
+    assert_eq!(
+        from_str("10000000000000000000").exp(),
+        from_str("22026465794806716516958")
+    );
+
+    assert_eq!(
+        from_str("2000000000000000000").ln(),
+        Some(from_str("693147180559945309"))
+    );
+    assert_eq!(
+        from_str("2718281828459045235").ln(),
+        Some(from_str("1000000000000000000"))
+    );
+    assert_eq!(
+        from_str("1050000000000000000").ln(),
+        Some(from_str("48790164169432003"))
+    );
+    assert_eq!(
+        from_str("1000000000000000000000").ln(),
+        Some(from_str("6907755278982137052"))
+    );
+
+    // the result would be negative
+    assert_eq!(from_str("999999999999999999").ln(), None);
+    assert_eq!(zero.ln(), None);
+
+    // few decimals
+    assert_eq!(dec(15, 1).exp(), dec(45, 1));
+    assert_eq!(dec(100, 1).ln(), Some(dec(23, 1)));
+}
+
+#[test]
+fn test_managed_decimal_codec() {
+    check_top_encode_decode(dec6(1_500_000), &[0x16, 0xe3, 0x60]);
+    check_dep_encode_decode(dec6(1_500_000), &[0, 0, 0, 3, 0x16, 0xe3, 0x60]);
+
+    // the number of decimals is also serialized when only known at runtime
+    check_top_encode_decode(dec(15, 1), &[0, 0, 0, 1, 15, 0, 0, 0, 1]);
+    check_dep_encode_decode(dec(15, 1), &[0, 0, 0, 1, 15, 0, 0, 0, 1]);
+
+    assert_eq!(Decimal6::type_name(), "ManagedDecimal<6>");
+    assert_eq!(
+        ManagedDecimal::<StaticApi, NumDecimals>::type_name(),
+        "ManagedDecimal<usize>"
+    );
+}
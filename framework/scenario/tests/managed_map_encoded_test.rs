@@ -0,0 +1,83 @@
+use multiversx_sc::types::{BigUint, ManagedBuffer, ManagedMapEncoded, TokenIdentifier};
+use multiversx_sc_scenario::api::StaticApi;
+
+#[test]
+fn test_managed_map_encoded_put_get() {
+    let mut map = ManagedMapEncoded::<StaticApi, u32, u64>::new();
+    assert!(map.is_empty());
+    assert_eq!(map.get(&1), None);
+    assert!(!map.contains(&1));
+
+    map.put(&1, &100);
+    map.put(&2, &200);
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&1), Some(100));
+    assert_eq!(map.get(&2), Some(200));
+    assert!(map.contains(&2));
+
+    // overwriting does not add the key again
+    map.put(&1, &101);
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&1), Some(101));
+    assert_eq!(map.keys().collect::<Vec<_>>(), vec![1, 2]);
+}
+
+#[test]
+fn test_managed_map_encoded_empty_encoding() {
+    // zero values and empty keys top-encode to empty buffers, but are still present
+    let mut map = ManagedMapEncoded::<StaticApi, ManagedBuffer<StaticApi>, u32>::new();
+    map.put(&ManagedBuffer::new(), &0);
+    assert!(map.contains(&ManagedBuffer::new()));
+    assert_eq!(map.get(&ManagedBuffer::new()), Some(0));
+    assert_eq!(map.len(), 1);
+
+    assert_eq!(map.remove(&ManagedBuffer::new()), Some(0));
+    assert!(map.is_empty());
+    assert_eq!(map.remove(&ManagedBuffer::new()), None);
+}
+
+#[test]
+fn test_managed_map_encoded_remove() {
+    let mut map = ManagedMapEncoded::<StaticApi, u32, u32>::new();
+    for key in 0..5 {
+        map.put(&key, &(key * 10));
+    }
+
+    // the last key takes the place of the removed one
+    assert_eq!(map.remove(&1), Some(10));
+    assert_eq!(map.keys().collect::<Vec<_>>(), vec![0, 4, 2, 3]);
+    assert_eq!(map.values().collect::<Vec<_>>(), vec![0, 40, 20, 30]);
+
+    assert_eq!(map.remove(&3), Some(30));
+    assert_eq!(map.remove(&3), None);
+    assert_eq!(
+        map.iter().collect::<Vec<_>>(),
+        vec![(0, 0), (4, 40), (2, 20)]
+    );
+
+    // moved keys can still be removed
+    assert_eq!(map.remove(&4), Some(40));
+    assert_eq!(map.remove(&0), Some(0));
+    assert_eq!(map.remove(&2), Some(20));
+    assert!(map.is_empty());
+    assert_eq!(map.keys().count(), 0);
+
+    map.put(&7, &70);
+    assert_eq!(map.iter().collect::<Vec<_>>(), vec![(7, 70)]);
+}
+
+#[test]
+fn test_managed_map_encoded_managed_types() {
+    let mut totals =
+        ManagedMapEncoded::<StaticApi, TokenIdentifier<StaticApi>, BigUint<StaticApi>>::new();
+    let token_a = TokenIdentifier::from("TOKENA-123456");
+    let token_b = TokenIdentifier::from("TOKENB-123456");
+    for (token_id, amount) in [(&token_a, 5u64), (&token_b, 7), (&token_a, 11)] {
+        let total = totals.get(token_id).unwrap_or_default() + amount;
+        totals.put(token_id, &total);
+    }
+
+    assert_eq!(totals.len(), 2);
+    assert_eq!(totals.get(&token_a), Some(BigUint::from(16u64)));
+    assert_eq!(totals.get(&token_b), Some(BigUint::from(7u64)));
+}
@@ -0,0 +1,22 @@
+use multiversx_sc::types::{ManagedBuffer, ManagedMap};
+use multiversx_sc_scenario::api::StaticApi;
+
+#[test]
+fn test_managed_map_len_and_keys() {
+    let mut map = ManagedMap::<StaticApi>::new();
+    assert!(map.is_empty());
+
+    for (key, value) in [("c", "3"), ("a", "1"), ("b", "2")] {
+        map.put(&ManagedBuffer::from(key), &ManagedBuffer::from(value));
+    }
+    assert_eq!(map.len(), 3);
+
+    // keys with empty values are considered missing
+    map.put(&ManagedBuffer::from("b"), &ManagedBuffer::new());
+    assert_eq!(map.len(), 2);
+
+    let keys = map.keys();
+    assert_eq!(keys.len(), 2);
+    assert_eq!(*keys.get(0), ManagedBuffer::from("a"));
+    assert_eq!(*keys.get(1), ManagedBuffer::from("c"));
+}
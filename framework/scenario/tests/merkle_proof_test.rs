@@ -0,0 +1,97 @@
+use multiversx_sc::types::{
+    Keccak256MerkleHasher, ManagedBuffer, ManagedVec, MerkleHash, MerkleHasher,
+    MerkleProofVerifier, Sha256MerkleHasher,
+};
+use multiversx_sc_scenario::api::StaticApi;
+
+fn zero_hash() -> MerkleHash<StaticApi> {
+    MerkleHash::default()
+}
+
+fn hash_from_hex(hex_str: &str) -> MerkleHash<StaticApi> {
+    let bytes: [u8; 32] = hex::decode(hex_str).unwrap().try_into().unwrap();
+    MerkleHash::new_from_bytes(&bytes)
+}
+
+fn leaf<H: MerkleHasher>(data: &str) -> MerkleHash<StaticApi> {
+    H::hash(&ManagedBuffer::from(data))
+}
+
+/// The first zero hashes, as used by the Ethereum deposit contract (sha256)
+/// and by most keccak256 incremental Merkle trees.
+#[test]
+fn test_merkle_hash_pair_known_values() {
+    let sha256 = MerkleProofVerifier::<StaticApi, Sha256MerkleHasher>::new();
+    let level_1 = sha256.hash_pair(&zero_hash(), &zero_hash());
+    assert_eq!(
+        level_1,
+        hash_from_hex("f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a92759fb4b")
+    );
+    assert_eq!(
+        sha256.hash_pair(&level_1, &level_1),
+        hash_from_hex("db56114e00fdd4c1f85c892bf35ac9a89289aaecb1ebd0a96cde606a748b5d71")
+    );
+
+    let keccak256 = MerkleProofVerifier::<StaticApi, Keccak256MerkleHasher>::new();
+    let level_1 = keccak256.hash_pair(&zero_hash(), &zero_hash());
+    assert_eq!(
+        level_1,
+        hash_from_hex("ad3228b676f7d3cd4284a5443f17f1962b36e491b30a40b2405849e597ba5fb5")
+    );
+    assert_eq!(
+        keccak256.hash_pair(&level_1, &level_1),
+        hash_from_hex("b4c11951957c6f8f642c4af61cd6b24640fec6dc7fc607ee8206a99e92410d30")
+    );
+}
+
+#[test]
+fn test_merkle_sorted_proof() {
+    let verifier = MerkleProofVerifier::<StaticApi, Keccak256MerkleHasher>::new();
+    let leaves: Vec<MerkleHash<StaticApi>> = ["a", "b", "c", "d"]
+        .iter()
+        .map(|data| leaf::<Keccak256MerkleHasher>(data))
+        .collect();
+    let node_ab = verifier.hash_sorted_pair(&leaves[0], &leaves[1]);
+    let node_cd = verifier.hash_sorted_pair(&leaves[2], &leaves[3]);
+    let root = verifier.hash_sorted_pair(&node_ab, &node_cd);
+
+    // the order of the siblings does not matter
+    assert_eq!(
+        verifier.hash_sorted_pair(&node_cd, &node_ab),
+        verifier.hash_sorted_pair(&node_ab, &node_cd)
+    );
+
+    let mut proof = ManagedVec::new();
+    proof.push(leaves[3].clone());
+    proof.push(node_ab.clone());
+    assert!(verifier.verify_sorted_proof(&root, &leaves[2], &proof));
+    assert!(!verifier.verify_sorted_proof(&root, &leaves[0], &proof));
+    assert!(!verifier.verify_sorted_proof(&node_ab, &leaves[2], &proof));
+
+    // a proof with a single leaf is the leaf itself
+    assert!(verifier.verify_sorted_proof(&leaves[0], &leaves[0], &ManagedVec::new()));
+}
+
+#[test]
+fn test_merkle_indexed_proof() {
+    let verifier = MerkleProofVerifier::<StaticApi, Sha256MerkleHasher>::new();
+    let leaves: Vec<MerkleHash<StaticApi>> = ["a", "b", "c", "d"]
+        .iter()
+        .map(|data| leaf::<Sha256MerkleHasher>(data))
+        .collect();
+    let node_ab = verifier.hash_pair(&leaves[0], &leaves[1]);
+    let node_cd = verifier.hash_pair(&leaves[2], &leaves[3]);
+    let root = verifier.hash_pair(&node_ab, &node_cd);
+
+    let mut proof = ManagedVec::new();
+    proof.push(leaves[2].clone());
+    proof.push(node_ab.clone());
+    assert!(verifier.verify_indexed_proof(&root, &leaves[3], 3, &proof));
+
+    // the position matters
+    assert!(!verifier.verify_indexed_proof(&root, &leaves[3], 2, &proof));
+    assert!(!verifier.verify_indexed_proof(&root, &leaves[3], 1, &proof));
+
+    // indexes that do not fit in the tree are rejected
+    assert_eq!(verifier.process_indexed_proof(&leaves[3], 7, &proof), None);
+}
@@ -0,0 +1,21 @@
+#![cfg(feature = "wasm-executor")]
+
+use multiversx_sc_scenario::{multiversx_chain_vm::schedule::GasSchedule, *};
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::wasm_executor();
+    blockchain.set_current_dir_from_workspace("framework/scenario");
+    blockchain
+}
+
+#[test]
+fn adder_wasm() {
+    world().run("tests/scenarios-wasm/adder.scen.json");
+}
+
+#[test]
+fn adder_out_of_gas_wasm() {
+    let mut world = world();
+    world.set_gas_schedule(GasSchedule::default());
+    world.run("tests/scenarios-wasm/adder-out-of-gas.scen.json");
+}
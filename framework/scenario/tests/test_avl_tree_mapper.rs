@@ -0,0 +1,327 @@
+use std::collections::BTreeSet;
+
+use multiversx_chain_vm::vm_hooks::StorageAccessCount;
+use multiversx_sc::storage::{
+    mappers::{AvlTreeMapper, StorageClearable, StorageMapper},
+    StorageKey,
+};
+use multiversx_sc_scenario::api::SingleTxApi;
+
+type TestTree = AvlTreeMapper<SingleTxApi, u64>;
+
+fn create_tree() -> TestTree {
+    let base_key = StorageKey::new(&b"my_tree"[..]);
+    AvlTreeMapper::new(base_key)
+}
+
+/// Deterministic xorshift generator, so that failures are reproducible.
+struct TestRng(u64);
+
+impl TestRng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+/// The height of an AVL tree with n nodes is less than 1.4405 * log2(n + 2) - 0.3277.
+fn max_avl_height(len: usize) -> usize {
+    (1.4405 * ((len + 2) as f64).log2() - 0.3277) as usize
+}
+
+/// The tree info is decoded with a default, which first loads its length, then its contents.
+const INFO_READS: usize = 2;
+
+fn count_storage_access<R, F: FnOnce() -> R>(f: F) -> (R, StorageAccessCount) {
+    SingleTxApi::reset_storage_access_count();
+    let result = f();
+    (result, SingleTxApi::storage_access_count())
+}
+
+fn check_tree(tree: &TestTree, expected: &BTreeSet<u64>) {
+    assert!(tree.check_internal_consistency());
+    assert_eq!(tree.len(), expected.len());
+    assert!(tree.height() as usize <= max_avl_height(expected.len()));
+    let items: Vec<u64> = tree.iter().collect();
+    let expected_items: Vec<u64> = expected.iter().cloned().collect();
+    assert_eq!(items, expected_items);
+}
+
+#[test]
+fn test_avl_tree_simple() {
+    let mut tree = create_tree();
+    assert!(tree.is_empty());
+    assert_eq!(tree.height(), 0);
+    assert_eq!(tree.first(), None);
+    assert_eq!(tree.last(), None);
+    assert!(tree.check_internal_consistency());
+
+    assert!(tree.insert(42));
+    assert!(tree.insert(7));
+    assert!(tree.insert(100));
+    assert!(!tree.insert(42));
+    assert_eq!(tree.len(), 3);
+    assert!(tree.contains(&7));
+    assert!(!tree.contains(&8));
+    assert_eq!(tree.first(), Some(7));
+    assert_eq!(tree.last(), Some(100));
+    assert!(tree.check_internal_consistency());
+
+    assert!(tree.remove(&42));
+    assert!(!tree.remove(&42));
+    assert_eq!(tree.iter().collect::<Vec<u64>>(), vec![7, 100]);
+    assert!(tree.check_internal_consistency());
+}
+
+#[test]
+fn test_avl_tree_sequential_inserts_stay_balanced() {
+    let mut tree = create_tree();
+    let mut expected = BTreeSet::new();
+    for value in 1..=1000u64 {
+        tree.insert(value);
+        expected.insert(value);
+    }
+    check_tree(&tree, &expected);
+    // a perfectly balanced tree of 1000 nodes has 10 levels
+    assert!(tree.height() <= 11);
+
+    // removing every other element from the left keeps the tree balanced
+    for value in (1..=1000u64).step_by(2) {
+        assert!(tree.remove(&value));
+        expected.remove(&value);
+    }
+    check_tree(&tree, &expected);
+}
+
+#[test]
+fn test_avl_tree_descending_inserts_stay_balanced() {
+    let mut tree = create_tree();
+    let mut expected = BTreeSet::new();
+    for value in (1..=500u64).rev() {
+        tree.insert(value);
+        expected.insert(value);
+    }
+    check_tree(&tree, &expected);
+}
+
+#[test]
+fn test_avl_tree_random_operations() {
+    let mut tree = create_tree();
+    let mut expected = BTreeSet::new();
+    let mut rng = TestRng(0x2545F4914F6CDD1D);
+    for i in 0..2000 {
+        let value = rng.next() % 500;
+        if rng.next() % 3 == 1 {
+            assert_eq!(tree.remove(&value), expected.remove(&value));
+        } else {
+            assert_eq!(tree.insert(value), expected.insert(value));
+        }
+        if i % 250 == 0 {
+            check_tree(&tree, &expected);
+        }
+    }
+    check_tree(&tree, &expected);
+}
+
+#[test]
+fn test_avl_tree_bounds_rank_nth() {
+    let mut tree = create_tree();
+    let expected: BTreeSet<u64> = (0..200u64).map(|i| i * 5).collect();
+    for value in expected.iter().rev() {
+        tree.insert(*value);
+    }
+
+    for query in 0..1010u64 {
+        assert_eq!(
+            tree.lower_bound(&query),
+            expected.range(query..).next().cloned()
+        );
+        assert_eq!(
+            tree.upper_bound(&query),
+            expected.range(query + 1..).next().cloned()
+        );
+        assert_eq!(tree.rank(&query), expected.range(..query).count());
+    }
+
+    for (index, value) in expected.iter().enumerate() {
+        assert_eq!(tree.nth(index), Some(*value));
+        assert_eq!(tree.rank(value), index);
+    }
+    assert_eq!(tree.nth(expected.len()), None);
+}
+
+#[test]
+fn test_avl_tree_range() {
+    let mut tree = create_tree();
+    let mut rng = TestRng(0x9E3779B97F4A7C15);
+    let mut expected = BTreeSet::new();
+    for _ in 0..300 {
+        let value = rng.next() % 1000;
+        tree.insert(value);
+        expected.insert(value);
+    }
+
+    for _ in 0..50 {
+        let a = rng.next() % 1100;
+        let b = rng.next() % 1100;
+        let (start, end) = (a.min(b), a.max(b));
+        assert_eq!(
+            tree.range(start..end).collect::<Vec<u64>>(),
+            expected.range(start..end).cloned().collect::<Vec<u64>>()
+        );
+        assert_eq!(
+            tree.range(start..=end).collect::<Vec<u64>>(),
+            expected.range(start..=end).cloned().collect::<Vec<u64>>()
+        );
+        assert_eq!(
+            tree.range(start..).collect::<Vec<u64>>(),
+            expected.range(start..).cloned().collect::<Vec<u64>>()
+        );
+        assert_eq!(
+            tree.range(..end).collect::<Vec<u64>>(),
+            expected.range(..end).cloned().collect::<Vec<u64>>()
+        );
+    }
+    assert_eq!(tree.range(2000..).next(), None);
+}
+
+#[test]
+fn test_avl_tree_clear() {
+    let mut tree = create_tree();
+    for value in 0..100u64 {
+        tree.insert(value);
+    }
+    tree.clear();
+    assert!(tree.is_empty());
+    assert_eq!(tree.iter().next(), None);
+    assert!(tree.check_internal_consistency());
+
+    // node ids are not reused, but the tree works the same
+    tree.insert(5);
+    assert_eq!(tree.iter().collect::<Vec<u64>>(), vec![5]);
+    assert!(tree.check_internal_consistency());
+}
+
+/// Inserting loads and saves one node per level, plus 2 more nodes for the rebalancing, and the info.
+#[test]
+fn test_avl_tree_insert_storage_access_worst_case() {
+    let mut tree = create_tree();
+    let mut rng = TestRng(0xD1B54A32D192ED03);
+    let orders: [Vec<u64>; 3] = [
+        (0..1000).collect(),
+        (1000..2000).rev().collect(),
+        (0..1000).map(|_| 2000 + rng.next() % 1_000_000).collect(),
+    ];
+    for value in orders.iter().flatten() {
+        let height = max_avl_height(tree.len() + 1);
+        let (inserted, count) = count_storage_access(|| tree.insert(*value));
+        if inserted {
+            assert!(
+                count.reads <= INFO_READS + height + 2,
+                "insert {value}: {count:?}"
+            );
+            assert!(count.writes <= height + 4, "insert {value}: {count:?}");
+        }
+    }
+    assert!(tree.check_internal_consistency());
+
+    // duplicates only walk down the tree, without writing anything
+    let height = max_avl_height(tree.len());
+    let (inserted, count) = count_storage_access(|| tree.insert(500));
+    assert!(!inserted);
+    assert!(count.reads <= INFO_READS + height);
+    assert_eq!(count.writes, 0);
+}
+
+/// Removing can rebalance at every level, each time loading and saving up to 2 extra nodes.
+#[test]
+fn test_avl_tree_remove_storage_access_worst_case() {
+    let mut tree = create_tree();
+    let mut rng = TestRng(0xA0761D6478BD642F);
+    let mut values: Vec<u64> = (0..2000).collect();
+    for value in values.iter() {
+        tree.insert(*value);
+    }
+
+    // remove in random order, all the way to the empty tree
+    for i in (1..values.len()).rev() {
+        values.swap(i, (rng.next() % (i as u64 + 1)) as usize);
+    }
+    for value in values.iter() {
+        let height = max_avl_height(tree.len());
+        let (removed, count) = count_storage_access(|| tree.remove(value));
+        assert!(removed);
+        assert!(
+            count.reads <= INFO_READS + 3 * height,
+            "remove {value}: {count:?}"
+        );
+        assert!(count.writes <= 3 * height + 2, "remove {value}: {count:?}");
+    }
+    assert!(tree.is_empty());
+    assert!(tree.check_internal_consistency());
+
+    let (removed, count) = count_storage_access(|| tree.remove(&5));
+    assert!(!removed);
+    assert_eq!(count.reads, INFO_READS);
+    assert_eq!(count.writes, 0);
+}
+
+/// Lookups load one node per level, and never write.
+#[test]
+fn test_avl_tree_query_storage_access_worst_case() {
+    let mut tree = create_tree();
+    for value in 0..4096u64 {
+        tree.insert(value * 2);
+    }
+    let height = max_avl_height(tree.len());
+
+    for query in (0..8200u64).step_by(7) {
+        let queries: [fn(&TestTree, u64); 5] = [
+            |tree, query| {
+                tree.contains(&query);
+            },
+            |tree, query| {
+                tree.lower_bound(&query);
+            },
+            |tree, query| {
+                tree.upper_bound(&query);
+            },
+            |tree, query| {
+                tree.rank(&query);
+            },
+            |tree, query| {
+                tree.nth(query as usize);
+            },
+        ];
+        for run_query in queries {
+            let ((), count) = count_storage_access(|| run_query(&tree, query));
+            assert!(
+                count.reads <= INFO_READS + height,
+                "query {query}: {count:?}"
+            );
+            assert_eq!(count.writes, 0);
+        }
+    }
+}
+
+/// Iterating over k elements loads each of them twice, plus up to 2 nodes per level on the boundaries.
+#[test]
+fn test_avl_tree_range_storage_access() {
+    let mut tree = create_tree();
+    for value in 0..4096u64 {
+        tree.insert(value);
+    }
+    let height = max_avl_height(tree.len());
+
+    for (start, end) in [(0, 10), (1000, 1100), (2047, 2049), (4000, 5000)] {
+        let (items, count) = count_storage_access(|| tree.range(start..end).collect::<Vec<u64>>());
+        assert_eq!(items, (start..end.min(4096)).collect::<Vec<u64>>());
+        assert!(
+            count.reads <= INFO_READS + 2 * (items.len() + 1) + 2 * height,
+            "range {start}..{end}: {count:?}"
+        );
+        assert_eq!(count.writes, 0);
+    }
+}
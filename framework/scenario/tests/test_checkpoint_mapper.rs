@@ -0,0 +1,121 @@
+use multiversx_sc::{
+    abi::{TypeAbi, TypeContents, TypeDescriptionContainer, TypeDescriptionContainerImpl},
+    storage::{
+        mappers::{Checkpoint, CheckpointMapper, StorageClearable, StorageMapper},
+        StorageKey,
+    },
+    types::BigUint,
+};
+use multiversx_sc_scenario::api::SingleTxApi;
+
+fn create_checkpoints() -> CheckpointMapper<SingleTxApi, u64> {
+    let base_key = StorageKey::new(&b"my_checkpoints"[..]);
+    CheckpointMapper::new(base_key)
+}
+
+#[test]
+fn test_checkpoints_simple() {
+    let mut checkpoints = create_checkpoints();
+    assert!(checkpoints.is_empty());
+    assert_eq!(checkpoints.latest(), None);
+    assert_eq!(checkpoints.upper_lookup(100), None);
+
+    assert_eq!(checkpoints.push(10, 100), None);
+    assert_eq!(checkpoints.push(20, 200), Some(100));
+    assert_eq!(checkpoints.len(), 2);
+    assert_eq!(checkpoints.latest(), Some(200));
+    assert_eq!(
+        checkpoints.latest_checkpoint(),
+        Some(Checkpoint {
+            timepoint: 20,
+            value: 200
+        })
+    );
+    assert_eq!(
+        checkpoints.get(1),
+        Checkpoint {
+            timepoint: 10,
+            value: 100
+        }
+    );
+}
+
+#[test]
+fn test_checkpoints_same_timepoint_overwrites() {
+    let mut checkpoints = create_checkpoints();
+    checkpoints.push(10, 100);
+    assert_eq!(checkpoints.push(10, 150), Some(100));
+    assert_eq!(checkpoints.len(), 1);
+    assert_eq!(checkpoints.upper_lookup(10), Some(150));
+}
+
+#[test]
+fn test_checkpoints_upper_lookup() {
+    let mut checkpoints = create_checkpoints();
+    // checkpoint i is at timepoint 10 * i, with value i
+    for i in 1..=100u64 {
+        checkpoints.push(10 * i, i);
+    }
+
+    assert_eq!(checkpoints.upper_lookup(0), None);
+    assert_eq!(checkpoints.upper_lookup(9), None);
+    for timepoint in 10..1100u64 {
+        let expected = core::cmp::min(timepoint / 10, 100);
+        assert_eq!(checkpoints.upper_lookup(timepoint), Some(expected));
+    }
+    assert_eq!(checkpoints.upper_lookup(u64::MAX), Some(100));
+}
+
+#[test]
+fn test_checkpoints_iter_and_clear() {
+    let mut checkpoints = create_checkpoints();
+    checkpoints.push(1, 5);
+    checkpoints.push(3, 6);
+    checkpoints.push(3, 7);
+    let timepoints: Vec<(u64, u64)> = checkpoints
+        .iter()
+        .map(|checkpoint| (checkpoint.timepoint, checkpoint.value))
+        .collect();
+    assert_eq!(timepoints, vec![(1, 5), (3, 7)]);
+
+    checkpoints.clear();
+    assert!(checkpoints.is_empty());
+    assert_eq!(checkpoints.upper_lookup(3), None);
+
+    // after clearing, history can start over from any timepoint
+    checkpoints.push(0, 1);
+    assert_eq!(checkpoints.latest(), Some(1));
+}
+
+#[test]
+#[should_panic]
+fn test_checkpoints_push_in_the_past() {
+    let mut checkpoints = create_checkpoints();
+    checkpoints.push(10, 100);
+    checkpoints.push(9, 90);
+}
+
+#[test]
+fn test_checkpoint_type_abi() {
+    assert_eq!(Checkpoint::<u64>::type_name(), "Checkpoint<u64>");
+    assert_eq!(
+        Checkpoint::<BigUint<SingleTxApi>>::type_name(),
+        "Checkpoint<BigUint>"
+    );
+
+    // checkpoints of different value types get separate type descriptions
+    let mut accumulator = TypeDescriptionContainerImpl::new();
+    Checkpoint::<u64>::provide_type_descriptions(&mut accumulator);
+    Checkpoint::<BigUint<SingleTxApi>>::provide_type_descriptions(&mut accumulator);
+    assert!(accumulator.contains_type("Checkpoint<u64>"));
+    assert!(accumulator.contains_type("Checkpoint<BigUint>"));
+    let (_, description) = accumulator
+        .0
+        .iter()
+        .find(|(type_name, _)| type_name == "Checkpoint<BigUint>")
+        .unwrap();
+    let TypeContents::Struct(fields) = &description.contents else {
+        panic!("Checkpoint should be described as a struct");
+    };
+    assert_eq!(fields[1].field_type, "BigUint");
+}
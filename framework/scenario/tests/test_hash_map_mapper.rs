@@ -1,5 +1,5 @@
 use multiversx_sc::storage::{
-    mappers::{MapMapper, StorageClearable, StorageMapper},
+    mappers::{MapMapper, PaginatedStorageMapper, StorageClearable, StorageMapper},
     StorageKey,
 };
 use multiversx_sc_scenario::api::SingleTxApi;
@@ -44,3 +44,28 @@ fn test_map_clear() {
     assert_eq!(map.len(), 0);
     assert!(map.is_empty());
 }
+
+#[test]
+fn test_map_load_page() {
+    let mut map = create_map();
+    for key in 1..=5 {
+        map.insert(key, key * 100);
+    }
+    let page = map.load_page(None, 3);
+    let entries: Vec<(u64, u64)> = page
+        .items
+        .into_iter()
+        .map(|entry| entry.into_tuple())
+        .collect();
+    assert_eq!(entries, vec![(1, 100), (2, 200), (3, 300)]);
+    assert_eq!(page.next_cursor, Some(4));
+
+    let page = map.load_page(page.next_cursor, 3);
+    let entries: Vec<(u64, u64)> = page
+        .items
+        .into_iter()
+        .map(|entry| entry.into_tuple())
+        .collect();
+    assert_eq!(entries, vec![(4, 400), (5, 500)]);
+    assert_eq!(page.next_cursor, None);
+}
@@ -1,5 +1,8 @@
 use multiversx_sc::storage::{
-    mappers::{MapMapper, MapStorageMapper, StorageClearable, StorageMapper},
+    mappers::{
+        BiDiMapper, MapMapper, MapStorageMapper, SetMapper, SingleValueMapper, StorageClearable,
+        StorageMapper, UniqueIdMapper, VecMapper,
+    },
     StorageKey,
 };
 use multiversx_sc_scenario::api::SingleTxApi;
@@ -55,3 +58,115 @@ fn test_map_storage_clear() {
     assert_eq!(map.len(), 0);
     assert!(map.is_empty());
 }
+
+#[test]
+fn test_map_storage_nested_set() {
+    let base_key = StorageKey::new(&b"ids_by_user"[..]);
+    let mut ids_by_user: MapStorageMapper<SingleTxApi, u64, SetMapper<SingleTxApi, u64>> =
+        MapStorageMapper::new(base_key);
+    for user in [1u64, 2, 3] {
+        let mut ids = ids_by_user.entry(user).or_default().get();
+        for id in 0..user {
+            ids.insert(user * 10 + id);
+        }
+    }
+
+    // all the nested mappers can be iterated over
+    let all: Vec<(u64, Vec<u64>)> = ids_by_user
+        .iter()
+        .map(|(user, ids)| (user, ids.iter().collect()))
+        .collect();
+    assert_eq!(
+        all,
+        vec![(1, vec![10]), (2, vec![20, 21]), (3, vec![30, 31, 32])]
+    );
+
+    // removing a key clears its whole nested mapper
+    assert!(ids_by_user.remove(&2));
+    assert!(ids_by_user.get(&2).is_none());
+    ids_by_user.insert_default(2);
+    assert!(ids_by_user.get(&2).unwrap().is_empty());
+    assert_eq!(ids_by_user.get(&3).unwrap().len(), 3);
+}
+
+#[test]
+fn test_map_storage_nested_vec() {
+    let base_key = StorageKey::new(&b"history"[..]);
+    let mut history: MapStorageMapper<SingleTxApi, u32, VecMapper<SingleTxApi, u64>> =
+        MapStorageMapper::new(base_key);
+    history.insert_default(7);
+    let mut entries = history.get(&7).unwrap();
+    entries.push(&100);
+    entries.push(&200);
+    assert_eq!(history.values().map(|vec| vec.len()).sum::<usize>(), 2);
+
+    history.clear();
+    assert!(history.is_empty());
+    assert!(entries.is_empty());
+}
+
+#[test]
+fn test_map_storage_nested_single_value() {
+    let base_key = StorageKey::new(&b"balance"[..]);
+    let mut balances: MapStorageMapper<SingleTxApi, u32, SingleValueMapper<SingleTxApi, u64>> =
+        MapStorageMapper::new(base_key);
+    balances
+        .entry(1)
+        .or_default()
+        .update(|balance| balance.set(5));
+    balances
+        .entry(2)
+        .or_default()
+        .update(|balance| balance.set(8));
+    let total: u64 = balances.values().map(|balance| balance.get()).sum();
+    assert_eq!(total, 13);
+
+    let balance_1 = balances.get(&1).unwrap();
+    balances.remove(&1);
+    assert!(balance_1.is_empty());
+}
+
+#[test]
+fn test_map_storage_nested_bi_di_and_unique_id() {
+    let base_key = StorageKey::new(&b"bi_di"[..]);
+    let mut bi_di: MapStorageMapper<SingleTxApi, u32, BiDiMapper<SingleTxApi, u32, u64>> =
+        MapStorageMapper::new(base_key);
+    let mut inner = bi_di.entry(1).or_default().get();
+    inner.insert(10, 100);
+    inner.insert(11, 110);
+    bi_di.remove(&1);
+    assert!(inner.is_empty());
+    assert!(!inner.contains_id(&10));
+    assert!(!inner.contains_value(&110));
+
+    let base_key = StorageKey::new(&b"unique_ids"[..]);
+    let mut unique_ids: MapStorageMapper<SingleTxApi, u32, UniqueIdMapper<SingleTxApi>> =
+        MapStorageMapper::new(base_key);
+    let mut inner = unique_ids.entry(1).or_default().get();
+    inner.set_initial_len(5);
+    inner.swap_remove(2);
+    unique_ids.remove(&1);
+    assert!(inner.is_empty());
+
+    // can be initialized again
+    inner.set_initial_len(3);
+    assert_eq!(inner.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_map_storage_two_levels() {
+    let base_key = StorageKey::new(&b"two_levels"[..]);
+    let mut map: MapStorageMapper<
+        SingleTxApi,
+        u32,
+        MapStorageMapper<SingleTxApi, u32, SetMapper<SingleTxApi, u32>>,
+    > = MapStorageMapper::new(base_key);
+    let mut level_1 = map.entry(1).or_default().get();
+    let mut level_2 = level_1.entry(2).or_default().get();
+    level_2.insert(3);
+    assert!(map.get(&1).unwrap().get(&2).unwrap().contains(&3));
+
+    map.remove(&1);
+    assert!(level_1.is_empty());
+    assert!(level_2.is_empty());
+}
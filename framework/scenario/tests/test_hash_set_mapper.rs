@@ -1,6 +1,6 @@
 use multiversx_sc::{
     storage::{
-        mappers::{SetMapper, StorageClearable, StorageMapper},
+        mappers::{PaginatedStorageMapper, SetMapper, StorageClearable, StorageMapper},
         StorageKey,
     },
     types::ManagedAddress,
@@ -104,3 +104,23 @@ fn test_set_at_address() {
     let set = create_set_at_address(ManagedAddress::default());
     check_set_at_address(&set, 0usize);
 }
+
+#[test]
+fn test_set_load_page() {
+    let mut set = create_set();
+    for item in 40..45 {
+        set.insert(item);
+    }
+    let page = set.load_page(None, 2);
+    assert_eq!(page.items.to_vec().into_vec(), vec![40, 41]);
+    assert_eq!(page.next_cursor, Some(42));
+
+    // removing items before the cursor does not invalidate it
+    set.remove(&41);
+    let page = set.load_page(page.next_cursor, 2);
+    assert_eq!(page.items.to_vec().into_vec(), vec![42, 43]);
+    assert_eq!(page.next_cursor, Some(44));
+    let page = set.load_page(page.next_cursor, 2);
+    assert_eq!(page.items.to_vec().into_vec(), vec![44]);
+    assert_eq!(page.next_cursor, None);
+}
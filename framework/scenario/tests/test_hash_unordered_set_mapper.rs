@@ -1,5 +1,5 @@
 use multiversx_sc::storage::{
-    mappers::{StorageClearable, StorageMapper, UnorderedSetMapper},
+    mappers::{PaginatedStorageMapper, StorageClearable, StorageMapper, UnorderedSetMapper},
     StorageKey,
 };
 use multiversx_sc_scenario::api::SingleTxApi;
@@ -130,3 +130,17 @@ fn test_set_clear() {
     assert_eq!(set.len(), 0);
     assert!(set.is_empty());
 }
+
+#[test]
+fn test_set_load_page() {
+    let mut set = create_set();
+    for item in 40..45 {
+        set.insert(item);
+    }
+    let page = set.load_page(None, 3);
+    assert_eq!(page.items.to_vec().into_vec(), vec![40, 41, 42]);
+    assert_eq!(page.next_cursor, Some(4));
+    let page = set.load_page(page.next_cursor, 3);
+    assert_eq!(page.items.to_vec().into_vec(), vec![43, 44]);
+    assert_eq!(page.next_cursor, None);
+}
@@ -1,5 +1,5 @@
 use multiversx_sc::storage::{
-    mappers::{LinkedListMapper, StorageClearable, StorageMapper},
+    mappers::{LinkedListMapper, PaginatedStorageMapper, StorageClearable, StorageMapper},
     StorageKey,
 };
 use multiversx_sc_scenario::api::SingleTxApi;
@@ -102,3 +102,24 @@ fn test_list_clear() {
     assert_eq!(list.len(), 0);
     assert!(list.is_empty());
 }
+
+#[test]
+fn test_list_load_page() {
+    let mut list = create_list();
+    for item in 40..45 {
+        list.push_back(item);
+    }
+    let page = list.load_page(None, 2);
+    assert_eq!(page.items.to_vec().into_vec(), vec![40, 41]);
+    let next_cursor = page.next_cursor.unwrap();
+    assert_eq!(list.get_node_by_id(next_cursor).unwrap().into_value(), 42);
+
+    // the cursor is a node id, so it survives removing the front of the list
+    list.pop_front();
+    list.pop_front();
+    let page = list.load_page(Some(next_cursor), 2);
+    assert_eq!(page.items.to_vec().into_vec(), vec![42, 43]);
+    let page = list.load_page(page.next_cursor, 2);
+    assert_eq!(page.items.to_vec().into_vec(), vec![44]);
+    assert_eq!(page.next_cursor, None);
+}
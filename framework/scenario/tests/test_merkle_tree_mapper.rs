@@ -0,0 +1,146 @@
+use multiversx_sc::{
+    storage::{
+        mappers::{MerkleTreeMapper, StorageClearable, StorageMapper},
+        StorageKey,
+    },
+    types::{
+        Keccak256MerkleHasher, ManagedBuffer, ManagedVec, MerkleHash, MerkleHasher,
+        MerkleProofVerifier,
+    },
+};
+use multiversx_sc_scenario::api::SingleTxApi;
+
+type Hasher = Keccak256MerkleHasher;
+type TestTree = MerkleTreeMapper<SingleTxApi, Hasher>;
+
+fn create_tree() -> TestTree {
+    let base_key = StorageKey::new(&b"my_merkle_tree"[..]);
+    MerkleTreeMapper::new(base_key)
+}
+
+fn leaf(i: u64) -> MerkleHash<SingleTxApi> {
+    Hasher::hash(&ManagedBuffer::new_from_bytes(&i.to_be_bytes()))
+}
+
+/// Builds all the levels of the tree naively, with the missing leaves empty.
+fn reference_levels(
+    leaves: &[MerkleHash<SingleTxApi>],
+    depth: u8,
+) -> Vec<Vec<MerkleHash<SingleTxApi>>> {
+    let verifier = MerkleProofVerifier::<SingleTxApi, Hasher>::new();
+    let mut level: Vec<MerkleHash<SingleTxApi>> = (0..1usize << depth)
+        .map(|i| leaves.get(i).cloned().unwrap_or_default())
+        .collect();
+    let mut levels = Vec::new();
+    while level.len() > 1 {
+        let next_level = level
+            .chunks(2)
+            .map(|pair| verifier.hash_pair(&pair[0], &pair[1]))
+            .collect();
+        levels.push(level);
+        level = next_level;
+    }
+    levels.push(level);
+    levels
+}
+
+fn reference_proof(
+    levels: &[Vec<MerkleHash<SingleTxApi>>],
+    leaf_index: usize,
+) -> ManagedVec<SingleTxApi, MerkleHash<SingleTxApi>> {
+    let mut proof = ManagedVec::new();
+    let mut index = leaf_index;
+    for level in &levels[..levels.len() - 1] {
+        proof.push(level[index ^ 1].clone());
+        index >>= 1;
+    }
+    proof
+}
+
+#[test]
+fn test_merkle_tree_empty() {
+    let tree = create_tree();
+    assert!(!tree.is_initialized());
+    tree.init(3);
+    assert!(tree.is_initialized());
+    assert!(tree.is_empty());
+    assert_eq!(tree.depth(), 3);
+    assert_eq!(tree.capacity(), 8);
+    assert_eq!(tree.root(), reference_levels(&[], 3)[3][0]);
+}
+
+#[test]
+fn test_merkle_tree_insert() {
+    let mut tree = create_tree();
+    tree.init(3);
+
+    let mut leaves = Vec::new();
+    for i in 0..8u64 {
+        assert_eq!(tree.insert(&leaf(i)), i);
+        leaves.push(leaf(i));
+        assert_eq!(tree.len(), i + 1);
+
+        let levels = reference_levels(&leaves, 3);
+        assert_eq!(tree.root(), levels[3][0]);
+        for (leaf_index, leaf) in leaves.iter().enumerate() {
+            let proof = reference_proof(&levels, leaf_index);
+            assert!(tree.verify_proof(leaf, leaf_index as u64, &proof));
+        }
+    }
+}
+
+#[test]
+fn test_merkle_tree_verify_proof() {
+    let mut tree = create_tree();
+    tree.init(4);
+    let leaves: Vec<MerkleHash<SingleTxApi>> = (0..5).map(leaf).collect();
+    for leaf in leaves.iter() {
+        tree.insert(leaf);
+    }
+    let levels = reference_levels(&leaves, 4);
+    let proof = reference_proof(&levels, 2);
+
+    assert!(tree.verify_proof(&leaves[2], 2, &proof));
+    assert!(!tree.verify_proof(&leaves[3], 2, &proof));
+    assert!(!tree.verify_proof(&leaves[2], 3, &proof));
+
+    // empty leaves are part of the root, but were never inserted
+    let empty_leaf_proof = reference_proof(&levels, 6);
+    assert!(!tree.verify_proof(&MerkleHash::default(), 6, &empty_leaf_proof));
+
+    // proofs must go all the way up to the root
+    let mut short_proof = proof.clone();
+    short_proof.remove(3);
+    assert!(!tree.verify_proof(&leaves[2], 2, &short_proof));
+}
+
+#[test]
+#[should_panic]
+fn test_merkle_tree_full() {
+    let mut tree = create_tree();
+    tree.init(1);
+    tree.insert(&leaf(0));
+    tree.insert(&leaf(1));
+    tree.insert(&leaf(2));
+}
+
+#[test]
+#[should_panic]
+fn test_merkle_tree_init_twice() {
+    let tree = create_tree();
+    tree.init(3);
+    tree.init(3);
+}
+
+#[test]
+fn test_merkle_tree_clear() {
+    let mut tree = create_tree();
+    tree.init(2);
+    tree.insert(&leaf(0));
+    tree.clear();
+    assert!(!tree.is_initialized());
+    assert!(tree.is_empty());
+
+    tree.init(5);
+    assert_eq!(tree.root(), reference_levels(&[], 5)[5][0]);
+}
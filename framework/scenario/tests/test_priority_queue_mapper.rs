@@ -0,0 +1,180 @@
+use std::collections::BTreeSet;
+
+use multiversx_sc::storage::{
+    mappers::{
+        MaxFirst, MinFirst, PriorityQueueMapper, PriorityQueueOrder, StorageClearable,
+        StorageMapper,
+    },
+    StorageKey,
+};
+use multiversx_sc_scenario::api::SingleTxApi;
+
+fn create_queue<O: PriorityQueueOrder<u64>>() -> PriorityQueueMapper<SingleTxApi, u64, O> {
+    let base_key = StorageKey::new(&b"my_priority_queue"[..]);
+    PriorityQueueMapper::new(base_key)
+}
+
+/// Orders by the last digit only, so that many items have the same priority.
+struct LastDigitFirst;
+
+impl PriorityQueueOrder<u64> for LastDigitFirst {
+    fn has_priority(a: &u64, b: &u64) -> bool {
+        a % 10 < b % 10
+    }
+}
+
+#[test]
+fn test_priority_queue_push_pop() {
+    let mut queue = create_queue::<MinFirst>();
+    assert!(queue.is_empty());
+    assert_eq!(queue.peek(), None);
+    assert_eq!(queue.pop(), None);
+
+    for item in [5, 3, 8, 1, 9, 2, 7] {
+        queue.push(item);
+        assert!(queue.check_internal_consistency());
+    }
+    assert_eq!(queue.len(), 7);
+    assert_eq!(queue.peek(), Some(1));
+
+    let mut popped = Vec::new();
+    while let Some(item) = queue.pop() {
+        assert!(queue.check_internal_consistency());
+        popped.push(item);
+    }
+    assert_eq!(popped, vec![1, 2, 3, 5, 7, 8, 9]);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn test_priority_queue_max_first() {
+    let mut queue = create_queue::<MaxFirst>();
+    for item in [5, 3, 8, 1, 9, 2, 7] {
+        queue.push(item);
+    }
+    let popped: Vec<u64> = core::iter::from_fn(|| queue.pop()).collect();
+    assert_eq!(popped, vec![9, 8, 7, 5, 3, 2, 1]);
+}
+
+#[test]
+fn test_priority_queue_custom_order_ties() {
+    let mut queue = create_queue::<LastDigitFirst>();
+    for item in [12, 31, 41, 22, 10, 52, 20] {
+        queue.push(item);
+        assert!(queue.check_internal_consistency());
+    }
+
+    // items with the same priority come out in the order they were pushed
+    let popped: Vec<u64> = core::iter::from_fn(|| queue.pop()).collect();
+    assert_eq!(popped, vec![10, 20, 31, 41, 12, 22, 52]);
+}
+
+#[test]
+fn test_priority_queue_remove() {
+    let mut queue = create_queue::<MinFirst>();
+    let ids: Vec<_> = [50, 40, 30, 20, 10, 60, 70]
+        .iter()
+        .map(|item| queue.push(*item))
+        .collect();
+
+    assert_eq!(queue.get(ids[2]), Some(30));
+    assert_eq!(queue.remove(ids[2]), Some(30));
+    assert!(queue.check_internal_consistency());
+    assert!(!queue.contains_id(ids[2]));
+    assert_eq!(queue.get(ids[2]), None);
+    assert_eq!(queue.remove(ids[2]), None);
+
+    // removing the top
+    assert_eq!(queue.peek_id(), Some(ids[4]));
+    assert_eq!(queue.remove(ids[4]), Some(10));
+    assert!(queue.check_internal_consistency());
+
+    // removing the last slot
+    let last_id = queue.iter_ids().last().unwrap();
+    let last_item = queue.remove(last_id).unwrap();
+    assert!(queue.check_internal_consistency());
+
+    let popped: Vec<u64> = core::iter::from_fn(|| queue.pop()).collect();
+    let mut expected = vec![20, 40, 50, 60, 70];
+    expected.retain(|item| *item != last_item);
+    assert_eq!(popped, expected);
+}
+
+#[test]
+fn test_priority_queue_iter() {
+    let mut queue = create_queue::<MinFirst>();
+    for item in [4, 2, 6, 1] {
+        queue.push(item);
+    }
+    let items: Vec<u64> = queue.iter().collect();
+    assert_eq!(items.len(), 4);
+    assert_eq!(items[0], 1);
+
+    let mut sorted = items.clone();
+    sorted.sort();
+    assert_eq!(sorted, vec![1, 2, 4, 6]);
+
+    let ids: Vec<_> = queue.iter_ids().collect();
+    let items_by_id: Vec<u64> = ids.iter().map(|id| queue.get(*id).unwrap()).collect();
+    assert_eq!(items_by_id, items);
+}
+
+#[test]
+fn test_priority_queue_clear() {
+    let mut queue = create_queue::<MinFirst>();
+    let first_id = queue.push(3);
+    queue.push(1);
+    queue.clear();
+    assert!(queue.is_empty());
+    assert_eq!(queue.peek(), None);
+    assert!(!queue.contains_id(first_id));
+
+    // ids are not reused
+    assert!(queue.push(2) > first_id + 1);
+    assert_eq!(queue.pop(), Some(2));
+}
+
+/// Pseudo-random push, pop and remove operations, checked against a sorted set.
+#[test]
+fn test_priority_queue_random_operations() {
+    let mut queue = create_queue::<MinFirst>();
+    let mut reference = BTreeSet::new();
+    let mut live_ids = Vec::new();
+
+    let mut seed = 0x2545_f491_4f6c_dd1du64;
+    let mut next_random = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+
+    for _ in 0..500 {
+        match next_random() % 4 {
+            0 | 1 => {
+                let item = next_random() % 100;
+                let id = queue.push(item);
+                reference.insert((item, id));
+                live_ids.push(id);
+            },
+            2 => {
+                let expected = reference.pop_first();
+                assert_eq!(queue.pop(), expected.map(|(item, _)| item));
+                if let Some((_, id)) = expected {
+                    live_ids.retain(|live_id| *live_id != id);
+                }
+            },
+            _ => {
+                if live_ids.is_empty() {
+                    continue;
+                }
+                let id = live_ids.swap_remove(next_random() as usize % live_ids.len());
+                let item = queue.remove(id).unwrap();
+                assert!(reference.remove(&(item, id)));
+            },
+        }
+        assert_eq!(queue.len(), reference.len());
+        assert_eq!(queue.peek(), reference.first().map(|(item, _)| *item));
+    }
+    assert!(queue.check_internal_consistency());
+}
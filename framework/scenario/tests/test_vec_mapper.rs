@@ -1,5 +1,5 @@
 use multiversx_sc::storage::{
-    mappers::{StorageMapper, VecMapper},
+    mappers::{PaginatedStorageMapper, StorageMapper, VecMapper},
     StorageKey,
 };
 use multiversx_sc_scenario::api::SingleTxApi;
@@ -102,3 +102,28 @@ fn test_vec_clear() {
     assert_eq!(vect.len(), 0);
     assert!(vect.is_empty());
 }
+
+#[test]
+fn test_vec_load_page() {
+    let mut vect = create_vec();
+    let page = vect.load_page(None, 2);
+    assert!(page.items.is_empty());
+    assert_eq!(page.next_cursor, None);
+
+    for item in 50..55 {
+        vect.push(&item);
+    }
+    let page = vect.load_page(None, 2);
+    assert_eq!(page.items.to_vec().into_vec(), vec![50, 51]);
+    assert_eq!(page.next_cursor, Some(3));
+    let page = vect.load_page(page.next_cursor, 2);
+    assert_eq!(page.items.to_vec().into_vec(), vec![52, 53]);
+    assert_eq!(page.next_cursor, Some(5));
+    let page = vect.load_page(page.next_cursor, 2);
+    assert_eq!(page.items.to_vec().into_vec(), vec![54]);
+    assert_eq!(page.next_cursor, None);
+
+    let page = vect.load_page(Some(2), 10);
+    assert_eq!(page.items.to_vec().into_vec(), vec![51, 52, 53, 54]);
+    assert_eq!(page.next_cursor, None);
+}
@@ -10,7 +10,7 @@ use multiversx_sdk::{
 };
 use std::{collections::HashMap, path::Path, time::Duration};
 
-use crate::Sender;
+use crate::{NonceTracker, Sender, TxPollingPolicy};
 
 pub const INTERACTOR_SCENARIO_TRACE_PATH: &str = "interactor_trace.scen.json";
 
@@ -18,8 +18,9 @@ pub struct Interactor {
     pub proxy: CommunicationProxy,
     pub network_config: NetworkConfig,
     pub sender_map: HashMap<Address, Sender>,
+    pub(crate) relayer: Option<Address>,
+    pub tx_polling_policy: TxPollingPolicy,
 
-    pub(crate) waiting_time_ms: u64,
     pub pre_runners: ScenarioRunnerList,
     pub post_runners: ScenarioRunnerList,
 }
@@ -32,7 +33,8 @@ impl Interactor {
             proxy,
             network_config,
             sender_map: HashMap::new(),
-            waiting_time_ms: 0,
+            relayer: None,
+            tx_polling_policy: TxPollingPolicy::default(),
             pre_runners: ScenarioRunnerList::empty(),
             post_runners: ScenarioRunnerList::empty(),
         }
@@ -45,14 +47,13 @@ impl Interactor {
             Sender {
                 address: address.clone(),
                 wallet,
-                current_nonce: None,
+                nonce_tracker: NonceTracker::default(),
             },
         );
         address
     }
 
     pub async fn sleep(&mut self, duration: Duration) {
-        self.waiting_time_ms += duration.as_millis() as u64;
         tokio::time::sleep(duration).await;
     }
 
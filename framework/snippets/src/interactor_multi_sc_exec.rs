@@ -1,5 +1,5 @@
 use crate::{
-    interactor_multi_sc_process::{update_nonces_and_sign_tx, SenderSet, Txs},
+    interactor_multi_sc_process::{SenderSet, Txs},
     Interactor, StepBuffer, TransactionSpec,
 };
 
@@ -18,9 +18,17 @@ impl Interactor {
         let txs = self.retrieve_txs(&mut buffer);
         let results = self.process_txs(txs).await;
 
-        for (i, sc_call_step) in buffer.refs.iter_mut().enumerate() {
-            sc_call_step.set_response(TxResponse::from_network_tx(results.get(i).unwrap().clone()));
+        let mut rejected = Vec::new();
+        for (i, (sc_call_step, result)) in buffer.refs.iter_mut().zip(results).enumerate() {
+            match result {
+                Some(tx) => sc_call_step.set_response(TxResponse::from_network_tx(tx)),
+                None => rejected.push(i),
+            }
         }
+        assert!(
+            rejected.is_empty(),
+            "steps {rejected:?} were rejected by the node, check the senders for nonce gaps"
+        );
 
         for step in buffer.refs.iter_mut() {
             step.run_step(&mut self.post_runners);
@@ -33,12 +41,14 @@ impl Interactor {
         for sc_call_step in &mut buffer.refs {
             let mut transaction = sc_call_step.to_transaction(self);
             let sender_address = &sc_call_step.to_address().value;
-            let sender = self
+            let nonce = self
                 .sender_map
                 .get_mut(sender_address)
-                .expect("sender not registered");
+                .expect("sender not registered")
+                .nonce_tracker
+                .acquire();
 
-            update_nonces_and_sign_tx(&mut transaction, sender);
+            self.sign_tx_with_nonce(sender_address, &mut transaction, nonce);
             txs.push(transaction);
         }
         txs
@@ -1,47 +1,316 @@
-use crate::{multiversx_sc::types::Address, Interactor, Sender};
+use crate::{erdrs_address_to_h256, multiversx_sc::types::Address, Interactor};
 use futures::future::join_all;
+use log::info;
 use multiversx_sdk::data::transaction::{Transaction, TransactionOnNetwork};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 pub(crate) type Txs = Vec<Transaction>;
 pub(crate) type SenderSet = HashSet<Address>;
 
 impl Interactor {
-    pub(crate) async fn recall_senders_nonce(&mut self, senders: HashSet<Address>) {
-        for sender_address in &senders {
-            let nonce = self.recall_nonce(sender_address).await;
-            let sender = self
+    /// Signs several transactions, sends them all at once, then waits for all of them to be processed.
+    ///
+    /// The transactions of each sender get consecutive nonces, in the order given.
+    /// The results are in the same order, with `None` for the transactions rejected by the node.
+    pub async fn send_txs(
+        &mut self,
+        mut txs: Vec<Transaction>,
+    ) -> Vec<Option<TransactionOnNetwork>> {
+        let senders = txs
+            .iter()
+            .map(|tx| erdrs_address_to_h256(tx.sender.clone()))
+            .collect();
+        self.recall_senders_nonce(senders).await;
+
+        self.sign_txs(&mut txs);
+        self.process_txs(txs).await
+    }
+
+    /// Gives each transaction the next nonce of its sender, then signs it.
+    fn sign_txs(&mut self, txs: &mut [Transaction]) {
+        for transaction in txs {
+            let sender_address = erdrs_address_to_h256(transaction.sender.clone());
+            let nonce = self
                 .sender_map
-                .get_mut(sender_address)
-                .expect("sender not registered");
+                .get_mut(&sender_address)
+                .expect("sender not registered")
+                .nonce_tracker
+                .acquire();
+            self.sign_tx_with_nonce(&sender_address, transaction, nonce);
+        }
+    }
 
-            sender.current_nonce = Some(nonce);
-            println!("sender's recalled nonce: {nonce}");
+    pub(crate) async fn recall_senders_nonce(&mut self, senders: SenderSet) {
+        for sender_address in &senders {
+            self.sync_nonce_if_needed(sender_address).await;
         }
     }
 
-    pub(crate) async fn process_txs(&mut self, txs: Vec<Transaction>) -> Vec<TransactionOnNetwork> {
-        let mut futures = Vec::new();
+    /// Sends a batch of signed transactions and waits for the accepted ones to be processed.
+    ///
+    /// The node can reject some of the transactions, and accept the others.
+    /// The nonces of the rejected ones are given back, as far as possible, and their results are `None`.
+    pub(crate) async fn process_txs(
+        &mut self,
+        txs: Vec<Transaction>,
+    ) -> Vec<Option<TransactionOnNetwork>> {
+        if txs.is_empty() {
+            return Vec::new();
+        }
+
+        let tx_hashes = match self.proxy.send_transactions_by_index(&txs).await {
+            Ok(tx_hashes) => tx_hashes,
+            Err(err) => {
+                self.release_nonces(&txs);
+                panic!("failed to send transactions: {err}");
+            },
+        };
+        let accepted = self.record_sent_txs(&txs, tx_hashes);
 
-        for tx in &txs {
-            let tx_hash = self
-                .proxy
-                .send_transaction(tx)
-                .await
-                .expect("failed to send transaction");
+        let interactor = &*self;
+        let futures = accepted.into_iter().map(|tx_hash| async move {
+            match tx_hash {
+                Some(tx_hash) => Some(interactor.retrieve_tx_on_network(tx_hash).await),
+                None => None,
+            }
+        });
+        join_all(futures).await
+    }
 
-            println!("process tx hash: {tx_hash} with nonce: {}", tx.nonce);
-            futures.push(self.retrieve_tx_on_network(tx_hash.clone()));
+    /// Records the transactions accepted by the node as sent, and gives back the nonces of the rejected ones.
+    ///
+    /// Returns the hash of each transaction, `None` for the rejected ones.
+    fn record_sent_txs(
+        &mut self,
+        txs: &[Transaction],
+        mut tx_hashes: HashMap<i32, String>,
+    ) -> Vec<Option<String>> {
+        let mut accepted = Vec::with_capacity(txs.len());
+        let mut rejected = Vec::new();
+        for (index, tx) in txs.iter().enumerate() {
+            let tx_hash = tx_hashes.remove(&(index as i32));
+            if let Some(tx_hash) = &tx_hash {
+                println!("process tx hash: {tx_hash} with nonce: {}", tx.nonce);
+                info!("process tx hash: {}", tx_hash);
+                self.sender_map
+                    .get_mut(&erdrs_address_to_h256(tx.sender.clone()))
+                    .expect("sender not registered")
+                    .nonce_tracker
+                    .mark_sent(tx.nonce, tx_hash.clone());
+            } else {
+                println!("tx with nonce {} rejected by the node", tx.nonce);
+                rejected.push(tx.clone());
+            }
+            accepted.push(tx_hash);
         }
+        self.release_nonces(&rejected);
+        accepted
+    }
 
-        join_all(futures).await
+    /// Gives back the nonces of transactions that were not sent, last ones first.
+    ///
+    /// Nonces followed by ones already sent cannot be given back, they remain gaps until filled.
+    fn release_nonces(&mut self, txs: &[Transaction]) {
+        for tx in txs.iter().rev() {
+            self.sender_map
+                .get_mut(&erdrs_address_to_h256(tx.sender.clone()))
+                .expect("sender not registered")
+                .nonce_tracker
+                .release(tx.nonce);
+        }
     }
 }
 
-pub(crate) fn update_nonces_and_sign_tx(transaction: &mut Transaction, sender: &mut Sender) {
-    transaction.nonce = sender.current_nonce.unwrap();
-    sender.current_nonce = Some(sender.current_nonce.unwrap() + 1);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TxPollingPolicy;
+    use multiversx_sc_scenario::{mandos_system::run_list::ScenarioRunnerList, test_wallets};
+    use multiversx_sdk::{
+        blockchain::CommunicationProxy, data::transaction::TransactionOptions, wallet::Wallet,
+    };
+
+    const MIN_GAS_LIMIT: u64 = 50_000;
 
-    let signature = sender.wallet.sign_tx(&*transaction);
-    transaction.signature = Some(hex::encode(signature));
+    /// Never reaches the network, only the local bookkeeping is tested.
+    fn offline_interactor() -> Interactor {
+        let network_config = serde_json::from_value(serde_json::json!({
+            "erd_chain_id": "local-testnet",
+            "erd_denomination": 18,
+            "erd_gas_per_data_byte": 1500,
+            "erd_latest_tag_software_version": "",
+            "erd_meta_consensus_group_size": 1,
+            "erd_min_gas_limit": MIN_GAS_LIMIT,
+            "erd_min_gas_price": 1_000_000_000,
+            "erd_min_transaction_version": 1,
+            "erd_num_metachain_nodes": 1,
+            "erd_num_nodes_in_shard": 1,
+            "erd_num_shards_without_meta": 1,
+            "erd_round_duration": 6000,
+            "erd_shard_consensus_group_size": 1,
+            "erd_start_time": 0,
+        }))
+        .unwrap();
+
+        Interactor {
+            proxy: CommunicationProxy::new("http://localhost".to_string()),
+            network_config,
+            sender_map: HashMap::new(),
+            relayer: None,
+            tx_polling_policy: TxPollingPolicy::default(),
+            pre_runners: ScenarioRunnerList::empty(),
+            post_runners: ScenarioRunnerList::empty(),
+        }
+    }
+
+    fn register_synced_wallet(interactor: &mut Interactor, wallet: Wallet, nonce: u64) -> Address {
+        let address = interactor.register_wallet(wallet);
+        interactor
+            .sender_map
+            .get_mut(&address)
+            .unwrap()
+            .nonce_tracker
+            .resync(nonce);
+        address
+    }
+
+    fn unsigned_tx(sender: &Address) -> Transaction {
+        Transaction {
+            nonce: 0,
+            value: "0".to_string(),
+            receiver: crate::address_h256_to_erdrs(sender),
+            sender: crate::address_h256_to_erdrs(sender),
+            gas_price: 1_000_000_000,
+            gas_limit: 100_000,
+            data: None,
+            signature: None,
+            chain_id: "local-testnet".to_string(),
+            version: 1,
+            options: TransactionOptions::default(),
+            guardian: None,
+            guardian_signature: None,
+            relayer: None,
+            relayer_signature: None,
+        }
+    }
+
+    fn tx_hashes(entries: &[(i32, &str)]) -> HashMap<i32, String> {
+        entries
+            .iter()
+            .map(|(index, tx_hash)| (*index, tx_hash.to_string()))
+            .collect()
+    }
+
+    fn nonce_tracker<'a>(interactor: &'a Interactor, address: &Address) -> &'a crate::NonceTracker {
+        &interactor.sender_map[address].nonce_tracker
+    }
+
+    #[test]
+    fn test_batch_nonces_per_sender() {
+        let mut interactor = offline_interactor();
+        let alice = register_synced_wallet(&mut interactor, test_wallets::alice(), 5);
+        let bob = register_synced_wallet(&mut interactor, test_wallets::bob(), 20);
+
+        let mut txs = vec![
+            unsigned_tx(&alice),
+            unsigned_tx(&bob),
+            unsigned_tx(&alice),
+            unsigned_tx(&bob),
+        ];
+        interactor.sign_txs(&mut txs);
+
+        // each sender gets consecutive nonces, in the order of the batch
+        let nonces: Vec<u64> = txs.iter().map(|tx| tx.nonce).collect();
+        assert_eq!(nonces, vec![5, 20, 6, 21]);
+        assert!(txs.iter().all(|tx| tx.signature.is_some()));
+
+        let accepted = interactor.record_sent_txs(
+            &txs,
+            tx_hashes(&[
+                (0, "hash-a5"),
+                (1, "hash-b20"),
+                (2, "hash-a6"),
+                (3, "hash-b21"),
+            ]),
+        );
+        assert!(accepted.iter().all(Option::is_some));
+        assert_eq!(
+            nonce_tracker(&interactor, &alice)
+                .pending_txs()
+                .keys()
+                .collect::<Vec<_>>(),
+            vec![&5, &6]
+        );
+        assert_eq!(
+            nonce_tracker(&interactor, &bob).pending_txs()[&21],
+            "hash-b21"
+        );
+    }
+
+    #[test]
+    fn test_batch_partially_accepted() {
+        let mut interactor = offline_interactor();
+        let alice = register_synced_wallet(&mut interactor, test_wallets::alice(), 5);
+
+        let mut txs = vec![unsigned_tx(&alice); 4];
+        interactor.sign_txs(&mut txs);
+
+        // the node rejects the second and the last transactions
+        let accepted = interactor.record_sent_txs(&txs, tx_hashes(&[(0, "hash-5"), (2, "hash-7")]));
+        assert_eq!(
+            accepted,
+            vec![
+                Some("hash-5".to_string()),
+                None,
+                Some("hash-7".to_string()),
+                None
+            ]
+        );
+
+        // the last nonce is given back, the one followed by an accepted transaction remains a gap
+        let nonce_tracker = nonce_tracker(&interactor, &alice);
+        assert_eq!(nonce_tracker.next_nonce(), Some(8));
+        assert_eq!(nonce_tracker.gaps(5), vec![6]);
+    }
+
+    #[test]
+    fn test_replace_relayed_tx() {
+        let mut interactor = offline_interactor();
+        let alice = register_synced_wallet(&mut interactor, test_wallets::alice(), 5);
+        let bob = register_synced_wallet(&mut interactor, test_wallets::bob(), 0);
+        interactor.set_relayer(Some(bob));
+
+        let mut txs = vec![unsigned_tx(&alice)];
+        interactor.sign_txs(&mut txs);
+        interactor.record_sent_txs(&txs, tx_hashes(&[(0, "hash-5")]));
+        let mut transaction = txs.pop().unwrap();
+        assert_eq!(transaction.gas_limit, 100_000 + MIN_GAS_LIMIT);
+
+        // signing the relayed transaction again does not add the relayer gas a second time
+        transaction.gas_price *= 2;
+        interactor.sign_replacement_tx(5, &mut transaction);
+        assert_eq!(transaction.nonce, 5);
+        assert_eq!(transaction.gas_limit, 100_000 + MIN_GAS_LIMIT);
+        assert!(transaction.relayer_signature.is_some());
+
+        interactor.record_sent_txs(&[transaction], tx_hashes(&[(0, "hash-5-replaced")]));
+        let nonce_tracker = nonce_tracker(&interactor, &alice);
+        assert_eq!(nonce_tracker.pending_txs()[&5], "hash-5-replaced");
+        assert_eq!(nonce_tracker.next_nonce(), Some(6));
+    }
+
+    #[test]
+    #[should_panic(expected = "nonce 6 was never used, there is no transaction to replace")]
+    fn test_replace_unused_nonce() {
+        let mut interactor = offline_interactor();
+        let alice = register_synced_wallet(&mut interactor, test_wallets::alice(), 5);
+        interactor
+            .sender_map
+            .get_mut(&alice)
+            .unwrap()
+            .nonce_tracker
+            .acquire();
+
+        interactor.sign_replacement_tx(6, &mut unsigned_tx(&alice));
+    }
 }
@@ -0,0 +1,120 @@
+use multiversx_sc_scenario::multiversx_sc::types::Address;
+use std::collections::BTreeMap;
+
+use crate::Interactor;
+
+/// Keeps track locally of the nonces of a sender,
+/// so that several of its transactions can be sent before the first one gets processed.
+///
+/// The nonce is only read from the network the first time, or on an explicit resync.
+#[derive(Debug, Default, Clone)]
+pub struct NonceTracker {
+    next_nonce: Option<u64>,
+    pending: BTreeMap<u64, String>,
+}
+
+impl NonceTracker {
+    /// The nonce of the next transaction, `None` if never synced with the network.
+    pub fn next_nonce(&self) -> Option<u64> {
+        self.next_nonce
+    }
+
+    /// The hashes of the transactions sent and not yet known to be processed, by nonce.
+    pub fn pending_txs(&self) -> &BTreeMap<u64, String> {
+        &self.pending
+    }
+
+    /// Forgets everything and starts over from the account nonce on the network.
+    ///
+    /// Transactions still in the mempool will get replaced by the next ones sent.
+    pub fn resync(&mut self, account_nonce: u64) {
+        self.next_nonce = Some(account_nonce);
+        self.pending.clear();
+    }
+
+    /// Hands out the nonce for the next transaction.
+    pub fn acquire(&mut self) -> u64 {
+        let nonce = self
+            .next_nonce
+            .expect("sender nonce not synced with the network");
+        self.next_nonce = Some(nonce + 1);
+        nonce
+    }
+
+    /// Gives back a nonce that did not get used, e.g. because sending failed.
+    ///
+    /// Only the last nonce handed out can be given back, any other one is left as a gap.
+    pub fn release(&mut self, nonce: u64) {
+        if self.next_nonce == Some(nonce + 1) && !self.pending.contains_key(&nonce) {
+            self.next_nonce = Some(nonce);
+        }
+    }
+
+    /// Records the transaction sent with the given nonce, replacing any previous one.
+    pub fn mark_sent(&mut self, nonce: u64, tx_hash: String) {
+        self.pending.insert(nonce, tx_hash);
+    }
+
+    /// Catches up with the account nonce on the network, all transactions below it are processed.
+    ///
+    /// If the network is ahead, transactions were sent from elsewhere, so those nonces are skipped.
+    pub fn update_account_nonce(&mut self, account_nonce: u64) {
+        self.pending = self.pending.split_off(&account_nonce);
+        if !matches!(self.next_nonce, Some(next_nonce) if next_nonce >= account_nonce) {
+            self.next_nonce = Some(account_nonce);
+        }
+    }
+
+    /// The nonces handed out for which no transaction was sent.
+    ///
+    /// The network will not process any of the transactions after them until they are filled.
+    pub fn gaps(&self, account_nonce: u64) -> Vec<u64> {
+        let Some(next_nonce) = self.next_nonce else {
+            return Vec::new();
+        };
+
+        (account_nonce..next_nonce)
+            .filter(|nonce| !self.pending.contains_key(nonce))
+            .collect()
+    }
+}
+
+impl Interactor {
+    /// Discards the locally tracked nonce of a sender and reads it again from the network.
+    pub async fn resync_nonce(&mut self, address: &Address) -> u64 {
+        let nonce = self.recall_nonce(address).await;
+        self.sender_map
+            .get_mut(address)
+            .expect("sender not registered")
+            .nonce_tracker
+            .resync(nonce);
+        println!("sender's resynced nonce: {nonce}");
+        nonce
+    }
+
+    /// Compares the local nonces of a sender with the account nonce on the network,
+    /// and returns the ones that were skipped, blocking the transactions after them.
+    ///
+    /// Gaps can be filled with `replace_tx`.
+    pub async fn nonce_gaps(&mut self, address: &Address) -> Vec<u64> {
+        let account_nonce = self.recall_nonce(address).await;
+        let nonce_tracker = &mut self
+            .sender_map
+            .get_mut(address)
+            .expect("sender not registered")
+            .nonce_tracker;
+        nonce_tracker.update_account_nonce(account_nonce);
+        nonce_tracker.gaps(account_nonce)
+    }
+
+    /// Reads the nonce from the network, only if not already tracked locally.
+    pub(crate) async fn sync_nonce_if_needed(&mut self, address: &Address) {
+        let sender = self
+            .sender_map
+            .get(address)
+            .expect("the wallet that was supposed to sign is not registered");
+        if sender.nonce_tracker.next_nonce().is_none() {
+            self.resync_nonce(address).await;
+        }
+    }
+}
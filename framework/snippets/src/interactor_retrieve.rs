@@ -1,64 +1,373 @@
 use crate::Interactor;
 use log::info;
 use multiversx_sdk::data::transaction::TransactionOnNetwork;
-use std::time::Duration;
+use std::{
+    fmt::Display,
+    future::Future,
+    time::{Duration, Instant},
+};
 
-const TX_GET_RESULTS_NUM_RETRIES: usize = 8;
-const EXTRA_WAITING_TIME_MS: Duration = Duration::from_millis(8000);
-const WAITING_TIME_MS: Duration = Duration::from_secs(25);
-const WAIT: u64 = 1000;
+const COMPLETED_TX_EVENT: &str = "completedTxEvent";
+const SIGNAL_ERROR_EVENT: &str = "signalError";
+const SC_ADDRESS_NUM_LEADING_ZEROS: usize = 8;
+
+/// How the interactor waits for the transactions it sends to be processed.
+#[derive(Debug, Clone)]
+pub struct TxPollingPolicy {
+    /// Waiting time before the first check, no transaction is processed sooner than the next block.
+    pub initial_delay: Duration,
+
+    /// Waiting time between two consecutive checks.
+    pub interval: Duration,
+
+    /// Gives up waiting for a transaction after this long.
+    pub timeout: Duration,
+
+    /// How many consecutive failed requests to the proxy are tolerated.
+    pub max_request_errors: usize,
+}
+
+impl Default for TxPollingPolicy {
+    /// Suited for the public networks, with 6 seconds rounds.
+    fn default() -> Self {
+        TxPollingPolicy {
+            initial_delay: Duration::from_secs(6),
+            interval: Duration::from_secs(2),
+            timeout: Duration::from_secs(300),
+            max_request_errors: 8,
+        }
+    }
+}
+
+impl TxPollingPolicy {
+    /// Suited for a local chain, or a chain simulator, where blocks are produced quickly.
+    pub fn fast() -> Self {
+        TxPollingPolicy {
+            initial_delay: Duration::ZERO,
+            interval: Duration::from_millis(200),
+            timeout: Duration::from_secs(60),
+            max_request_errors: 8,
+        }
+    }
+}
 
 impl Interactor {
-    /// Retrieves a transaction from the network.
+    /// Sets how to wait for the transactions to be processed, e.g. more often on a local chain.
+    pub fn with_tx_polling_policy(mut self, tx_polling_policy: TxPollingPolicy) -> Self {
+        self.tx_polling_policy = tx_polling_policy;
+        self
+    }
+
+    /// Retrieves a transaction from the network, once fully processed,
+    /// including all its cross-shard smart contract results and callbacks.
     pub(crate) async fn retrieve_tx_on_network(&self, tx_hash: String) -> TransactionOnNetwork {
-        let mut waiting_time_ms = 0;
-        let mut break_outer = false;
-        sleep(&mut waiting_time_ms, WAITING_TIME_MS).await;
-
-        let tx = 'outer: loop {
-            let mut retries = TX_GET_RESULTS_NUM_RETRIES;
-            let mut wait = WAIT;
-            loop {
-                let tx_info_result = self.proxy.get_transaction_info_with_results(&tx_hash).await;
-                match tx_info_result {
-                    Ok(tx) => {
-                        if break_outer {
-                            break 'outer tx;
-                        }
-
-                        // reset waiting time
-                        waiting_time_ms = WAITING_TIME_MS.as_millis() as u64;
-
-                        tokio::time::sleep(EXTRA_WAITING_TIME_MS).await;
-                        break_outer = true;
-
-                        break;
-                    },
-                    Err(err) => {
-                        assert!(
-                            retries > 0,
-                            "still no answer after {TX_GET_RESULTS_NUM_RETRIES} retries"
-                        );
-
-                        info!(
-                            "tx result fetch error after {} ms: {}",
-                            self.waiting_time_ms, err
-                        );
-                        retries -= 1;
-                        sleep(&mut waiting_time_ms, Duration::from_millis(wait)).await;
-                        wait *= 2;
-                    },
-                }
+        let tx = poll_until_completed(&self.tx_polling_policy, &tx_hash, || async {
+            match self.proxy.get_transaction_status(&tx_hash).await {
+                Ok(status) if is_final_status(&status) => self
+                    .proxy
+                    .get_transaction_info_with_results(&tx_hash)
+                    .await
+                    .map(|tx| is_tx_completed(&tx).then_some(tx)),
+                Ok(_) => Ok(None),
+                Err(err) => Err(err),
             }
-        };
+        })
+        .await;
 
         info!("tx with results: {:#?}", tx);
         tx
     }
 }
 
-/// Sleeps for the given duration and adds the duration to the waiting time.
-pub async fn sleep(waiting_time_ms: &mut u64, duration: Duration) {
-    *waiting_time_ms += duration.as_millis() as u64;
-    tokio::time::sleep(duration).await;
+/// Checks the transaction according to the policy, until `check` returns it.
+///
+/// `check` returns `None` while the transaction is still being processed.
+/// Only consecutive request errors count towards the limit.
+async fn poll_until_completed<F, Fut, E>(
+    policy: &TxPollingPolicy,
+    tx_hash: &str,
+    mut check: F,
+) -> TransactionOnNetwork
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Option<TransactionOnNetwork>, E>>,
+    E: Display,
+{
+    let start = Instant::now();
+    let mut request_errors = 0;
+    tokio::time::sleep(policy.initial_delay).await;
+
+    loop {
+        match check().await {
+            Ok(Some(tx)) => return tx,
+            Ok(None) => request_errors = 0,
+            Err(err) => {
+                request_errors += 1;
+                info!("tx fetch error after {:?}: {}", start.elapsed(), err);
+            },
+        }
+
+        assert!(
+            request_errors <= policy.max_request_errors,
+            "still no answer for tx {tx_hash} after {request_errors} failed requests"
+        );
+        assert!(
+            start.elapsed() < policy.timeout,
+            "tx {tx_hash} still not completed after {:?}",
+            policy.timeout
+        );
+        tokio::time::sleep(policy.interval).await;
+    }
+}
+
+/// The transaction got executed, or was rejected. Cross-shard results might still be pending.
+fn is_final_status(status: &str) -> bool {
+    matches!(
+        status,
+        "success" | "executed" | "fail" | "invalid" | "reward-reverted"
+    )
+}
+
+/// Cross-shard calls to smart contracts are only done when the network signals so, after the callback.
+///
+/// The events can be in the logs of the transaction, or in those of its smart contract results.
+fn is_tx_completed(tx: &TransactionOnNetwork) -> bool {
+    if !is_final_status(&tx.status) {
+        return false;
+    }
+
+    let scr_logs = tx
+        .smart_contract_results
+        .iter()
+        .flatten()
+        .filter_map(|scr| scr.logs.as_ref());
+    let has_event = |identifier: &str| {
+        tx.logs.iter().chain(scr_logs.clone()).any(|logs| {
+            logs.events
+                .iter()
+                .any(|event| event.identifier == identifier)
+        })
+    };
+    if has_event(COMPLETED_TX_EVENT) || has_event(SIGNAL_ERROR_EVENT) {
+        return true;
+    }
+
+    let is_sc_call = tx.data.is_some() && is_smart_contract_address(&tx.receiver.to_bytes());
+    tx.status != "success" || tx.source_shard == tx.destination_shard || !is_sc_call
+}
+
+fn is_smart_contract_address(address: &[u8; 32]) -> bool {
+    address[..SC_ADDRESS_NUM_LEADING_ZEROS]
+        .iter()
+        .all(|byte| *byte == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use multiversx_sdk::data::transaction::ApiSmartContractResult;
+
+    const USER_ADDRESS: &str = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+    const SC_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgqnhvsujzd95jz6fyv3ldmynlf97tscs9nqqqq49en6w";
+    const SYSTEM_SC_ADDRESS: &str =
+        "erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u";
+
+    fn tx_on_network(
+        receiver: &str,
+        destination_shard: u32,
+        status: &str,
+        events: &[&str],
+    ) -> TransactionOnNetwork {
+        let events: Vec<_> = events
+            .iter()
+            .map(|identifier| serde_json::json!({ "address": receiver, "identifier": identifier }))
+            .collect();
+
+        serde_json::from_value(serde_json::json!({
+            "type": "normal",
+            "hash": "2a5b...",
+            "nonce": 5,
+            "round": 100,
+            "epoch": 1,
+            "value": "0",
+            "receiver": receiver,
+            "sender": USER_ADDRESS,
+            "gasPrice": 1_000_000_000,
+            "gasLimit": 60_000_000,
+            "signature": "",
+            "sourceShard": 1,
+            "destinationShard": destination_shard,
+            "blockNonce": 100,
+            "blockHash": "",
+            "notarizedAtSourceInMetaNonce": 0,
+            "NotarizedAtSourceInMetaHash": "",
+            "notarizedAtDestinationInMetaNonce": 0,
+            "notarizedAtDestinationInMetaHash": "",
+            "processingTypeOnDestination": "",
+            "miniblockType": "",
+            "miniblockHash": "",
+            "timestamp": 0,
+            "data": "aXNzdWU=",
+            "status": status,
+            "hyperblockNonce": 0,
+            "hyperblockHash": "",
+            "logs": { "address": receiver, "events": events },
+        }))
+        .unwrap()
+    }
+
+    fn scr_with_events(receiver: &str, events: &[&str]) -> ApiSmartContractResult {
+        let events: Vec<_> = events
+            .iter()
+            .map(|identifier| serde_json::json!({ "address": receiver, "identifier": identifier }))
+            .collect();
+
+        serde_json::from_value(serde_json::json!({
+            "hash": "3b6c...",
+            "nonce": 0,
+            "value": 0,
+            "receiver": USER_ADDRESS,
+            "sender": receiver,
+            "data": "@6f6b",
+            "prevTxHash": "2a5b...",
+            "originalTxHash": "2a5b...",
+            "gasLimit": 0,
+            "gasPrice": 1_000_000_000,
+            "callType": 0,
+            "logs": { "address": receiver, "events": events },
+        }))
+        .unwrap()
+    }
+
+    fn fast_policy() -> TxPollingPolicy {
+        TxPollingPolicy {
+            initial_delay: Duration::ZERO,
+            interval: Duration::from_millis(1),
+            timeout: Duration::from_millis(50),
+            max_request_errors: 2,
+        }
+    }
+
+    #[test]
+    fn test_final_status() {
+        assert!(!is_final_status("pending"));
+        assert!(!is_final_status("received"));
+        assert!(!is_final_status("partially-executed"));
+        assert!(is_final_status("success"));
+        assert!(is_final_status("fail"));
+        assert!(is_final_status("invalid"));
+    }
+
+    #[test]
+    fn test_intra_shard_tx_completed() {
+        assert!(!is_tx_completed(&tx_on_network(
+            SC_ADDRESS,
+            1,
+            "pending",
+            &[]
+        )));
+        assert!(is_tx_completed(&tx_on_network(
+            SC_ADDRESS,
+            1,
+            "success",
+            &[]
+        )));
+        assert!(is_tx_completed(&tx_on_network(
+            USER_ADDRESS,
+            0,
+            "success",
+            &[]
+        )));
+    }
+
+    #[test]
+    fn test_cross_shard_sc_call_completed() {
+        // the callback did not arrive yet
+        let metachain = 4294967295;
+        assert!(!is_tx_completed(&tx_on_network(
+            SYSTEM_SC_ADDRESS,
+            metachain,
+            "success",
+            &["issue"]
+        )));
+        assert!(is_tx_completed(&tx_on_network(
+            SYSTEM_SC_ADDRESS,
+            metachain,
+            "success",
+            &["issue", COMPLETED_TX_EVENT]
+        )));
+        assert!(is_tx_completed(&tx_on_network(
+            SC_ADDRESS,
+            2,
+            "success",
+            &[SIGNAL_ERROR_EVENT]
+        )));
+        assert!(is_tx_completed(&tx_on_network(SC_ADDRESS, 2, "fail", &[])));
+    }
+
+    #[test]
+    fn test_cross_shard_completed_in_scr_logs() {
+        let mut tx = tx_on_network(SC_ADDRESS, 2, "success", &[]);
+        tx.smart_contract_results = Some(vec![scr_with_events(SC_ADDRESS, &["transferValueOnly"])]);
+        assert!(!is_tx_completed(&tx));
+
+        tx.smart_contract_results
+            .as_mut()
+            .unwrap()
+            .push(scr_with_events(SC_ADDRESS, &[COMPLETED_TX_EVENT]));
+        assert!(is_tx_completed(&tx));
+    }
+
+    #[test]
+    fn test_cross_shard_not_completed_in_hyperblock() {
+        // notarizing the transaction does not mean its results and callback were executed
+        let mut tx = tx_on_network(SC_ADDRESS, 2, "success", &[]);
+        tx.hyperblock_nonce = 120;
+        assert!(!is_tx_completed(&tx));
+
+        tx.smart_contract_results = Some(vec![scr_with_events(SC_ADDRESS, &[SIGNAL_ERROR_EVENT])]);
+        assert!(is_tx_completed(&tx));
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_completed() {
+        // request errors only count while consecutive
+        let mut responses = vec![
+            Err("timeout"),
+            Err("timeout"),
+            Ok(None),
+            Err("timeout"),
+            Err("timeout"),
+            Ok(None),
+            Ok(Some(tx_on_network(SC_ADDRESS, 1, "success", &[]))),
+        ]
+        .into_iter();
+        let mut num_checks = 0;
+        let tx = poll_until_completed(&fast_policy(), "2a5b...", || {
+            num_checks += 1;
+            let response = responses.next().unwrap();
+            async move { response }
+        })
+        .await;
+        assert_eq!(tx.status, "success");
+        assert_eq!(num_checks, 7);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "still no answer for tx 2a5b... after 3 failed requests")]
+    async fn test_poll_until_completed_request_errors() {
+        poll_until_completed(&fast_policy(), "2a5b...", || async {
+            Err::<Option<TransactionOnNetwork>, _>("timeout")
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "tx 2a5b... still not completed after 50ms")]
+    async fn test_poll_until_completed_timeout() {
+        poll_until_completed(&fast_policy(), "2a5b...", || async {
+            Ok::<_, String>(None)
+        })
+        .await;
+    }
 }
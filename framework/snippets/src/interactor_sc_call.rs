@@ -6,7 +6,10 @@ use multiversx_sc_scenario::{
     scenario::ScenarioRunner,
     scenario_model::{ScCallStep, SetStateStep, TxCall, TxResponse},
 };
-use multiversx_sdk::{data::transaction::Transaction, utils::base64_encode};
+use multiversx_sdk::{
+    data::transaction::{Transaction, TransactionOptions},
+    utils::base64_encode,
+};
 
 impl Interactor {
     pub async fn sc_call<S>(&mut self, mut sc_call_step: S)
@@ -38,7 +41,7 @@ impl Interactor {
         let mut transaction = self.tx_call_to_blockchain_tx(&sc_call_step.tx);
         self.set_nonce_and_sign_tx(sender_address, &mut transaction)
             .await;
-        let tx_hash = self.proxy.send_transaction(&transaction).await.unwrap();
+        let tx_hash = self.send_tx(&transaction).await;
         println!("sc call tx hash: {tx_hash}");
         info!("sc call tx hash: {}", tx_hash);
 
@@ -65,7 +68,11 @@ impl Interactor {
             signature: None,
             chain_id: self.network_config.chain_id.clone(),
             version: self.network_config.min_transaction_version,
-            options: 0,
+            options: TransactionOptions::default(),
+            guardian: None,
+            guardian_signature: None,
+            relayer: None,
+            relayer_signature: None,
         }
     }
 }
@@ -6,7 +6,10 @@ use multiversx_sc_scenario::{
     scenario_model::{ScDeployStep, SetStateStep, TxResponse},
 };
 use multiversx_sdk::{
-    data::{address::Address as ErdrsAddress, transaction::Transaction},
+    data::{
+        address::Address as ErdrsAddress,
+        transaction::{Transaction, TransactionOptions},
+    },
     utils::base64_encode,
 };
 
@@ -25,7 +28,11 @@ impl Interactor {
             signature: None,
             chain_id: self.network_config.chain_id.clone(),
             version: self.network_config.min_transaction_version,
-            options: 0,
+            options: TransactionOptions::default(),
+            guardian: None,
+            guardian_signature: None,
+            relayer: None,
+            relayer_signature: None,
         }
     }
 
@@ -36,11 +43,7 @@ impl Interactor {
         let mut transaction = self.sc_deploy_to_blockchain_tx(sc_deploy_step);
         self.set_nonce_and_sign_tx(sender_address, &mut transaction)
             .await;
-        let tx_hash = self
-            .proxy
-            .send_transaction(&transaction)
-            .await
-            .expect("error sending tx (possible API failure)");
+        let tx_hash = self.send_tx(&transaction).await;
         println!("sc deploy tx hash: {tx_hash}");
         info!("sc deploy tx hash: {}", tx_hash);
 
@@ -1,14 +1,20 @@
-use log::debug;
-use multiversx_sc_scenario::multiversx_sc::types::Address;
-use multiversx_sdk::{data::transaction::Transaction, wallet::Wallet};
+use log::{debug, info};
+use multiversx_sc_scenario::{
+    multiversx_chain_vm::{types::VMAddress, world_mock::ShardCoordinator},
+    multiversx_sc::types::Address,
+};
+use multiversx_sdk::{
+    data::transaction::{Transaction, TransactionOnNetwork},
+    wallet::Wallet,
+};
 
-use crate::{address_h256_to_erdrs, Interactor};
+use crate::{address_h256_to_erdrs, erdrs_address_to_h256, Interactor, NonceTracker};
 
 /// A user account that can sign transactions (a pem is present).
 pub struct Sender {
     pub address: Address,
     pub wallet: Wallet,
-    pub current_nonce: Option<u64>,
+    pub nonce_tracker: NonceTracker,
 }
 
 impl Interactor {
@@ -22,35 +28,196 @@ impl Interactor {
         account.nonce
     }
 
+    /// All transactions sent from now on are relayed by the given wallet, which pays for their gas.
+    ///
+    /// The relayer needs to be registered, and to be in the same shard as the senders,
+    /// which gets checked for each transaction.
+    pub fn set_relayer(&mut self, relayer: Option<Address>) {
+        if let Some(relayer) = &relayer {
+            assert!(
+                self.sender_map.contains_key(relayer),
+                "the relayer wallet is not registered"
+            );
+        }
+        self.relayer = relayer;
+    }
+
+    /// Makes the transaction relayed, if a relayer is set. Needs to happen before the sender signs.
+    ///
+    /// Transactions that are already relayed, e.g. when signed again, are left as they are,
+    /// so the relayer gas is only added once.
+    pub(crate) fn set_tx_relayer(&self, sender_address: &Address, transaction: &mut Transaction) {
+        if transaction.relayer.is_some() {
+            return;
+        }
+        if let Some(relayer) = &self.relayer {
+            assert!(
+                same_shard(
+                    self.network_config.num_shards_without_meta,
+                    sender_address,
+                    relayer
+                ),
+                "the relayer is not in the same shard as the sender"
+            );
+            transaction.set_relayer(address_h256_to_erdrs(relayer), &self.network_config);
+        }
+    }
+
+    /// The relayer co-signs the transaction, after the sender.
+    pub(crate) fn relayer_sign_tx(&self, transaction: &mut Transaction) {
+        let Some(relayer) = &self.relayer else {
+            return;
+        };
+
+        let relayer = self
+            .sender_map
+            .get(relayer)
+            .expect("the relayer wallet is not registered");
+        let signature = relayer
+            .wallet
+            .relayer_sign_tx(transaction)
+            .expect("failed to sign as relayer");
+        transaction.relayer_signature = Some(hex::encode(signature));
+    }
+
+    /// Sets the next nonce of the sender, then signs. The nonce is only read from the network the first time.
     pub(crate) async fn set_nonce_and_sign_tx(
         &mut self,
         sender_address: &Address,
         transaction: &mut Transaction,
     ) {
-        // read
-        let sender = self
+        self.sync_nonce_if_needed(sender_address).await;
+        let nonce = self
             .sender_map
-            .get(sender_address)
-            .expect("the wallet that was supposed to sign is not registered");
+            .get_mut(sender_address)
+            .expect("the wallet that was supposed to sign is not registered")
+            .nonce_tracker
+            .acquire();
+        self.sign_tx_with_nonce(sender_address, transaction, nonce);
+    }
 
-        // recall
-        let nonce = self.recall_nonce(&sender.address).await;
-        println!("sender's recalled nonce: {nonce}");
+    pub(crate) fn sign_tx_with_nonce(
+        &self,
+        sender_address: &Address,
+        transaction: &mut Transaction,
+        nonce: u64,
+    ) {
+        self.set_tx_relayer(sender_address, transaction);
 
         // set tx nonce
         transaction.nonce = nonce;
         println!("-- tx nonce: {}", transaction.nonce);
 
-        // update
+        // sign
         let sender = self
             .sender_map
-            .get_mut(sender_address)
+            .get(sender_address)
             .expect("the wallet that was supposed to sign is not registered");
-        sender.current_nonce = Some(nonce + 1);
-
-        // sign
         let signature = sender.wallet.sign_tx(transaction);
         transaction.signature = Some(hex::encode(signature));
+        self.relayer_sign_tx(transaction);
         debug!("transaction {:#?}", transaction);
     }
+
+    /// Sends a signed transaction and records it as pending.
+    ///
+    /// If sending fails, its nonce is given back to the sender.
+    pub(crate) async fn send_tx(&mut self, transaction: &Transaction) -> String {
+        let result = self.proxy.send_transaction(transaction).await;
+
+        let nonce_tracker = &mut self
+            .sender_map
+            .get_mut(&erdrs_address_to_h256(transaction.sender.clone()))
+            .expect("sender not registered")
+            .nonce_tracker;
+        match result {
+            Ok(tx_hash) => {
+                nonce_tracker.mark_sent(transaction.nonce, tx_hash.clone());
+                tx_hash
+            },
+            Err(err) => {
+                nonce_tracker.release(transaction.nonce);
+                panic!("error sending tx (possible API failure): {err}");
+            },
+        }
+    }
+
+    /// Sends a transaction with a nonce that was already handed out,
+    /// either replacing the pending transaction with that nonce, or filling a gap.
+    ///
+    /// To replace a transaction that is still in the mempool, the new one needs a higher gas price.
+    pub async fn replace_tx(
+        &mut self,
+        nonce: u64,
+        mut transaction: Transaction,
+    ) -> TransactionOnNetwork {
+        self.sign_replacement_tx(nonce, &mut transaction);
+        let tx_hash = self.send_tx(&transaction).await;
+        println!("replacement tx hash: {tx_hash} with nonce: {nonce}");
+        info!("replacement tx hash: {}", tx_hash);
+
+        self.retrieve_tx_on_network(tx_hash).await
+    }
+
+    /// Signs a transaction with a nonce that was already handed out.
+    pub(crate) fn sign_replacement_tx(&self, nonce: u64, transaction: &mut Transaction) {
+        let sender_address = erdrs_address_to_h256(transaction.sender.clone());
+        let next_nonce = self
+            .sender_map
+            .get(&sender_address)
+            .expect("the wallet that was supposed to sign is not registered")
+            .nonce_tracker
+            .next_nonce();
+        assert!(
+            next_nonce.is_some_and(|next_nonce| nonce < next_nonce),
+            "nonce {nonce} was never used, there is no transaction to replace"
+        );
+
+        self.sign_tx_with_nonce(&sender_address, transaction, nonce);
+    }
+}
+
+/// Relayed transactions are only accepted when the relayer is in the same shard as the sender.
+fn same_shard(num_shards: u32, address_1: &Address, address_2: &Address) -> bool {
+    let shard_coordinator = ShardCoordinator::new(num_shards);
+    shard_coordinator.same_shard(
+        &VMAddress::from(address_1.as_array()),
+        &VMAddress::from(address_2.as_array()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address_with_last_byte(last_byte: u8) -> Address {
+        let mut bytes = [1u8; 32];
+        bytes[31] = last_byte;
+        Address::from(bytes)
+    }
+
+    #[test]
+    fn test_relayer_same_shard() {
+        // with 3 shards, the last 2 bits give the shard, 3 falls back to the last bit
+        assert!(same_shard(
+            3,
+            &address_with_last_byte(0),
+            &address_with_last_byte(4)
+        ));
+        assert!(same_shard(
+            3,
+            &address_with_last_byte(1),
+            &address_with_last_byte(3)
+        ));
+        assert!(!same_shard(
+            3,
+            &address_with_last_byte(1),
+            &address_with_last_byte(2)
+        ));
+        assert!(same_shard(
+            1,
+            &address_with_last_byte(1),
+            &address_with_last_byte(2)
+        ));
+    }
 }
@@ -2,6 +2,7 @@ mod interactor;
 mod interactor_dns;
 mod interactor_multi_sc_exec;
 mod interactor_multi_sc_process;
+mod interactor_nonce;
 mod interactor_retrieve;
 mod interactor_sc_call;
 mod interactor_sc_deploy;
@@ -16,6 +17,8 @@ pub use env_logger;
 pub use hex;
 pub use interactor::*;
 pub use interactor_dns::*;
+pub use interactor_nonce::*;
+pub use interactor_retrieve::TxPollingPolicy;
 pub use interactor_sender::*;
 pub use interactor_tx_spec::*;
 pub use log;
@@ -0,0 +1,93 @@
+use multiversx_sc_snippets::NonceTracker;
+
+fn synced_tracker(account_nonce: u64) -> NonceTracker {
+    let mut nonce_tracker = NonceTracker::default();
+    nonce_tracker.resync(account_nonce);
+    nonce_tracker
+}
+
+#[test]
+fn nonce_tracker_consecutive_nonces() {
+    let mut nonce_tracker = NonceTracker::default();
+    assert_eq!(nonce_tracker.next_nonce(), None);
+
+    nonce_tracker.resync(5);
+    assert_eq!(nonce_tracker.acquire(), 5);
+    assert_eq!(nonce_tracker.acquire(), 6);
+    assert_eq!(nonce_tracker.acquire(), 7);
+    assert_eq!(nonce_tracker.next_nonce(), Some(8));
+}
+
+#[test]
+#[should_panic = "sender nonce not synced with the network"]
+fn nonce_tracker_not_synced() {
+    NonceTracker::default().acquire();
+}
+
+#[test]
+fn nonce_tracker_release() {
+    let mut nonce_tracker = synced_tracker(5);
+    let first = nonce_tracker.acquire();
+    let second = nonce_tracker.acquire();
+    nonce_tracker.mark_sent(first, "hash-5".to_string());
+
+    // the last nonce can be given back
+    nonce_tracker.release(second);
+    assert_eq!(nonce_tracker.next_nonce(), Some(6));
+
+    // sent nonces are never given back
+    nonce_tracker.release(first);
+    assert_eq!(nonce_tracker.next_nonce(), Some(6));
+}
+
+#[test]
+fn nonce_tracker_gaps() {
+    let mut nonce_tracker = synced_tracker(5);
+    for _ in 0..4 {
+        nonce_tracker.acquire();
+    }
+    nonce_tracker.mark_sent(5, "hash-5".to_string());
+    nonce_tracker.mark_sent(7, "hash-7".to_string());
+    nonce_tracker.release(6); // too late, 7 was already handed out
+    assert_eq!(nonce_tracker.gaps(5), vec![6, 8]);
+
+    // filling a gap
+    nonce_tracker.mark_sent(6, "hash-6".to_string());
+    assert_eq!(nonce_tracker.gaps(5), vec![8]);
+
+    // processed transactions are no longer pending
+    nonce_tracker.update_account_nonce(7);
+    assert_eq!(
+        nonce_tracker
+            .pending_txs()
+            .keys()
+            .copied()
+            .collect::<Vec<_>>(),
+        vec![7]
+    );
+    assert_eq!(nonce_tracker.gaps(7), vec![8]);
+    assert_eq!(nonce_tracker.next_nonce(), Some(9));
+}
+
+#[test]
+fn nonce_tracker_replace_and_resync() {
+    let mut nonce_tracker = synced_tracker(5);
+    let nonce = nonce_tracker.acquire();
+    nonce_tracker.mark_sent(nonce, "original".to_string());
+    nonce_tracker.mark_sent(nonce, "replacement".to_string());
+    assert_eq!(nonce_tracker.pending_txs()[&5], "replacement");
+    assert_eq!(nonce_tracker.next_nonce(), Some(6));
+
+    // the network is ahead, transactions were sent from elsewhere
+    nonce_tracker.update_account_nonce(10);
+    assert!(nonce_tracker.pending_txs().is_empty());
+    assert_eq!(nonce_tracker.next_nonce(), Some(10));
+    assert!(nonce_tracker.gaps(10).is_empty());
+
+    // an explicit resync forgets everything, even if behind
+    nonce_tracker.acquire();
+    nonce_tracker.mark_sent(10, "hash-10".to_string());
+    nonce_tracker.resync(10);
+    assert!(nonce_tracker.pending_txs().is_empty());
+    assert_eq!(nonce_tracker.acquire(), 10);
+}
@@ -38,6 +38,7 @@ extern "C" {
     fn getPrevBlockEpoch() -> i64;
     fn getPrevBlockRandomSeed(resultOffset: *const u8);
     fn getOriginalTxHash(resultOffset: *const u8);
+    fn getBlockHash(nonce: i64, resultOffset: *mut u8) -> i32;
 
     // Managed versions of the above
     fn managedGetPrevBlockRandomSeed(resultHandle: i32);
@@ -227,6 +228,15 @@ impl BlockchainApiImpl for VmApiImpl {
         }
     }
 
+    #[inline]
+    fn load_block_hash_managed(&self, nonce: u64, dest: Self::ManagedBufferHandle) {
+        let mut block_hash = [0u8; 32];
+        unsafe {
+            let _ = getBlockHash(nonce as i64, block_hash.as_mut_ptr());
+        }
+        self.mb_overwrite(dest, &block_hash);
+    }
+
     #[inline]
     fn get_prev_block_timestamp(&self) -> u64 {
         unsafe { getPrevBlockTimestamp() as u64 }
@@ -7,6 +7,10 @@ extern "C" {
     fn managedMapGet(map_handle: i32, key_handle: i32, out_value_handle: i32) -> i32;
     fn managedMapRemove(map_handle: i32, key_handle: i32, out_value_handle: i32) -> i32;
     fn managedMapContains(map_handle: i32, key_handle: i32) -> i32;
+
+    // only reached through the `managed-map-iteration` feature, the Go VM does not have them yet
+    fn managedMapLen(map_handle: i32) -> i32;
+    fn managedMapKeys(map_handle: i32, out_keys_handle: i32) -> i32;
 }
 
 impl ManagedMapApiImpl for crate::api::VmApiImpl {
@@ -54,4 +58,18 @@ impl ManagedMapApiImpl for crate::api::VmApiImpl {
     ) -> bool {
         unsafe { managedMapContains(map_handle, key_handle) > 0 }
     }
+
+    fn mm_len(&self, map_handle: Self::ManagedMapHandle) -> usize {
+        unsafe { managedMapLen(map_handle) as usize }
+    }
+
+    fn mm_keys(
+        &self,
+        map_handle: Self::ManagedMapHandle,
+        out_keys_handle: Self::ManagedBufferHandle,
+    ) {
+        unsafe {
+            let _ = managedMapKeys(map_handle, out_keys_handle);
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use multiversx_sdk::{
     blockchain::{CommunicationProxy, DEVNET_GATEWAY},
-    data::transaction::Transaction,
+    data::transaction::{Transaction, TransactionOptions},
     wallet::Wallet,
 };
 
@@ -31,6 +31,10 @@ async fn main() {
         chain_id: arg.chain_id,
         version: arg.version,
         options: arg.options,
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
     };
 
     let mut txs: Vec<Transaction> = vec![];
@@ -40,7 +44,7 @@ async fn main() {
     txs.push(unsign_tx.clone());
 
     unsign_tx.version = 2;
-    unsign_tx.options = 1;
+    unsign_tx.options = TransactionOptions::HASH_SIGNING;
     unsign_tx.nonce += 1;
 
     let signature = wl.sign_tx(&unsign_tx);
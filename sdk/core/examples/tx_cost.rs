@@ -1,6 +1,9 @@
 use multiversx_sdk::{
     blockchain::{CommunicationProxy, DEVNET_GATEWAY},
-    data::{address::Address, transaction::Transaction},
+    data::{
+        address::Address,
+        transaction::{Transaction, TransactionOptions},
+    },
     utils::base64_encode,
 };
 
@@ -20,7 +23,11 @@ async fn main() {
         data: Some(base64_encode("hello")),
         chain_id: "1".to_string(),
         version: 1,
-        options: 0,
+        options: TransactionOptions::default(),
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
         gas_limit: 0,
         gas_price: 0,
         signature: None,
@@ -11,12 +11,12 @@ use crate::data::{
     network_status::NetworkStatusResponse,
     transaction::{
         ArgCreateTransaction, ResponseTxCost, SendTransactionResponse, SendTransactionsResponse,
-        Transaction, TransactionInfo, TransactionOnNetwork, TransactionStatus, TxCostResponseData,
+        Transaction, TransactionInfo, TransactionOnNetwork, TransactionOptions, TransactionStatus,
+        TxCostResponseData,
     },
     vm::{ResponseVmValue, VmValueRequest, VmValuesResponseData},
 };
 use anyhow::{anyhow, Result};
-use itertools::Itertools;
 use reqwest::Client;
 
 pub const MAINNET_GATEWAY: &str = "https://gateway.multiversx.com";
@@ -336,7 +336,7 @@ impl CommunicationProxy {
             signature: "".to_string(),
             chain_id: network_configs.chain_id.clone(),
             version: network_configs.min_transaction_version,
-            options: 0,
+            options: TransactionOptions::default(),
             available_balance: account.balance,
         })
     }
@@ -359,6 +359,22 @@ impl CommunicationProxy {
     }
 
     pub async fn send_transactions(&self, txs: &Vec<Transaction>) -> Result<Vec<String>> {
+        let txs_hashes = self.send_transactions_by_index(txs).await?;
+        let mut indices: Vec<&i32> = txs_hashes.keys().collect();
+        indices.sort();
+        Ok(indices
+            .into_iter()
+            .map(|index| txs_hashes[index].clone())
+            .collect())
+    }
+
+    /// Returns the hashes of the transactions accepted by the node, by their index in the batch.
+    ///
+    /// The node can reject some of the transactions and still accept the others.
+    pub async fn send_transactions_by_index(
+        &self,
+        txs: &Vec<Transaction>,
+    ) -> Result<HashMap<i32, String>> {
         let endpoint = self.get_endpoint(SEND_MULTIPLE_TRANSACTIONS_ENDPOINT);
         let resp = self
             .client
@@ -371,14 +387,7 @@ impl CommunicationProxy {
 
         match resp.data {
             None => Err(anyhow!("{}", resp.error)),
-            Some(b) => {
-                let mut tx_hashs: Vec<String> = vec![];
-                for key in b.txs_hashes.keys().sorted() {
-                    tx_hashs.push(b.txs_hashes[key].clone());
-                }
-
-                Ok(tx_hashs)
-            },
+            Some(b) => Ok(b.txs_hashes),
         }
     }
 
@@ -1,13 +1,28 @@
 use super::private_key::PrivateKey;
+use crate::data::address::Address;
 use anyhow::Result;
 use bech32::{self, ToBase32, Variant};
+use ed25519_dalek::Verifier;
 use serde::{
     de::{Deserialize, Deserializer},
     ser::{Serialize, Serializer},
 };
+use sha3::{Digest, Keccak256};
 
 pub const PUBLIC_KEY_LENGTH: usize = 32;
 
+pub const SIGNED_MESSAGE_PREFIX: &[u8] = b"\x17Elrond Signed Message:\n";
+
+// SignedMessageHash computes what actually gets signed for a message:
+// keccak256 of the prefix, the message length in decimal, then the message itself
+pub fn signed_message_hash(message: &[u8]) -> [u8; 32] {
+    let mut h = Keccak256::new();
+    h.update(SIGNED_MESSAGE_PREFIX);
+    h.update(message.len().to_string().as_bytes());
+    h.update(message);
+    h.finalize().into()
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct PublicKey([u8; PUBLIC_KEY_LENGTH]);
 
@@ -31,6 +46,23 @@ impl PublicKey {
         bits.copy_from_slice(&bytes[32..]);
         Ok(Self(bits))
     }
+
+    // Verify checks an ed25519 signature of the raw message, the same way the VM verify_ed25519 does
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        let Ok(public_key) = ed25519_dalek::PublicKey::from_bytes(&self.0) else {
+            return false;
+        };
+        let Ok(signature) = ed25519_dalek::Signature::from_bytes(signature) else {
+            return false;
+        };
+
+        public_key.verify(message, &signature).is_ok()
+    }
+
+    // VerifyMessage checks a signature produced following the MultiversX signed message convention
+    pub fn verify_message(&self, message: &[u8], signature: &[u8]) -> bool {
+        self.verify(&signed_message_hash(message), signature)
+    }
 }
 
 impl<'a> From<&'a PrivateKey> for PublicKey {
@@ -44,6 +76,12 @@ impl<'a> From<&'a PrivateKey> for PublicKey {
     }
 }
 
+impl From<&Address> for PublicKey {
+    fn from(address: &Address) -> PublicKey {
+        PublicKey(address.to_bytes())
+    }
+}
+
 impl ToString for PublicKey {
     fn to_string(&self) -> String {
         hex::encode(self.0)
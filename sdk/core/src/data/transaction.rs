@@ -1,7 +1,8 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, ops::BitOr};
 
-use super::{address::Address, vm::CallType};
+use super::{address::Address, network_config::NetworkConfig, vm::CallType};
 use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
 
 // Transaction holds the fields of a transaction to be broadcasted to the network
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,14 +21,89 @@ pub struct Transaction {
     #[serde(rename = "chainID")]
     pub chain_id: String,
     pub version: u32,
-    #[serde(skip_serializing_if = "is_zero")]
-    pub options: u32,
+    #[serde(default, skip_serializing_if = "TransactionOptions::is_empty")]
+    pub options: TransactionOptions,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guardian: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guardian_signature: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relayer: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relayer_signature: Option<String>,
 }
 
-/// This is only used for serialize
-#[allow(clippy::trivially_copy_pass_by_ref)]
-fn is_zero(num: &u32) -> bool {
-    *num == 0
+// Options and guardians are only taken into account starting with this transaction version
+pub const TRANSACTION_VERSION_WITH_OPTIONS: u32 = 2;
+
+// ExtraGasLimitGuardedTx is the gas that the node charges on top for guarded transactions
+pub const EXTRA_GAS_LIMIT_GUARDED_TX: u64 = 50_000;
+
+impl Transaction {
+    // SerializeForSigning returns the bytes signed by the sender, the guardian and the relayer alike:
+    // the transaction without any of the signatures, or its keccak256 hash when hash signing is enabled
+    pub fn serialize_for_signing(&self) -> Vec<u8> {
+        let mut unsigned_tx = self.clone();
+        unsigned_tx.signature = None;
+        unsigned_tx.guardian_signature = None;
+        unsigned_tx.relayer_signature = None;
+
+        let tx_bytes = serde_json::to_vec(&unsigned_tx).unwrap();
+        if self.version >= TRANSACTION_VERSION_WITH_OPTIONS
+            && self.options.contains(TransactionOptions::HASH_SIGNING)
+        {
+            return Keccak256::digest(tx_bytes).to_vec();
+        }
+
+        tx_bytes
+    }
+
+    // SetGuardian makes the transaction guarded, it then also needs the signature of the guardian
+    // The gas limit is increased by the extra cost of guarded transactions, only the first time
+    pub fn set_guardian(&mut self, guardian: Address) {
+        if !self.options.contains(TransactionOptions::GUARDED) {
+            self.gas_limit += EXTRA_GAS_LIMIT_GUARDED_TX;
+        }
+        self.guardian = Some(guardian);
+        self.options = self.options | TransactionOptions::GUARDED;
+        self.version = self.version.max(TRANSACTION_VERSION_WITH_OPTIONS);
+    }
+
+    // SetRelayer turns the transaction into a relayed transaction, whose gas is paid by the relayer
+    // The relayer also needs to sign it, after the sender; the gas limit is increased by the cost of a move balance
+    pub fn set_relayer(&mut self, relayer: Address, network_config: &NetworkConfig) {
+        self.relayer = Some(relayer);
+        self.gas_limit += network_config.min_gas_limit;
+        self.version = self.version.max(TRANSACTION_VERSION_WITH_OPTIONS);
+    }
+}
+
+// TransactionOptions holds the option flags of a transaction, only used starting with transaction version 2
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TransactionOptions(pub u32);
+
+impl TransactionOptions {
+    // the signatures are computed over the keccak256 hash of the transaction, instead of the transaction itself
+    pub const HASH_SIGNING: TransactionOptions = TransactionOptions(1);
+    // the transaction is co-signed by the guardian of the sender
+    pub const GUARDED: TransactionOptions = TransactionOptions(1 << 1);
+
+    pub fn contains(&self, other: TransactionOptions) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for TransactionOptions {
+    type Output = TransactionOptions;
+
+    fn bitor(self, other: TransactionOptions) -> TransactionOptions {
+        TransactionOptions(self.0 | other.0)
+    }
 }
 
 // TxCostResponseData follows the format of the data field of a transaction cost request
@@ -122,6 +198,7 @@ pub struct ApiSmartContractResult {
     pub code_metadata: Option<String>,
     pub return_message: Option<String>,
     pub original_sender: Option<String>,
+    pub logs: Option<ApiLogs>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -163,7 +240,7 @@ pub struct ArgCreateTransaction {
     pub signature: String,
     pub chain_id: String,
     pub version: u32,
-    pub options: u32,
+    pub options: TransactionOptions,
     pub available_balance: String,
 }
 
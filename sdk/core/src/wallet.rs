@@ -1,22 +1,24 @@
 extern crate rand;
 
-use anyhow::Result;
+pub mod keystore;
+
+use anyhow::{anyhow, Result};
 use bip39::{Language, Mnemonic};
 use hmac::{Hmac, Mac};
 use pbkdf2::pbkdf2;
-use serde_json::json;
-use sha2::{Digest, Sha512};
-use sha3::Keccak256;
+use sha2::Sha512;
 use zeroize::Zeroize;
 
 use crate::{
     crypto::{
         private_key::{PrivateKey, PRIVATE_KEY_LENGTH},
-        public_key::PublicKey,
+        public_key::{signed_message_hash, PublicKey},
     },
     data::{address::Address, transaction::Transaction},
 };
 
+use self::keystore::{Keystore, KeystoreSecret};
+
 const EGLD_COIN_TYPE: u32 = 508;
 const HARDENED: u32 = 0x80000000;
 
@@ -111,24 +113,63 @@ impl Wallet {
         Ok(Self { priv_key: pri_key })
     }
 
+    pub fn from_keystore_file(file_path: &str, password: &str) -> Result<Self> {
+        let contents = std::fs::read_to_string(file_path)?;
+        Self::from_keystore_file_contents(contents, password)
+    }
+
+    // Mnemonic keystores yield the first address, like the web wallet does
+    pub fn from_keystore_file_contents(contents: String, password: &str) -> Result<Self> {
+        let keystore = Keystore::from_json(&contents)?;
+        let priv_key = match keystore.decrypt(password)? {
+            KeystoreSecret::SecretKey(priv_key) => priv_key,
+            KeystoreSecret::Mnemonic(mnemonic) => {
+                Self::get_private_key_from_mnemonic(mnemonic, 0, 0)
+            },
+        };
+        Ok(Self { priv_key })
+    }
+
+    pub fn to_keystore(&self, password: &str) -> Keystore {
+        Keystore::encrypt_secret_key(&self.priv_key, password)
+    }
+
+    pub fn export_keystore_file(&self, file_path: &str, password: &str) -> Result<()> {
+        self.to_keystore(password).save_to_file(file_path)
+    }
+
     pub fn address(&self) -> Address {
         let public_key = PublicKey::from(&self.priv_key);
         Address::from(&public_key)
     }
 
     pub fn sign_tx(&self, unsign_tx: &Transaction) -> [u8; 64] {
-        let mut unsign_tx = unsign_tx.clone();
-        unsign_tx.signature = None;
+        self.priv_key.sign(unsign_tx.serialize_for_signing())
+    }
 
-        let mut tx_bytes = json!(unsign_tx).to_string().as_bytes().to_vec();
+    // GuardianSignTx co-signs a guarded transaction, the wallet has to be the guardian set on the transaction
+    pub fn guardian_sign_tx(&self, tx: &Transaction) -> Result<[u8; 64]> {
+        if tx.guardian.as_ref().map(Address::to_bytes) != Some(self.address().to_bytes()) {
+            return Err(anyhow!("the wallet is not the guardian of the transaction"));
+        }
+        Ok(self.sign_tx(tx))
+    }
 
-        let should_sign_on_tx_hash = unsign_tx.version >= 2 && unsign_tx.options & 1 > 0;
-        if should_sign_on_tx_hash {
-            let mut h = Keccak256::new();
-            h.update(tx_bytes);
-            tx_bytes = h.finalize().as_slice().to_vec();
+    // RelayerSignTx co-signs a relayed transaction, the wallet has to be the relayer set on the transaction
+    pub fn relayer_sign_tx(&self, tx: &Transaction) -> Result<[u8; 64]> {
+        if tx.relayer.as_ref().map(Address::to_bytes) != Some(self.address().to_bytes()) {
+            return Err(anyhow!("the wallet is not the relayer of the transaction"));
         }
+        Ok(self.sign_tx(tx))
+    }
+
+    // SignMessage signs a message following the MultiversX signed message convention,
+    // so that it can be checked with verify_message, by the web wallet or by mxpy
+    pub fn sign_message(&self, message: &[u8]) -> [u8; 64] {
+        self.priv_key.sign(signed_message_hash(message).to_vec())
+    }
 
-        self.priv_key.sign(tx_bytes)
+    pub fn verify_message(&self, message: &[u8], signature: &[u8]) -> bool {
+        PublicKey::from(&self.priv_key).verify_message(message, signature)
     }
 }
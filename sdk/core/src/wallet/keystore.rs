@@ -0,0 +1,250 @@
+use aes::cipher::{KeyIvInit, StreamCipher};
+use anyhow::{anyhow, Result};
+use bip39::Mnemonic;
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use scrypt::{scrypt, Params};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use zeroize::Zeroize;
+
+use crate::{
+    crypto::{
+        private_key::{PrivateKey, SEED_LENGTH},
+        public_key::PublicKey,
+    },
+    data::address::Address,
+};
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+type HmacSha256 = Hmac<Sha256>;
+
+pub const KEYSTORE_VERSION: u32 = 4;
+
+const CIPHER_ALGORITHM: &str = "aes-128-ctr";
+const KDF_ALGORITHM: &str = "scrypt";
+const KDF_N: u32 = 4096;
+const KDF_R: u32 = 8;
+const KDF_P: u32 = 1;
+const KDF_DKLEN: usize = 32;
+const SALT_LENGTH: usize = 32;
+const IV_LENGTH: usize = 16;
+
+// KeystoreKind tells what the keystore encrypts: a single secret key, or a whole mnemonic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum KeystoreKind {
+    // older keystores have no kind, they all hold a secret key
+    #[default]
+    SecretKey,
+    Mnemonic,
+}
+
+// Keystore is the standard MultiversX JSON wallet, as produced by the web wallet and mxpy
+// The secret is encrypted with AES-128-CTR, with a key derived from the password using scrypt
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Keystore {
+    pub version: u32,
+    #[serde(default)]
+    pub kind: KeystoreKind,
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bech32: Option<String>,
+    pub crypto: KeystoreCrypto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeystoreCrypto {
+    pub ciphertext: String,
+    pub cipherparams: CipherParams,
+    pub cipher: String,
+    pub kdf: String,
+    pub kdfparams: KdfParams,
+    pub mac: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CipherParams {
+    pub iv: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KdfParams {
+    pub dklen: usize,
+    pub salt: String,
+    pub n: u32,
+    pub r: u32,
+    pub p: u32,
+}
+
+// KeystoreSecret is the decrypted content of a keystore
+#[derive(Debug, Clone)]
+pub enum KeystoreSecret {
+    SecretKey(PrivateKey),
+    Mnemonic(Mnemonic),
+}
+
+impl Keystore {
+    pub fn from_file(file_path: &str) -> Result<Self> {
+        let contents = std::fs::read_to_string(file_path)?;
+        Self::from_json(&contents)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        let keystore: Keystore = serde_json::from_str(json)?;
+        if keystore.version != KEYSTORE_VERSION {
+            return Err(anyhow!(
+                "unsupported keystore version: {}",
+                keystore.version
+            ));
+        }
+        Ok(keystore)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    pub fn save_to_file(&self, file_path: &str) -> Result<()> {
+        std::fs::write(file_path, self.to_json())?;
+        Ok(())
+    }
+
+    // Encrypts the secret key, the same way the web wallet does: the 32 bytes seed, followed by the public key
+    pub fn encrypt_secret_key(private_key: &PrivateKey, password: &str) -> Self {
+        let public_key = PublicKey::from(private_key);
+        let address = Address::from(&public_key);
+
+        let mut keystore = Self::encrypt(KeystoreKind::SecretKey, private_key.as_bytes(), password);
+        keystore.address = Some(public_key.to_string());
+        keystore.bech32 = Some(address.to_string());
+        keystore
+    }
+
+    pub fn encrypt_mnemonic(mnemonic: &Mnemonic, password: &str) -> Self {
+        let mut phrase = mnemonic.to_string();
+        let keystore = Self::encrypt(KeystoreKind::Mnemonic, phrase.as_bytes(), password);
+        phrase.zeroize();
+        keystore
+    }
+
+    fn encrypt(kind: KeystoreKind, data: &[u8], password: &str) -> Self {
+        let mut rng = rand::thread_rng();
+        let mut salt = [0u8; SALT_LENGTH];
+        rng.fill_bytes(&mut salt);
+        let mut iv = [0u8; IV_LENGTH];
+        rng.fill_bytes(&mut iv);
+
+        let kdfparams = KdfParams {
+            dklen: KDF_DKLEN,
+            salt: hex::encode(salt),
+            n: KDF_N,
+            r: KDF_R,
+            p: KDF_P,
+        };
+        let mut derived_key = derive_key(password, &kdfparams).unwrap();
+
+        let mut ciphertext = data.to_vec();
+        let mut cipher = Aes128Ctr::new(derived_key[..16].into(), iv.as_slice().into());
+        cipher.apply_keystream(&mut ciphertext);
+        let mac = keystore_mac(&derived_key, &ciphertext)
+            .finalize()
+            .into_bytes();
+        derived_key.zeroize();
+
+        Keystore {
+            version: KEYSTORE_VERSION,
+            kind,
+            id: uuid::Uuid::new_v4().to_string(),
+            address: None,
+            bech32: None,
+            crypto: KeystoreCrypto {
+                ciphertext: hex::encode(ciphertext),
+                cipherparams: CipherParams {
+                    iv: hex::encode(iv),
+                },
+                cipher: CIPHER_ALGORITHM.to_string(),
+                kdf: KDF_ALGORITHM.to_string(),
+                kdfparams,
+                mac: hex::encode(mac),
+            },
+        }
+    }
+
+    // Decrypts the keystore, fails if the password is wrong
+    pub fn decrypt(&self, password: &str) -> Result<KeystoreSecret> {
+        if self.crypto.cipher != CIPHER_ALGORITHM {
+            return Err(anyhow!("unsupported cipher: {}", self.crypto.cipher));
+        }
+        if self.crypto.kdf != KDF_ALGORITHM {
+            return Err(anyhow!(
+                "unsupported key derivation function: {}",
+                self.crypto.kdf
+            ));
+        }
+
+        let mut derived_key = derive_key(password, &self.crypto.kdfparams)?;
+        let mut data = hex::decode(&self.crypto.ciphertext)?;
+        let iv = hex::decode(&self.crypto.cipherparams.iv)?;
+        if iv.len() != IV_LENGTH {
+            return Err(anyhow!("invalid keystore iv length"));
+        }
+
+        let expected_mac = hex::decode(&self.crypto.mac)?;
+        if keystore_mac(&derived_key, &data)
+            .verify_slice(&expected_mac)
+            .is_err()
+        {
+            derived_key.zeroize();
+            return Err(anyhow!(
+                "keystore MAC mismatch, the password is probably wrong"
+            ));
+        }
+
+        let mut cipher = Aes128Ctr::new(derived_key[..16].into(), iv.as_slice().into());
+        cipher.apply_keystream(&mut data);
+        derived_key.zeroize();
+
+        let secret = match self.kind {
+            KeystoreKind::SecretKey => {
+                if data.len() < SEED_LENGTH {
+                    data.zeroize();
+                    return Err(anyhow!("invalid keystore secret key length"));
+                }
+                PrivateKey::from_bytes(&data[..SEED_LENGTH]).map(KeystoreSecret::SecretKey)
+            },
+            KeystoreKind::Mnemonic => std::str::from_utf8(&data)
+                .map_err(|err| anyhow!(err))
+                .and_then(|phrase| Mnemonic::parse_normalized(phrase).map_err(|err| anyhow!(err)))
+                .map(KeystoreSecret::Mnemonic),
+        };
+        data.zeroize();
+        secret
+    }
+}
+
+fn derive_key(password: &str, kdfparams: &KdfParams) -> Result<Vec<u8>> {
+    if !kdfparams.n.is_power_of_two() || kdfparams.dklen != KDF_DKLEN {
+        return Err(anyhow!("invalid keystore scrypt parameters"));
+    }
+
+    let log_n = kdfparams.n.trailing_zeros() as u8;
+    let params = Params::new(log_n, kdfparams.r, kdfparams.p, kdfparams.dklen)
+        .map_err(|err| anyhow!("invalid keystore scrypt parameters: {err}"))?;
+    let salt = hex::decode(&kdfparams.salt)?;
+
+    let mut derived_key = vec![0u8; kdfparams.dklen];
+    scrypt(password.as_bytes(), &salt, &params, &mut derived_key)
+        .map_err(|err| anyhow!("scrypt failed: {err}"))?;
+    Ok(derived_key)
+}
+
+// The MAC is keyed with the second half of the derived key, the first half is the AES key
+fn keystore_mac(derived_key: &[u8], ciphertext: &[u8]) -> HmacSha256 {
+    let mut mac =
+        HmacSha256::new_from_slice(&derived_key[16..]).expect("HMAC can take key of any size");
+    mac.update(ciphertext);
+    mac
+}
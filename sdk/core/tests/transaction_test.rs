@@ -0,0 +1,174 @@
+use multiversx_sdk::{
+    crypto::public_key::PublicKey,
+    data::{
+        network_config::NetworkConfig,
+        transaction::{Transaction, TransactionOptions},
+    },
+    wallet::Wallet,
+};
+use sha3::{Digest, Keccak256};
+
+const BOB_PRIVATE_KEY: &str = "b8ca6f8203fb4b545a8e83c5384da033c415db155b53fb5b8eba7ff5a039d639";
+const CAROL_PRIVATE_KEY: &str = "e253a571ca153dc2aee845819f74bcc9773b0586edead15a94cb7235a5027436";
+
+fn alice() -> Wallet {
+    Wallet::from_pem_file("tests/alice.pem").unwrap()
+}
+
+fn network_config() -> NetworkConfig {
+    serde_json::from_str(
+        r#"{
+            "erd_chain_id": "D",
+            "erd_denomination": 18,
+            "erd_gas_per_data_byte": 1500,
+            "erd_latest_tag_software_version": "",
+            "erd_meta_consensus_group_size": 400,
+            "erd_min_gas_limit": 50000,
+            "erd_min_gas_price": 1000000000,
+            "erd_min_transaction_version": 1,
+            "erd_num_metachain_nodes": 400,
+            "erd_num_nodes_in_shard": 400,
+            "erd_num_shards_without_meta": 3,
+            "erd_round_duration": 6000,
+            "erd_shard_consensus_group_size": 63,
+            "erd_start_time": 0
+        }"#,
+    )
+    .unwrap()
+}
+
+fn transfer_tx(sender: &Wallet) -> Transaction {
+    Transaction {
+        nonce: 7,
+        value: "1000000000000000000".to_string(),
+        receiver: Wallet::from_private_key(BOB_PRIVATE_KEY).unwrap().address(),
+        sender: sender.address(),
+        gas_price: 1_000_000_000,
+        gas_limit: 50_000,
+        data: None,
+        signature: None,
+        chain_id: "D".to_string(),
+        version: 1,
+        options: TransactionOptions::default(),
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
+    }
+}
+
+fn verify(signer: &Wallet, tx: &Transaction, signature: &[u8]) -> bool {
+    PublicKey::from(&signer.address()).verify(&tx.serialize_for_signing(), signature)
+}
+
+#[test]
+fn test_options_serialization() {
+    let mut tx = transfer_tx(&alice());
+    let json = serde_json::to_value(&tx).unwrap();
+    assert!(json.get("options").is_none());
+    assert!(json.get("guardian").is_none());
+    assert!(json.get("relayer").is_none());
+
+    tx.version = 2;
+    tx.options = TransactionOptions::HASH_SIGNING | TransactionOptions::GUARDED;
+    let json = serde_json::to_value(&tx).unwrap();
+    assert_eq!(json["options"], 3);
+
+    let tx: Transaction = serde_json::from_value(json).unwrap();
+    assert!(tx.options.contains(TransactionOptions::HASH_SIGNING));
+    assert!(tx.options.contains(TransactionOptions::GUARDED));
+}
+
+#[test]
+fn test_sign_tx_with_hash_signing() {
+    let alice = alice();
+    let mut tx = transfer_tx(&alice);
+    tx.version = 2;
+    tx.options = TransactionOptions::HASH_SIGNING;
+
+    let plain_json = serde_json::to_vec(&tx).unwrap();
+    assert_eq!(
+        tx.serialize_for_signing(),
+        Keccak256::digest(&plain_json).to_vec()
+    );
+
+    let signature = alice.sign_tx(&tx);
+    assert!(verify(&alice, &tx, &signature));
+
+    // the sender signature is not part of what gets signed
+    tx.signature = Some(hex::encode(signature));
+    assert!(verify(&alice, &tx, &signature));
+}
+
+#[test]
+fn test_guarded_tx() {
+    let alice = alice();
+    let guardian = Wallet::from_private_key(CAROL_PRIVATE_KEY).unwrap();
+
+    let mut tx = transfer_tx(&alice);
+    tx.set_guardian(guardian.address());
+    assert_eq!(tx.version, 2);
+    assert!(tx.options.contains(TransactionOptions::GUARDED));
+    assert_eq!(tx.gas_limit, 100_000);
+
+    // the extra gas is only added once
+    tx.set_guardian(guardian.address());
+    assert_eq!(tx.gas_limit, 100_000);
+
+    let signature = alice.sign_tx(&tx);
+    tx.signature = Some(hex::encode(signature));
+    let guardian_signature = guardian.guardian_sign_tx(&tx).unwrap();
+    tx.guardian_signature = Some(hex::encode(guardian_signature));
+
+    // both sign the same bytes, which include the guardian address, but none of the signatures
+    assert_eq!(signature, alice.sign_tx(&tx));
+    assert!(verify(&alice, &tx, &signature));
+    assert!(verify(&guardian, &tx, &guardian_signature));
+
+    // same field order as the node, which matters for the signatures
+    assert_eq!(
+        serde_json::to_string(&tx).unwrap(),
+        format!(
+            r#"{{"nonce":7,"value":"1000000000000000000","receiver":"{}","sender":"{}","gasPrice":1000000000,"gasLimit":100000,"signature":"{}","chainID":"D","version":2,"options":2,"guardian":"{}","guardianSignature":"{}"}}"#,
+            tx.receiver.to_string(),
+            alice.address().to_string(),
+            hex::encode(signature),
+            guardian.address().to_string(),
+            hex::encode(guardian_signature),
+        )
+    );
+
+    assert!(alice.guardian_sign_tx(&tx).is_err());
+}
+
+#[test]
+fn test_relayed_tx() {
+    let alice = alice();
+    let relayer = Wallet::from_private_key(CAROL_PRIVATE_KEY).unwrap();
+
+    let mut tx = transfer_tx(&alice);
+    assert!(relayer.relayer_sign_tx(&tx).is_err());
+
+    tx.set_relayer(relayer.address(), &network_config());
+    assert_eq!(tx.version, 2);
+    assert_eq!(tx.gas_limit, 100_000);
+
+    tx.signature = Some(hex::encode(alice.sign_tx(&tx)));
+    let relayer_signature = relayer.relayer_sign_tx(&tx).unwrap();
+    tx.relayer_signature = Some(hex::encode(relayer_signature));
+    assert!(verify(&relayer, &tx, &relayer_signature));
+
+    let json = serde_json::to_value(&tx).unwrap();
+    assert_eq!(json["relayer"], relayer.address().to_string());
+    assert_eq!(json["relayerSignature"], hex::encode(relayer_signature));
+    assert!(json.get("options").is_none());
+
+    let decoded: Transaction = serde_json::from_value(json).unwrap();
+    assert_eq!(
+        decoded.relayer.unwrap().to_bytes(),
+        relayer.address().to_bytes()
+    );
+    assert_eq!(decoded.relayer_signature, tx.relayer_signature);
+
+    assert!(alice.relayer_sign_tx(&tx).is_err());
+}
@@ -1,6 +1,19 @@
 use bip39::Mnemonic;
+use sha3::{Digest, Keccak256};
 
-use multiversx_sdk::{crypto::public_key::PublicKey, data::address::Address, wallet::Wallet};
+use multiversx_sdk::{
+    crypto::public_key::{signed_message_hash, PublicKey},
+    data::address::Address,
+    wallet::{
+        keystore::{Keystore, KeystoreKind, KeystoreSecret},
+        Wallet,
+    },
+};
+
+const ALICE_ADDRESS: &str = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+
+/// The signature of the message "hello" by alice, as computed by mxpy and the sdk-js.
+const ALICE_HELLO_SIGNATURE: &str = "561bc58f1dc6b10de208b2d2c22c9a474ea5e8cabb59c3d3ce06bbda21cc46454aa71a85d5a60442bd7784effa2e062fcb8fb421c521f898abf7f5ec165e5d0f";
 
 #[test]
 fn test_private_key_from_mnemonic() {
@@ -43,8 +56,81 @@ fn test_private_key_from_mnemonic() {
 fn test_load_from_pem() {
     let wallet = Wallet::from_pem_file("tests/alice.pem").unwrap();
     let addr = wallet.address();
+    assert_eq!(addr.to_bech32_string().unwrap(), ALICE_ADDRESS);
+}
+
+#[test]
+fn test_load_from_keystore() {
+    let wallet = Wallet::from_keystore_file("tests/alice.json", "password").unwrap();
+    assert_eq!(wallet.address().to_bech32_string().unwrap(), ALICE_ADDRESS);
+
+    let err = Wallet::from_keystore_file("tests/alice.json", "wrong password").unwrap_err();
+    assert!(err.to_string().contains("MAC mismatch"));
+}
+
+#[test]
+fn test_secret_key_keystore_roundtrip() {
+    let wallet = Wallet::from_pem_file("tests/alice.pem").unwrap();
+    let keystore = wallet.to_keystore("secret");
+    assert_eq!(keystore.kind, KeystoreKind::SecretKey);
+    assert_eq!(keystore.bech32.as_deref(), Some(ALICE_ADDRESS));
     assert_eq!(
-        addr.to_bech32_string().unwrap(),
-        "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th"
+        keystore.address.as_deref(),
+        Some("0139472eff6886771a982f3083da5d421f24c29181e63888228dc81ca60d69e1")
     );
+
+    let json = keystore.to_json();
+    let reloaded = Wallet::from_keystore_file_contents(json, "secret").unwrap();
+    assert_eq!(
+        reloaded.address().to_bech32_string().unwrap(),
+        ALICE_ADDRESS
+    );
+}
+
+#[test]
+fn test_mnemonic_keystore_roundtrip() {
+    let mnemonic: Mnemonic = Mnemonic::parse_normalized("acid twice post genre topic observe valid viable gesture fortune funny dawn around blood enemy page update reduce decline van bundle zebra rookie real").unwrap();
+    let keystore = Keystore::encrypt_mnemonic(&mnemonic, "secret");
+    assert_eq!(keystore.kind, KeystoreKind::Mnemonic);
+    assert!(keystore.bech32.is_none());
+
+    let keystore = Keystore::from_json(&keystore.to_json()).unwrap();
+    match keystore.decrypt("secret").unwrap() {
+        KeystoreSecret::Mnemonic(decrypted) => assert_eq!(decrypted, mnemonic),
+        KeystoreSecret::SecretKey(_) => panic!("expected a mnemonic"),
+    }
+
+    // the wallet uses the first address of the mnemonic
+    let wallet = Wallet::from_keystore_file_contents(keystore.to_json(), "secret").unwrap();
+    assert_eq!(
+        wallet.address().to_bech32_string().unwrap(),
+        "erd1mlh7q3fcgrjeq0et65vaaxcw6m5ky8jhu296pdxpk9g32zga6uhsemxx2a"
+    );
+}
+
+#[test]
+fn test_sign_message() {
+    let wallet = Wallet::from_pem_file("tests/alice.pem").unwrap();
+    let message = b"hello";
+    let expected_hash: [u8; 32] = Keccak256::digest(b"\x17Elrond Signed Message:\n5hello").into();
+    assert_eq!(signed_message_hash(message), expected_hash);
+
+    let signature = wallet.sign_message(message);
+    assert_eq!(hex::encode(signature), ALICE_HELLO_SIGNATURE);
+    assert!(wallet.verify_message(message, &signature));
+    assert!(!wallet.verify_message(b"hello!", &signature));
+
+    // the verifier only needs the address of the signer
+    let address = Address::from_bech32_string(ALICE_ADDRESS).unwrap();
+    let public_key = PublicKey::from(&address);
+    assert!(public_key.verify_message(message, &signature));
+
+    let mut bad_signature = signature;
+    bad_signature[0] ^= 1;
+    assert!(!public_key.verify_message(message, &bad_signature));
+    assert!(!public_key.verify_message(message, &signature[..32]));
+
+    // the signature is over the message hash
+    assert!(public_key.verify(&signed_message_hash(message), &signature));
+    assert!(!public_key.verify(message, &signature));
 }
@@ -24,4 +24,12 @@ pub struct BlockInfoRaw {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub block_random_seed: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_root_hash: Option<ValueSubTree>,
 }
@@ -0,0 +1,246 @@
+#!/usr/bin/env python3
+"""
+Generates `vm/src/wasm_executor/wasmi_imports.rs`, which registers all VM hooks as wasmi imports.
+
+The hooks are taken from:
+- the `VMHooks` trait of the multiversx-chain-vm-executor crate;
+- the public methods of `VMHooksDispatcher` that extend it (see `vh_dispatcher.rs`).
+
+Usage, from the repository root:
+
+    python3 tools/wasmi-imports-generator/generate_wasmi_imports.py [path/to/executor/src/vm_hooks.rs]
+
+Without an argument, the executor sources are located via `cargo metadata`.
+The output is formatted with `rustfmt`.
+"""
+
+import json
+import re
+import subprocess
+import sys
+from pathlib import Path
+from typing import List, NamedTuple, Optional
+
+REPO_ROOT = Path(__file__).resolve().parents[2]
+DISPATCHER_PATH = REPO_ROOT / "vm/src/vm_hooks/vh_dispatcher.rs"
+OUTPUT_PATH = REPO_ROOT / "vm/src/wasm_executor/wasmi_imports.rs"
+EXECUTOR_PACKAGE = "multiversx-chain-vm-executor"
+
+# Words that are not simply capitalized in the import names.
+IMPORT_NAME_WORDS = {
+    "esdt": "ESDT",
+    "nft": "NFT",
+    "uri": "URI",
+    "sc": "SC",
+    "bls": "BLS",
+    "ec": "EC",
+    "tdiv": "TDiv",
+    "tmod": "TMod",
+    "ediv": "EDiv",
+    "emod": "EMod",
+}
+FIRST_IMPORT_NAME_WORDS = {
+    "mbuffer": "mBuffer",
+}
+
+HOOK_PATTERN = re.compile(
+    r"fn\s+(\w+)\s*\(\s*&self\s*,?(.*?)\)\s*(?:->\s*(\w+))?\s*[;{]", re.DOTALL)
+# The extension hooks are the public methods of the `impl VMHooksDispatcher` block documented like this.
+EXTENSION_HOOKS_DOC = "/// Hooks that the debugger offers on top of the executor `VMHooks` interface."
+DISPATCHER_IMPL_PATTERN = re.compile(
+    r"^impl VMHooksDispatcher \{\n(.*?)^\}", re.DOTALL | re.MULTILINE)
+PUB_FN_PATTERN = re.compile(r"^    pub fn .*?[;{]", re.DOTALL | re.MULTILINE)
+VM_HOOKS_TRAIT_PATTERN = re.compile(
+    r"^pub trait VMHooks\b.*?\{\n(.*?)^\}", re.DOTALL | re.MULTILINE)
+
+# Lengths that are not named after the pointer they belong to, with the candidate pointers, in order.
+LENGTH_SUFFIXES = ("_length", "_len")
+POINTER_SUFFIXES = ("", "_offset", "_pointer", "_ptr")
+UNNAMED_LENGTH_POINTERS = ("code_offset", "data_offset", "pointer")
+
+
+class Param(NamedTuple):
+    name: str
+    rust_type: str
+
+
+class Hook(NamedTuple):
+    name: str
+    params: List[Param]
+    return_type: Optional[str]
+
+
+def parse_hooks(source: str) -> List[Hook]:
+    hooks = []
+    for match in HOOK_PATTERN.finditer(source):
+        name, params_str, return_type = match.groups()
+        params = []
+        for param_str in params_str.split(","):
+            param_str = param_str.strip()
+            if param_str:
+                param_name, param_type = param_str.split(":")
+                params.append(Param(param_name.strip(), param_type.strip()))
+        hooks.append(Hook(name, params, return_type))
+    return hooks
+
+
+def parse_vm_hooks_trait(source: str) -> List[Hook]:
+    trait_block = VM_HOOKS_TRAIT_PATTERN.search(source)
+    if trait_block is None:
+        raise Exception("VMHooks trait not found in the executor sources")
+    return parse_hooks(trait_block.group(1))
+
+
+def parse_dispatcher_extension_hooks(source: str) -> List[Hook]:
+    doc_start = source.find(EXTENSION_HOOKS_DOC)
+    if doc_start < 0:
+        raise Exception("extension hooks not found in the dispatcher")
+    impl_block = DISPATCHER_IMPL_PATTERN.search(source, doc_start).group(1)
+    hooks = []
+    for pub_fn in PUB_FN_PATTERN.findall(impl_block):
+        hooks.extend(parse_hooks(pub_fn))
+    return hooks
+
+
+def find_executor_vm_hooks_path() -> Path:
+    metadata = json.loads(subprocess.check_output(
+        ["cargo", "metadata", "--format-version", "1"], cwd=REPO_ROOT))
+    for package in metadata["packages"]:
+        if package["name"] == EXECUTOR_PACKAGE:
+            return Path(package["manifest_path"]).parent / "src/vm_hooks.rs"
+    raise Exception(f"{EXECUTOR_PACKAGE} not found in the cargo metadata")
+
+
+def import_name(hook_name: str) -> str:
+    words = hook_name.split("_")
+    first = FIRST_IMPORT_NAME_WORDS.get(words[0], words[0])
+    rest = [IMPORT_NAME_WORDS.get(word, word.capitalize()) for word in words[1:]]
+    return first + "".join(rest)
+
+
+def wasm_type(rust_type: str) -> str:
+    if rust_type in ("MemPtr", "MemLength"):
+        return "i32"
+    return rust_type
+
+
+def length_pointer_name(length: Param, pointer_names: List[str]) -> Optional[str]:
+    """The pointer that a length refers to, either by name (`key_offset`/`key_length`) or by convention."""
+    for length_suffix in LENGTH_SUFFIXES:
+        if length.name.endswith(length_suffix):
+            prefix = length.name[: -len(length_suffix)]
+            candidates = [prefix + pointer_suffix for pointer_suffix in POINTER_SUFFIXES]
+            break
+    else:
+        candidates = list(UNNAMED_LENGTH_POINTERS)
+    return next((name for name in candidates if name in pointer_names), None)
+
+
+def memory_conversions(hook: Hook) -> List[str]:
+    """Pointers with a length are checked together, the others are checked by the VM hooks on access."""
+    pointer_names = [param.name for param in hook.params if param.rust_type == "MemPtr"]
+    lengths_by_pointer = {}
+    for param in hook.params:
+        if param.rust_type == "MemLength":
+            pointer_name = length_pointer_name(param, pointer_names)
+            if pointer_name is not None and pointer_name not in lengths_by_pointer:
+                lengths_by_pointer[pointer_name] = param.name
+
+    paired_lengths = set(lengths_by_pointer.values())
+    lines = []
+    for param in hook.params:
+        if param.rust_type == "MemPtr":
+            length_name = lengths_by_pointer.get(param.name)
+            if length_name is not None:
+                lines.append(
+                    f"let ({param.name}, {length_name}) = memory.slice({param.name}, {length_name})?;")
+            else:
+                lines.append(f"let {param.name} = memory.ptr({param.name})?;")
+        elif param.rust_type == "MemLength" and param.name not in paired_lengths:
+            lines.append(f"let {param.name} = memory.length({param.name})?;")
+    return lines
+
+
+def generate_hook(hook: Hook) -> str:
+    uses_memory = any(param.rust_type in ("MemPtr", "MemLength") for param in hook.params)
+    caller = "mut caller" if uses_memory else "caller"
+    closure_params = "".join(
+        f", {param.name}: {wasm_type(param.rust_type)}" for param in hook.params)
+    return_type = hook.return_type or "()"
+    call = f"vm_hooks.{hook.name}({', '.join(param.name for param in hook.params)})"
+    if hook.return_type:
+        call_lines = [f"Ok({call})"]
+    else:
+        call_lines = [f"{call};", "Ok(())"]
+
+    if uses_memory:
+        body = "\n".join(
+            ["with_vm_hooks(&mut caller, |vm_hooks, memory| {"]
+            + memory_conversions(hook)
+            + call_lines
+            + ["})"])
+    else:
+        body = "\n".join(["let vm_hooks = &caller.data().vm_hooks;"] + call_lines)
+
+    return f"""    linker.func_wrap(
+        "env",
+        "{import_name(hook.name)}",
+        |{caller}: Caller<'_, WasmiHostState>{closure_params}| -> Result<{return_type}, Error> {{
+            {body}
+        }},
+    )?;
+"""
+
+
+HEADER = """// Code generated by tools/wasmi-imports-generator. DO NOT EDIT.
+//
+// Contains the `VMHooks` trait of multiversx-chain-vm-executor, and the hooks that `VMHooksDispatcher` adds to it,
+// with the import names that contracts use for the VM hooks.
+
+use multiversx_chain_vm_executor::VMHooks;
+use wasmi::{Caller, Error, Linker};
+
+use crate::vm_hooks::VMHooksDispatcher;
+
+use super::{wasmi_instance::WasmiHostState, wasmi_memory::WasmiMemoryView};
+
+/// Gives a VM hook access to both the dispatcher and the instance memory.
+///
+/// The dispatcher gets the current memory bounds, to check the regions that the hook accesses.
+fn with_vm_hooks<R, F>(caller: &mut Caller<'_, WasmiHostState>, f: F) -> Result<R, Error>
+where
+    F: FnOnce(&VMHooksDispatcher, WasmiMemoryView) -> Result<R, Error>,
+{
+    let memory = caller
+        .data()
+        .memory
+        .ok_or_else(|| Error::new("contract does not export its memory"))?;
+    let (data, host_state) = memory.data_and_store_mut(caller);
+    let memory_view = WasmiMemoryView::new(data);
+    let (start, end) = memory_view.bounds();
+    host_state.vm_hooks.set_memory_bounds(start, end);
+    f(&host_state.vm_hooks, memory_view)
+}
+
+/// Registers all VM hooks as functions of the `env` module.
+pub(super) fn add_vm_hooks_imports(linker: &mut Linker<WasmiHostState>) -> Result<(), Error> {
+"""
+
+FOOTER = """
+    Ok(())
+}
+"""
+
+
+def main():
+    vm_hooks_path = Path(sys.argv[1]) if len(sys.argv) > 1 else find_executor_vm_hooks_path()
+    hooks = parse_vm_hooks_trait(vm_hooks_path.read_text())
+    hooks += parse_dispatcher_extension_hooks(DISPATCHER_PATH.read_text())
+
+    OUTPUT_PATH.write_text(HEADER + "".join(generate_hook(hook) for hook in hooks) + FOOTER)
+    subprocess.check_call(["rustfmt", "--edition", "2021", str(OUTPUT_PATH)])
+    print(f"{len(hooks)} VM hooks written to {OUTPUT_PATH.relative_to(REPO_ROOT)}")
+
+
+if __name__ == "__main__":
+    main()
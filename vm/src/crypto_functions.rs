@@ -1,9 +1,19 @@
+mod crypto_bls;
+mod crypto_elliptic_curve;
+mod crypto_secp256k1;
+
 use ed25519_dalek::*;
+use ripemd::Ripemd160;
 use sha2::Sha256;
 use sha3::{Digest, Keccak256};
 
+pub use crypto_bls::{verify_bls, BLS_PUBLIC_KEY_LEN, BLS_SIGNATURE_LEN};
+pub use crypto_elliptic_curve::EllipticCurve;
+pub use crypto_secp256k1::{encode_secp256k1_der_signature, verify_secp256k1, MessageHashType};
+
 pub const SHA256_RESULT_LEN: usize = 32;
 pub const KECCAK256_RESULT_LEN: usize = 32;
+pub const RIPEMD160_RESULT_LEN: usize = 20;
 
 pub fn sha256(data: &[u8]) -> [u8; SHA256_RESULT_LEN] {
     let mut hasher = Sha256::new();
@@ -17,6 +27,12 @@ pub fn keccak256(data: &[u8]) -> [u8; KECCAK256_RESULT_LEN] {
     hasher.finalize().into()
 }
 
+pub fn ripemd160(data: &[u8]) -> [u8; RIPEMD160_RESULT_LEN] {
+    let mut hasher = Ripemd160::new();
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
 pub fn verify_ed25519(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
     let public = PublicKey::from_bytes(key);
     if public.is_err() {
@@ -0,0 +1,177 @@
+//! BLS signature verification, compatible with the signatures produced by the node.
+//!
+//! The node uses the herumi BLS library over BLS12-381, in its original (non-Ethereum) mode:
+//! - public keys are points in G2 (96 bytes), signatures are points in G1 (48 bytes);
+//! - points are serialized as the little endian x coordinate, with the y sign flag in the most significant bit;
+//! - messages are mapped to G1 with the Shallue-van de Woestijne encoding applied to their SHA-512 hash;
+//! - the G2 generator is not the standard one, but the image of 1 through the same map, with the cofactor cleared.
+
+use bls12_381::{pairing, G1Affine, G1Projective, G2Affine, Scalar};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use sha2::{Digest, Sha512};
+
+pub const BLS_PUBLIC_KEY_LEN: usize = 96;
+pub const BLS_SIGNATURE_LEN: usize = 48;
+
+const FP_BYTE_LEN: usize = 48;
+
+/// The BLS12-381 base field modulus, big endian.
+const FIELD_MODULUS_HEX: &[u8] = b"1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab";
+
+/// The G1 cofactor, `(z - 1)^2 / 3`.
+const G1_COFACTOR: [u64; 4] = [0x8c00aaab0000aaab, 0x396c8c005555e156, 0, 0];
+
+/// The herumi G2 generator, in standard compressed form.
+const G2_GENERATOR_COMPRESSED: [u8; 96] = hex_literal::hex!(
+    "971df7a5080f908a16c2658ea90164e28c924c3f0e6655f6d82adca6bfbdfb5f9efca82c1609676fa15cd30396f1a4b3"
+    "0f3d011af81acf00140aab3c122c61bbdf0628db81c37664bdfc828163ce074ee33a1a5ce5488556603bc5d8d9f21ecc"
+);
+
+/// Verifies a single BLS signature, or an aggregated signature against the corresponding aggregated key.
+pub fn verify_bls(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let Some(public_key) = decode_g2(key) else {
+        return false;
+    };
+    let Some(signature) = decode_g1(signature) else {
+        return false;
+    };
+    let Some(hashed_message) = hash_to_g1(message) else {
+        return false;
+    };
+
+    let generator = Option::<G2Affine>::from(G2Affine::from_compressed(&G2_GENERATOR_COMPRESSED))
+        .expect("invalid BLS G2 generator");
+    pairing(&signature, &generator) == pairing(&hashed_message, &public_key)
+}
+
+/// Converts a herumi-serialized G1 point to the standard (ZCash) compressed form and decodes it.
+fn decode_g1(bytes: &[u8]) -> Option<G1Affine> {
+    if bytes.len() != BLS_SIGNATURE_LEN {
+        return None;
+    }
+    let mut compressed = [0u8; BLS_SIGNATURE_LEN];
+    compressed.copy_from_slice(bytes);
+    compressed.reverse();
+    if !set_compressed_flags(&mut compressed) {
+        return None;
+    }
+    Option::from(G1Affine::from_compressed(&compressed))
+}
+
+/// Converts a herumi-serialized G2 point to the standard (ZCash) compressed form and decodes it.
+///
+/// Herumi serializes the coordinate as `c0 || c1`, the standard form is `c1 || c0`.
+fn decode_g2(bytes: &[u8]) -> Option<G2Affine> {
+    if bytes.len() != BLS_PUBLIC_KEY_LEN {
+        return None;
+    }
+    let mut compressed = [0u8; BLS_PUBLIC_KEY_LEN];
+    for (dest, src) in compressed.iter_mut().zip(bytes.iter().rev()) {
+        *dest = *src;
+    }
+    if !set_compressed_flags(&mut compressed) {
+        return None;
+    }
+    Option::from(G2Affine::from_compressed(&compressed))
+}
+
+/// Moves the herumi sign flag to the position expected by the standard compressed form,
+/// also setting the compression flag.
+///
+/// Returns false if the unused top bits are not zero, since the coordinate cannot be valid.
+fn set_compressed_flags(big_endian: &mut [u8]) -> bool {
+    if big_endian[0] & 0x60 != 0 {
+        return false;
+    }
+    let sign = big_endian[0] & 0x80 != 0;
+    big_endian[0] &= 0x1f;
+    big_endian[0] |= 0x80;
+    if sign {
+        big_endian[0] |= 0x20;
+    }
+    true
+}
+
+/// Maps a message to a point in G1, the way the node does it.
+fn hash_to_g1(message: &[u8]) -> Option<G1Affine> {
+    let digest = Sha512::digest(message);
+    let mut t = BigUint::from_bytes_le(&digest[..FP_BYTE_LEN]);
+    t %= BigUint::one() << (field_modulus().bits() - 1);
+
+    let (x, y) = map_to_curve_sw(&t)?;
+    let mut uncompressed = [0u8; 2 * FP_BYTE_LEN];
+    uncompressed[..FP_BYTE_LEN].copy_from_slice(&fp_to_bytes(&x));
+    uncompressed[FP_BYTE_LEN..].copy_from_slice(&fp_to_bytes(&y));
+    let point: G1Affine = Option::from(G1Affine::from_uncompressed_unchecked(&uncompressed))?;
+    if !bool::from(point.is_on_curve()) {
+        return None;
+    }
+
+    Some((G1Projective::from(point) * Scalar::from_raw(G1_COFACTOR)).into())
+}
+
+/// Shallue-van de Woestijne encoding onto `y^2 = x^3 + 4`, as implemented in mcl.
+fn map_to_curve_sw(t: &BigUint) -> Option<(BigUint, BigUint)> {
+    let p = field_modulus();
+    if t.is_zero() {
+        return None;
+    }
+    let negative = fp_pow(t, &((&p - 1u32) >> 1)) == &p - 1u32;
+
+    let c1 = fp_sqrt(&fp_neg(&BigUint::from(3u32)))?;
+    let c2 = (&c1 + &p - 1u32) * fp_inv(&BigUint::from(2u32)) % &p;
+
+    let w = (t * t + 5u32) % &p;
+    if w.is_zero() {
+        return None;
+    }
+    let w = fp_inv(&w) * &c1 % &p * t % &p;
+
+    let x1 = (fp_neg(&(t * &w % &p)) + &c2) % &p;
+    let x2 = (fp_neg(&x1) + &p - 1u32) % &p;
+    let x3 = (fp_inv(&(&w * &w % &p)) + 1u32) % &p;
+    for x in [x1, x2, x3] {
+        let y_squared = (&x * &x % &p * &x + 4u32) % &p;
+        if let Some(y) = fp_sqrt(&y_squared) {
+            let y = if negative { fp_neg(&y) } else { y };
+            return Some((x, y));
+        }
+    }
+    None
+}
+
+fn field_modulus() -> BigUint {
+    BigUint::parse_bytes(FIELD_MODULUS_HEX, 16).unwrap()
+}
+
+fn fp_pow(base: &BigUint, exponent: &BigUint) -> BigUint {
+    base.modpow(exponent, &field_modulus())
+}
+
+fn fp_inv(value: &BigUint) -> BigUint {
+    fp_pow(value, &(field_modulus() - 2u32))
+}
+
+fn fp_neg(value: &BigUint) -> BigUint {
+    let p = field_modulus();
+    (&p - value % &p) % &p
+}
+
+/// Since `p = 3 mod 4`, the square root is `a^((p+1)/4)`, if it exists.
+fn fp_sqrt(value: &BigUint) -> Option<BigUint> {
+    let p = field_modulus();
+    let root = fp_pow(value, &((&p + 1u32) >> 2));
+    if &root * &root % &p == value % &p {
+        Some(root)
+    } else {
+        None
+    }
+}
+
+fn fp_to_bytes(value: &BigUint) -> [u8; FP_BYTE_LEN] {
+    let bytes = value.to_bytes_be();
+    let mut result = [0u8; FP_BYTE_LEN];
+    result[FP_BYTE_LEN - bytes.len()..].copy_from_slice(&bytes);
+    result
+}
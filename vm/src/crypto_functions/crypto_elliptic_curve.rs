@@ -0,0 +1,300 @@
+use num_bigint::{BigInt, Sign};
+use num_traits::{One, Zero};
+
+/// Parameters of a short Weierstrass curve `y² = x³ - 3x + b` over a prime field,
+/// the form shared by all the NIST P-curves.
+///
+/// Mirrors the generic curve implementation of the Go VM: the point at infinity is encoded as (0, 0),
+/// and all coordinates are plain big integers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EllipticCurve {
+    /// The order of the underlying field (P).
+    pub field_order: BigInt,
+    /// The order of the base point (N).
+    pub base_point_order: BigInt,
+    /// The constant of the curve equation (B).
+    pub eq_constant: BigInt,
+    pub x_base_point: BigInt,
+    pub y_base_point: BigInt,
+    pub size_in_bits: u32,
+}
+
+fn hex_int(hex: &str) -> BigInt {
+    BigInt::parse_bytes(hex.as_bytes(), 16).expect("invalid curve constant")
+}
+
+impl EllipticCurve {
+    fn from_hex_params(p: &str, n: &str, b: &str, gx: &str, gy: &str, size_in_bits: u32) -> Self {
+        EllipticCurve {
+            field_order: hex_int(p),
+            base_point_order: hex_int(n),
+            eq_constant: hex_int(b),
+            x_base_point: hex_int(gx),
+            y_base_point: hex_int(gy),
+            size_in_bits,
+        }
+    }
+
+    pub fn p224() -> Self {
+        Self::from_hex_params(
+            "ffffffffffffffffffffffffffffffff000000000000000000000001",
+            "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d",
+            "b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4",
+            "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+            "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+            224,
+        )
+    }
+
+    pub fn p256() -> Self {
+        Self::from_hex_params(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+            "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+            "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+            "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+            256,
+        )
+    }
+
+    pub fn p384() -> Self {
+        Self::from_hex_params(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+            "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+            "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+            "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+            384,
+        )
+    }
+
+    pub fn p521() -> Self {
+        Self::from_hex_params(
+            "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+            "0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00",
+            "00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+            "011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+            521,
+        )
+    }
+
+    /// Looks up one of the supported curves by name (`p224`, `p256`, `p384` or `p521`).
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        match name {
+            b"p224" => Some(Self::p224()),
+            b"p256" => Some(Self::p256()),
+            b"p384" => Some(Self::p384()),
+            b"p521" => Some(Self::p521()),
+            _ => None,
+        }
+    }
+
+    /// Length of a single coordinate, in bytes.
+    pub fn byte_length(&self) -> usize {
+        (self.size_in_bits as usize).div_ceil(8)
+    }
+
+    pub fn private_key_byte_length(&self) -> usize {
+        (self.base_point_order.bits() as usize).div_ceil(8)
+    }
+
+    fn reduce(&self, value: BigInt) -> BigInt {
+        let remainder = value % &self.field_order;
+        if remainder.sign() == Sign::Minus {
+            remainder + &self.field_order
+        } else {
+            remainder
+        }
+    }
+
+    fn inverse(&self, value: &BigInt) -> BigInt {
+        let exponent = &self.field_order - 2u32;
+        value.modpow(&exponent, &self.field_order)
+    }
+
+    /// Computes `x³ - 3x + b`.
+    fn polynomial(&self, x: &BigInt) -> BigInt {
+        let x3 = x * x * x;
+        let three_x = x * 3u32;
+        self.reduce(x3 - three_x + &self.eq_constant)
+    }
+
+    fn is_infinity(x: &BigInt, y: &BigInt) -> bool {
+        x.is_zero() && y.is_zero()
+    }
+
+    fn in_field(&self, value: &BigInt) -> bool {
+        value.sign() != Sign::Minus && value < &self.field_order
+    }
+
+    pub fn is_on_curve(&self, x: &BigInt, y: &BigInt) -> bool {
+        if !self.in_field(x) || !self.in_field(y) {
+            return false;
+        }
+        self.reduce(y * y) == self.polynomial(x)
+    }
+
+    pub fn add(&self, x1: &BigInt, y1: &BigInt, x2: &BigInt, y2: &BigInt) -> (BigInt, BigInt) {
+        if Self::is_infinity(x1, y1) {
+            return (x2.clone(), y2.clone());
+        }
+        if Self::is_infinity(x2, y2) {
+            return (x1.clone(), y1.clone());
+        }
+        if x1 == x2 {
+            if y1 == y2 {
+                return self.double(x1, y1);
+            }
+            return (BigInt::zero(), BigInt::zero());
+        }
+
+        let slope = self.reduce((y2 - y1) * self.inverse(&self.reduce(x2 - x1)));
+        let x3 = self.reduce(&slope * &slope - x1 - x2);
+        let y3 = self.reduce(slope * (x1 - &x3) - y1);
+        (x3, y3)
+    }
+
+    pub fn double(&self, x: &BigInt, y: &BigInt) -> (BigInt, BigInt) {
+        if y.is_zero() {
+            return (BigInt::zero(), BigInt::zero());
+        }
+
+        let numerator = self.reduce(x * x * 3u32 - 3u32);
+        let slope = self.reduce(numerator * self.inverse(&self.reduce(y * 2u32)));
+        let x3 = self.reduce(&slope * &slope - x * 2u32);
+        let y3 = self.reduce(slope * (x - &x3) - y);
+        (x3, y3)
+    }
+
+    /// Multiplies a point by a scalar, given as big endian bytes.
+    pub fn scalar_mult(&self, x: &BigInt, y: &BigInt, scalar: &[u8]) -> (BigInt, BigInt) {
+        let mut result = (BigInt::zero(), BigInt::zero());
+        for byte in scalar {
+            for bit in (0..8).rev() {
+                result = self.double(&result.0, &result.1);
+                if (byte >> bit) & 1 == 1 {
+                    result = self.add(&result.0, &result.1, x, y);
+                }
+            }
+        }
+        result
+    }
+
+    pub fn scalar_base_mult(&self, scalar: &[u8]) -> (BigInt, BigInt) {
+        self.scalar_mult(&self.x_base_point, &self.y_base_point, scalar)
+    }
+
+    fn coordinate_bytes(&self, value: &BigInt) -> Vec<u8> {
+        let (_, bytes) = value.to_bytes_be();
+        let mut result = vec![0u8; self.byte_length()];
+        let start = result.len() - bytes.len();
+        result[start..].copy_from_slice(&bytes);
+        result
+    }
+
+    /// Uncompressed SEC 1 encoding: `0x04 || x || y`.
+    pub fn marshal(&self, x: &BigInt, y: &BigInt) -> Vec<u8> {
+        let mut result = Vec::with_capacity(1 + 2 * self.byte_length());
+        result.push(4u8);
+        result.extend_from_slice(&self.coordinate_bytes(x));
+        result.extend_from_slice(&self.coordinate_bytes(y));
+        result
+    }
+
+    /// Compressed SEC 1 encoding: `0x02 | (y & 1) || x`.
+    pub fn marshal_compressed(&self, x: &BigInt, y: &BigInt) -> Vec<u8> {
+        let mut result = Vec::with_capacity(1 + self.byte_length());
+        result.push(2u8 | if y.bit(0) { 1 } else { 0 });
+        result.extend_from_slice(&self.coordinate_bytes(x));
+        result
+    }
+
+    /// Decodes an uncompressed point.
+    ///
+    /// Returns `None` if the encoding is malformed or if the point is not on the curve.
+    pub fn unmarshal(&self, data: &[u8]) -> Option<(BigInt, BigInt)> {
+        let byte_len = self.byte_length();
+        if data.len() != 1 + 2 * byte_len || data[0] != 4 {
+            return None;
+        }
+
+        let x = BigInt::from_bytes_be(Sign::Plus, &data[1..1 + byte_len]);
+        let y = BigInt::from_bytes_be(Sign::Plus, &data[1 + byte_len..]);
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    /// Decodes a compressed point.
+    ///
+    /// Returns `None` if the encoding is malformed or if there is no curve point with the given x.
+    pub fn unmarshal_compressed(&self, data: &[u8]) -> Option<(BigInt, BigInt)> {
+        let byte_len = self.byte_length();
+        if data.len() != 1 + byte_len || (data[0] != 2 && data[0] != 3) {
+            return None;
+        }
+
+        let x = BigInt::from_bytes_be(Sign::Plus, &data[1..]);
+        if !self.in_field(&x) {
+            return None;
+        }
+
+        let mut y = self.sqrt(&self.polynomial(&x))?;
+        if y.bit(0) != (data[0] & 1 == 1) {
+            y = self.reduce(-y);
+        }
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    /// Modular square root (Tonelli-Shanks), `None` for non-residues.
+    fn sqrt(&self, value: &BigInt) -> Option<BigInt> {
+        let p = &self.field_order;
+        if value.is_zero() {
+            return Some(BigInt::zero());
+        }
+
+        let one = BigInt::one();
+        let p_minus_one: BigInt = p - 1u32;
+        let legendre_exponent: BigInt = &p_minus_one >> 1;
+        if value.modpow(&legendre_exponent, p) != one {
+            return None;
+        }
+
+        let mut q = p_minus_one.clone();
+        let mut s = 0u32;
+        while !q.bit(0) {
+            q >>= 1;
+            s += 1;
+        }
+
+        let mut non_residue = BigInt::from(2u32);
+        while non_residue.modpow(&legendre_exponent, p) != p_minus_one {
+            non_residue += 1u32;
+        }
+
+        let mut m = s;
+        let mut c = non_residue.modpow(&q, p);
+        let mut t = value.modpow(&q, p);
+        let mut r = value.modpow(&((&q + 1u32) >> 1), p);
+        while t != one {
+            let mut i = 0u32;
+            let mut t_pow = t.clone();
+            while t_pow != one {
+                t_pow = self.reduce(&t_pow * &t_pow);
+                i += 1;
+            }
+
+            let b = c.modpow(&(BigInt::one() << (m - i - 1)), p);
+            m = i;
+            c = self.reduce(&b * &b);
+            t = self.reduce(t * &c);
+            r = self.reduce(r * b);
+        }
+        Some(r)
+    }
+}
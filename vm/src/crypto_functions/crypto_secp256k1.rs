@@ -0,0 +1,96 @@
+use k256::ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey};
+use ripemd::Ripemd160;
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+
+/// Specifies how the message is hashed before a secp256k1 signature is checked.
+///
+/// Mirrors the `MessageHashType` from the framework, the numeric values are the ones sent through the VM hooks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageHashType {
+    ECDSAPlainMsg,
+    ECDSASha256,
+    ECDSADoubleSha256,
+    ECDSAKeccak256,
+    ECDSARipemd160,
+}
+
+impl MessageHashType {
+    pub fn from_i32(value: i32) -> Self {
+        match value {
+            1 => Self::ECDSASha256,
+            2 => Self::ECDSADoubleSha256,
+            3 => Self::ECDSAKeccak256,
+            4 => Self::ECDSARipemd160,
+            _ => Self::ECDSAPlainMsg,
+        }
+    }
+
+    fn hash_message(self, message: &[u8]) -> Vec<u8> {
+        match self {
+            Self::ECDSAPlainMsg => message.to_vec(),
+            Self::ECDSASha256 => Sha256::digest(message).to_vec(),
+            Self::ECDSADoubleSha256 => Sha256::digest(Sha256::digest(message)).to_vec(),
+            Self::ECDSAKeccak256 => Keccak256::digest(message).to_vec(),
+            Self::ECDSARipemd160 => Ripemd160::digest(message).to_vec(),
+        }
+    }
+}
+
+/// Verifies a DER-encoded secp256k1 signature.
+///
+/// The key can be either compressed (33 bytes) or uncompressed (65 bytes).
+///
+/// Just like the node, signatures with a high `s` are accepted.
+pub fn verify_secp256k1(
+    key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+    hash_type: MessageHashType,
+) -> bool {
+    let Ok(verifying_key) = VerifyingKey::from_sec1_bytes(key) else {
+        return false;
+    };
+    let Ok(sig) = Signature::from_der(signature) else {
+        return false;
+    };
+    let sig = sig.normalize_s().unwrap_or(sig);
+
+    let prehash = hash_type.hash_message(message);
+    verifying_key.verify_prehash(&prehash, &sig).is_ok()
+}
+
+/// Produces the DER encoding of a signature, given its `r` and `s` components.
+pub fn encode_secp256k1_der_signature(r: &[u8], s: &[u8]) -> Vec<u8> {
+    let r = der_integer(r);
+    let s = der_integer(s);
+
+    let mut result = Vec::with_capacity(2 + r.len() + s.len());
+    result.push(0x30);
+    result.push((r.len() + s.len()) as u8);
+    result.extend_from_slice(&r);
+    result.extend_from_slice(&s);
+    result
+}
+
+/// Encodes an unsigned big endian integer as an ASN.1 INTEGER,
+/// stripping leading zeroes and adding a zero byte when the most significant bit is set.
+fn der_integer(bytes: &[u8]) -> Vec<u8> {
+    let first_non_zero = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    let stripped = &bytes[first_non_zero..];
+
+    let mut encoded = Vec::with_capacity(stripped.len() + 3);
+    encoded.push(0x02);
+    if stripped.is_empty() {
+        encoded.push(1);
+        encoded.push(0);
+    } else if stripped[0] & 0x80 != 0 {
+        encoded.push(stripped.len() as u8 + 1);
+        encoded.push(0);
+        encoded.extend_from_slice(stripped);
+    } else {
+        encoded.push(stripped.len() as u8);
+        encoded.extend_from_slice(stripped);
+    }
+    encoded
+}
@@ -1,11 +1,14 @@
 pub mod crypto_functions;
 pub mod display_util;
 pub mod mem_conv;
+pub mod schedule;
 pub mod tx_execution;
 pub mod tx_mock;
 pub mod types;
 pub mod vm_err_msg;
 pub mod vm_hooks;
+#[cfg(feature = "wasm-executor")]
+pub mod wasm_executor;
 pub mod with_shared;
 pub mod world_mock;
 
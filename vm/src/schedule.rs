@@ -0,0 +1,5 @@
+mod gas_schedule;
+mod gas_section;
+
+pub use gas_schedule::*;
+pub use gas_section::GasSection;
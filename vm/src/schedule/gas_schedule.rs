@@ -0,0 +1,79 @@
+use std::{collections::HashMap, path::Path};
+
+use super::GasSection;
+
+/// `MinGasLimit` in the mainnet economics config, the cost of a simple transfer.
+pub const MAINNET_MIN_GAS_LIMIT: u64 = 50_000;
+
+/// `GasPerDataByte` in the mainnet economics config.
+pub const MAINNET_GAS_PER_DATA_BYTE: u64 = 1_500;
+
+/// Gas costs, in the same format as the node gas schedule (e.g. `gasScheduleV7.toml`).
+///
+/// Only integer entries are kept. Missing entries cost nothing.
+///
+/// The base cost of transactions is not part of the node gas schedule, but of the economics config.
+/// Schedules loaded from TOML get the mainnet values, the default, empty schedule charges nothing.
+#[derive(Clone, Debug, Default)]
+pub struct GasSchedule {
+    sections: HashMap<String, HashMap<String, u64>>,
+    min_gas_limit: u64,
+    gas_per_data_byte: u64,
+}
+
+impl GasSchedule {
+    pub fn from_toml_str(toml_str: &str) -> Result<Self, toml::de::Error> {
+        let table = toml_str.parse::<toml::Table>()?;
+        let mut gas_schedule = GasSchedule {
+            min_gas_limit: MAINNET_MIN_GAS_LIMIT,
+            gas_per_data_byte: MAINNET_GAS_PER_DATA_BYTE,
+            ..Default::default()
+        };
+        for (section_name, section_value) in table {
+            let Some(section_table) = section_value.as_table() else {
+                continue;
+            };
+            let section = gas_schedule.sections.entry(section_name).or_default();
+            for (key, value) in section_table {
+                if let Some(cost) = value.as_integer().and_then(|cost| u64::try_from(cost).ok()) {
+                    section.insert(key.clone(), cost);
+                }
+            }
+        }
+        Ok(gas_schedule)
+    }
+
+    pub fn load_toml_file<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref();
+        let toml_str = std::fs::read_to_string(path)
+            .unwrap_or_else(|err| panic!("error reading gas schedule {}: {err}", path.display()));
+        Self::from_toml_str(&toml_str)
+            .unwrap_or_else(|err| panic!("error parsing gas schedule {}: {err}", path.display()))
+    }
+
+    pub fn cost(&self, section: GasSection, key: &str) -> u64 {
+        self.sections
+            .get(section.toml_name())
+            .and_then(|section| section.get(key))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn set_cost(&mut self, section: GasSection, key: &str, cost: u64) {
+        self.sections
+            .entry(section.toml_name().to_string())
+            .or_default()
+            .insert(key.to_string(), cost);
+    }
+
+    /// Sets what every transaction pays before execution: a fixed cost, plus a cost per byte of data.
+    pub fn set_tx_base_cost(&mut self, min_gas_limit: u64, gas_per_data_byte: u64) {
+        self.min_gas_limit = min_gas_limit;
+        self.gas_per_data_byte = gas_per_data_byte;
+    }
+
+    /// The gas a transaction with the given data length pays before execution.
+    pub fn tx_base_cost(&self, data_len: usize) -> u64 {
+        self.min_gas_limit + self.gas_per_data_byte * data_len as u64
+    }
+}
@@ -0,0 +1,32 @@
+/// The sections of the node gas schedule that are relevant to the Rust VM.
+///
+/// WASM opcode costs are missing on purpose, contracts run natively in the debugger.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GasSection {
+    BaseOperationCost,
+    BaseOpsApiCost,
+    BigIntApiCost,
+    BigFloatApiCost,
+    CryptoApiCost,
+    ManagedBufferApiCost,
+    ManagedMapApiCost,
+    BuiltInCost,
+    MetaChainSystemScsCost,
+}
+
+impl GasSection {
+    /// The name of the table in the gas schedule TOML file.
+    pub fn toml_name(&self) -> &'static str {
+        match self {
+            GasSection::BaseOperationCost => "BaseOperationCost",
+            GasSection::BaseOpsApiCost => "BaseOpsAPICost",
+            GasSection::BigIntApiCost => "BigIntAPICost",
+            GasSection::BigFloatApiCost => "BigFloatAPICost",
+            GasSection::CryptoApiCost => "CryptoAPICost",
+            GasSection::ManagedBufferApiCost => "ManagedBufferAPICost",
+            GasSection::ManagedMapApiCost => "ManagedMapAPICost",
+            GasSection::BuiltInCost => "BuiltInCost",
+            GasSection::MetaChainSystemScsCost => "MetaChainSystemSCsCost",
+        }
+    }
+}
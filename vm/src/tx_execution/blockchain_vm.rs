@@ -2,11 +2,16 @@ use std::{ops::Deref, sync::Arc};
 
 use multiversx_chain_vm_executor::Executor;
 
+use crate::schedule::GasSchedule;
+
 use super::BuiltinFunctionContainer;
 
 pub struct BlockchainVM {
     pub builtin_functions: BuiltinFunctionContainer,
     pub executor: Box<dyn Executor + Send + Sync>,
+
+    /// Gas is only metered when a gas schedule is set.
+    pub gas_schedule: Option<GasSchedule>,
 }
 
 #[derive(Clone)]
@@ -17,14 +22,32 @@ impl BlockchainVM {
         BlockchainVM {
             builtin_functions: BuiltinFunctionContainer,
             executor,
+            gas_schedule: None,
         }
     }
+
+    pub fn is_gas_metering_enabled(&self) -> bool {
+        self.gas_schedule.is_some()
+    }
 }
 
 impl BlockchainVMRef {
     pub fn new(executor: Box<dyn Executor + Send + Sync>) -> Self {
         BlockchainVMRef(Arc::new(BlockchainVM::new(executor)))
     }
+
+    /// Enables gas metering. Passing `None` disables it.
+    ///
+    /// Fails if the VM is shared, e.g. while a transaction is running.
+    pub fn set_gas_schedule(
+        &mut self,
+        gas_schedule: Option<GasSchedule>,
+    ) -> Result<(), &'static str> {
+        let vm = Arc::get_mut(&mut self.0)
+            .ok_or("cannot change the gas schedule while the VM is in use")?;
+        vm.gas_schedule = gas_schedule;
+        Ok(())
+    }
 }
 
 impl Deref for BlockchainVMRef {
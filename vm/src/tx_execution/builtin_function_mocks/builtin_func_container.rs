@@ -5,11 +5,15 @@ use super::{
         ESDTLocalBurn, ESDTLocalMint, ESDTNftAddQuantity, ESDTNftAddUri, ESDTNftBurn,
         ESDTNftCreate, ESDTNftUpdateAttributes,
     },
-    general::{ChangeOwner, ClaimDeveloperRewards, DeleteUsername, SetUsername, UpgradeContract},
+    general::{
+        ChangeOwner, ClaimDeveloperRewards, DeleteContract, DeleteUsername, SetUsername,
+        UpgradeContract,
+    },
     transfer::{ESDTMultiTransfer, ESDTNftTransfer, ESDTTransfer},
     BuiltinFunctionEsdtTransferInfo,
 };
 use crate::{
+    schedule::GasSection,
     tx_execution::BlockchainVMRef,
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::EsdtLocalRole,
@@ -39,6 +43,10 @@ impl BuiltinFunctionContainer {
         BuiltinFunctionCall::new(vm, tx_input, tx_cache).execute_or_else(f, or_else)
     }
 
+    pub fn is_builtin_function(&self, func_name: &str) -> bool {
+        VM_BUILTIN_FUNCTION_NAMES.contains(&func_name)
+    }
+
     /// Provides data on the builtin functions that perform ESDT token transfers.
     pub fn extract_token_transfers(&self, tx_input: &TxInput) -> BuiltinFunctionEsdtTransferInfo {
         match tx_input.func_name.as_str() {
@@ -112,6 +120,7 @@ impl<'a> BuiltinFunctionCall<'a> {
             SET_USERNAME_FUNC_NAME => self.execute_bf(SetUsername, f),
             DELETE_USERNAME_FUNC_NAME => self.execute_bf(DeleteUsername, f),
             UPGRADE_CONTRACT_FUNC_NAME => self.execute_bf(UpgradeContract, f),
+            DELETE_CONTRACT_FUNC_NAME => self.execute_bf(DeleteContract, f),
             MIGRATE_USERNAME_FUNC_NAME => {
                 panic!("builtin function {MIGRATE_USERNAME_FUNC_NAME} was dropped")
             },
@@ -124,7 +133,11 @@ impl<'a> BuiltinFunctionCall<'a> {
         B: BuiltinFunction,
         F: FnOnce(),
     {
-        builtin_func.execute(self.tx_input, self.tx_cache, self.vm, f)
+        let gas_limit = self.tx_input.gas_limit;
+        let gas_key = builtin_function_gas_key(self.tx_input.func_name.as_str()).to_string();
+        let result = builtin_func.execute(self.tx_input, self.tx_cache, self.vm, f);
+        self.vm
+            .charge_fixed_gas_cost(gas_limit, GasSection::BuiltInCost, &gas_key, result)
     }
 
     fn check_role_and_execute<B, F>(
@@ -148,6 +161,16 @@ impl<'a> BuiltinFunctionCall<'a> {
     }
 }
 
+/// Most builtin function names coincide with their key in the gas schedule.
+fn builtin_function_gas_key(func_name: &str) -> &str {
+    match func_name {
+        ESDT_MULTI_TRANSFER_FUNC_NAME => "ESDTNFTMultiTransfer",
+        ESDT_NFT_ADD_URI_FUNC_NAME => "ESDTNFTAddUri",
+        SET_USERNAME_FUNC_NAME => "SaveUserName",
+        _ => func_name,
+    }
+}
+
 fn check_allowed_to_execute(role: EsdtLocalRole, tx_input: &TxInput, tx_cache: &TxCache) -> bool {
     let token_identifier = tx_input.args[0].clone();
     let available_roles = tx_cache.with_account_mut(&tx_input.to, |account| {
@@ -60,6 +60,7 @@ impl BuiltinFunction for ESDTNftCreate {
                     uri: uris,
                     attributes,
                 },
+                frozen: false,
             });
 
             esdt_data.last_nonce
@@ -1,5 +1,6 @@
 mod change_owner_mock;
 mod claim_developer_rewards_mock;
+mod delete_contract_mock;
 mod delete_username_mock;
 mod migrate_username_mock;
 mod set_username_mock;
@@ -7,6 +8,7 @@ mod upgrade_contract;
 
 pub use change_owner_mock::*;
 pub use claim_developer_rewards_mock::*;
+pub use delete_contract_mock::DeleteContract;
 pub use delete_username_mock::DeleteUsername;
 pub use set_username_mock::*;
 pub use upgrade_contract::*;
@@ -0,0 +1,44 @@
+use crate::tx_execution::{builtin_function_names::DELETE_CONTRACT_FUNC_NAME, BlockchainVMRef};
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    world_mock::AccountData,
+};
+
+use super::super::builtin_func_trait::BuiltinFunction;
+
+/// Only the owner can delete a contract.
+///
+/// The mock has no way of removing accounts, so the account is reset to an empty one instead.
+pub struct DeleteContract;
+
+impl BuiltinFunction for DeleteContract {
+    fn name(&self) -> &str {
+        DELETE_CONTRACT_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        let caller_is_owner = tx_cache.with_account(&tx_input.to, |account| {
+            account.contract_owner == Some(tx_input.from.clone())
+        });
+        if !caller_is_owner {
+            return (
+                TxResult::from_vm_error("operation in account not permitted"),
+                BlockchainUpdate::empty(),
+            );
+        }
+
+        tx_cache.insert_account(AccountData::new_empty(tx_input.to.clone()));
+
+        (TxResult::empty(), tx_cache.into_blockchain_updates())
+    }
+}
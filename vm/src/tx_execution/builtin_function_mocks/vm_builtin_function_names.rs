@@ -14,3 +14,25 @@ pub const SET_USERNAME_FUNC_NAME: &str = "SetUserName";
 pub const MIGRATE_USERNAME_FUNC_NAME: &str = "migrateUserName";
 pub const DELETE_USERNAME_FUNC_NAME: &str = "DeleteUserName";
 pub const UPGRADE_CONTRACT_FUNC_NAME: &str = "upgradeContract";
+
+/// Handled by the VM itself, not a builtin function on the protocol level.
+pub const DELETE_CONTRACT_FUNC_NAME: &str = "DeleteContract";
+
+pub const VM_BUILTIN_FUNCTION_NAMES: [&str; 16] = [
+    ESDT_LOCAL_MINT_FUNC_NAME,
+    ESDT_LOCAL_BURN_FUNC_NAME,
+    ESDT_MULTI_TRANSFER_FUNC_NAME,
+    ESDT_NFT_TRANSFER_FUNC_NAME,
+    ESDT_NFT_CREATE_FUNC_NAME,
+    ESDT_NFT_ADD_QUANTITY_FUNC_NAME,
+    ESDT_NFT_ADD_URI_FUNC_NAME,
+    ESDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME,
+    ESDT_NFT_BURN_FUNC_NAME,
+    ESDT_TRANSFER_FUNC_NAME,
+    CHANGE_OWNER_BUILTIN_FUNC_NAME,
+    CLAIM_DEVELOPER_REWARDS_FUNC_NAME,
+    SET_USERNAME_FUNC_NAME,
+    MIGRATE_USERNAME_FUNC_NAME,
+    DELETE_USERNAME_FUNC_NAME,
+    UPGRADE_CONTRACT_FUNC_NAME,
+];
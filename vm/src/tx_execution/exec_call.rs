@@ -1,8 +1,8 @@
 use crate::{
     tx_mock::{
         async_call_tx_input, async_callback_tx_input, async_promise_callback_tx_input,
-        merge_results, AsyncCallTxData, BlockchainUpdate, CallType, Promise, TxCache, TxContext,
-        TxContextStack, TxInput, TxPanic, TxResult, TxResultCalls,
+        merge_results, AsyncCallTxData, BlockchainUpdate, CallType, CrossShardAsyncCall, GasPayer,
+        Promise, TxCache, TxContext, TxContextStack, TxInput, TxPanic, TxResult, TxResultCalls,
     },
     types::VMCodeMetadata,
     with_shared::Shareable,
@@ -12,7 +12,7 @@ use num_bigint::BigUint;
 use num_traits::Zero;
 use std::collections::HashMap;
 
-use super::BlockchainVMRef;
+use super::{exec_gas::call_data_len, BlockchainVMRef};
 
 /// Executes the SC endpoint, as given by the current TxInput in the current TxContext.
 ///
@@ -76,17 +76,40 @@ impl BlockchainVMRef {
         )
     }
 
+    /// Executes a transaction, without its async calls. The sender pays for the gas.
     pub fn execute_sc_call_lambda<F>(
         &self,
-        tx_input: TxInput,
+        mut tx_input: TxInput,
         state: &mut Shareable<BlockchainState>,
         f: F,
     ) -> TxResult
     where
         F: FnOnce(),
     {
-        state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
+        let gas_payer = GasPayer::from_tx_input(&tx_input);
+        let gas_limit = tx_input.gas_limit;
+        let data_len = call_data_len(&tx_input);
+        let base_cost = match self.pay_tx_gas_upfront(&mut tx_input, data_len, state) {
+            Ok(base_cost) => base_cost,
+            Err(tx_result) => return tx_result,
+        };
+
+        let mut tx_result = self.execute_sc_call_within_tx(tx_input, state, f);
+        self.settle_tx_gas(&gas_payer, gas_limit, base_cost, &mut tx_result, state);
+
+        tx_result
+    }
 
+    /// Executes a call that is part of a transaction already paid for, e.g. an async call or a callback.
+    pub(crate) fn execute_sc_call_within_tx<F>(
+        &self,
+        tx_input: TxInput,
+        state: &mut Shareable<BlockchainState>,
+        f: F,
+    ) -> TxResult
+    where
+        F: FnOnce(),
+    {
         let (tx_result, blockchain_updates) = state.with_shared(|state_arc| {
             let tx_cache = TxCache::new(state_arc);
             self.execute_builtin_function_or_default(tx_input, tx_cache, f)
@@ -102,20 +125,28 @@ impl BlockchainVMRef {
     pub fn execute_async_call_and_callback(
         &self,
         async_data: AsyncCallTxData,
+        gas_left: u64,
+        gas_payer: &GasPayer,
         state: &mut Shareable<BlockchainState>,
     ) -> (TxResult, TxResult) {
         if state.accounts.contains_key(&async_data.to) {
-            let async_input = async_call_tx_input(&async_data, CallType::AsyncCall);
+            let mut async_input = async_call_tx_input(&async_data, CallType::AsyncCall);
+            self.forward_async_gas(&mut async_input, gas_left);
 
-            let async_result = self.sc_call_with_async_and_callback(
+            let async_result = self.sc_call_with_async_and_callback_within_tx(
                 async_input,
+                gas_payer,
                 state,
                 execute_current_tx_context_input,
             );
 
-            let callback_input =
+            let mut callback_input =
                 async_callback_tx_input(&async_data, &async_result, &self.builtin_functions);
-            let callback_result = self.execute_sc_call_lambda(
+            self.forward_async_gas(
+                &mut callback_input,
+                gas_left.saturating_sub(async_result.gas_used),
+            );
+            let callback_result = self.execute_sc_call_within_tx(
                 callback_input,
                 state,
                 execute_current_tx_context_input,
@@ -126,30 +157,71 @@ impl BlockchainVMRef {
             );
             (async_result, callback_result)
         } else {
-            let result = self.insert_ghost_account(&async_data, state);
-            match result {
-                Ok(blockchain_updates) => {
-                    state.commit_updates(blockchain_updates);
-                    (TxResult::empty(), TxResult::empty())
-                },
-                Err(err) => (TxResult::from_panic_obj(&err), TxResult::empty()),
-            }
+            (
+                self.transfer_to_ghost_account(&async_data, state),
+                TxResult::empty(),
+            )
         }
     }
 
-    // TODO: refactor
+    /// Executes a transaction, together with its async calls and callbacks.
+    ///
+    /// The sender pays for the gas of all of them, the gas is only settled once they have all finished.
     pub fn sc_call_with_async_and_callback<F>(
+        &self,
+        mut tx_input: TxInput,
+        state: &mut Shareable<BlockchainState>,
+        f: F,
+    ) -> TxResult
+    where
+        F: FnOnce(),
+    {
+        let gas_payer = GasPayer::from_tx_input(&tx_input);
+        let gas_limit = tx_input.gas_limit;
+        let data_len = call_data_len(&tx_input);
+        let base_cost = match self.pay_tx_gas_upfront(&mut tx_input, data_len, state) {
+            Ok(base_cost) => base_cost,
+            Err(tx_result) => return tx_result,
+        };
+
+        let mut tx_result =
+            self.sc_call_with_async_and_callback_within_tx(tx_input, &gas_payer, state, f);
+        self.settle_tx_gas(&gas_payer, gas_limit, base_cost, &mut tx_result, state);
+
+        tx_result
+    }
+
+    // TODO: refactor
+    pub(crate) fn sc_call_with_async_and_callback_within_tx<F>(
         &self,
         tx_input: TxInput,
+        gas_payer: &GasPayer,
         state: &mut Shareable<BlockchainState>,
         f: F,
     ) -> TxResult
     where
         F: FnOnce(),
     {
+        let gas_limit = tx_input.gas_limit;
+
         // main call
-        let mut tx_result = self.execute_sc_call_lambda(tx_input, state, f);
+        let tx_result = self.execute_sc_call_within_tx(tx_input, state, f);
+
+        self.execute_pending_calls(tx_result, gas_limit, gas_payer, state)
+    }
 
+    /// Executes the legacy async call or the promises registered by a transaction, with their callbacks.
+    ///
+    /// The results are merged into the original transaction result.
+    /// Calls to other shards are only queued, they get executed in the next round.
+    /// The gas forwarded to them counts as used, until they complete and give back what is left.
+    pub(crate) fn execute_pending_calls(
+        &self,
+        mut tx_result: TxResult,
+        gas_limit: u64,
+        gas_payer: &GasPayer,
+        state: &mut Shareable<BlockchainState>,
+    ) -> TxResult {
         // take & clear pending calls
         let pending_calls = std::mem::replace(&mut tx_result.pending_calls, TxResultCalls::empty());
 
@@ -157,8 +229,20 @@ impl BlockchainVMRef {
         // the async call also gets reset
         if tx_result.result_status == 0 {
             if let Some(async_data) = pending_calls.async_call {
+                let gas_left = gas_limit.saturating_sub(tx_result.gas_used);
+                if self.is_cross_shard_call(&async_data, state) {
+                    self.queue_cross_shard_call(
+                        CrossShardAsyncCall::Legacy(async_data),
+                        gas_left,
+                        gas_payer,
+                        &mut tx_result,
+                        state,
+                    );
+                    return tx_result;
+                }
+
                 let (async_result, callback_result) =
-                    self.execute_async_call_and_callback(async_data, state);
+                    self.execute_async_call_and_callback(async_data, gas_left, gas_payer, state);
 
                 tx_result = merge_results(tx_result, async_result);
                 tx_result = merge_results(tx_result, callback_result);
@@ -170,8 +254,20 @@ impl BlockchainVMRef {
         // calling all promises
         // the promises are also reset
         for promise in pending_calls.promises {
+            let gas_left = gas_limit.saturating_sub(tx_result.gas_used);
+            if self.is_cross_shard_call(&promise.call, state) {
+                self.queue_cross_shard_call(
+                    CrossShardAsyncCall::Promise(promise),
+                    gas_left,
+                    gas_payer,
+                    &mut tx_result,
+                    state,
+                );
+                continue;
+            }
+
             let (async_result, callback_result) =
-                self.execute_promise_call_and_callback(&promise, state);
+                self.execute_promise_call_and_callback(&promise, gas_left, gas_payer, state);
 
             tx_result = merge_results(tx_result, async_result.clone());
             tx_result = merge_results(tx_result, callback_result.clone());
@@ -183,26 +279,28 @@ impl BlockchainVMRef {
     pub fn execute_promise_call_and_callback(
         &self,
         promise: &Promise,
+        gas_left: u64,
+        gas_payer: &GasPayer,
         state: &mut Shareable<BlockchainState>,
     ) -> (TxResult, TxResult) {
         if state.accounts.contains_key(&promise.call.to) {
-            let async_input = async_call_tx_input(&promise.call, CallType::AsyncCall);
-            let async_result = self.sc_call_with_async_and_callback(
+            let mut async_input = async_call_tx_input(&promise.call, CallType::AsyncCall);
+            self.forward_async_gas(&mut async_input, gas_left);
+            let async_result = self.sc_call_with_async_and_callback_within_tx(
                 async_input,
+                gas_payer,
                 state,
                 execute_current_tx_context_input,
             );
-            let callback_result = self.execute_promises_callback(&async_result, promise, state);
+            let callback_gas_left = gas_left.saturating_sub(async_result.gas_used);
+            let callback_result =
+                self.execute_promises_callback(&async_result, promise, callback_gas_left, state);
             (async_result, callback_result)
         } else {
-            let result = self.insert_ghost_account(&promise.call, state);
-            match result {
-                Ok(blockchain_updates) => {
-                    state.commit_updates(blockchain_updates);
-                    (TxResult::empty(), TxResult::empty())
-                },
-                Err(err) => (TxResult::from_panic_obj(&err), TxResult::empty()),
-            }
+            (
+                self.transfer_to_ghost_account(&promise.call, state),
+                TxResult::empty(),
+            )
         }
     }
 
@@ -210,15 +308,17 @@ impl BlockchainVMRef {
         &self,
         async_result: &TxResult,
         promise: &Promise,
+        gas_left: u64,
         state: &mut Shareable<BlockchainState>,
     ) -> TxResult {
         if !promise.has_callback() {
             return TxResult::empty();
         }
-        let callback_input =
+        let mut callback_input =
             async_promise_callback_tx_input(promise, async_result, &self.builtin_functions);
+        self.forward_async_gas(&mut callback_input, gas_left);
         let callback_result =
-            self.execute_sc_call_lambda(callback_input, state, execute_current_tx_context_input);
+            self.execute_sc_call_within_tx(callback_input, state, execute_current_tx_context_input);
         assert!(
             callback_result.pending_calls.promises.is_empty(),
             "successive promises currently not supported"
@@ -226,7 +326,21 @@ impl BlockchainVMRef {
         callback_result
     }
 
-    /// When calling a contract that is unknown to the state, we insert a ghost account.
+    /// When calling a contract that is unknown to the state, the call value goes to a ghost account.
+    pub(crate) fn transfer_to_ghost_account(
+        &self,
+        async_data: &AsyncCallTxData,
+        state: &mut Shareable<BlockchainState>,
+    ) -> TxResult {
+        match self.insert_ghost_account(async_data, state) {
+            Ok(blockchain_updates) => {
+                state.commit_updates(blockchain_updates);
+                TxResult::empty()
+            },
+            Err(err) => TxResult::from_panic_obj(&err),
+        }
+    }
+
     fn insert_ghost_account(
         &self,
         async_data: &AsyncCallTxData,
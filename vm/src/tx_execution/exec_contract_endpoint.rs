@@ -36,15 +36,16 @@ impl BlockchainVMRef {
     }
 }
 
+/// The code usually belongs to the recipient, except for `executeOnSameContext`.
 fn get_contract_identifier(tx_context: &TxContext) -> Vec<u8> {
-    tx_context
-        .tx_cache
-        .with_account(&tx_context.tx_input_box.to, |account| {
-            account.contract_path.clone().unwrap_or_else(|| {
-                panic!(
-                    "Recipient account is not a smart contract {}",
-                    address_hex(&tx_context.tx_input_box.to)
-                )
-            })
+    let tx_input = tx_context.input_ref();
+    let code_address = tx_input.code_address.as_ref().unwrap_or(&tx_input.to);
+    tx_context.tx_cache.with_account(code_address, |account| {
+        account.contract_path.clone().unwrap_or_else(|| {
+            panic!(
+                "Recipient account is not a smart contract {}",
+                address_hex(code_address)
+            )
         })
+    })
 }
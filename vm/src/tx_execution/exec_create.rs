@@ -1,16 +1,17 @@
 use crate::{
-    tx_mock::{TxCache, TxInput, TxResult},
+    tx_mock::{GasPayer, TxCache, TxInput, TxResult},
     types::{VMAddress, VMCodeMetadata},
     with_shared::Shareable,
     world_mock::BlockchainState,
 };
 
-use super::BlockchainVMRef;
+use super::{exec_gas::deploy_data_len, BlockchainVMRef};
 
 impl BlockchainVMRef {
+    /// Deploys a contract, without the async calls launched from its constructor. The sender pays for the gas.
     pub fn sc_create<F>(
         &self,
-        tx_input: TxInput,
+        mut tx_input: TxInput,
         contract_path: &[u8],
         code_metadata: VMCodeMetadata,
         state: &mut Shareable<BlockchainState>,
@@ -19,11 +20,76 @@ impl BlockchainVMRef {
     where
         F: FnOnce(),
     {
-        // nonce gets increased irrespective of whether the tx fails or not
-        // must be done after computing the new address
+        let gas_payer = GasPayer::from_tx_input(&tx_input);
+        let gas_limit = tx_input.gas_limit;
+        let base_cost = match self.pay_deploy_gas_upfront(&mut tx_input, contract_path, state) {
+            Ok(base_cost) => base_cost,
+            Err(tx_result) => return (VMAddress::zero(), tx_result),
+        };
+
+        let (new_address, mut tx_result) =
+            self.sc_create_within_tx(tx_input, contract_path, code_metadata, state, f);
+        self.settle_tx_gas(&gas_payer, gas_limit, base_cost, &mut tx_result, state);
+
+        (new_address, tx_result)
+    }
+
+    /// Deploys a contract, then executes the async calls and promises launched from its constructor.
+    ///
+    /// The gas is only settled once they have all finished.
+    pub fn sc_create_with_async_and_callback<F>(
+        &self,
+        mut tx_input: TxInput,
+        contract_path: &[u8],
+        code_metadata: VMCodeMetadata,
+        state: &mut Shareable<BlockchainState>,
+        f: F,
+    ) -> (VMAddress, TxResult)
+    where
+        F: FnOnce(),
+    {
+        let gas_payer = GasPayer::from_tx_input(&tx_input);
+        let gas_limit = tx_input.gas_limit;
+        let base_cost = match self.pay_deploy_gas_upfront(&mut tx_input, contract_path, state) {
+            Ok(base_cost) => base_cost,
+            Err(tx_result) => return (VMAddress::zero(), tx_result),
+        };
+
+        let execution_gas_limit = tx_input.gas_limit;
+        let (new_address, tx_result) =
+            self.sc_create_within_tx(tx_input, contract_path, code_metadata, state, f);
+        let mut tx_result =
+            self.execute_pending_calls(tx_result, execution_gas_limit, &gas_payer, state);
+        self.settle_tx_gas(&gas_payer, gas_limit, base_cost, &mut tx_result, state);
+
+        (new_address, tx_result)
+    }
+
+    /// The nonce gets increased irrespective of whether the tx fails or not,
+    /// even when the gas limit does not cover the base cost.
+    #[allow(clippy::result_large_err)]
+    fn pay_deploy_gas_upfront(
+        &self,
+        tx_input: &mut TxInput,
+        contract_path: &[u8],
+        state: &mut BlockchainState,
+    ) -> Result<u64, TxResult> {
         state.increase_account_nonce(&tx_input.from);
-        state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
+        let data_len = deploy_data_len(tx_input, contract_path);
+        self.pay_tx_gas_upfront(tx_input, data_len, state)
+    }
 
+    fn sc_create_within_tx<F>(
+        &self,
+        tx_input: TxInput,
+        contract_path: &[u8],
+        code_metadata: VMCodeMetadata,
+        state: &mut Shareable<BlockchainState>,
+        f: F,
+    ) -> (VMAddress, TxResult)
+    where
+        F: FnOnce(),
+    {
         let (tx_result, new_address, blockchain_updates) = state.with_shared(|state_arc| {
             let tx_cache = TxCache::new(state_arc);
 
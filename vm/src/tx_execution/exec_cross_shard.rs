@@ -0,0 +1,235 @@
+use num_bigint::BigUint;
+
+use crate::{
+    tx_mock::{
+        async_call_tx_input, real_recipient, AsyncCallTxData, CallType, CallValueInTransit,
+        CrossShardAsyncCall, CrossShardCall, GasPayer, TxResult, TxTokenTransfer,
+    },
+    types::VMAddress,
+    with_shared::Shareable,
+    world_mock::{AccountData, BlockchainState, EsdtInstanceMetadata},
+};
+
+use super::{execute_current_tx_context_input, BlockchainVMRef};
+
+impl BlockchainVMRef {
+    /// Only possible when sharding is enabled, and the real recipient lives in another shard than the caller.
+    pub(crate) fn is_cross_shard_call(
+        &self,
+        async_data: &AsyncCallTxData,
+        state: &BlockchainState,
+    ) -> bool {
+        let Some(shard_coordinator) = &state.shard_coordinator else {
+            return false;
+        };
+        let recipient = real_recipient(async_data, &self.builtin_functions);
+        !shard_coordinator.same_shard(&async_data.from, &recipient)
+    }
+
+    /// Sends an async call to another shard, with all the gas left in the transaction.
+    ///
+    /// The forwarded gas counts as used by the transaction, what remains of it is refunded when the call completes.
+    /// The EGLD and ESDT call value leaves the caller right away, like on the real chain.
+    pub(crate) fn queue_cross_shard_call(
+        &self,
+        async_call: CrossShardAsyncCall,
+        gas_left: u64,
+        gas_payer: &GasPayer,
+        tx_result: &mut TxResult,
+        state: &mut BlockchainState,
+    ) {
+        if self.is_gas_metering_enabled() {
+            tx_result.gas_used += gas_left;
+        }
+        let async_data = async_call.call_data();
+        let esdt_transfers = self
+            .builtin_functions
+            .extract_token_transfers(&async_call_tx_input(async_data, CallType::AsyncCall))
+            .transfers;
+        let call_value_in_transit = take_call_value_from_caller(
+            &async_data.from,
+            &async_data.call_value,
+            &esdt_transfers,
+            state,
+        );
+        state.cross_shard_calls.push(CrossShardCall::AsyncCall {
+            async_call,
+            call_value_in_transit,
+            gas_left,
+            gas_payer: gas_payer.clone(),
+        });
+    }
+
+    /// Starts a new round, in which all the cross-shard calls queued so far get executed, in order.
+    ///
+    /// Callbacks, as well as cross-shard calls made during this round, are queued for the next one.
+    /// The call value of a failed call goes back to the caller with the callback, not before.
+    pub fn execute_next_round(&self, state: &mut Shareable<BlockchainState>) -> Vec<TxResult> {
+        state.advance_round();
+        let cross_shard_calls = std::mem::take(&mut state.cross_shard_calls);
+        cross_shard_calls
+            .into_iter()
+            .map(|cross_shard_call| self.execute_cross_shard_call(cross_shard_call, state))
+            .collect()
+    }
+
+    fn execute_cross_shard_call(
+        &self,
+        cross_shard_call: CrossShardCall,
+        state: &mut Shareable<BlockchainState>,
+    ) -> TxResult {
+        match cross_shard_call {
+            CrossShardCall::AsyncCall {
+                async_call,
+                call_value_in_transit,
+                gas_left,
+                gas_payer,
+            } => {
+                let async_data = async_call.call_data();
+                // handed back just before the call, which then transfers it to the recipient as usual
+                give_back_call_value(&async_data.from, &call_value_in_transit, state);
+                if !state.accounts.contains_key(&async_data.to) {
+                    self.refund_forwarded_gas(&gas_payer, gas_left, state);
+                    return self.transfer_to_ghost_account(async_data, state);
+                }
+
+                let mut async_input = async_call_tx_input(async_data, CallType::AsyncCall);
+                self.forward_async_gas(&mut async_input, gas_left);
+                let async_result = self.sc_call_with_async_and_callback_within_tx(
+                    async_input,
+                    &gas_payer,
+                    state,
+                    execute_current_tx_context_input,
+                );
+
+                let gas_left = gas_left.saturating_sub(async_result.gas_used);
+                if async_call.has_callback() {
+                    // the failed call reverted its transfers, the call value travels back with the callback
+                    let call_value_in_transit = if async_result.result_status == 0 {
+                        CallValueInTransit::default()
+                    } else {
+                        take_back_call_value(&async_data.from, &call_value_in_transit, state)
+                    };
+                    state.cross_shard_calls.push(CrossShardCall::Callback {
+                        async_call,
+                        async_result: Box::new(async_result.clone()),
+                        call_value_in_transit,
+                        gas_left,
+                        gas_payer,
+                    });
+                } else {
+                    self.refund_forwarded_gas(&gas_payer, gas_left, state);
+                }
+                async_result
+            },
+            CrossShardCall::Callback {
+                async_call,
+                async_result,
+                call_value_in_transit,
+                gas_left,
+                gas_payer,
+            } => {
+                give_back_call_value(&async_call.call_data().from, &call_value_in_transit, state);
+                let mut callback_input =
+                    async_call.callback_tx_input(&async_result, &self.builtin_functions);
+                self.forward_async_gas(&mut callback_input, gas_left);
+                let callback_result = self.sc_call_with_async_and_callback_within_tx(
+                    callback_input,
+                    &gas_payer,
+                    state,
+                    execute_current_tx_context_input,
+                );
+
+                let gas_left = gas_left.saturating_sub(callback_result.gas_used);
+                self.refund_forwarded_gas(&gas_payer, gas_left, state);
+                callback_result
+            },
+        }
+    }
+}
+
+/// Only takes the call value if the caller can afford all of it, otherwise the call fails at its destination.
+fn take_call_value_from_caller(
+    caller: &VMAddress,
+    egld_value: &BigUint,
+    esdt_transfers: &[TxTokenTransfer],
+    state: &mut BlockchainState,
+) -> CallValueInTransit {
+    let mut call_value_in_transit = CallValueInTransit::default();
+    let Some(account) = state.accounts.get_mut(caller) else {
+        return call_value_in_transit;
+    };
+    if &account.egld_balance < egld_value {
+        return call_value_in_transit;
+    }
+
+    for transfer in esdt_transfers {
+        let Some(metadata) = take_esdt(account, transfer) else {
+            give_back_to_account(account, &call_value_in_transit);
+            return CallValueInTransit::default();
+        };
+        call_value_in_transit
+            .esdt_transfers
+            .push((transfer.clone(), metadata));
+    }
+    account.egld_balance -= egld_value;
+    call_value_in_transit.egld_value = egld_value.clone();
+    call_value_in_transit
+}
+
+/// Takes again the call value that was handed back for a call that then failed.
+fn take_back_call_value(
+    caller: &VMAddress,
+    call_value_in_transit: &CallValueInTransit,
+    state: &mut BlockchainState,
+) -> CallValueInTransit {
+    let esdt_transfers: Vec<TxTokenTransfer> = call_value_in_transit
+        .esdt_transfers
+        .iter()
+        .map(|(transfer, _)| transfer.clone())
+        .collect();
+    take_call_value_from_caller(
+        caller,
+        &call_value_in_transit.egld_value,
+        &esdt_transfers,
+        state,
+    )
+}
+
+fn take_esdt(
+    account: &mut AccountData,
+    transfer: &TxTokenTransfer,
+) -> Option<EsdtInstanceMetadata> {
+    let instance = account
+        .esdt
+        .get_mut_by_identifier(&transfer.token_identifier)?
+        .instances
+        .get_mut_by_nonce(transfer.nonce)?;
+    if instance.balance < transfer.value {
+        return None;
+    }
+    instance.balance -= &transfer.value;
+    Some(instance.metadata.clone())
+}
+
+fn give_back_call_value(
+    caller: &VMAddress,
+    call_value_in_transit: &CallValueInTransit,
+    state: &mut BlockchainState,
+) {
+    if let Some(account) = state.accounts.get_mut(caller) {
+        give_back_to_account(account, call_value_in_transit);
+    }
+}
+
+fn give_back_to_account(account: &mut AccountData, call_value_in_transit: &CallValueInTransit) {
+    account.egld_balance += &call_value_in_transit.egld_value;
+    for (transfer, metadata) in &call_value_in_transit.esdt_transfers {
+        account.esdt.increase_balance(
+            transfer.token_identifier.clone(),
+            transfer.nonce,
+            &transfer.value,
+            metadata.clone(),
+        );
+    }
+}
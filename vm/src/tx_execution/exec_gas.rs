@@ -0,0 +1,128 @@
+use crate::{
+    schedule::GasSection,
+    tx_mock::{BlockchainUpdate, GasPayer, TxInput, TxResult},
+    vm_err_msg::INSUFFICIENT_GAS_LIMIT,
+    world_mock::BlockchainState,
+};
+
+use super::BlockchainVMRef;
+
+impl BlockchainVMRef {
+    /// Cost from the gas schedule, 0 if gas metering is disabled.
+    pub fn gas_cost(&self, section: GasSection, key: &str) -> u64 {
+        self.gas_schedule
+            .as_ref()
+            .map(|gas_schedule| gas_schedule.cost(section, key))
+            .unwrap_or_default()
+    }
+
+    /// Adds the fixed cost of a builtin function or system SC call to its result.
+    ///
+    /// The whole call fails if it goes over the gas limit.
+    pub(crate) fn charge_fixed_gas_cost(
+        &self,
+        gas_limit: u64,
+        section: GasSection,
+        key: &str,
+        result: (TxResult, BlockchainUpdate),
+    ) -> (TxResult, BlockchainUpdate) {
+        if !self.is_gas_metering_enabled() {
+            return result;
+        }
+
+        let (mut tx_result, blockchain_updates) = result;
+        tx_result.gas_used += self.gas_cost(section, key);
+        if tx_result.gas_used > gas_limit {
+            return (
+                TxResult::from_out_of_gas(gas_limit),
+                BlockchainUpdate::empty(),
+            );
+        }
+        (tx_result, blockchain_updates)
+    }
+
+    /// With gas metering, async calls and callbacks get the gas left in the original transaction.
+    pub(crate) fn forward_async_gas(&self, tx_input: &mut TxInput, gas_left: u64) {
+        if self.is_gas_metering_enabled() {
+            tx_input.gas_limit = gas_left;
+        }
+    }
+
+    /// The gas every transaction pays before execution, 0 if gas metering is disabled.
+    pub fn tx_base_gas_cost(&self, data_len: usize) -> u64 {
+        self.gas_schedule
+            .as_ref()
+            .map(|gas_schedule| gas_schedule.tx_base_cost(data_len))
+            .unwrap_or_default()
+    }
+
+    /// Takes the whole gas limit from the sender upfront, then the base cost out of the gas available for execution.
+    ///
+    /// Returns the base cost, or the failed result if the gas limit does not even cover it.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn pay_tx_gas_upfront(
+        &self,
+        tx_input: &mut TxInput,
+        data_len: usize,
+        state: &mut BlockchainState,
+    ) -> Result<u64, TxResult> {
+        let base_cost = self.tx_base_gas_cost(data_len);
+        if base_cost > tx_input.gas_limit {
+            return Err(TxResult::from_vm_error(INSUFFICIENT_GAS_LIMIT));
+        }
+
+        state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
+        tx_input.gas_limit -= base_cost;
+        Ok(base_cost)
+    }
+
+    /// Settles the gas of a transaction, once its async calls and callbacks have finished too.
+    ///
+    /// Failed transactions consume all their gas, the rest gets refunded to the sender.
+    pub(crate) fn settle_tx_gas(
+        &self,
+        gas_payer: &GasPayer,
+        gas_limit: u64,
+        base_cost: u64,
+        tx_result: &mut TxResult,
+        state: &mut BlockchainState,
+    ) {
+        if !self.is_gas_metering_enabled() {
+            return;
+        }
+
+        tx_result.gas_used += base_cost;
+        if tx_result.result_status != 0 {
+            tx_result.gas_used = gas_limit;
+        }
+        let gas_left = gas_limit.saturating_sub(tx_result.gas_used);
+        state.refund_tx_gas(&gas_payer.address, gas_left, gas_payer.gas_price);
+    }
+
+    /// Gives back the gas that was forwarded to a cross-shard call, but not used, once it has completed.
+    pub(crate) fn refund_forwarded_gas(
+        &self,
+        gas_payer: &GasPayer,
+        gas_left: u64,
+        state: &mut BlockchainState,
+    ) {
+        if self.is_gas_metering_enabled() {
+            state.refund_tx_gas(&gas_payer.address, gas_left, gas_payer.gas_price);
+        }
+    }
+}
+
+/// Length of the data field of a transaction calling the given function, e.g. `function@0a@0b`.
+pub(crate) fn call_data_len(tx_input: &TxInput) -> usize {
+    tx_input.func_name.as_str().len() + args_data_len(&tx_input.args)
+}
+
+/// Length of the data field of a deploy transaction: `code@0500@metadata@args...`.
+pub(crate) fn deploy_data_len(tx_input: &TxInput, contract_code: &[u8]) -> usize {
+    const VM_TYPE_AND_METADATA_LEN: usize = "@0500@0000".len();
+    contract_code.len() * 2 + VM_TYPE_AND_METADATA_LEN + args_data_len(&tx_input.args)
+}
+
+fn args_data_len(args: &[Vec<u8>]) -> usize {
+    args.iter().map(|arg| 1 + arg.len() * 2).sum()
+}
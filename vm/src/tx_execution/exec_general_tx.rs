@@ -1,7 +1,8 @@
 use num_traits::Zero;
 
 use crate::{
-    tx_execution::execute_system_sc,
+    schedule::GasSection,
+    tx_execution::{execute_system_sc, system_sc_gas_key},
     tx_mock::{
         BlockchainUpdate, CallType, TxCache, TxContext, TxContextStack, TxFunctionName, TxInput,
         TxLog, TxResult,
@@ -104,7 +105,15 @@ impl BlockchainVMRef {
         }
 
         let (mut tx_result, blockchain_updates) = if is_system_sc_address(&tx_input.to) {
-            execute_system_sc(tx_input, tx_cache)
+            let gas_limit = tx_input.gas_limit;
+            let gas_key = system_sc_gas_key(tx_input.func_name.as_str());
+            let result = execute_system_sc(tx_input, tx_cache);
+            self.charge_fixed_gas_cost(
+                gas_limit,
+                GasSection::MetaChainSystemScsCost,
+                gas_key,
+                result,
+            )
         } else if should_execute_sc_call(&tx_input) {
             let tx_context = TxContext::new(self.clone(), tx_input, tx_cache);
             let mut tx_context_sh = Shareable::new(tx_context);
@@ -1,15 +1,18 @@
+mod system_sc_checks;
+mod system_sc_freeze;
 mod system_sc_issue;
 mod system_sc_special_roles;
-mod system_sc_unimplemented;
+mod system_sc_token_management;
 
 use crate::{
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::VMAddress,
 };
 use hex_literal::hex;
+use system_sc_freeze::*;
 use system_sc_issue::*;
 use system_sc_special_roles::*;
-use system_sc_unimplemented::*;
+use system_sc_token_management::*;
 
 /// Address of the system smart contract that manages ESDT.
 /// Bech32: erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u
@@ -20,6 +23,18 @@ pub fn is_system_sc_address(address: &VMAddress) -> bool {
     address.as_array() == &ESDT_SYSTEM_SC_ADDRESS_ARRAY
 }
 
+/// Issuing tokens costs more than all other operations.
+pub fn system_sc_gas_key(func_name: &str) -> &'static str {
+    match func_name {
+        "issue"
+        | "issueSemiFungible"
+        | "issueNonFungible"
+        | "registerMetaESDT"
+        | "registerAndSetAllRoles" => "ESDTIssue",
+        _ => "ESDTOperations",
+    }
+}
+
 pub fn execute_system_sc(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     let func_name = &tx_input.func_name;
     match func_name.as_str() {
@@ -0,0 +1,71 @@
+use num_bigint::BigUint;
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    types::VMAddress,
+    world_mock::EsdtSystemScData,
+};
+
+pub(super) fn error_result(message: &str) -> (TxResult, BlockchainUpdate) {
+    (TxResult::from_vm_error(message), BlockchainUpdate::empty())
+}
+
+pub(super) fn check_num_args(tx_input: &TxInput, min_args: usize) -> Result<(), &'static str> {
+    if tx_input.args.len() < min_args {
+        Err("not enough arguments")
+    } else {
+        Ok(())
+    }
+}
+
+pub(super) fn arg_address(tx_input: &TxInput, index: usize) -> Result<VMAddress, &'static str> {
+    let arg = &tx_input.args[index];
+    if arg.len() != 32 {
+        return Err("invalid address argument");
+    }
+    Ok(VMAddress::from_slice(arg))
+}
+
+pub(super) fn arg_big_uint(tx_input: &TxInput, index: usize) -> BigUint {
+    BigUint::from_bytes_be(tx_input.args[index].as_slice())
+}
+
+pub(super) fn account_exists(tx_cache: &TxCache, address: &VMAddress) -> bool {
+    tx_cache.with_account_or_else(address, |_| true, || false)
+}
+
+/// Checks that the caller is the owner of the token,
+/// and that the token has the property required by the operation.
+///
+/// Tokens unknown to the system SC (e.g. only created via `setState`) pass all checks.
+pub(super) fn check_token_owner_and_property<P>(
+    tx_cache: &TxCache,
+    caller: &VMAddress,
+    token_identifier: &[u8],
+    property: P,
+    property_err_msg: &'static str,
+) -> Result<(), &'static str>
+where
+    P: FnOnce(&EsdtSystemScData) -> bool,
+{
+    tx_cache.with_esdt_token(token_identifier, |token_data| {
+        let Some(token_data) = token_data else {
+            return Ok(());
+        };
+        if &token_data.owner != caller {
+            return Err("can be called by owner only");
+        }
+        if !property(token_data) {
+            return Err(property_err_msg);
+        }
+        Ok(())
+    })
+}
+
+pub(super) fn check_token_owner(
+    tx_cache: &TxCache,
+    caller: &VMAddress,
+    token_identifier: &[u8],
+) -> Result<(), &'static str> {
+    check_token_owner_and_property(tx_cache, caller, token_identifier, |_| true, "")
+}
@@ -0,0 +1,222 @@
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    types::{top_decode_u64, VMAddress, VMTokenType},
+};
+
+use super::system_sc_checks::*;
+
+/// Freezes the tokens held by an account, for all nonces.
+pub fn freeze(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    toggle_freeze(tx_input, tx_cache, true)
+}
+
+pub fn unfreeze(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    toggle_freeze(tx_input, tx_cache, false)
+}
+
+/// Freezes a single NFT/SFT/meta-ESDT nonce held by an account.
+pub fn freeze_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    toggle_freeze_single_nft(tx_input, tx_cache, true)
+}
+
+pub fn unfreeze_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    toggle_freeze_single_nft(tx_input, tx_cache, false)
+}
+
+/// Removes the balance of an account that was previously frozen.
+pub fn wipe(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 2) {
+        return error_result(err_msg);
+    }
+    let token_identifier = tx_input.args[0].clone();
+    let address = match arg_address(&tx_input, 1) {
+        Ok(address) => address,
+        Err(err_msg) => return error_result(err_msg),
+    };
+
+    perform_wipe(tx_input, tx_cache, token_identifier, 0, address)
+}
+
+/// Removes a single frozen NFT/SFT/meta-ESDT nonce from an account.
+pub fn wipe_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 3) {
+        return error_result(err_msg);
+    }
+    let token_identifier = tx_input.args[0].clone();
+    let nonce = top_decode_u64(tx_input.args[1].as_slice());
+    let address = match arg_address(&tx_input, 2) {
+        Ok(address) => address,
+        Err(err_msg) => return error_result(err_msg),
+    };
+    if let Err(err_msg) = check_nft_nonce(&tx_cache, &token_identifier, nonce) {
+        return error_result(err_msg);
+    }
+
+    perform_wipe(tx_input, tx_cache, token_identifier, nonce, address)
+}
+
+/// Suspends all transfers of the token.
+pub fn pause(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    toggle_pause(tx_input, tx_cache, true)
+}
+
+pub fn unpause(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    toggle_pause(tx_input, tx_cache, false)
+}
+
+fn toggle_freeze(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    frozen: bool,
+) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 2) {
+        return error_result(err_msg);
+    }
+    let token_identifier = tx_input.args[0].clone();
+    let address = match arg_address(&tx_input, 1) {
+        Ok(address) => address,
+        Err(err_msg) => return error_result(err_msg),
+    };
+
+    set_frozen(tx_input, tx_cache, token_identifier, 0, address, frozen)
+}
+
+fn toggle_freeze_single_nft(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    frozen: bool,
+) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 3) {
+        return error_result(err_msg);
+    }
+    let token_identifier = tx_input.args[0].clone();
+    let nonce = top_decode_u64(tx_input.args[1].as_slice());
+    let address = match arg_address(&tx_input, 2) {
+        Ok(address) => address,
+        Err(err_msg) => return error_result(err_msg),
+    };
+    if let Err(err_msg) = check_nft_nonce(&tx_cache, &token_identifier, nonce) {
+        return error_result(err_msg);
+    }
+
+    set_frozen(tx_input, tx_cache, token_identifier, nonce, address, frozen)
+}
+
+fn set_frozen(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    token_identifier: Vec<u8>,
+    nonce: u64,
+    address: VMAddress,
+    frozen: bool,
+) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_token_owner_and_property(
+        &tx_cache,
+        &tx_input.from,
+        &token_identifier,
+        |token_data| token_data.can_freeze,
+        "cannot freeze",
+    ) {
+        return error_result(err_msg);
+    }
+    if !account_exists(&tx_cache, &address) {
+        return error_result("account not found");
+    }
+
+    tx_cache.with_account_mut(&address, |account| {
+        account.esdt.set_frozen(&token_identifier, nonce, frozen);
+    });
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+fn perform_wipe(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    token_identifier: Vec<u8>,
+    nonce: u64,
+    address: VMAddress,
+) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_token_owner_and_property(
+        &tx_cache,
+        &tx_input.from,
+        &token_identifier,
+        |token_data| token_data.can_wipe,
+        "cannot wipe",
+    ) {
+        return error_result(err_msg);
+    }
+    if !account_exists(&tx_cache, &address) {
+        return error_result("account not found");
+    }
+
+    let wipe_result = tx_cache.with_account_mut(&address, |account| {
+        account.esdt.wipe(&token_identifier, nonce)
+    });
+    let wiped = match wipe_result {
+        Ok(wiped) => wiped,
+        Err(err_msg) => return error_result(err_msg),
+    };
+
+    tx_cache.with_esdt_token_mut(&token_identifier, |token_data| {
+        if let Some(token_data) = token_data {
+            token_data.burnt_value += wiped;
+            token_data.num_wiped += 1;
+        }
+    });
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+fn toggle_pause(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    paused: bool,
+) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 1) {
+        return error_result(err_msg);
+    }
+    let token_identifier = tx_input.args[0].as_slice();
+
+    let result = tx_cache.with_esdt_token_mut(token_identifier, |token_data| {
+        let token_data = token_data.ok_or("no ticker with given name")?;
+        if token_data.owner != tx_input.from {
+            return Err("can be called by owner only");
+        }
+        if !token_data.can_pause {
+            return Err("cannot pause/un-pause");
+        }
+        if token_data.is_paused == paused {
+            return Err(if paused {
+                "cannot pause an already paused contract"
+            } else {
+                "cannot unPause an already un-paused contract"
+            });
+        }
+        token_data.is_paused = paused;
+        Ok(())
+    });
+    if let Err(err_msg) = result {
+        return error_result(err_msg);
+    }
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+/// Single NFT operations only make sense for tokens with nonces.
+fn check_nft_nonce(
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+    nonce: u64,
+) -> Result<(), &'static str> {
+    if nonce == 0 {
+        return Err("invalid nonce");
+    }
+    let is_fungible = tx_cache.with_esdt_token(token_identifier, |token_data| {
+        token_data.is_some_and(|token_data| token_data.token_type == VMTokenType::Fungible)
+    });
+    if is_fungible {
+        return Err("only non fungible tokens can be frozen/wiped per nonce");
+    }
+    Ok(())
+}
@@ -1,13 +1,16 @@
 use num_bigint::BigUint;
+use num_traits::Zero;
 
 use crate::{
     crypto_functions::keccak256,
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::{top_decode_u64, VMTokenType},
+    world_mock::{EsdtInstanceMetadata, EsdtSystemScData},
 };
 
-/// Issues a new fungible token.
-#[allow(unused_variables)]
+use super::system_sc_checks::*;
+
+/// Issues a new fungible token, the initial supply goes to the caller.
 pub fn issue(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 4 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
@@ -18,12 +21,49 @@ pub fn issue(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdat
     let total_supply = BigUint::from_bytes_be(tx_input.args[2].clone().as_ref());
     let decimals = top_decode_u64(tx_input.args[3].clone().as_ref()) as u32;
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::Fungible)
+    let mut token_data = EsdtSystemScData::new(
+        tx_input.from.clone(),
+        name,
+        ticker.clone(),
+        VMTokenType::Fungible,
+        decimals,
+    );
+    if let Err(err_msg) = set_properties(&mut token_data, &tx_input.args[4..]) {
+        return error_result(err_msg);
+    }
+    token_data.minted_value = total_supply.clone();
+
+    let token_identifier =
+        register_and_set_roles(&tx_input, &tx_cache, ticker, VMTokenType::Fungible);
+    if total_supply > BigUint::zero() {
+        tx_cache.increase_esdt_balance(
+            &tx_input.from,
+            &token_identifier,
+            0,
+            &total_supply,
+            EsdtInstanceMetadata::default(),
+        );
+    }
+    tx_cache.insert_esdt_token(token_identifier.clone(), token_data);
+
+    token_identifier_result(token_identifier, tx_cache)
 }
 
 /// Issues a new semi-fungible token.
-#[allow(unused_variables)]
 pub fn issue_semi_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    issue_non_fungible_type(tx_input, tx_cache, VMTokenType::SemiFungible)
+}
+
+/// Issues a new non-fungible token.
+pub fn issue_non_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    issue_non_fungible_type(tx_input, tx_cache, VMTokenType::NonFungible)
+}
+
+fn issue_non_fungible_type(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    token_type: VMTokenType,
+) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 2 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
         return (tx_result, BlockchainUpdate::empty());
@@ -31,24 +71,46 @@ pub fn issue_semi_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, B
     let name = tx_input.args[0].clone();
     let ticker = tx_input.args[1].clone();
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::SemiFungible)
+    let mut token_data =
+        EsdtSystemScData::new(tx_input.from.clone(), name, ticker.clone(), token_type, 0);
+    if let Err(err_msg) = set_properties(&mut token_data, &tx_input.args[2..]) {
+        return error_result(err_msg);
+    }
+
+    let token_identifier = register_and_set_roles(&tx_input, &tx_cache, ticker, token_type);
+    tx_cache.insert_esdt_token(token_identifier.clone(), token_data);
+
+    token_identifier_result(token_identifier, tx_cache)
 }
 
-/// Issues a new non-fungible token.
-#[allow(unused_variables)]
-pub fn issue_non_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    if tx_input.args.len() < 2 {
+/// Issues a new meta-ESDT token, i.e. a semi-fungible token with decimals.
+pub fn register_meta_esdt(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() < 3 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
         return (tx_result, BlockchainUpdate::empty());
     }
     let name = tx_input.args[0].clone();
     let ticker = tx_input.args[1].clone();
+    let decimals = top_decode_u64(tx_input.args[2].clone().as_ref()) as u32;
+
+    let mut token_data = EsdtSystemScData::new(
+        tx_input.from.clone(),
+        name,
+        ticker.clone(),
+        VMTokenType::Meta,
+        decimals,
+    );
+    if let Err(err_msg) = set_properties(&mut token_data, &tx_input.args[3..]) {
+        return error_result(err_msg);
+    }
+
+    let token_identifier = register_and_set_roles(&tx_input, &tx_cache, ticker, VMTokenType::Meta);
+    tx_cache.insert_esdt_token(token_identifier.clone(), token_data);
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::NonFungible)
+    token_identifier_result(token_identifier, tx_cache)
 }
 
 // Issues a new token and sets all roles for its type.
-#[allow(unused_variables)]
 pub fn register_and_set_all_roles(
     tx_input: TxInput,
     tx_cache: TxCache,
@@ -63,15 +125,83 @@ pub fn register_and_set_all_roles(
     let token_type = VMTokenType::from_system_sc_arg(&tx_input.args[2]);
     let decimals = top_decode_u64(tx_input.args[3].clone().as_ref()) as u32;
 
-    register_and_set_roles(tx_input, tx_cache, ticker, token_type)
+    let token_data = EsdtSystemScData::new(
+        tx_input.from.clone(),
+        name,
+        ticker.clone(),
+        token_type,
+        decimals,
+    );
+
+    let token_identifier = register_and_set_roles(&tx_input, &tx_cache, ticker, token_type);
+    tx_cache.insert_esdt_token(token_identifier.clone(), token_data);
+
+    token_identifier_result(token_identifier, tx_cache)
 }
 
-fn register_and_set_roles(
+/// Converts a semi-fungible token to a meta-ESDT, setting its number of decimals.
+pub fn change_sft_to_meta_esdt(
     tx_input: TxInput,
     tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 2) {
+        return error_result(err_msg);
+    }
+    let token_identifier = tx_input.args[0].as_slice();
+    let decimals = top_decode_u64(tx_input.args[1].as_slice()) as u32;
+
+    let result = tx_cache.with_esdt_token_mut(token_identifier, |token_data| {
+        let token_data = token_data.ok_or("no ticker with given name")?;
+        if token_data.owner != tx_input.from {
+            return Err("can be called by owner only");
+        }
+        if token_data.token_type != VMTokenType::SemiFungible {
+            return Err("change can happen to semi fungible tokens only");
+        }
+        token_data.token_type = VMTokenType::Meta;
+        token_data.num_decimals = decimals;
+        Ok(())
+    });
+    if let Err(err_msg) = result {
+        return error_result(err_msg);
+    }
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+/// Properties come in name-value pairs, e.g. `canFreeze`, `true`.
+fn set_properties(
+    token_data: &mut EsdtSystemScData,
+    property_args: &[Vec<u8>],
+) -> Result<(), &'static str> {
+    let property_pairs = property_args.chunks_exact(2);
+    if !property_pairs.remainder().is_empty() {
+        return Err("odd number of property arguments");
+    }
+    for pair in property_pairs {
+        token_data.set_property(&pair[0], &pair[1])?;
+    }
+    Ok(())
+}
+
+fn token_identifier_result(
+    token_identifier: Vec<u8>,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let tx_result = TxResult {
+        result_values: vec![token_identifier],
+        ..Default::default()
+    };
+
+    (tx_result, tx_cache.into_blockchain_updates())
+}
+
+fn register_and_set_roles(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
     ticker: Vec<u8>,
     token_type: VMTokenType,
-) -> (TxResult, BlockchainUpdate) {
+) -> Vec<u8> {
     let mut new_token_identifiers = tx_cache.get_new_token_identifiers();
 
     let token_identifier = if let Some((i, ti)) =
@@ -80,7 +210,7 @@ fn register_and_set_roles(
         new_token_identifiers.remove(i);
         ti.into_bytes()
     } else {
-        generate_token_identifier_from_ticker(&tx_input, &tx_cache, &ticker)
+        generate_token_identifier_from_ticker(tx_input, tx_cache, &ticker)
     };
 
     tx_cache.with_account_mut(&tx_input.from, |account| {
@@ -90,12 +220,7 @@ fn register_and_set_roles(
     });
     tx_cache.set_new_token_identifiers(new_token_identifiers);
 
-    let tx_result = TxResult {
-        result_values: vec![token_identifier],
-        ..Default::default()
-    };
-
-    (tx_result, tx_cache.into_blockchain_updates())
+    token_identifier
 }
 
 fn first_token_identifier_with_ticker(
@@ -1,8 +1,14 @@
+use bech32::{ToBase32, Variant};
+
 use crate::{
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
-    types::VMAddress,
+    types::{VMAddress, VMTokenType},
 };
 
+use super::system_sc_checks::*;
+
+const ESDT_ROLE_NFT_CREATE: &[u8] = b"ESDTRoleNFTCreate";
+
 pub fn set_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 3 {
         return (
@@ -12,12 +18,306 @@ pub fn set_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Bloc
     }
 
     let token_identifier = tx_input.args[0].clone();
-    let address = VMAddress::from_slice(tx_input.args[1].as_slice());
-    let role = tx_input.args[2].clone();
+    let address = match arg_address(&tx_input, 1) {
+        Ok(address) => address,
+        Err(err_msg) => return error_result(err_msg),
+    };
+    let roles = &tx_input.args[2..];
+
+    if let Err(err_msg) = check_token_owner_and_property(
+        &tx_cache,
+        &tx_input.from,
+        &token_identifier,
+        |token_data| token_data.can_add_special_roles,
+        "cannot add special roles",
+    ) {
+        return error_result(err_msg);
+    }
+    let nft_create_stopped = tx_cache.with_esdt_token(&token_identifier, |token_data| {
+        token_data.is_some_and(|token_data| token_data.nft_create_stopped)
+    });
+    if nft_create_stopped && roles.iter().any(|role| role == ESDT_ROLE_NFT_CREATE) {
+        return error_result("cannot add NFT create role as NFT creation was stopped");
+    }
+    if !account_exists(&tx_cache, &address) {
+        return error_result("account not found");
+    }
+
+    tx_cache.with_account_mut(&address, |account| {
+        for role in roles {
+            account.esdt.add_special_role(&token_identifier, role);
+        }
+    });
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+pub fn unset_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 3) {
+        return error_result(err_msg);
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    let address = match arg_address(&tx_input, 1) {
+        Ok(address) => address,
+        Err(err_msg) => return error_result(err_msg),
+    };
+    let roles = &tx_input.args[2..];
+
+    if let Err(err_msg) = check_token_owner(&tx_cache, &tx_input.from, &token_identifier) {
+        return error_result(err_msg);
+    }
+    if !account_exists(&tx_cache, &address) {
+        return error_result("account not found");
+    }
 
     tx_cache.with_account_mut(&address, |account| {
-        account.esdt.set_special_role(&token_identifier, &role);
+        for role in roles {
+            account.esdt.unset_special_role(&token_identifier, role);
+        }
     });
 
     (TxResult::empty(), tx_cache.into_blockchain_updates())
 }
+
+/// Returns one result per address holding roles, formatted as `<bech32 address>:<role1>,<role2>,...`.
+pub fn get_special_roles(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 1) {
+        return error_result(err_msg);
+    }
+
+    let result_values = addresses_and_roles(&tx_cache, &tx_input.args[0])
+        .into_iter()
+        .map(|(address, roles)| {
+            let roles: Vec<String> = roles
+                .iter()
+                .map(|role| String::from_utf8_lossy(role).into_owned())
+                .collect();
+            format!("{}:{}", bech32_encode(&address), roles.join(",")).into_bytes()
+        })
+        .collect();
+
+    let tx_result = TxResult {
+        result_values,
+        ..Default::default()
+    };
+    (tx_result, tx_cache.into_blockchain_updates())
+}
+
+/// Returns, for each address holding roles, the address followed by its roles.
+pub fn get_all_addresses_and_roles(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 1) {
+        return error_result(err_msg);
+    }
+
+    let mut result_values = Vec::new();
+    for (address, roles) in addresses_and_roles(&tx_cache, &tx_input.args[0]) {
+        result_values.push(address.to_vec());
+        result_values.extend(roles);
+    }
+
+    let tx_result = TxResult {
+        result_values,
+        ..Default::default()
+    };
+    (tx_result, tx_cache.into_blockchain_updates())
+}
+
+/// Moves the NFT create role, together with the last created nonce, to another account.
+pub fn transfer_nft_create_role(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 3) {
+        return error_result(err_msg);
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    let old_address = match arg_address(&tx_input, 1) {
+        Ok(address) => address,
+        Err(err_msg) => return error_result(err_msg),
+    };
+    let new_address = match arg_address(&tx_input, 2) {
+        Ok(address) => address,
+        Err(err_msg) => return error_result(err_msg),
+    };
+
+    if let Err(err_msg) = check_token_owner_and_property(
+        &tx_cache,
+        &tx_input.from,
+        &token_identifier,
+        |token_data| token_data.can_transfer_nft_create_role,
+        "cannot transfer NFT create role",
+    ) {
+        return error_result(err_msg);
+    }
+    if let Err(err_msg) = check_not_fungible(&tx_cache, &token_identifier) {
+        return error_result(err_msg);
+    }
+    if !account_exists(&tx_cache, &old_address) || !account_exists(&tx_cache, &new_address) {
+        return error_result("account not found");
+    }
+
+    let last_nonce = tx_cache.with_account_mut(&old_address, |account| {
+        let esdt_data = account.esdt.get_mut_by_identifier(&token_identifier)?;
+        if !esdt_data.roles.contains(ESDT_ROLE_NFT_CREATE) {
+            return None;
+        }
+        esdt_data.roles.remove(ESDT_ROLE_NFT_CREATE);
+        Some(esdt_data.last_nonce)
+    });
+    let Some(last_nonce) = last_nonce else {
+        return error_result("address does not have the NFT create role");
+    };
+
+    tx_cache.with_account_mut(&new_address, |account| {
+        account
+            .esdt
+            .add_special_role(&token_identifier, ESDT_ROLE_NFT_CREATE);
+        let esdt_data = account
+            .esdt
+            .get_mut_by_identifier(&token_identifier)
+            .unwrap();
+        esdt_data.last_nonce = esdt_data.last_nonce.max(last_nonce);
+    });
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+/// Permanently removes the NFT create role from all accounts.
+pub fn stop_nft_create(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 1) {
+        return error_result(err_msg);
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    if let Err(err_msg) = check_token_owner(&tx_cache, &tx_input.from, &token_identifier) {
+        return error_result(err_msg);
+    }
+    if let Err(err_msg) = check_not_fungible(&tx_cache, &token_identifier) {
+        return error_result(err_msg);
+    }
+
+    let already_stopped = tx_cache.with_esdt_token_mut(&token_identifier, |token_data| {
+        let Some(token_data) = token_data else {
+            return false;
+        };
+        let already_stopped = token_data.nft_create_stopped;
+        token_data.nft_create_stopped = true;
+        already_stopped
+    });
+    if already_stopped {
+        return error_result("NFT create was already stopped");
+    }
+
+    for (address, roles) in addresses_and_roles(&tx_cache, &token_identifier) {
+        if roles.iter().any(|role| role == ESDT_ROLE_NFT_CREATE) {
+            tx_cache.with_account_mut(&address, |account| {
+                account
+                    .esdt
+                    .unset_special_role(&token_identifier, ESDT_ROLE_NFT_CREATE);
+            });
+        }
+    }
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+pub fn set_burn_role_globally(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    toggle_burn_role_globally(tx_input, tx_cache, true)
+}
+
+pub fn unset_burn_role_globally(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    toggle_burn_role_globally(tx_input, tx_cache, false)
+}
+
+/// On the real chain this propagates the transfer role addresses to all shards.
+/// There is only one shard in the VM mock, so there is nothing to send.
+pub fn send_all_transfer_role_addresses(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 1) {
+        return error_result(err_msg);
+    }
+    if let Err(err_msg) = check_token_owner(&tx_cache, &tx_input.from, &tx_input.args[0]) {
+        return error_result(err_msg);
+    }
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+fn toggle_burn_role_globally(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    burn_role_for_all: bool,
+) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 1) {
+        return error_result(err_msg);
+    }
+
+    let result = tx_cache.with_esdt_token_mut(&tx_input.args[0], |token_data| {
+        let token_data = token_data.ok_or("no ticker with given name")?;
+        if token_data.owner != tx_input.from {
+            return Err("can be called by owner only");
+        }
+        if token_data.burn_role_for_all == burn_role_for_all {
+            return Err(if burn_role_for_all {
+                "cannot set burn role globally as it was already set"
+            } else {
+                "cannot unset burn role globally as it was not set"
+            });
+        }
+        token_data.burn_role_for_all = burn_role_for_all;
+        Ok(())
+    });
+    if let Err(err_msg) = result {
+        return error_result(err_msg);
+    }
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+fn check_not_fungible(tx_cache: &TxCache, token_identifier: &[u8]) -> Result<(), &'static str> {
+    let is_fungible = tx_cache.with_esdt_token(token_identifier, |token_data| {
+        token_data.is_some_and(|token_data| token_data.token_type == VMTokenType::Fungible)
+    });
+    if is_fungible {
+        return Err("invalid operation for fungible tokens");
+    }
+    Ok(())
+}
+
+/// All accounts holding at least one role for the token, in address order.
+fn addresses_and_roles(
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+) -> Vec<(VMAddress, Vec<Vec<u8>>)> {
+    tx_cache
+        .account_addresses()
+        .into_iter()
+        .filter_map(|address| {
+            let roles =
+                tx_cache.with_account(&address, |account| account.esdt.get_roles(token_identifier));
+            if roles.is_empty() {
+                None
+            } else {
+                Some((address, roles))
+            }
+        })
+        .collect()
+}
+
+fn bech32_encode(address: &VMAddress) -> String {
+    bech32::encode("erd", address.as_bytes().to_base32(), Variant::Bech32)
+        .expect("bech32 encode error")
+}
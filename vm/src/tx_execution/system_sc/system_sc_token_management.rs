@@ -0,0 +1,237 @@
+use num_traits::Zero;
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    types::VMTokenType,
+    world_mock::EsdtInstanceMetadata,
+};
+
+use super::system_sc_checks::*;
+
+/// Mints more fungible tokens, to the caller or to an optional destination.
+pub fn mint(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 2) {
+        return error_result(err_msg);
+    }
+    let token_identifier = tx_input.args[0].clone();
+    let amount = arg_big_uint(&tx_input, 1);
+    let destination = if tx_input.args.len() > 2 {
+        match arg_address(&tx_input, 2) {
+            Ok(address) => address,
+            Err(err_msg) => return error_result(err_msg),
+        }
+    } else {
+        tx_input.from.clone()
+    };
+
+    if amount.is_zero() {
+        return error_result("negative or zero mint value");
+    }
+    let result = tx_cache.with_esdt_token_mut(&token_identifier, |token_data| {
+        let token_data = token_data.ok_or("no ticker with given name")?;
+        if token_data.owner != tx_input.from {
+            return Err("can be called by owner only");
+        }
+        if !token_data.can_mint {
+            return Err("token is not mintable");
+        }
+        if token_data.token_type != VMTokenType::Fungible {
+            return Err("only fungible tokens can be minted");
+        }
+        token_data.minted_value += &amount;
+        Ok(())
+    });
+    if let Err(err_msg) = result {
+        return error_result(err_msg);
+    }
+    if !account_exists(&tx_cache, &destination) {
+        return error_result("account not found");
+    }
+
+    tx_cache.increase_esdt_balance(
+        &destination,
+        &token_identifier,
+        0,
+        &amount,
+        EsdtInstanceMetadata::default(),
+    );
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+/// Burns fungible tokens held by the caller.
+pub fn esdt_burn(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 2) {
+        return error_result(err_msg);
+    }
+    let token_identifier = tx_input.args[0].clone();
+    let amount = arg_big_uint(&tx_input, 1);
+
+    let can_burn = tx_cache.with_esdt_token(&token_identifier, |token_data| match token_data {
+        Some(token_data) => token_data.can_burn || token_data.burn_role_for_all,
+        None => true,
+    });
+    if !can_burn {
+        return error_result("token is not burnable");
+    }
+
+    if let Err(err) = tx_cache.subtract_esdt_balance(&tx_input.from, &token_identifier, 0, &amount)
+    {
+        return (TxResult::from_panic_obj(&err), BlockchainUpdate::empty());
+    }
+    tx_cache.with_esdt_token_mut(&token_identifier, |token_data| {
+        if let Some(token_data) = token_data {
+            token_data.burnt_value += &amount;
+        }
+    });
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+pub fn transfer_ownership(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 2) {
+        return error_result(err_msg);
+    }
+    let new_owner = match arg_address(&tx_input, 1) {
+        Ok(address) => address,
+        Err(err_msg) => return error_result(err_msg),
+    };
+
+    let result = tx_cache.with_esdt_token_mut(&tx_input.args[0], |token_data| {
+        let token_data = token_data.ok_or("no ticker with given name")?;
+        if token_data.owner != tx_input.from {
+            return Err("can be called by owner only");
+        }
+        if !token_data.can_change_owner {
+            return Err("cannot change owner of the token");
+        }
+        token_data.owner = new_owner;
+        Ok(())
+    });
+    if let Err(err_msg) = result {
+        return error_result(err_msg);
+    }
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+/// Changes the token properties, given as name-value pairs.
+pub fn control_changes(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 3) {
+        return error_result(err_msg);
+    }
+    let property_pairs = tx_input.args[1..].chunks_exact(2);
+    if !property_pairs.remainder().is_empty() {
+        return error_result("odd number of property arguments");
+    }
+
+    let result = tx_cache.with_esdt_token_mut(&tx_input.args[0], |token_data| {
+        let token_data = token_data.ok_or("no ticker with given name")?;
+        if token_data.owner != tx_input.from {
+            return Err("can be called by owner only");
+        }
+        if !token_data.can_upgrade {
+            return Err("token is not upgradable");
+        }
+        let mut new_token_data = token_data.clone();
+        for pair in property_pairs {
+            new_token_data.set_property(&pair[0], &pair[1])?;
+        }
+        *token_data = new_token_data;
+        Ok(())
+    });
+    if let Err(err_msg) = result {
+        return error_result(err_msg);
+    }
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+/// Same output layout as the system SC on the real chain.
+pub fn get_token_properties(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 1) {
+        return error_result(err_msg);
+    }
+
+    let result_values = tx_cache.with_esdt_token(&tx_input.args[0], |token_data| {
+        token_data.map(|token_data| {
+            vec![
+                token_data.token_name.clone(),
+                token_data.token_type_name().as_bytes().to_vec(),
+                token_data.owner.to_vec(),
+                token_data.minted_value.to_string().into_bytes(),
+                token_data.burnt_value.to_string().into_bytes(),
+                format!("NumDecimals-{}", token_data.num_decimals).into_bytes(),
+                format!("IsPaused-{}", token_data.is_paused).into_bytes(),
+                format!("CanUpgrade-{}", token_data.can_upgrade).into_bytes(),
+                format!("CanMint-{}", token_data.can_mint).into_bytes(),
+                format!("CanBurn-{}", token_data.can_burn).into_bytes(),
+                format!("CanChangeOwner-{}", token_data.can_change_owner).into_bytes(),
+                format!("CanPause-{}", token_data.can_pause).into_bytes(),
+                format!("CanFreeze-{}", token_data.can_freeze).into_bytes(),
+                format!("CanWipe-{}", token_data.can_wipe).into_bytes(),
+                format!("CanAddSpecialRoles-{}", token_data.can_add_special_roles).into_bytes(),
+                format!(
+                    "CanTransferNFTCreateRole-{}",
+                    token_data.can_transfer_nft_create_role
+                )
+                .into_bytes(),
+                format!("NFTCreateStopped-{}", token_data.nft_create_stopped).into_bytes(),
+                format!("NumWiped-{}", token_data.num_wiped).into_bytes(),
+            ]
+        })
+    });
+    let Some(result_values) = result_values else {
+        return error_result("no ticker with given name");
+    };
+
+    let tx_result = TxResult {
+        result_values,
+        ..Default::default()
+    };
+    (tx_result, tx_cache.into_blockchain_updates())
+}
+
+pub fn change_to_multi_shard_create(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    if let Err(err_msg) = check_num_args(&tx_input, 1) {
+        return error_result(err_msg);
+    }
+
+    let result = tx_cache.with_esdt_token_mut(&tx_input.args[0], |token_data| {
+        let token_data = token_data.ok_or("no ticker with given name")?;
+        if token_data.owner != tx_input.from {
+            return Err("can be called by owner only");
+        }
+        if token_data.token_type == VMTokenType::Fungible {
+            return Err("invalid operation for fungible tokens");
+        }
+        if token_data.can_create_multi_shard {
+            return Err("it is already multi shard create");
+        }
+        token_data.can_create_multi_shard = true;
+        Ok(())
+    });
+    if let Err(err_msg) = result {
+        return error_result(err_msg);
+    }
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+/// Governance of the system SC itself is not modelled in the VM mock.
+pub fn claim(_tx_input: TxInput, _tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    error_result("claim is not supported by the ESDT system SC mock")
+}
+
+/// Governance of the system SC itself is not modelled in the VM mock.
+pub fn config_change(_tx_input: TxInput, _tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    error_result("configChange is not supported by the ESDT system SC mock")
+}
+
+/// Governance of the system SC itself is not modelled in the VM mock.
+pub fn get_contract_config(_tx_input: TxInput, _tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    error_result("getContractConfig is not supported by the ESDT system SC mock")
+}
@@ -1,135 +0,0 @@
-#![allow(unused_variables)]
-
-use crate::tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult};
-
-/// Every unimplemented fn will be implemented and moved to its corresponding file.
-/// This file will be deleted.
-
-pub fn register_meta_esdt(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn change_sft_to_meta_esdt(
-    tx_input: TxInput,
-    tx_cache: TxCache,
-) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn esdt_burn(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn mint(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn freeze(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn unfreeze(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn wipe(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn pause(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn unpause(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn freeze_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn unfreeze_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn wipe_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn claim(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn config_change(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn control_changes(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn transfer_ownership(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn get_token_properties(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn get_special_roles(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn unset_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn transfer_nft_create_role(
-    tx_input: TxInput,
-    tx_cache: TxCache,
-) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn stop_nft_create(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn get_all_addresses_and_roles(
-    tx_input: TxInput,
-    tx_cache: TxCache,
-) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn get_contract_config(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn change_to_multi_shard_create(
-    tx_input: TxInput,
-    tx_cache: TxCache,
-) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn set_burn_role_globally(
-    tx_input: TxInput,
-    tx_cache: TxCache,
-) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn unset_burn_role_globally(
-    tx_input: TxInput,
-    tx_cache: TxCache,
-) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn send_all_transfer_role_addresses(
-    tx_input: TxInput,
-    tx_cache: TxCache,
-) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
@@ -11,6 +11,8 @@ mod tx_cache_source;
 mod tx_context;
 mod tx_context_ref;
 mod tx_context_stack;
+mod tx_cross_shard_call;
+mod tx_gas_payer;
 mod tx_input;
 mod tx_input_call_type;
 mod tx_input_function;
@@ -30,6 +32,8 @@ pub use tx_cache_source::*;
 pub use tx_context::*;
 pub use tx_context_ref::*;
 pub use tx_context_stack::*;
+pub use tx_cross_shard_call::*;
+pub use tx_gas_payer::*;
 pub use tx_input::*;
 pub use tx_input_call_type::CallType;
 pub use tx_input_function::*;
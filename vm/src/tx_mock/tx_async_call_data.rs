@@ -42,7 +42,7 @@ fn result_status_bytes(result_status: u64) -> Vec<u8> {
     }
 }
 
-fn real_recipient(
+pub fn real_recipient(
     async_data: &AsyncCallTxData,
     builtin_functions: &BuiltinFunctionContainer,
 ) -> VMAddress {
@@ -120,12 +120,15 @@ pub fn async_promise_callback_tx_input(
 }
 
 pub fn merge_results(mut original: TxResult, mut new: TxResult) -> TxResult {
-    if original.result_status == 0 {
+    let gas_used = original.gas_used + new.gas_used;
+    let mut merged = if original.result_status == 0 {
         original.result_values.append(&mut new.result_values);
         original.result_logs.append(&mut new.result_logs);
         original.result_message = new.result_message;
         original
     } else {
         new
-    }
+    };
+    merged.gas_used = gas_used;
+    merged
 }
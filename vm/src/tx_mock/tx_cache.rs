@@ -9,7 +9,7 @@ use colored::Colorize;
 use crate::{
     display_util::address_hex,
     types::VMAddress,
-    world_mock::{AccountData, BlockchainState},
+    world_mock::{AccountData, BlockchainState, EsdtSystemScData},
 };
 
 use super::{BlockchainUpdate, TxCacheSource};
@@ -18,6 +18,7 @@ pub struct TxCache {
     source_ref: Arc<dyn TxCacheSource>,
     pub(super) accounts: Mutex<HashMap<VMAddress, AccountData>>,
     pub(super) new_token_identifiers: Mutex<Option<Vec<String>>>,
+    pub(super) esdt_tokens: Mutex<HashMap<Vec<u8>, EsdtSystemScData>>,
 }
 
 impl fmt::Debug for TxCache {
@@ -34,6 +35,7 @@ impl TxCache {
             source_ref,
             accounts: Mutex::new(HashMap::new()),
             new_token_identifiers: Mutex::new(None),
+            esdt_tokens: Mutex::new(HashMap::new()),
         }
     }
 
@@ -85,6 +87,18 @@ impl TxCache {
         f(account)
     }
 
+    /// Addresses of all accounts, sorted, so the iteration order is deterministic.
+    pub fn account_addresses(&self) -> Vec<VMAddress> {
+        let mut addresses = self.source_ref.account_addresses();
+        for address in self.accounts.lock().unwrap().keys() {
+            if !addresses.contains(address) {
+                addresses.push(address.clone());
+            }
+        }
+        addresses.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
+        addresses
+    }
+
     pub fn insert_account(&self, account_data: AccountData) {
         self.accounts
             .lock()
@@ -119,6 +133,41 @@ impl TxCache {
             })
     }
 
+    fn load_esdt_token_if_necessary(&self, token_identifier: &[u8]) {
+        let mut esdt_tokens_mut = self.esdt_tokens.lock().unwrap();
+        if !esdt_tokens_mut.contains_key(token_identifier) {
+            if let Some(token_data) = self.source_ref.load_esdt_token(token_identifier) {
+                esdt_tokens_mut.insert(token_identifier.to_vec(), token_data);
+            }
+        }
+    }
+
+    /// Provides the system SC data of a token, if the token was registered.
+    pub fn with_esdt_token<R, F>(&self, token_identifier: &[u8], f: F) -> R
+    where
+        F: FnOnce(Option<&EsdtSystemScData>) -> R,
+    {
+        self.load_esdt_token_if_necessary(token_identifier);
+        let esdt_tokens = self.esdt_tokens.lock().unwrap();
+        f(esdt_tokens.get(token_identifier))
+    }
+
+    pub fn with_esdt_token_mut<R, F>(&self, token_identifier: &[u8], f: F) -> R
+    where
+        F: FnOnce(Option<&mut EsdtSystemScData>) -> R,
+    {
+        self.load_esdt_token_if_necessary(token_identifier);
+        let mut esdt_tokens = self.esdt_tokens.lock().unwrap();
+        f(esdt_tokens.get_mut(token_identifier))
+    }
+
+    pub fn insert_esdt_token(&self, token_identifier: Vec<u8>, token_data: EsdtSystemScData) {
+        self.esdt_tokens
+            .lock()
+            .unwrap()
+            .insert(token_identifier, token_data);
+    }
+
     pub fn get_new_token_identifiers(&self) -> Vec<String> {
         self.blockchain_ref().get_new_token_identifiers()
     }
@@ -131,10 +180,12 @@ impl TxCache {
         BlockchainUpdate {
             accounts: self.accounts.into_inner().unwrap(),
             new_token_identifiers: self.new_token_identifiers.into_inner().unwrap(),
+            esdt_tokens: self.esdt_tokens.into_inner().unwrap(),
         }
     }
 
     pub fn commit_updates(&self, updates: BlockchainUpdate) {
         self.accounts.lock().unwrap().extend(updates.accounts);
+        self.esdt_tokens.lock().unwrap().extend(updates.esdt_tokens);
     }
 }
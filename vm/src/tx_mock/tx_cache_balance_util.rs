@@ -110,11 +110,41 @@ impl TxCache {
         value: &BigUint,
     ) -> Result<(), TxPanic> {
         if !is_system_sc_address(from) && !is_system_sc_address(to) {
+            self.check_esdt_transferable(from, to, esdt_token_identifier, nonce)?;
             let metadata = self.subtract_esdt_balance(from, esdt_token_identifier, nonce, value)?;
             self.increase_esdt_balance(to, esdt_token_identifier, nonce, value, metadata);
         }
         Ok(())
     }
+
+    /// Paused tokens cannot be transferred at all,
+    /// frozen tokens cannot leave or enter the frozen account.
+    fn check_esdt_transferable(
+        &self,
+        from: &VMAddress,
+        to: &VMAddress,
+        esdt_token_identifier: &[u8],
+        nonce: u64,
+    ) -> Result<(), TxPanic> {
+        let is_paused = self.with_esdt_token(esdt_token_identifier, |token_data| {
+            token_data.is_some_and(|token_data| token_data.is_paused)
+        });
+        if is_paused {
+            return Err(TxPanic::vm_error("esdt token is paused"));
+        }
+
+        for address in [from, to] {
+            let is_frozen = self.with_account_or_else(
+                address,
+                |account| account.esdt.is_frozen(esdt_token_identifier, nonce),
+                || false,
+            );
+            if is_frozen {
+                return Err(TxPanic::vm_error("account is frozen for this esdt token"));
+            }
+        }
+        Ok(())
+    }
 }
 
 fn err_insufficient_funds() -> TxPanic {
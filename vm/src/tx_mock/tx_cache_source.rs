@@ -1,6 +1,6 @@
 use crate::{
     types::VMAddress,
-    world_mock::{AccountData, BlockchainState},
+    world_mock::{AccountData, BlockchainState, EsdtSystemScData},
 };
 
 use super::TxCache;
@@ -8,6 +8,11 @@ use super::TxCache;
 pub trait TxCacheSource: Send + Sync {
     fn load_account(&self, address: &VMAddress) -> Option<AccountData>;
 
+    fn load_esdt_token(&self, token_identifier: &[u8]) -> Option<EsdtSystemScData>;
+
+    /// All account addresses known at this level, including the ones of the parent sources.
+    fn account_addresses(&self) -> Vec<VMAddress>;
+
     fn blockchain_ref(&self) -> &BlockchainState;
 }
 
@@ -16,6 +21,14 @@ impl TxCacheSource for TxCache {
         Some(self.with_account(address, AccountData::clone))
     }
 
+    fn load_esdt_token(&self, token_identifier: &[u8]) -> Option<EsdtSystemScData> {
+        self.with_esdt_token(token_identifier, |token_data| token_data.cloned())
+    }
+
+    fn account_addresses(&self) -> Vec<VMAddress> {
+        self.account_addresses()
+    }
+
     fn blockchain_ref(&self) -> &BlockchainState {
         self.blockchain_ref()
     }
@@ -26,6 +39,14 @@ impl TxCacheSource for BlockchainState {
         self.accounts.get(address).map(AccountData::clone)
     }
 
+    fn load_esdt_token(&self, token_identifier: &[u8]) -> Option<EsdtSystemScData> {
+        self.esdt_tokens.get(token_identifier).cloned()
+    }
+
+    fn account_addresses(&self) -> Vec<VMAddress> {
+        self.accounts.keys().cloned().collect()
+    }
+
     fn blockchain_ref(&self) -> &BlockchainState {
         self
     }
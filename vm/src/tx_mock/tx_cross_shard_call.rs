@@ -0,0 +1,83 @@
+use num_bigint::BigUint;
+
+use crate::{tx_execution::BuiltinFunctionContainer, world_mock::EsdtInstanceMetadata};
+
+use super::{
+    async_callback_tx_input, async_promise_callback_tx_input, AsyncCallTxData, GasPayer, Promise,
+    TxInput, TxResult, TxTokenTransfer,
+};
+
+/// An async call that crosses shards: either a legacy async call, or a promise.
+#[derive(Clone, Debug)]
+pub enum CrossShardAsyncCall {
+    Legacy(AsyncCallTxData),
+    Promise(Promise),
+}
+
+impl CrossShardAsyncCall {
+    pub fn call_data(&self) -> &AsyncCallTxData {
+        match self {
+            CrossShardAsyncCall::Legacy(async_data) => async_data,
+            CrossShardAsyncCall::Promise(promise) => &promise.call,
+        }
+    }
+
+    /// Promises can be fire-and-forget, legacy async calls always call back.
+    pub fn has_callback(&self) -> bool {
+        match self {
+            CrossShardAsyncCall::Legacy(_) => true,
+            CrossShardAsyncCall::Promise(promise) => promise.has_callback(),
+        }
+    }
+
+    pub fn callback_tx_input(
+        &self,
+        async_result: &TxResult,
+        builtin_functions: &BuiltinFunctionContainer,
+    ) -> TxInput {
+        match self {
+            CrossShardAsyncCall::Legacy(async_data) => {
+                async_callback_tx_input(async_data, async_result, builtin_functions)
+            },
+            CrossShardAsyncCall::Promise(promise) => {
+                async_promise_callback_tx_input(promise, async_result, builtin_functions)
+            },
+        }
+    }
+}
+
+/// The EGLD and ESDT transfers of a cross-shard call, which left the caller when the call was sent.
+///
+/// The metadata of the tokens is kept, so that they can be handed back as they were.
+#[derive(Clone, Debug, Default)]
+pub struct CallValueInTransit {
+    pub egld_value: BigUint,
+    pub esdt_transfers: Vec<(TxTokenTransfer, EsdtInstanceMetadata)>,
+}
+
+/// Work that was sent to another shard, waiting for the next round.
+///
+/// The gas left is refunded to the payer of the original transaction once it is done.
+#[derive(Clone, Debug)]
+pub enum CrossShardCall {
+    /// The async call, to be executed in the shard of the recipient.
+    ///
+    /// The call value already left the caller, it is carried along until the call is executed.
+    AsyncCall {
+        async_call: CrossShardAsyncCall,
+        call_value_in_transit: CallValueInTransit,
+        gas_left: u64,
+        gas_payer: GasPayer,
+    },
+
+    /// The callback, to be executed back in the shard of the caller.
+    ///
+    /// If the call failed, its call value is carried back, and returned to the caller just before the callback.
+    Callback {
+        async_call: CrossShardAsyncCall,
+        async_result: Box<TxResult>,
+        call_value_in_transit: CallValueInTransit,
+        gas_left: u64,
+        gas_payer: GasPayer,
+    },
+}
@@ -0,0 +1,19 @@
+use crate::types::VMAddress;
+
+use super::TxInput;
+
+/// The sender of the original transaction, who pays for the gas of all its async calls and callbacks.
+#[derive(Clone, Debug)]
+pub struct GasPayer {
+    pub address: VMAddress,
+    pub gas_price: u64,
+}
+
+impl GasPayer {
+    pub fn from_tx_input(tx_input: &TxInput) -> Self {
+        GasPayer {
+            address: tx_input.from.clone(),
+            gas_price: tx_input.gas_price,
+        }
+    }
+}
@@ -23,6 +23,13 @@ pub struct TxInput {
     pub tx_hash: H256,
     pub promise_callback_closure_data: Option<Vec<u8>>,
     pub callback_payments: CallbackPayments,
+
+    /// Only set for `executeOnSameContext`: the code of this contract runs
+    /// in the context (storage, balance) of the recipient.
+    pub code_address: Option<VMAddress>,
+
+    /// Read-only calls, and all calls nested within them, cannot modify the state.
+    pub readonly: bool,
 }
 
 impl Default for TxInput {
@@ -40,6 +47,8 @@ impl Default for TxInput {
             tx_hash: H256::zero(),
             promise_callback_closure_data: None,
             callback_payments: Default::default(),
+            code_address: None,
+            readonly: false,
         }
     }
 }
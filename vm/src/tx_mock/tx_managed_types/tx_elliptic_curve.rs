@@ -0,0 +1,13 @@
+use crate::{crypto_functions::EllipticCurve, types::RawHandle};
+
+use super::TxManagedTypes;
+
+impl TxManagedTypes {
+    pub fn ec_new(&mut self, curve: EllipticCurve) -> RawHandle {
+        self.elliptic_curve_map.insert_new_handle_raw(curve)
+    }
+
+    pub fn ec_get(&self, handle: RawHandle) -> EllipticCurve {
+        self.elliptic_curve_map.get(handle).clone()
+    }
+}
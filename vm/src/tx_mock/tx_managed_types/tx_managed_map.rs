@@ -31,4 +31,22 @@ impl TxManagedTypes {
         let mmap = self.managed_map_map.get_mut(map_handle);
         mmap.remove(key).unwrap_or_default()
     }
+
+    /// Number of keys present, i.e. with non-empty values.
+    pub fn mm_len(&self, map_handle: RawHandle) -> usize {
+        let mmap = self.managed_map_map.get(map_handle);
+        mmap.values().filter(|value| !value.is_empty()).count()
+    }
+
+    /// The keys present, in ascending byte order, so that iterating is deterministic.
+    pub fn mm_keys(&self, map_handle: RawHandle) -> Vec<Vec<u8>> {
+        let mmap = self.managed_map_map.get(map_handle);
+        let mut keys: Vec<Vec<u8>> = mmap
+            .iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(key, _)| key.clone())
+            .collect();
+        keys.sort();
+        keys
+    }
 }
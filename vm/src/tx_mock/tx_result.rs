@@ -1,5 +1,7 @@
 use std::fmt;
 
+use crate::vm_err_msg;
+
 use super::{AsyncCallTxData, TxLog, TxPanic, TxResultCalls};
 
 #[derive(Clone, Debug)]
@@ -19,6 +21,9 @@ pub struct TxResult {
     ///
     /// Is never cleared of its contents.
     pub all_calls: Vec<AsyncCallTxData>,
+
+    /// Only computed when gas metering is enabled, 0 otherwise.
+    pub gas_used: u64,
 }
 
 impl Default for TxResult {
@@ -30,6 +35,7 @@ impl Default for TxResult {
             result_logs: Vec::new(),
             pending_calls: TxResultCalls::empty(),
             all_calls: Vec::new(),
+            gas_used: 0,
         }
     }
 }
@@ -78,6 +84,16 @@ impl TxResult {
         }
     }
 
+    /// Running out of gas consumes the entire gas limit.
+    pub fn from_out_of_gas(gas_limit: u64) -> Self {
+        TxResult {
+            result_status: 5,
+            result_message: vm_err_msg::OUT_OF_GAS.to_string(),
+            gas_used: gas_limit,
+            ..Default::default()
+        }
+    }
+
     pub fn merge_after_sync_call(&mut self, sync_call_result: &TxResult) {
         self.result_values
             .extend_from_slice(sync_call_result.result_values.as_slice());
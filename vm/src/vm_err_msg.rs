@@ -5,12 +5,39 @@ pub const BIG_INT_BITWISE_OPERATION_NEGATIVE: &str =
     "bitwise operations only allowed on positive integers";
 pub const DIVISION_BY_0: &str = "division by 0";
 pub const BAD_BOUNDS_LOWER: &str = "bad bounds (lower)";
+pub const BAD_BOUNDS_UPPER: &str = "bad bounds (upper)";
+pub const NEGATIVE_LENGTH: &str = "negative length";
 pub const EXPONENT_IS_POSITIVE: &str = "exponent must be negative";
 pub const NUMBER_IS_NOT_NORMAL: &str =
     "number is not normal. It is either infinite, NaN or subnormal";
 pub const CANNOT_COMPARE_VALUES: &str = "values are not comparable";
+pub const INFINITY_FLOAT_OPERATION: &str = "infinity operations are not allowed";
+pub const BIG_FLOAT_DECODE_ERROR: &str = "cannot decode big float";
 
 pub const ERROR_SIGNALLED_BY_SMARTCONTRACT: &str = "error signalled by smartcontract";
 
+pub const OUT_OF_GAS: &str = "out of gas";
+pub const INSUFFICIENT_GAS_LIMIT: &str = "insufficient gas limit in tx";
+
+pub const FUNCTION_NOT_FOUND: &str = "invalid function (not found)";
+pub const EXECUTION_FAILED: &str = "execution failed";
+
+pub const ARGUMENT_OUT_OF_RANGE: &str = "argument out of range";
+pub const INVALID_TOKEN_INDEX: &str = "invalid token index";
+pub const STORAGE_VALUE_OUT_OF_RANGE: &str = "storage value out of range";
+pub const INVALID_CALL_DATA: &str = "invalid call data";
+pub const ASYNC_CONTEXT_CALLBACK_NOT_SUPPORTED: &str = "async context callbacks are not supported";
+
+pub const CANNOT_WRITE_ON_READ_ONLY: &str = "cannot write on read only mode";
+pub const INVALID_CALL_ON_READ_ONLY_MODE: &str = "operation not permitted in read only mode";
+pub const BUILTIN_CALL_ON_SAME_CONTEXT_DISALLOWED: &str =
+    "calling built-in function on the same context is disallowed";
+pub const CONTRACT_NOT_FOUND: &str = "contract not found";
+pub const INVALID_ADDRESS: &str = "invalid address";
+
 pub const ERROR_NO_CALLBACK_CLOSURE: &str =
     "no callback for closure, cannot call callback directly";
+
+pub const EC_UNSUPPORTED_CURVE: &str = "elliptic curve not supported";
+pub const EC_POINT_NOT_ON_CURVE: &str = "point is not on curve";
+pub const EC_BUFFER_LENGTH_NOT_CORRECT: &str = "length of buffer is not correct";
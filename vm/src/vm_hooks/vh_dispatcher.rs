@@ -1,23 +1,327 @@
-use std::ffi::c_void;
+use std::{cell::Cell, ffi::c_void};
 
 use multiversx_chain_vm_executor::{MemLength, MemPtr, VMHooks};
 
-use crate::mem_conv;
+use crate::{
+    crypto_functions::{self, MessageHashType},
+    mem_conv,
+    schedule::GasSection,
+    tx_mock::{TxFunctionName, TxTokenTransfer},
+    types::{top_decode_u64, RawHandle, VMAddress, VMCodeMetadata},
+    vm_err_msg,
+};
+use num_bigint::BigUint;
+use num_traits::Zero;
 
 use super::VMHooksHandler;
 
+/// Memory layout constants of the legacy (non-managed) hooks.
+const ADDRESS_LEN: MemLength = 32;
+const BALANCE_LEN: usize = 32;
+const CODE_METADATA_LEN: MemLength = 2;
+const BLS_PUBLIC_KEY_LEN: MemLength = 96;
+const BLS_SIGNATURE_LEN: MemLength = 48;
+const ED25519_PUBLIC_KEY_LEN: MemLength = 32;
+const ED25519_SIGNATURE_LEN: MemLength = 64;
+
+/// Legacy token types, as reported by `getESDTTokenType`.
+const ESDT_TYPE_FUNGIBLE: i32 = 0;
+const ESDT_TYPE_NON_FUNGIBLE: i32 = 1;
+
+/// The token data fields that the legacy hooks copy to contract memory.
+struct EsdtTokenDataBytes {
+    properties: Vec<u8>,
+    hash: Vec<u8>,
+    name: Vec<u8>,
+    attributes: Vec<u8>,
+    creator: Vec<u8>,
+    uris: Vec<Vec<u8>>,
+}
+
 /// Dispatches messages coming via VMHooks to the underlying implementation (the VMHooksHandler).
 #[derive(Debug)]
 pub struct VMHooksDispatcher {
     handler: Box<dyn VMHooksHandler>,
+    memory_bounds: Cell<Option<(MemPtr, MemPtr)>>,
 }
 
 impl VMHooksDispatcher {
     pub fn new(handler: Box<dyn VMHooksHandler>) -> Self {
-        VMHooksDispatcher { handler }
+        VMHooksDispatcher {
+            handler,
+            memory_bounds: Cell::new(None),
+        }
+    }
+
+    /// Restricts the memory that the hooks can access to `start..end`.
+    ///
+    /// Executors set this before each hook call, since memory can move when it grows.
+    /// Without it, pointers are trusted as they are.
+    pub fn set_memory_bounds(&self, start: MemPtr, end: MemPtr) {
+        self.memory_bounds.set(Some((start, end)));
+    }
+
+    /// Charges the cost of a VM hook, as configured in the gas schedule.
+    fn use_gas(&self, section: GasSection, key: &str) {
+        if let Some(gas_schedule) = self.handler.gas_schedule() {
+            self.handler.use_gas(gas_schedule.cost(section, key));
+        }
+    }
+}
+
+/// Helpers for the legacy hooks, which work with contract memory instead of managed types.
+///
+/// Where the logic is non-trivial, they reuse the managed implementation via temporary handles.
+impl VMHooksDispatcher {
+    /// Stops execution if the region does not lie entirely within the memory bounds, when these are known.
+    fn check_memory_bounds(&self, offset: MemPtr, length: MemLength) {
+        let Some((start, end)) = self.memory_bounds.get() else {
+            return;
+        };
+        if length < 0 {
+            self.handler.vm_error(vm_err_msg::NEGATIVE_LENGTH);
+        }
+        if offset < start {
+            self.handler.vm_error(vm_err_msg::BAD_BOUNDS_LOWER);
+        }
+        match offset.checked_add(length) {
+            Some(region_end) if region_end <= end => {},
+            _ => self.handler.vm_error(vm_err_msg::BAD_BOUNDS_UPPER),
+        }
+    }
+
+    /// Same as `mem_conv::with_bytes`, but within the memory bounds.
+    unsafe fn with_bytes<F, R>(&self, offset: MemPtr, length: MemLength, f: F) -> R
+    where
+        F: FnOnce(&[u8]) -> R,
+    {
+        self.check_memory_bounds(offset, length);
+        mem_conv::with_bytes(offset, length, f)
+    }
+
+    /// Same as `mem_conv::with_bytes_mut`, but within the memory bounds.
+    unsafe fn with_bytes_mut<F, R>(&self, offset: MemPtr, length: MemLength, f: F) -> R
+    where
+        F: FnOnce(&mut [u8]) -> R,
+    {
+        self.check_memory_bounds(offset, length);
+        mem_conv::with_bytes_mut(offset, length, f)
+    }
+
+    /// Copies the bytes to contract memory, returns their length.
+    fn store_bytes(&self, offset: MemPtr, bytes: &[u8]) -> i32 {
+        unsafe {
+            self.with_bytes_mut(offset, bytes.len() as MemLength, |dest| {
+                dest.copy_from_slice(bytes);
+            });
+        }
+        bytes.len() as i32
+    }
+
+    fn load_bytes(&self, offset: MemPtr, length: MemLength) -> Vec<u8> {
+        unsafe { self.with_bytes(offset, length, |bytes| bytes.to_vec()) }
+    }
+
+    fn load_address(&self, offset: MemPtr) -> VMAddress {
+        VMAddress::from_slice(&self.load_bytes(offset, ADDRESS_LEN))
+    }
+
+    /// EGLD and ESDT values are passed as 32 bytes, big endian.
+    fn load_balance_value(&self, offset: MemPtr) -> BigUint {
+        BigUint::from_bytes_be(&self.load_bytes(offset, BALANCE_LEN as MemLength))
+    }
+
+    fn load_code_metadata(&self, offset: MemPtr) -> VMCodeMetadata {
+        VMCodeMetadata::from(&self.load_bytes(offset, CODE_METADATA_LEN))
+    }
+
+    fn load_function_name(&self, offset: MemPtr, length: MemLength) -> TxFunctionName {
+        self.load_bytes(offset, length).into()
+    }
+
+    /// Arguments come as a list of lengths (i32, little endian), followed by all the data, concatenated.
+    fn load_arguments(
+        &self,
+        num_arguments: i32,
+        arguments_length_offset: MemPtr,
+        data_offset: MemPtr,
+    ) -> Vec<Vec<u8>> {
+        let lengths = self.load_bytes(
+            arguments_length_offset,
+            num_arguments.max(0) as MemLength * 4,
+        );
+        let mut offset = data_offset;
+        lengths
+            .chunks(4)
+            .map(|length_bytes| {
+                let length = i32::from_le_bytes(length_bytes.try_into().unwrap()) as MemLength;
+                let argument = self.load_bytes(offset, length);
+                offset += length;
+                argument
+            })
+            .collect()
+    }
+
+    /// The DER signature length is encoded in its second byte.
+    fn load_der_signature(&self, offset: MemPtr) -> Vec<u8> {
+        let length = self.load_bytes(offset + 1, 1)[0] as MemLength + 2;
+        self.load_bytes(offset, length)
+    }
+
+    /// Call data has the form `function@arg1@arg2...`, with hex-encoded arguments.
+    fn parse_call_data(&self, data: &[u8]) -> (TxFunctionName, Vec<Vec<u8>>) {
+        let mut parts = data.split(|b| *b == b'@');
+        let function = parts.next().unwrap_or_default();
+        let arguments = parts
+            .map(|part| {
+                hex::decode(part)
+                    .unwrap_or_else(|_| self.handler.vm_error(vm_err_msg::INVALID_CALL_DATA))
+            })
+            .collect();
+        (function.into(), arguments)
+    }
+
+    fn mb_new(&self, bytes: Vec<u8>) -> RawHandle {
+        self.handler.mb_new_from_bytes(&bytes)
+    }
+
+    fn mb_get(&self, handle: RawHandle) -> Vec<u8> {
+        self.handler.m_types_lock().mb_to_bytes(handle)
+    }
+
+    fn load_esdt_token_data(
+        &self,
+        address_offset: MemPtr,
+        token_id_offset: MemPtr,
+        token_id_len: MemLength,
+        nonce: i64,
+        value_handle: RawHandle,
+        royalties_handle: RawHandle,
+    ) -> EsdtTokenDataBytes {
+        let properties_handle = self.handler.mb_new_empty();
+        let hash_handle = self.handler.mb_new_empty();
+        let name_handle = self.handler.mb_new_empty();
+        let attributes_handle = self.handler.mb_new_empty();
+        let creator_handle = self.handler.mb_new_empty();
+        let uris_handle = self.handler.mb_new_empty();
+        self.handler.managed_get_esdt_token_data(
+            self.mb_new(self.load_bytes(address_offset, ADDRESS_LEN)),
+            self.mb_new(self.load_bytes(token_id_offset, token_id_len)),
+            nonce as u64,
+            value_handle,
+            properties_handle,
+            hash_handle,
+            name_handle,
+            attributes_handle,
+            creator_handle,
+            royalties_handle,
+            uris_handle,
+        );
+        EsdtTokenDataBytes {
+            properties: self.mb_get(properties_handle),
+            hash: self.mb_get(hash_handle),
+            name: self.mb_get(name_handle),
+            attributes: self.mb_get(attributes_handle),
+            creator: self.mb_get(creator_handle),
+            uris: self.handler.m_types_lock().mb_get_vec_of_bytes(uris_handle),
+        }
+    }
+
+    /// Zero values are not written at all.
+    fn store_esdt_value(&self, result_offset: MemPtr, index: i32) -> i32 {
+        let value = self
+            .handler
+            .esdt_transfer_by_index(index)
+            .filter(|transfer| !transfer.value.is_zero())
+            .map(|transfer| pad_balance(transfer.value.to_bytes_be()))
+            .unwrap_or_default();
+        self.store_bytes(result_offset, &value)
+    }
+
+    fn store_esdt_token_name(&self, result_offset: MemPtr, index: i32) -> i32 {
+        let token_name = self
+            .handler
+            .esdt_transfer_by_index(index)
+            .map(|transfer| transfer.token_identifier)
+            .unwrap_or_default();
+        self.store_bytes(result_offset, &token_name)
+    }
+
+    fn esdt_token_nonce(&self, index: i32) -> i64 {
+        self.handler
+            .esdt_transfer_by_index(index)
+            .map_or(0, |transfer| transfer.nonce as i64)
+    }
+
+    fn esdt_token_type(&self, index: i32) -> i32 {
+        match self.handler.esdt_transfer_by_index(index) {
+            Some(transfer) if transfer.nonce > 0 => ESDT_TYPE_NON_FUNGIBLE,
+            _ => ESDT_TYPE_FUNGIBLE,
+        }
+    }
+
+    /// Falls back to the EGLD value and an empty token name if there is no ESDT transfer.
+    fn store_call_value_token_name(
+        &self,
+        call_value_offset: MemPtr,
+        token_name_offset: MemPtr,
+        index: i32,
+    ) -> i32 {
+        let (call_value, token_name) = match self.handler.esdt_transfer_by_index(index) {
+            Some(transfer) => (transfer.value, transfer.token_identifier),
+            None => (self.handler.input_ref().received_egld().clone(), Vec::new()),
+        };
+        self.store_bytes(call_value_offset, &pad_balance(call_value.to_bytes_be()));
+        self.store_bytes(token_name_offset, &token_name)
+    }
+
+    fn load_esdt_call_value(&self, dest: RawHandle, index: i32) {
+        let value = self
+            .handler
+            .esdt_transfer_by_index(index)
+            .map(|transfer| transfer.value)
+            .unwrap_or_default();
+        self.handler
+            .bi_set_unsigned_bytes(dest, &value.to_bytes_be());
+    }
+
+    fn return_data(&self, result_id: i32) -> Vec<u8> {
+        let result_data = usize::try_from(result_id)
+            .ok()
+            .and_then(|index| self.handler.result_lock().result_values.get(index).cloned());
+        result_data.unwrap_or_else(|| self.handler.vm_error(vm_err_msg::ARGUMENT_OUT_OF_RANGE))
+    }
+}
+
+/// Hooks that the debugger offers on top of the executor `VMHooks` interface.
+///
+/// They have the same signatures as their wasm imports, `managedMapLen` and `managedMapKeys`.
+/// `tools/wasmi-imports-generator` registers all public methods of this block as wasmi imports.
+impl VMHooksDispatcher {
+    /// Number of keys in a managed map, not counting the ones with empty values.
+    pub fn managed_map_len(&self, map_handle: i32) -> i32 {
+        self.use_gas(GasSection::ManagedMapApiCost, "ManagedMapLen");
+        self.handler.mm_len(map_handle) as i32
+    }
+
+    /// Writes the keys of a managed map, in ascending byte order, as a `ManagedVec<ManagedBuffer>`.
+    pub fn managed_map_keys(&self, map_handle: i32, out_keys_handle: i32) -> i32 {
+        self.use_gas(GasSection::ManagedMapApiCost, "ManagedMapKeys");
+        self.handler.mm_keys(map_handle, out_keys_handle);
+        0
     }
 }
 
+/// Pads big endian values to the 32 bytes expected by the legacy hooks.
+fn pad_balance(value: Vec<u8>) -> Vec<u8> {
+    if value.len() >= BALANCE_LEN {
+        return value;
+    }
+    let mut padded = vec![0u8; BALANCE_LEN - value.len()];
+    padded.extend(value);
+    padded
+}
+
 fn bool_to_i32(b: bool) -> i32 {
     if b {
         1
@@ -31,47 +335,70 @@ impl VMHooks for VMHooksDispatcher {
     fn set_vm_hooks_ptr(&mut self, _vm_hooks_ptr: *mut c_void) {}
 
     fn get_gas_left(&self) -> i64 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetGasLeft");
         self.handler.get_gas_left() as i64
     }
 
     fn get_sc_address(&self, result_offset: MemPtr) {
-        panic!("Unavailable: get_sc_address");
+        self.use_gas(GasSection::BaseOpsApiCost, "GetSCAddress");
+        self.store_bytes(result_offset, self.handler.current_address().as_bytes());
     }
 
     fn get_owner_address(&self, result_offset: MemPtr) {
-        panic!("Unavailable: get_owner_address");
+        self.use_gas(GasSection::BaseOpsApiCost, "GetOwnerAddress");
+        let owner_handle = self.handler.mb_new_empty();
+        self.handler.managed_owner_address(owner_handle);
+        self.store_bytes(result_offset, &self.mb_get(owner_handle));
     }
 
     fn get_shard_of_address(&self, address_offset: MemPtr) -> i32 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetShardOfAddress");
         unsafe {
-            mem_conv::with_bytes(address_offset, 32, |address_bytes| {
+            self.with_bytes(address_offset, 32, |address_bytes| {
                 self.handler.get_shard_of_address(address_bytes)
             })
         }
     }
 
     fn is_smart_contract(&self, address_offset: MemPtr) -> i32 {
+        self.use_gas(GasSection::BaseOpsApiCost, "IsSmartContract");
         unsafe {
-            bool_to_i32(mem_conv::with_bytes(address_offset, 32, |address_bytes| {
+            bool_to_i32(self.with_bytes(address_offset, 32, |address_bytes| {
                 self.handler.is_smart_contract(address_bytes)
             }))
         }
     }
 
     fn signal_error(&self, message_offset: MemPtr, message_length: MemLength) {
+        self.use_gas(GasSection::BaseOpsApiCost, "SignalError");
         unsafe {
-            mem_conv::with_bytes(message_offset, message_length, |message| {
+            self.with_bytes(message_offset, message_length, |message| {
                 self.handler.signal_error(message);
             });
         }
     }
 
     fn get_external_balance(&self, address_offset: MemPtr, result_offset: MemPtr) {
-        panic!("Unavailable: get_external_balance");
+        self.use_gas(GasSection::BaseOpsApiCost, "GetExternalBalance");
+        let balance_handle = self.handler.bi_new(0);
+        unsafe {
+            self.with_bytes(address_offset, 32, |address_bytes| {
+                self.handler.load_balance(address_bytes, balance_handle);
+            })
+        }
+        let balance = self.handler.bi_get_unsigned_bytes(balance_handle);
+        self.store_bytes(result_offset, &pad_balance(balance));
     }
 
     fn get_block_hash(&self, nonce: i64, result_offset: MemPtr) -> i32 {
-        panic!("Unavailable: get_block_hash")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetBlockHash");
+        match self.handler.get_block_hash(nonce as u64) {
+            Some(block_hash) => {
+                self.store_bytes(result_offset, block_hash.as_slice());
+                0
+            },
+            None => 1,
+        }
     }
 
     fn get_esdt_balance(
@@ -82,7 +409,24 @@ impl VMHooks for VMHooksDispatcher {
         nonce: i64,
         result_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: get_esdt_balance")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetExternalBalance");
+        let value_handle = self.handler.bi_new(0);
+        unsafe {
+            self.with_bytes(address_offset, 32, |address_bytes| {
+                self.with_bytes(token_id_offset, token_id_len, |token_id_bytes| {
+                    self.handler.big_int_get_esdt_external_balance(
+                        address_bytes,
+                        token_id_bytes,
+                        nonce as u64,
+                        value_handle,
+                    );
+                })
+            })
+        }
+        self.store_bytes(
+            result_offset,
+            &self.handler.bi_get_unsigned_bytes(value_handle),
+        )
     }
 
     fn get_esdt_nft_name_length(
@@ -92,7 +436,16 @@ impl VMHooks for VMHooksDispatcher {
         token_id_len: MemLength,
         nonce: i64,
     ) -> i32 {
-        panic!("Unavailable: get_esdt_nft_name_length")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetExternalBalance");
+        let token_data = self.load_esdt_token_data(
+            address_offset,
+            token_id_offset,
+            token_id_len,
+            nonce,
+            self.handler.bi_new(0),
+            self.handler.bi_new(0),
+        );
+        token_data.name.len() as i32
     }
 
     fn get_esdt_nft_attribute_length(
@@ -102,7 +455,16 @@ impl VMHooks for VMHooksDispatcher {
         token_id_len: MemLength,
         nonce: i64,
     ) -> i32 {
-        panic!("Unavailable: get_esdt_nft_attribute_length")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetExternalBalance");
+        let token_data = self.load_esdt_token_data(
+            address_offset,
+            token_id_offset,
+            token_id_len,
+            nonce,
+            self.handler.bi_new(0),
+            self.handler.bi_new(0),
+        );
+        token_data.attributes.len() as i32
     }
 
     fn get_esdt_nft_uri_length(
@@ -112,7 +474,16 @@ impl VMHooks for VMHooksDispatcher {
         token_id_len: MemLength,
         nonce: i64,
     ) -> i32 {
-        panic!("Unavailable: get_esdt_nft_uri_length")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetExternalBalance");
+        let token_data = self.load_esdt_token_data(
+            address_offset,
+            token_id_offset,
+            token_id_len,
+            nonce,
+            self.handler.bi_new(0),
+            self.handler.bi_new(0),
+        );
+        token_data.uris.first().map_or(0, |uri| uri.len() as i32)
     }
 
     fn get_esdt_token_data(
@@ -130,15 +501,34 @@ impl VMHooks for VMHooksDispatcher {
         royalties_handle: i32,
         uris_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: get_esdt_token_data")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetExternalBalance");
+        let token_data = self.load_esdt_token_data(
+            address_offset,
+            token_id_offset,
+            token_id_len,
+            nonce,
+            value_handle,
+            royalties_handle,
+        );
+        self.store_bytes(properties_offset, &token_data.properties);
+        self.store_bytes(hash_offset, &token_data.hash);
+        self.store_bytes(name_offset, &token_data.name);
+        self.store_bytes(attributes_offset, &token_data.attributes);
+        self.store_bytes(creator_offset, &token_data.creator);
+        if let Some(uri) = token_data.uris.first() {
+            self.store_bytes(uris_offset, uri);
+        }
+        self.handler.bi_unsigned_byte_length(value_handle) as i32
     }
 
     fn get_esdt_local_roles(&self, token_id_handle: i32) -> i64 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetStorageMetadata");
         self.handler.get_esdt_local_roles_bits(token_id_handle) as i64
     }
 
     fn validate_token_identifier(&self, token_id_handle: i32) -> i32 {
-        panic!("Unavailable: validate_token_identifier")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetArgument");
+        bool_to_i32(self.handler.validate_token_identifier(token_id_handle))
     }
 
     fn transfer_value(
@@ -148,7 +538,15 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: transfer_value")
+        self.use_gas(GasSection::BaseOpsApiCost, "TransferValue");
+        let (function, arguments) = self.parse_call_data(&self.load_bytes(data_offset, length));
+        self.handler.perform_transfer_execute(
+            self.load_address(dest_offset),
+            self.load_balance_value(value_offset),
+            function,
+            arguments,
+        );
+        0
     }
 
     fn transfer_value_execute(
@@ -162,7 +560,14 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: transfer_value_execute")
+        self.use_gas(GasSection::BaseOpsApiCost, "TransferValue");
+        self.handler.perform_transfer_execute(
+            self.load_address(dest_offset),
+            self.load_balance_value(value_offset),
+            self.load_function_name(function_offset, function_length),
+            self.load_arguments(num_arguments, arguments_length_offset, data_offset),
+        );
+        0
     }
 
     fn transfer_esdt_execute(
@@ -178,7 +583,16 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: transfer_esdt_execute")
+        self.use_gas(GasSection::BaseOpsApiCost, "TransferValue");
+        self.handler.perform_transfer_execute_esdt(
+            self.load_address(dest_offset),
+            self.load_bytes(token_id_offset, token_id_len),
+            self.load_balance_value(value_offset),
+            gas_limit as u64,
+            self.load_function_name(function_offset, function_length),
+            self.load_arguments(num_arguments, arguments_length_offset, data_offset),
+        );
+        0
     }
 
     fn transfer_esdt_nft_execute(
@@ -195,7 +609,17 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: transfer_esdt_nft_execute")
+        self.use_gas(GasSection::BaseOpsApiCost, "TransferValue");
+        self.handler.perform_transfer_execute_nft(
+            self.load_address(dest_offset),
+            self.load_bytes(token_id_offset, token_id_len),
+            nonce as u64,
+            self.load_balance_value(value_offset),
+            gas_limit as u64,
+            self.load_function_name(function_offset, function_length),
+            self.load_arguments(num_arguments, arguments_length_offset, data_offset),
+        );
+        0
     }
 
     fn multi_transfer_esdt_nft_execute(
@@ -211,7 +635,29 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: multi_transfer_esdt_nft_execute")
+        self.use_gas(GasSection::BaseOpsApiCost, "TransferValue");
+        // each transfer is given as 3 arguments: token identifier, nonce, value
+        let payments = self
+            .load_arguments(
+                num_token_transfers * 3,
+                token_transfers_args_length_offset,
+                token_transfer_data_offset,
+            )
+            .chunks(3)
+            .map(|transfer_args| TxTokenTransfer {
+                token_identifier: transfer_args[0].clone(),
+                nonce: top_decode_u64(&transfer_args[1]),
+                value: BigUint::from_bytes_be(&transfer_args[2]),
+            })
+            .collect();
+        self.handler.perform_multi_transfer_esdt_nft_execute(
+            self.load_address(dest_offset),
+            payments,
+            gas_limit as u64,
+            self.load_function_name(function_offset, function_length),
+            self.load_arguments(num_arguments, arguments_length_offset, data_offset),
+        );
+        0
     }
 
     fn create_async_call(
@@ -227,9 +673,23 @@ impl VMHooks for VMHooksDispatcher {
         gas: i64,
         extra_gas_for_callback: i64,
     ) -> i32 {
-        panic!("Unavailable: create_async_call")
+        self.use_gas(GasSection::BaseOpsApiCost, "CreateAsyncCall");
+        let (function, arguments) =
+            self.parse_call_data(&self.load_bytes(data_offset, data_length));
+        self.handler.register_promise(
+            self.load_address(dest_offset),
+            self.load_balance_value(value_offset),
+            function,
+            arguments,
+            &self.load_bytes(success_offset, success_length),
+            &self.load_bytes(error_offset, error_length),
+            Vec::new(),
+        );
+        0
     }
 
+    /// Deliberately not supported: no version of the framework imports it,
+    /// so contracts built with it never reach this hook.
     fn set_async_context_callback(
         &self,
         callback: MemPtr,
@@ -238,7 +698,9 @@ impl VMHooks for VMHooksDispatcher {
         data_length: MemLength,
         gas: i64,
     ) -> i32 {
-        panic!("Unavailable: set_async_context_callback")
+        self.use_gas(GasSection::BaseOpsApiCost, "SetAsyncContextCallback");
+        self.handler
+            .vm_error(vm_err_msg::ASYNC_CONTEXT_CALLBACK_NOT_SUPPORTED)
     }
 
     fn upgrade_contract(
@@ -253,7 +715,14 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) {
-        panic!("Unavailable: upgrade_contract");
+        self.use_gas(GasSection::BaseOpsApiCost, "CreateContract");
+        self.handler.perform_upgrade_contract(
+            self.load_address(dest_offset),
+            self.load_balance_value(value_offset),
+            self.load_bytes(code_offset, length),
+            self.load_code_metadata(code_metadata_offset),
+            self.load_arguments(num_arguments, arguments_length_offset, data_offset),
+        );
     }
 
     fn upgrade_from_source_contract(
@@ -267,7 +736,15 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) {
-        panic!("Unavailable: upgrade_from_source_contract");
+        self.use_gas(GasSection::BaseOpsApiCost, "CreateContract");
+        let source_contract_address = self.load_address(source_contract_address_offset);
+        self.handler.perform_upgrade_contract(
+            self.load_address(dest_offset),
+            self.load_balance_value(value_offset),
+            self.handler.account_code(&source_contract_address),
+            self.load_code_metadata(code_metadata_offset),
+            self.load_arguments(num_arguments, arguments_length_offset, data_offset),
+        );
     }
 
     fn delete_contract(
@@ -278,7 +755,11 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) {
-        panic!("Unavailable: delete_contract");
+        self.use_gas(GasSection::BaseOpsApiCost, "CreateContract");
+        self.handler.perform_delete_contract(
+            self.load_address(dest_offset),
+            self.load_arguments(num_arguments, arguments_length_offset, data_offset),
+        );
     }
 
     fn async_call(
@@ -288,22 +769,39 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) {
-        panic!("Unavailable: async_call");
+        self.use_gas(GasSection::BaseOpsApiCost, "AsyncCallStep");
+        let (function, arguments) = self.parse_call_data(&self.load_bytes(data_offset, length));
+        self.handler.perform_async_call(
+            self.load_address(dest_offset),
+            self.load_balance_value(value_offset),
+            function,
+            arguments,
+        );
     }
 
     fn get_argument_length(&self, id: i32) -> i32 {
-        panic!("Unavailable: get_argument_length")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetArgument");
+        self.handler.get_argument_len(id) as i32
     }
 
     fn get_argument(&self, id: i32, arg_offset: MemPtr) -> i32 {
-        panic!("Unavailable: get_argument")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetArgument");
+        self.store_bytes(
+            arg_offset,
+            &self.handler.input_ref().get_argument_vec_u8(id),
+        )
     }
 
     fn get_function(&self, function_offset: MemPtr) -> i32 {
-        panic!("Unavailable: get_function")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetFunction");
+        self.store_bytes(
+            function_offset,
+            self.handler.input_ref().func_name.as_str().as_bytes(),
+        )
     }
 
     fn get_num_arguments(&self) -> i32 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetNumArguments");
         self.handler.get_num_arguments()
     }
 
@@ -314,11 +812,16 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         data_length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: storage_store")
+        self.use_gas(GasSection::BaseOpsApiCost, "StorageStore");
+        let key = self.load_bytes(key_offset, key_length);
+        let value = self.load_bytes(data_offset, data_length);
+        self.handler.storage_store_with_status(&key, &value)
     }
 
     fn storage_load_length(&self, key_offset: MemPtr, key_length: MemLength) -> i32 {
-        panic!("Unavailable: storage_load_length")
+        self.use_gas(GasSection::BaseOpsApiCost, "StorageLoad");
+        let key = self.load_bytes(key_offset, key_length);
+        self.handler.storage_read(&key).len() as i32
     }
 
     fn storage_load_from_address(
@@ -328,11 +831,18 @@ impl VMHooks for VMHooksDispatcher {
         key_length: MemLength,
         data_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: storage_load_from_address")
+        self.use_gas(GasSection::BaseOpsApiCost, "StorageLoad");
+        let address = self.load_address(address_offset);
+        let key = self.load_bytes(key_offset, key_length);
+        let value = self.handler.storage_read_any_address(&address, &key);
+        self.store_bytes(data_offset, &value)
     }
 
     fn storage_load(&self, key_offset: MemPtr, key_length: MemLength, data_offset: MemPtr) -> i32 {
-        panic!("Unavailable: storage_load")
+        self.use_gas(GasSection::BaseOpsApiCost, "StorageLoad");
+        let key = self.load_bytes(key_offset, key_length);
+        let value = self.handler.storage_read(&key);
+        self.store_bytes(data_offset, &value)
     }
 
     fn set_storage_lock(
@@ -341,55 +851,73 @@ impl VMHooks for VMHooksDispatcher {
         key_length: MemLength,
         lock_timestamp: i64,
     ) -> i32 {
-        panic!("Unavailable: set_storage_lock")
+        self.use_gas(GasSection::BaseOpsApiCost, "StorageStore");
+        let key = self.load_bytes(key_offset, key_length);
+        self.handler.set_storage_lock(&key, lock_timestamp)
     }
 
     fn get_storage_lock(&self, key_offset: MemPtr, key_length: MemLength) -> i64 {
-        panic!("Unavailable: get_storage_lock")
+        self.use_gas(GasSection::BaseOpsApiCost, "StorageLoad");
+        let key = self.load_bytes(key_offset, key_length);
+        self.handler.get_storage_lock(&key)
     }
 
     fn is_storage_locked(&self, key_offset: MemPtr, key_length: MemLength) -> i32 {
-        panic!("Unavailable: is_storage_locked")
+        self.use_gas(GasSection::BaseOpsApiCost, "StorageLoad");
+        let key = self.load_bytes(key_offset, key_length);
+        self.handler.is_storage_locked(&key)
     }
 
     fn clear_storage_lock(&self, key_offset: MemPtr, key_length: MemLength) -> i32 {
-        panic!("Unavailable: clear_storage_lock")
+        self.use_gas(GasSection::BaseOpsApiCost, "StorageStore");
+        let key = self.load_bytes(key_offset, key_length);
+        self.handler.set_storage_lock(&key, 0)
     }
 
     fn get_caller(&self, result_offset: MemPtr) {
-        panic!("Unavailable: get_caller");
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCaller");
+        self.store_bytes(result_offset, self.handler.input_ref().from.as_bytes());
     }
 
     fn check_no_payment(&self) {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallValue");
         self.handler.check_not_payable();
     }
 
     fn get_call_value(&self, result_offset: MemPtr) -> i32 {
-        panic!("Unavailable: get_call_value")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallValue");
+        let value = self.handler.input_ref().received_egld().to_bytes_be();
+        self.store_bytes(result_offset, &pad_balance(value))
     }
 
     fn get_esdt_value(&self, result_offset: MemPtr) -> i32 {
-        panic!("Unavailable: get_esdt_value")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallValue");
+        self.store_esdt_value(result_offset, 0)
     }
 
     fn get_esdt_value_by_index(&self, result_offset: MemPtr, index: i32) -> i32 {
-        panic!("Unavailable: get_esdt_value_by_index")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallValue");
+        self.store_esdt_value(result_offset, index)
     }
 
     fn get_esdt_token_name(&self, result_offset: MemPtr) -> i32 {
-        panic!("Unavailable: get_esdt_token_name")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallValue");
+        self.store_esdt_token_name(result_offset, 0)
     }
 
     fn get_esdt_token_name_by_index(&self, result_offset: MemPtr, index: i32) -> i32 {
-        panic!("Unavailable: get_esdt_token_name_by_index")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallValue");
+        self.store_esdt_token_name(result_offset, index)
     }
 
     fn get_esdt_token_nonce(&self) -> i64 {
-        panic!("Unavailable: get_esdt_token_nonce")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallValue");
+        self.esdt_token_nonce(0)
     }
 
     fn get_esdt_token_nonce_by_index(&self, index: i32) -> i64 {
-        panic!("Unavailable: get_esdt_token_nonce_by_index")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallValue");
+        self.esdt_token_nonce(index)
     }
 
     fn get_current_esdt_nft_nonce(
@@ -398,9 +926,10 @@ impl VMHooks for VMHooksDispatcher {
         token_id_offset: MemPtr,
         token_id_len: MemLength,
     ) -> i64 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallValue");
         unsafe {
-            mem_conv::with_bytes(address_offset, 32, |address_bytes| {
-                mem_conv::with_bytes(token_id_offset, token_id_len, |token_id_bytes| {
+            self.with_bytes(address_offset, 32, |address_bytes| {
+                self.with_bytes(token_id_offset, token_id_len, |token_id_bytes| {
                     self.handler
                         .get_current_esdt_nft_nonce(address_bytes, token_id_bytes)
                         as i64
@@ -410,14 +939,17 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_esdt_token_type(&self) -> i32 {
-        panic!("Unavailable: get_esdt_token_type")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallValue");
+        self.esdt_token_type(0)
     }
 
     fn get_esdt_token_type_by_index(&self, index: i32) -> i32 {
-        panic!("Unavailable: get_esdt_token_type_by_index")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallValue");
+        self.esdt_token_type(index)
     }
 
     fn get_num_esdt_transfers(&self) -> i32 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallValue");
         self.handler.esdt_num_transfers() as i32
     }
 
@@ -426,7 +958,8 @@ impl VMHooks for VMHooksDispatcher {
         call_value_offset: MemPtr,
         token_name_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: get_call_value_token_name")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallValue");
+        self.store_call_value_token_name(call_value_offset, token_name_offset, 0)
     }
 
     fn get_call_value_token_name_by_index(
@@ -435,7 +968,8 @@ impl VMHooks for VMHooksDispatcher {
         token_name_offset: MemPtr,
         index: i32,
     ) -> i32 {
-        panic!("Unavailable: get_call_value_token_name_by_index")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallValue");
+        self.store_call_value_token_name(call_value_offset, token_name_offset, index)
     }
 
     fn write_log(
@@ -445,7 +979,13 @@ impl VMHooks for VMHooksDispatcher {
         topic_ptr: MemPtr,
         num_topics: i32,
     ) {
-        panic!("Unavailable: write_log");
+        self.use_gas(GasSection::BaseOpsApiCost, "Log");
+        // topics have a fixed length of 32 bytes
+        let topics = (0..num_topics as MemLength)
+            .map(|index| self.load_bytes(topic_ptr + index * 32, 32))
+            .collect();
+        self.handler
+            .write_log_bytes(topics, self.load_bytes(data_pointer, data_length));
     }
 
     fn write_event_log(
@@ -456,56 +996,86 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         data_length: MemLength,
     ) {
-        panic!("Unavailable: write_event_log");
+        self.use_gas(GasSection::BaseOpsApiCost, "Log");
+        let topics = self.load_arguments(num_topics, topic_lengths_offset, topic_offset);
+        self.handler
+            .write_log_bytes(topics, self.load_bytes(data_offset, data_length));
     }
 
     fn get_block_timestamp(&self) -> i64 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetBlockTimeStamp");
         self.handler.get_block_timestamp() as i64
     }
 
     fn get_block_nonce(&self) -> i64 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetBlockNonce");
         self.handler.get_block_nonce() as i64
     }
 
     fn get_block_round(&self) -> i64 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetBlockRound");
         self.handler.get_block_round() as i64
     }
 
     fn get_block_epoch(&self) -> i64 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetBlockEpoch");
         self.handler.get_block_epoch() as i64
     }
 
     fn get_block_random_seed(&self, pointer: MemPtr) {
-        panic!("Unavailable: get_block_random_seed");
+        self.use_gas(GasSection::BaseOpsApiCost, "GetBlockRandomSeed");
+        let seed = self
+            .handler
+            .get_current_block_info()
+            .block_random_seed
+            .clone();
+        self.store_bytes(pointer, seed.as_slice());
     }
 
     fn get_state_root_hash(&self, pointer: MemPtr) {
-        panic!("Unavailable: get_state_root_hash");
+        self.use_gas(GasSection::BaseOpsApiCost, "GetStateRootHash");
+        let state_root_hash = self
+            .handler
+            .get_current_block_info()
+            .state_root_hash
+            .clone();
+        self.store_bytes(pointer, state_root_hash.as_slice());
     }
 
     fn get_prev_block_timestamp(&self) -> i64 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetBlockTimeStamp");
         self.handler.get_prev_block_timestamp() as i64
     }
 
     fn get_prev_block_nonce(&self) -> i64 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetBlockNonce");
         self.handler.get_prev_block_nonce() as i64
     }
 
     fn get_prev_block_round(&self) -> i64 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetBlockRound");
         self.handler.get_prev_block_round() as i64
     }
 
     fn get_prev_block_epoch(&self) -> i64 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetBlockEpoch");
         self.handler.get_prev_block_epoch() as i64
     }
 
     fn get_prev_block_random_seed(&self, pointer: MemPtr) {
-        panic!("Unavailable: get_prev_block_random_seed");
+        self.use_gas(GasSection::BaseOpsApiCost, "GetBlockRandomSeed");
+        let seed = self
+            .handler
+            .get_previous_block_info()
+            .block_random_seed
+            .clone();
+        self.store_bytes(pointer, seed.as_slice());
     }
 
     fn finish(&self, pointer: MemPtr, length: MemLength) {
+        self.use_gas(GasSection::BaseOpsApiCost, "Finish");
         unsafe {
-            mem_conv::with_bytes(pointer, length, |bytes| {
+            self.with_bytes(pointer, length, |bytes| {
                 self.handler.finish_slice_u8(bytes);
             })
         }
@@ -522,7 +1092,14 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: execute_on_same_context")
+        self.use_gas(GasSection::BaseOpsApiCost, "ExecuteOnSameContext");
+        self.handler.perform_execute_on_same_context(
+            self.load_address(address_offset),
+            self.load_balance_value(value_offset),
+            self.load_function_name(function_offset, function_length),
+            self.load_arguments(num_arguments, arguments_length_offset, data_offset),
+        );
+        0
     }
 
     fn execute_on_dest_context(
@@ -536,7 +1113,14 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: execute_on_dest_context")
+        self.use_gas(GasSection::BaseOpsApiCost, "ExecuteOnDestContext");
+        self.handler.perform_execute_on_dest_context(
+            self.load_address(address_offset),
+            self.load_balance_value(value_offset),
+            self.load_function_name(function_offset, function_length),
+            self.load_arguments(num_arguments, arguments_length_offset, data_offset),
+        );
+        0
     }
 
     fn execute_read_only(
@@ -549,7 +1133,13 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: execute_read_only")
+        self.use_gas(GasSection::BaseOpsApiCost, "ExecuteReadOnly");
+        self.handler.perform_execute_read_only(
+            self.load_address(address_offset),
+            self.load_function_name(function_offset, function_length),
+            self.load_arguments(num_arguments, arguments_length_offset, data_offset),
+        );
+        0
     }
 
     fn create_contract(
@@ -564,7 +1154,15 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: create_contract")
+        self.use_gas(GasSection::BaseOpsApiCost, "CreateContract");
+        let (new_address, _) = self.handler.perform_deploy(
+            self.load_balance_value(value_offset),
+            self.load_bytes(code_offset, length),
+            self.load_code_metadata(code_metadata_offset),
+            self.load_arguments(num_arguments, arguments_length_offset, data_offset),
+        );
+        self.store_bytes(result_offset, new_address.as_bytes());
+        0
     }
 
     fn deploy_from_source_contract(
@@ -578,82 +1176,111 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: deploy_from_source_contract")
+        self.use_gas(GasSection::BaseOpsApiCost, "CreateContract");
+        let source_contract_address = self.load_address(source_contract_address_offset);
+        let (new_address, _) = self.handler.perform_deploy(
+            self.load_balance_value(value_offset),
+            self.handler.account_code(&source_contract_address),
+            self.load_code_metadata(code_metadata_offset),
+            self.load_arguments(num_arguments, arguments_length_offset, data_offset),
+        );
+        self.store_bytes(result_address_offset, new_address.as_bytes());
+        0
     }
 
     fn get_num_return_data(&self) -> i32 {
-        panic!("Unavailable: get_num_return_data")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetNumReturnData");
+        self.handler.result_lock().result_values.len() as i32
     }
 
     fn get_return_data_size(&self, result_id: i32) -> i32 {
-        panic!("Unavailable: get_return_data_size")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetReturnDataSize");
+        self.return_data(result_id).len() as i32
     }
 
     fn get_return_data(&self, result_id: i32, data_offset: MemPtr) -> i32 {
-        panic!("Unavailable: get_return_data")
+        self.use_gas(GasSection::BaseOpsApiCost, "GetReturnData");
+        self.store_bytes(data_offset, &self.return_data(result_id))
     }
 
     fn clean_return_data(&self) {
+        self.use_gas(GasSection::BaseOpsApiCost, "CleanReturnData");
         self.handler.clean_return_data();
     }
 
     fn delete_from_return_data(&self, result_id: i32) {
+        self.use_gas(GasSection::BaseOpsApiCost, "DeleteFromReturnData");
         self.handler.delete_from_return_data(result_id as usize);
     }
 
     fn get_original_tx_hash(&self, data_offset: MemPtr) {
-        panic!("Unavailable: get_original_tx_hash");
+        self.use_gas(GasSection::BaseOpsApiCost, "GetOriginalTxHash");
+        self.store_bytes(data_offset, self.handler.tx_hash().as_bytes());
     }
 
     fn get_current_tx_hash(&self, data_offset: MemPtr) {
-        panic!("Unavailable: get_current_tx_hash");
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCurrentTxHash");
+        self.store_bytes(data_offset, self.handler.tx_hash().as_bytes());
     }
 
     fn get_prev_tx_hash(&self, data_offset: MemPtr) {
-        panic!("Unavailable: get_prev_tx_hash");
+        self.use_gas(GasSection::BaseOpsApiCost, "GetPrevTxHash");
+        self.store_bytes(data_offset, self.handler.tx_hash().as_bytes());
     }
 
     fn managed_sc_address(&self, destination_handle: i32) {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetSCAddress");
         self.handler.managed_sc_address(destination_handle);
     }
 
     fn managed_owner_address(&self, destination_handle: i32) {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetOwnerAddress");
         self.handler.managed_owner_address(destination_handle);
     }
 
     fn managed_caller(&self, destination_handle: i32) {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCaller");
         self.handler.managed_caller(destination_handle);
     }
 
     fn managed_signal_error(&self, err_handle: i32) {
+        self.use_gas(GasSection::BaseOpsApiCost, "SignalError");
         self.handler.signal_error_from_buffer(err_handle);
     }
 
     fn managed_write_log(&self, topics_handle: i32, data_handle: i32) {
+        self.use_gas(GasSection::BaseOpsApiCost, "Log");
         self.handler.managed_write_log(topics_handle, data_handle);
     }
 
     fn managed_get_original_tx_hash(&self, result_handle: i32) {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetOriginalTxHash");
         self.handler.get_tx_hash(result_handle);
     }
 
     fn managed_get_state_root_hash(&self, result_handle: i32) {
-        panic!("Unavailable: managed_get_state_root_hash");
+        self.use_gas(GasSection::BaseOpsApiCost, "GetStateRootHash");
+        self.handler.get_state_root_hash(result_handle);
     }
 
     fn managed_get_block_random_seed(&self, result_handle: i32) {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetBlockRandomSeed");
         self.handler.get_block_random_seed(result_handle);
     }
 
     fn managed_get_prev_block_random_seed(&self, result_handle: i32) {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetBlockRandomSeed");
         self.handler.get_prev_block_random_seed(result_handle);
     }
 
     fn managed_get_return_data(&self, result_id: i32, result_handle: i32) {
-        panic!("Unavailable: managed_get_return_data");
+        self.use_gas(GasSection::BaseOpsApiCost, "GetReturnData");
+        self.handler
+            .mb_set(result_handle, &self.return_data(result_id));
     }
 
     fn managed_get_multi_esdt_call_value(&self, multi_call_value_handle: i32) {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallValue");
         self.handler
             .load_all_esdt_transfers(multi_call_value_handle)
     }
@@ -665,7 +1292,13 @@ impl VMHooks for VMHooksDispatcher {
         nonce: i64,
         value_handle: i32,
     ) {
-        panic!("Unavailable: managed_get_esdt_balance");
+        self.use_gas(GasSection::BaseOpsApiCost, "GetExternalBalance");
+        self.handler.big_int_get_esdt_external_balance(
+            &self.mb_get(address_handle),
+            &self.mb_get(token_id_handle),
+            nonce as u64,
+            value_handle,
+        );
     }
 
     fn managed_get_esdt_token_data(
@@ -682,6 +1315,7 @@ impl VMHooks for VMHooksDispatcher {
         royalties_handle: i32,
         uris_handle: i32,
     ) {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetExternalBalance");
         self.handler.managed_get_esdt_token_data(
             address_handle,
             token_id_handle,
@@ -698,6 +1332,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_get_back_transfers(&self, esdt_transfer_value_handle: i32, call_value_handle: i32) {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallValue");
         self.handler
             .managed_get_back_transfers(esdt_transfer_value_handle, call_value_handle);
     }
@@ -709,6 +1344,7 @@ impl VMHooks for VMHooksDispatcher {
         function_handle: i32,
         arguments_handle: i32,
     ) {
+        self.use_gas(GasSection::BaseOpsApiCost, "AsyncCallStep");
         self.handler
             .async_call_raw(dest_handle, value_handle, function_handle, arguments_handle)
     }
@@ -727,9 +1363,10 @@ impl VMHooks for VMHooksDispatcher {
         extra_gas_for_callback: i64,
         callback_closure_handle: i32,
     ) -> i32 {
+        self.use_gas(GasSection::BaseOpsApiCost, "CreateAsyncCall");
         unsafe {
-            mem_conv::with_bytes(success_offset, success_length, |success_callback| {
-                mem_conv::with_bytes(error_offset, error_length, |error_callback| {
+            self.with_bytes(success_offset, success_length, |success_callback| {
+                self.with_bytes(error_offset, error_length, |error_callback| {
                     self.handler.create_async_call_raw(
                         dest_handle,
                         value_handle,
@@ -748,6 +1385,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_get_callback_closure(&self, callback_closure_handle: i32) {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCallbackClosure");
         self.handler
             .load_callback_closure_buffer(callback_closure_handle)
     }
@@ -762,6 +1400,7 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         _result_handle: i32,
     ) {
+        self.use_gas(GasSection::BaseOpsApiCost, "CreateContract");
         self.handler.upgrade_from_source_contract(
             dest_handle,
             gas as u64,
@@ -782,6 +1421,7 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         _result_handle: i32,
     ) {
+        self.use_gas(GasSection::BaseOpsApiCost, "CreateContract");
         self.handler.upgrade_contract(
             dest_handle,
             gas as u64,
@@ -793,7 +1433,9 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_delete_contract(&self, dest_handle: i32, gas_limit: i64, arguments_handle: i32) {
-        panic!("Unavailable: managed_delete_contract");
+        self.use_gas(GasSection::BaseOpsApiCost, "CreateContract");
+        self.handler
+            .delete_contract(dest_handle, gas_limit as u64, arguments_handle);
     }
 
     fn managed_deploy_from_source_contract(
@@ -806,6 +1448,7 @@ impl VMHooks for VMHooksDispatcher {
         result_address_handle: i32,
         result_handle: i32,
     ) -> i32 {
+        self.use_gas(GasSection::BaseOpsApiCost, "CreateContract");
         self.handler.deploy_from_source_contract(
             gas as u64,
             value_handle,
@@ -828,6 +1471,7 @@ impl VMHooks for VMHooksDispatcher {
         result_address_handle: i32,
         result_handle: i32,
     ) -> i32 {
+        self.use_gas(GasSection::BaseOpsApiCost, "CreateContract");
         self.handler.deploy_contract(
             gas as u64,
             value_handle,
@@ -848,7 +1492,15 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_execute_read_only")
+        self.use_gas(GasSection::BaseOpsApiCost, "ExecuteReadOnly");
+        self.handler.execute_read_only_raw(
+            gas as u64,
+            address_handle,
+            function_handle,
+            arguments_handle,
+            result_handle,
+        );
+        0
     }
 
     fn managed_execute_on_same_context(
@@ -860,7 +1512,16 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_execute_on_same_context")
+        self.use_gas(GasSection::BaseOpsApiCost, "ExecuteOnSameContext");
+        self.handler.execute_on_same_context_raw(
+            gas as u64,
+            address_handle,
+            value_handle,
+            function_handle,
+            arguments_handle,
+            result_handle,
+        );
+        0
     }
 
     fn managed_execute_on_dest_context(
@@ -872,6 +1533,7 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         result_handle: i32,
     ) -> i32 {
+        self.use_gas(GasSection::BaseOpsApiCost, "ExecuteOnDestContext");
         self.handler.execute_on_dest_context_raw(
             gas as u64,
             address_handle,
@@ -891,6 +1553,7 @@ impl VMHooks for VMHooksDispatcher {
         function_handle: i32,
         arguments_handle: i32,
     ) -> i32 {
+        self.use_gas(GasSection::BaseOpsApiCost, "TransferValue");
         self.handler.multi_transfer_esdt_nft_execute(
             dst_handle,
             token_transfers_handle,
@@ -909,6 +1572,7 @@ impl VMHooks for VMHooksDispatcher {
         function_handle: i32,
         arguments_handle: i32,
     ) -> i32 {
+        self.use_gas(GasSection::BaseOpsApiCost, "TransferValue");
         self.handler.transfer_value_execute(
             dst_handle,
             value_handle,
@@ -920,6 +1584,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_is_esdt_frozen(&self, address_handle: i32, token_id_handle: i32, nonce: i64) -> i32 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetExternalBalance");
         bool_to_i32(
             self.handler
                 .check_esdt_frozen(address_handle, token_id_handle, nonce as u64),
@@ -927,23 +1592,28 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_is_esdt_limited_transfer(&self, _token_id_handle: i32) -> i32 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetExternalBalance");
         bool_to_i32(false)
     }
 
-    fn managed_is_esdt_paused(&self, _token_id_handle: i32) -> i32 {
-        bool_to_i32(false)
+    fn managed_is_esdt_paused(&self, token_id_handle: i32) -> i32 {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetExternalBalance");
+        bool_to_i32(self.handler.check_esdt_paused(token_id_handle))
     }
 
     fn managed_buffer_to_hex(&self, source_handle: i32, dest_handle: i32) {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferAppend");
         self.handler.mb_to_hex(source_handle, dest_handle);
     }
 
     fn managed_get_code_metadata(&self, address_handle: i32, response_handle: i32) {
+        self.use_gas(GasSection::BaseOpsApiCost, "GetCodeMetadata");
         self.handler
             .managed_get_code_metadata(address_handle, response_handle);
     }
 
     fn managed_is_builtin_function(&self, function_name_handle: i32) -> i32 {
+        self.use_gas(GasSection::BaseOpsApiCost, "IsBuiltinFunction");
         bool_to_i32(
             self.handler
                 .managed_is_builtin_function(function_name_handle),
@@ -956,104 +1626,132 @@ impl VMHooks for VMHooksDispatcher {
         fractional_part: i32,
         exponent: i32,
     ) -> i32 {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatNewFromParts");
         self.handler
             .bf_from_parts(integral_part, fractional_part, exponent)
     }
 
     fn big_float_new_from_frac(&self, numerator: i64, denominator: i64) -> i32 {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatNewFromParts");
         self.handler.bf_from_frac(numerator, denominator)
     }
 
     fn big_float_new_from_sci(&self, significand: i64, exponent: i64) -> i32 {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatNewFromParts");
         self.handler.bf_from_sci(significand, exponent)
     }
 
     fn big_float_add(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatAdd");
         self.handler
             .bf_add(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_sub(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatSub");
         self.handler
             .bf_sub(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_mul(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatMul");
         self.handler
             .bf_mul(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_div(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatDiv");
         self.handler
             .bf_div(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_neg(&self, destination_handle: i32, op_handle: i32) {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatNeg");
         self.handler.bf_neg(destination_handle, op_handle);
     }
 
     fn big_float_clone(&self, destination_handle: i32, op_handle: i32) {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatClone");
         self.handler.bf_clone(destination_handle, op_handle);
     }
 
     fn big_float_cmp(&self, op1_handle: i32, op2_handle: i32) -> i32 {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatCmp");
         self.handler.bf_cmp(op1_handle, op2_handle)
     }
 
     fn big_float_abs(&self, destination_handle: i32, op_handle: i32) {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatAbs");
         self.handler.bf_abs(destination_handle, op_handle);
     }
 
     fn big_float_sign(&self, op_handle: i32) -> i32 {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatCmp");
         self.handler.bf_sign(op_handle)
     }
 
     fn big_float_sqrt(&self, destination_handle: i32, op_handle: i32) {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatSqrt");
         self.handler.bf_sqrt(destination_handle, op_handle);
     }
 
     fn big_float_pow(&self, destination_handle: i32, op_handle: i32, exponent: i32) {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatPow");
         self.handler.bf_pow(destination_handle, op_handle, exponent);
     }
 
     fn big_float_floor(&self, dest_big_int_handle: i32, op_handle: i32) {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatFloor");
         self.handler.bf_floor(dest_big_int_handle, op_handle);
     }
 
     fn big_float_ceil(&self, dest_big_int_handle: i32, op_handle: i32) {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatCeil");
         self.handler.bf_ceil(dest_big_int_handle, op_handle);
     }
 
     fn big_float_truncate(&self, dest_big_int_handle: i32, op_handle: i32) {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatTruncate");
         self.handler.bf_trunc(dest_big_int_handle, op_handle);
     }
 
     fn big_float_set_int64(&self, destination_handle: i32, value: i64) {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatSetInt64");
         self.handler.bf_set_i64(destination_handle, value);
     }
 
     fn big_float_is_int(&self, op_handle: i32) -> i32 {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatIsInt");
         bool_to_i32(self.handler.bf_is_bi(op_handle))
     }
 
     fn big_float_set_big_int(&self, destination_handle: i32, big_int_handle: i32) {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatSetBigInt");
         self.handler.bf_set_bi(destination_handle, big_int_handle);
     }
 
     fn big_float_get_const_pi(&self, destination_handle: i32) {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatGetConst");
         self.handler.bf_get_const_pi(destination_handle);
     }
 
     fn big_float_get_const_e(&self, destination_handle: i32) {
+        self.use_gas(GasSection::BigFloatApiCost, "BigFloatGetConst");
         self.handler.bf_get_const_e(destination_handle);
     }
 
     fn big_int_get_unsigned_argument(&self, id: i32, destination_handle: i32) {
-        panic!("Unavailable: big_int_get_unsigned_argument");
+        self.use_gas(GasSection::BigIntApiCost, "BigIntGetUnsignedArgument");
+        let argument = self.handler.input_ref().get_argument_vec_u8(id);
+        self.handler
+            .bi_set_unsigned_bytes(destination_handle, &argument);
     }
 
     fn big_int_get_signed_argument(&self, id: i32, destination_handle: i32) {
-        panic!("Unavailable: big_int_get_signed_argument");
+        self.use_gas(GasSection::BigIntApiCost, "BigIntGetSignedArgument");
+        let argument = self.handler.input_ref().get_argument_vec_u8(id);
+        self.handler
+            .bi_set_signed_bytes(destination_handle, &argument);
     }
 
     fn big_int_storage_store_unsigned(
@@ -1062,7 +1760,10 @@ impl VMHooks for VMHooksDispatcher {
         key_length: MemLength,
         source_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: big_int_storage_store_unsigned")
+        self.use_gas(GasSection::BigIntApiCost, "BigIntStorageStoreUnsigned");
+        let key = self.load_bytes(key_offset, key_length);
+        let value = self.handler.bi_get_unsigned_bytes(source_handle);
+        self.handler.storage_store_with_status(&key, &value)
     }
 
     fn big_int_storage_load_unsigned(
@@ -1071,24 +1772,33 @@ impl VMHooks for VMHooksDispatcher {
         key_length: MemLength,
         destination_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: big_int_storage_load_unsigned")
+        self.use_gas(GasSection::BigIntApiCost, "BigIntStorageLoadUnsigned");
+        let key = self.load_bytes(key_offset, key_length);
+        let value = self.handler.storage_read(&key);
+        self.handler
+            .bi_set_unsigned_bytes(destination_handle, &value);
+        value.len() as i32
     }
 
     fn big_int_get_call_value(&self, destination_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntGetCallValue");
         self.handler.load_egld_value(destination_handle);
     }
 
     fn big_int_get_esdt_call_value(&self, destination: i32) {
-        panic!("Unavailable: big_int_get_esdt_call_value");
+        self.use_gas(GasSection::BigIntApiCost, "BigIntGetCallValue");
+        self.load_esdt_call_value(destination, 0);
     }
 
     fn big_int_get_esdt_call_value_by_index(&self, destination_handle: i32, index: i32) {
-        panic!("Unavailable: big_int_get_esdt_call_value_by_index");
+        self.use_gas(GasSection::BigIntApiCost, "BigIntGetCallValue");
+        self.load_esdt_call_value(destination_handle, index);
     }
 
     fn big_int_get_external_balance(&self, address_offset: MemPtr, result: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntGetExternalBalance");
         unsafe {
-            mem_conv::with_bytes(address_offset, 32, |address_bytes| {
+            self.with_bytes(address_offset, 32, |address_bytes| {
                 self.handler.load_balance(address_bytes, result);
             })
         }
@@ -1102,9 +1812,10 @@ impl VMHooks for VMHooksDispatcher {
         nonce: i64,
         result_handle: i32,
     ) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntGetExternalBalance");
         unsafe {
-            mem_conv::with_bytes(address_offset, 32, |address_bytes| {
-                mem_conv::with_bytes(token_id_offset, token_id_len, |token_id_bytes| {
+            self.with_bytes(address_offset, 32, |address_bytes| {
+                self.with_bytes(token_id_offset, token_id_len, |token_id_bytes| {
                     self.handler.big_int_get_esdt_external_balance(
                         address_bytes,
                         token_id_bytes,
@@ -1117,23 +1828,34 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_new(&self, small_value: i64) -> i32 {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntNew");
         self.handler.bi_new(small_value)
     }
 
     fn big_int_unsigned_byte_length(&self, reference_handle: i32) -> i32 {
-        panic!("Unavailable: big_int_unsigned_byte_length")
+        self.use_gas(GasSection::BigIntApiCost, "BigIntUnsignedByteLength");
+        self.handler.bi_unsigned_byte_length(reference_handle) as i32
     }
 
     fn big_int_signed_byte_length(&self, reference_handle: i32) -> i32 {
-        panic!("Unavailable: big_int_signed_byte_length")
+        self.use_gas(GasSection::BigIntApiCost, "BigIntSignedByteLength");
+        self.handler.bi_get_signed_bytes(reference_handle).len() as i32
     }
 
     fn big_int_get_unsigned_bytes(&self, reference_handle: i32, byte_offset: MemPtr) -> i32 {
-        panic!("Unavailable: big_int_get_unsigned_bytes")
+        self.use_gas(GasSection::BigIntApiCost, "BigIntGetUnsignedBytes");
+        self.store_bytes(
+            byte_offset,
+            &self.handler.bi_get_unsigned_bytes(reference_handle),
+        )
     }
 
     fn big_int_get_signed_bytes(&self, reference_handle: i32, byte_offset: MemPtr) -> i32 {
-        panic!("Unavailable: big_int_get_signed_bytes")
+        self.use_gas(GasSection::BigIntApiCost, "BigIntGetSignedBytes");
+        self.store_bytes(
+            byte_offset,
+            &self.handler.bi_get_signed_bytes(reference_handle),
+        )
     }
 
     fn big_int_set_unsigned_bytes(
@@ -1142,8 +1864,9 @@ impl VMHooks for VMHooksDispatcher {
         byte_offset: MemPtr,
         byte_length: MemLength,
     ) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntSetUnsignedBytes");
         unsafe {
-            mem_conv::with_bytes_mut(byte_offset, byte_length, |bytes| {
+            self.with_bytes_mut(byte_offset, byte_length, |bytes| {
                 self.handler
                     .bi_set_unsigned_bytes(destination_handle, bytes);
             })
@@ -1156,146 +1879,177 @@ impl VMHooks for VMHooksDispatcher {
         byte_offset: MemPtr,
         byte_length: MemLength,
     ) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntSetSignedBytes");
         unsafe {
-            mem_conv::with_bytes_mut(byte_offset, byte_length, |bytes| {
+            self.with_bytes_mut(byte_offset, byte_length, |bytes| {
                 self.handler.bi_set_signed_bytes(destination_handle, bytes);
             })
         }
     }
 
     fn big_int_is_int64(&self, destination_handle: i32) -> i32 {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntIsInt64");
         self.handler.bi_is_int64(destination_handle)
     }
 
     fn big_int_get_int64(&self, destination_handle: i32) -> i64 {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntGetInt64");
         self.handler.bi_get_int64(destination_handle)
     }
 
     fn big_int_set_int64(&self, destination_handle: i32, value: i64) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntSetInt64");
         self.handler.bi_set_int64(destination_handle, value);
     }
 
     fn big_int_add(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntAdd");
         self.handler
             .bi_add(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_sub(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntSub");
         self.handler
             .bi_sub(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_mul(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntMul");
         self.handler
             .bi_mul(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_tdiv(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntTDiv");
         self.handler
             .bi_t_div(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_tmod(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntTMod");
         self.handler
             .bi_t_mod(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_ediv(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntEDiv");
         panic!("Not supported: big_int_ediv");
     }
 
     fn big_int_emod(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntEMod");
         panic!("Not supported: big_int_emod");
     }
 
     fn big_int_sqrt(&self, destination_handle: i32, op_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntSqrt");
         self.handler.bi_sqrt(destination_handle, op_handle);
     }
 
     fn big_int_pow(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntPow");
         self.handler
             .bi_pow(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_log2(&self, op_handle: i32) -> i32 {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntLog");
         self.handler.bi_log2(op_handle)
     }
 
     fn big_int_abs(&self, destination_handle: i32, op_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntAbs");
         self.handler.bi_abs(destination_handle, op_handle);
     }
 
     fn big_int_neg(&self, destination_handle: i32, op_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntNeg");
         self.handler.bi_neg(destination_handle, op_handle);
     }
 
     fn big_int_sign(&self, op_handle: i32) -> i32 {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntSign");
         self.handler.bi_sign(op_handle)
     }
 
     fn big_int_cmp(&self, op1_handle: i32, op2_handle: i32) -> i32 {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntCmp");
         self.handler.bi_cmp(op1_handle, op2_handle)
     }
 
     fn big_int_not(&self, destination_handle: i32, op_handle: i32) {
-        panic!("Unavailable: big_int_not");
+        self.use_gas(GasSection::BigIntApiCost, "BigIntNot");
+        self.handler.bi_not(destination_handle, op_handle);
     }
 
     fn big_int_and(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntAnd");
         self.handler
             .bi_and(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_or(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntOr");
         self.handler
             .bi_or(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_xor(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntXor");
         self.handler
             .bi_xor(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_shr(&self, destination_handle: i32, op_handle: i32, bits: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntShr");
         self.handler
             .bi_shr(destination_handle, op_handle, bits as usize);
     }
 
     fn big_int_shl(&self, destination_handle: i32, op_handle: i32, bits: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntShl");
         self.handler
             .bi_shl(destination_handle, op_handle, bits as usize);
     }
 
     fn big_int_finish_unsigned(&self, reference_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntFinishUnsigned");
         self.handler.finish_big_uint_raw(reference_handle);
     }
 
     fn big_int_finish_signed(&self, reference_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntFinishSigned");
         self.handler.finish_big_int_raw(reference_handle);
     }
 
     fn big_int_to_string(&self, big_int_handle: i32, destination_handle: i32) {
+        self.use_gas(GasSection::BigIntApiCost, "BigIntGetSignedBytes");
         self.handler
             .bi_to_string(big_int_handle, destination_handle);
     }
 
     fn mbuffer_new(&self) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferNew");
         self.handler.mb_new_empty()
     }
 
     fn mbuffer_new_from_bytes(&self, data_offset: MemPtr, data_length: MemLength) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferNewFromBytes");
         unsafe {
-            mem_conv::with_bytes_mut(data_offset, data_length, |bytes| {
+            self.with_bytes_mut(data_offset, data_length, |bytes| {
                 self.handler.mb_new_from_bytes(bytes)
             })
         }
     }
 
     fn mbuffer_get_length(&self, m_buffer_handle: i32) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferGetLength");
         self.handler.mb_len(m_buffer_handle) as i32
     }
 
     fn mbuffer_get_bytes(&self, m_buffer_handle: i32, result_offset: MemPtr) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferGetBytes");
         unsafe {
             self.handler
                 .mb_copy_bytes(m_buffer_handle, result_offset as *mut u8) as i32
@@ -1309,8 +2063,9 @@ impl VMHooks for VMHooksDispatcher {
         slice_length: i32,
         result_offset: MemPtr,
     ) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferGetByteSlice");
         unsafe {
-            mem_conv::with_bytes_mut(result_offset, slice_length as isize, |bytes| {
+            self.with_bytes_mut(result_offset, slice_length as isize, |bytes| {
                 self.handler
                     .mb_load_slice(source_handle, starting_position as usize, bytes)
             })
@@ -1324,6 +2079,7 @@ impl VMHooks for VMHooksDispatcher {
         slice_length: i32,
         destination_handle: i32,
     ) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferCopyByteSlice");
         self.handler.mb_copy_slice(
             source_handle,
             starting_position as usize,
@@ -1333,6 +2089,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn mbuffer_eq(&self, m_buffer_handle1: i32, m_buffer_handle2: i32) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferGetBytes");
         self.handler.mb_eq(m_buffer_handle1, m_buffer_handle2)
     }
 
@@ -1342,8 +2099,9 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         data_length: MemLength,
     ) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferSetBytes");
         unsafe {
-            mem_conv::with_bytes(data_offset, data_length, |bytes| {
+            self.with_bytes(data_offset, data_length, |bytes| {
                 self.handler.mb_set(m_buffer_handle, bytes);
             });
         }
@@ -1357,8 +2115,9 @@ impl VMHooks for VMHooksDispatcher {
         data_length: MemLength,
         data_offset: MemPtr,
     ) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferSetBytes");
         unsafe {
-            mem_conv::with_bytes(data_offset, data_length, |bytes| {
+            self.with_bytes(data_offset, data_length, |bytes| {
                 self.handler
                     .mb_set_slice(m_buffer_handle, starting_position as usize, bytes)
             })
@@ -1366,6 +2125,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn mbuffer_append(&self, accumulator_handle: i32, data_handle: i32) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferAppend");
         self.handler.mb_append(accumulator_handle, data_handle);
         0
     }
@@ -1376,8 +2136,9 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         data_length: MemLength,
     ) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferAppendBytes");
         unsafe {
-            mem_conv::with_bytes(data_offset, data_length, |bytes| {
+            self.with_bytes(data_offset, data_length, |bytes| {
                 self.handler.mb_append_bytes(accumulator_handle, bytes);
             });
         }
@@ -1385,44 +2146,59 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn mbuffer_to_big_int_unsigned(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferToBigIntUnsigned");
         self.handler
             .mb_to_big_int_unsigned(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_to_big_int_signed(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferToBigIntSigned");
         self.handler
             .mb_to_big_int_signed(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_from_big_int_unsigned(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
+        self.use_gas(
+            GasSection::ManagedBufferApiCost,
+            "MBufferFromBigIntUnsigned",
+        );
         self.handler
             .mb_from_big_int_unsigned(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_from_big_int_signed(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferFromBigIntSigned");
         self.handler
             .mb_from_big_int_signed(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_to_big_float(&self, m_buffer_handle: i32, big_float_handle: i32) -> i32 {
-        panic!("Unavailable: mbuffer_to_big_float")
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferToBigFloat");
+        self.handler
+            .mb_to_big_float(m_buffer_handle, big_float_handle);
+        0
     }
 
     fn mbuffer_from_big_float(&self, m_buffer_handle: i32, big_float_handle: i32) -> i32 {
-        panic!("Unavailable: mbuffer_from_big_float")
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferFromBigFloat");
+        self.handler
+            .mb_from_big_float(m_buffer_handle, big_float_handle);
+        0
     }
 
     fn mbuffer_storage_store(&self, key_handle: i32, source_handle: i32) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferStorageStore");
         self.handler
             .storage_store_managed_buffer_raw(key_handle, source_handle);
         0
     }
 
     fn mbuffer_storage_load(&self, key_handle: i32, destination_handle: i32) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferStorageLoad");
         self.handler
             .storage_load_managed_buffer_raw(key_handle, destination_handle);
         0
@@ -1434,65 +2210,78 @@ impl VMHooks for VMHooksDispatcher {
         key_handle: i32,
         destination_handle: i32,
     ) {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferStorageLoad");
         self.handler
             .storage_load_from_address(address_handle, key_handle, destination_handle);
     }
 
     fn mbuffer_get_argument(&self, id: i32, destination_handle: i32) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferGetArgument");
         self.handler
             .load_argument_managed_buffer(id, destination_handle);
         0
     }
 
     fn mbuffer_finish(&self, source_handle: i32) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferFinish");
         self.handler.finish_managed_buffer_raw(source_handle);
         0
     }
 
     fn mbuffer_set_random(&self, destination_handle: i32, length: i32) -> i32 {
+        self.use_gas(GasSection::ManagedBufferApiCost, "MBufferSetRandom");
         self.handler
             .mb_set_random(destination_handle, length as usize);
         0
     }
 
     fn managed_map_new(&self) -> i32 {
+        self.use_gas(GasSection::ManagedMapApiCost, "ManagedMapNew");
         self.handler.mm_new()
     }
 
     fn managed_map_put(&self, map_handle: i32, key_handle: i32, value_handle: i32) -> i32 {
+        self.use_gas(GasSection::ManagedMapApiCost, "ManagedMapPut");
         self.handler.mm_put(map_handle, key_handle, value_handle);
         0
     }
 
     fn managed_map_get(&self, map_handle: i32, key_handle: i32, out_value_handle: i32) -> i32 {
+        self.use_gas(GasSection::ManagedMapApiCost, "ManagedMapGet");
         self.handler
             .mm_get(map_handle, key_handle, out_value_handle);
         0
     }
 
     fn managed_map_remove(&self, map_handle: i32, key_handle: i32, out_value_handle: i32) -> i32 {
+        self.use_gas(GasSection::ManagedMapApiCost, "ManagedMapRemove");
         self.handler
             .mm_remove(map_handle, key_handle, out_value_handle);
         0
     }
 
     fn managed_map_contains(&self, map_handle: i32, key_handle: i32) -> i32 {
+        self.use_gas(GasSection::ManagedMapApiCost, "ManagedMapContains");
         bool_to_i32(self.handler.mm_contains(map_handle, key_handle))
     }
 
     fn small_int_get_unsigned_argument(&self, id: i32) -> i64 {
+        self.use_gas(GasSection::BaseOpsApiCost, "Int64GetArgument");
         self.handler.get_argument_u64(id) as i64
     }
 
     fn small_int_get_signed_argument(&self, id: i32) -> i64 {
+        self.use_gas(GasSection::BaseOpsApiCost, "Int64GetArgument");
         self.handler.get_argument_i64(id)
     }
 
     fn small_int_finish_unsigned(&self, value: i64) {
+        self.use_gas(GasSection::BaseOpsApiCost, "Int64Finish");
         self.handler.finish_u64(value as u64);
     }
 
     fn small_int_finish_signed(&self, value: i64) {
+        self.use_gas(GasSection::BaseOpsApiCost, "Int64Finish");
         self.handler.finish_i64(value);
     }
 
@@ -1502,7 +2291,9 @@ impl VMHooks for VMHooksDispatcher {
         key_length: MemLength,
         value: i64,
     ) -> i32 {
-        panic!("Unavailable: small_int_storage_store_unsigned")
+        self.use_gas(GasSection::BaseOpsApiCost, "Int64StorageStore");
+        let key = self.load_bytes(key_offset, key_length);
+        self.handler.storage_store_u64(&key, value as u64)
     }
 
     fn small_int_storage_store_signed(
@@ -1511,57 +2302,83 @@ impl VMHooks for VMHooksDispatcher {
         key_length: MemLength,
         value: i64,
     ) -> i32 {
-        panic!("Unavailable: small_int_storage_store_signed")
+        self.use_gas(GasSection::BaseOpsApiCost, "Int64StorageStore");
+        let key = self.load_bytes(key_offset, key_length);
+        self.handler.storage_store_i64(&key, value)
     }
 
     fn small_int_storage_load_unsigned(&self, key_offset: MemPtr, key_length: MemLength) -> i64 {
-        panic!("Unavailable: small_int_storage_load_unsigned")
+        self.use_gas(GasSection::BaseOpsApiCost, "Int64StorageLoad");
+        let key = self.load_bytes(key_offset, key_length);
+        self.handler.storage_load_u64(&key) as i64
     }
 
     fn small_int_storage_load_signed(&self, key_offset: MemPtr, key_length: MemLength) -> i64 {
-        panic!("Unavailable: small_int_storage_load_signed")
+        self.use_gas(GasSection::BaseOpsApiCost, "Int64StorageLoad");
+        let key = self.load_bytes(key_offset, key_length);
+        self.handler.storage_load_i64(&key)
     }
 
     fn int64get_argument(&self, id: i32) -> i64 {
-        panic!("Unavailable: int64get_argument")
+        self.use_gas(GasSection::BaseOpsApiCost, "Int64GetArgument");
+        self.handler.get_argument_i64(id)
     }
 
     fn int64finish(&self, value: i64) {
-        panic!("Unavailable: int64finish");
+        self.use_gas(GasSection::BaseOpsApiCost, "Int64Finish");
+        self.handler.finish_i64(value);
     }
 
     fn int64storage_store(&self, key_offset: MemPtr, key_length: MemLength, value: i64) -> i32 {
-        panic!("Unavailable: int64storage_store")
+        self.use_gas(GasSection::BaseOpsApiCost, "Int64StorageStore");
+        // only the absolute value is stored, like in the Go VM
+        let key = self.load_bytes(key_offset, key_length);
+        self.handler.storage_store_u64(&key, value.unsigned_abs())
     }
 
     fn int64storage_load(&self, key_offset: MemPtr, key_length: MemLength) -> i64 {
-        panic!("Unavailable: int64storage_load")
+        self.use_gas(GasSection::BaseOpsApiCost, "Int64StorageLoad");
+        let key = self.load_bytes(key_offset, key_length);
+        self.handler.storage_load_u64(&key) as i64
     }
 
     fn sha256(&self, data_offset: MemPtr, length: MemLength, result_offset: MemPtr) -> i32 {
-        panic!("Unavailable: sha256")
+        self.use_gas(GasSection::CryptoApiCost, "SHA256");
+        let data = self.load_bytes(data_offset, length);
+        self.store_bytes(result_offset, &crypto_functions::sha256(&data));
+        0
     }
 
     fn managed_sha256(&self, input_handle: i32, output_handle: i32) -> i32 {
+        self.use_gas(GasSection::CryptoApiCost, "SHA256");
         self.handler.sha256_managed(output_handle, input_handle);
         0
     }
 
     fn keccak256(&self, data_offset: MemPtr, length: MemLength, result_offset: MemPtr) -> i32 {
-        panic!("Unavailable: keccak256")
+        self.use_gas(GasSection::CryptoApiCost, "Keccak256");
+        let data = self.load_bytes(data_offset, length);
+        self.store_bytes(result_offset, &crypto_functions::keccak256(&data));
+        0
     }
 
     fn managed_keccak256(&self, input_handle: i32, output_handle: i32) -> i32 {
+        self.use_gas(GasSection::CryptoApiCost, "Keccak256");
         self.handler.keccak256_managed(output_handle, input_handle);
         0
     }
 
     fn ripemd160(&self, data_offset: MemPtr, length: MemLength, result_offset: MemPtr) -> i32 {
-        panic!("Unavailable: ripemd160")
+        self.use_gas(GasSection::CryptoApiCost, "Ripemd160");
+        let data = self.load_bytes(data_offset, length);
+        self.store_bytes(result_offset, &crypto_functions::ripemd160(&data));
+        0
     }
 
     fn managed_ripemd160(&self, input_handle: i32, output_handle: i32) -> i32 {
-        panic!("Unavailable: managed_ripemd160")
+        self.use_gas(GasSection::CryptoApiCost, "Ripemd160");
+        self.handler.ripemd160_managed(output_handle, input_handle);
+        0
     }
 
     fn verify_bls(
@@ -1571,11 +2388,20 @@ impl VMHooks for VMHooksDispatcher {
         message_length: MemLength,
         sig_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: verify_bls")
+        self.use_gas(GasSection::CryptoApiCost, "VerifyBLS");
+        self.handler.verify_bls_managed(
+            self.mb_new(self.load_bytes(key_offset, BLS_PUBLIC_KEY_LEN)),
+            self.mb_new(self.load_bytes(message_offset, message_length)),
+            self.mb_new(self.load_bytes(sig_offset, BLS_SIGNATURE_LEN)),
+        );
+        0
     }
 
     fn managed_verify_bls(&self, key_handle: i32, message_handle: i32, sig_handle: i32) -> i32 {
-        panic!("Unavailable: managed_verify_bls")
+        self.use_gas(GasSection::CryptoApiCost, "VerifyBLS");
+        self.handler
+            .verify_bls_managed(key_handle, message_handle, sig_handle);
+        0
     }
 
     fn verify_ed25519(
@@ -1585,10 +2411,17 @@ impl VMHooks for VMHooksDispatcher {
         message_length: MemLength,
         sig_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: verify_ed25519")
+        self.use_gas(GasSection::CryptoApiCost, "VerifyEd25519");
+        self.handler.verify_ed25519_managed(
+            self.mb_new(self.load_bytes(key_offset, ED25519_PUBLIC_KEY_LEN)),
+            self.mb_new(self.load_bytes(message_offset, message_length)),
+            self.mb_new(self.load_bytes(sig_offset, ED25519_SIGNATURE_LEN)),
+        );
+        0
     }
 
     fn managed_verify_ed25519(&self, key_handle: i32, message_handle: i32, sig_handle: i32) -> i32 {
+        self.use_gas(GasSection::CryptoApiCost, "VerifyEd25519");
         self.handler
             .verify_ed25519_managed(key_handle, message_handle, sig_handle);
         0
@@ -1603,7 +2436,14 @@ impl VMHooks for VMHooksDispatcher {
         sig_offset: MemPtr,
         hash_type: i32,
     ) -> i32 {
-        panic!("Unavailable: verify_custom_secp256k1")
+        self.use_gas(GasSection::CryptoApiCost, "VerifySecp256k1");
+        self.handler.verify_custom_secp256k1_managed(
+            self.mb_new(self.load_bytes(key_offset, key_length)),
+            self.mb_new(self.load_bytes(message_offset, message_length)),
+            self.mb_new(self.load_der_signature(sig_offset)),
+            MessageHashType::from_i32(hash_type),
+        );
+        0
     }
 
     fn managed_verify_custom_secp256k1(
@@ -1613,7 +2453,14 @@ impl VMHooks for VMHooksDispatcher {
         sig_handle: i32,
         hash_type: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_verify_custom_secp256k1")
+        self.use_gas(GasSection::CryptoApiCost, "VerifySecp256k1");
+        self.handler.verify_custom_secp256k1_managed(
+            key_handle,
+            message_handle,
+            sig_handle,
+            MessageHashType::from_i32(hash_type),
+        );
+        0
     }
 
     fn verify_secp256k1(
@@ -1624,7 +2471,13 @@ impl VMHooks for VMHooksDispatcher {
         message_length: MemLength,
         sig_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: verify_secp256k1")
+        self.use_gas(GasSection::CryptoApiCost, "VerifySecp256k1");
+        self.handler.verify_secp256k1_managed(
+            self.mb_new(self.load_bytes(key_offset, key_length)),
+            self.mb_new(self.load_bytes(message_offset, message_length)),
+            self.mb_new(self.load_der_signature(sig_offset)),
+        );
+        0
     }
 
     fn managed_verify_secp256k1(
@@ -1633,7 +2486,10 @@ impl VMHooks for VMHooksDispatcher {
         message_handle: i32,
         sig_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_verify_secp256k1")
+        self.use_gas(GasSection::CryptoApiCost, "VerifySecp256k1");
+        self.handler
+            .verify_secp256k1_managed(key_handle, message_handle, sig_handle);
+        0
     }
 
     fn encode_secp256k1_der_signature(
@@ -1644,7 +2500,13 @@ impl VMHooks for VMHooksDispatcher {
         s_length: MemLength,
         sig_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: encode_secp256k1_der_signature")
+        self.use_gas(GasSection::CryptoApiCost, "EncodeDERSig");
+        let signature = crypto_functions::encode_secp256k1_der_signature(
+            &self.load_bytes(r_offset, r_length),
+            &self.load_bytes(s_offset, s_length),
+        );
+        self.store_bytes(sig_offset, &signature);
+        0
     }
 
     fn managed_encode_secp256k1_der_signature(
@@ -1653,7 +2515,10 @@ impl VMHooks for VMHooksDispatcher {
         s_handle: i32,
         sig_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_encode_secp256k1_der_signature")
+        self.use_gas(GasSection::CryptoApiCost, "EncodeDERSig");
+        self.handler
+            .encode_secp256k1_der_signature_managed(r_handle, s_handle, sig_handle);
+        0
     }
 
     fn add_ec(
@@ -1666,7 +2531,16 @@ impl VMHooks for VMHooksDispatcher {
         snd_point_xhandle: i32,
         snd_point_yhandle: i32,
     ) {
-        panic!("Unavailable: add_ec");
+        self.use_gas(GasSection::CryptoApiCost, "AddECC");
+        self.handler.ec_add(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            fst_point_xhandle,
+            fst_point_yhandle,
+            snd_point_xhandle,
+            snd_point_yhandle,
+        );
     }
 
     fn double_ec(
@@ -1677,11 +2551,22 @@ impl VMHooks for VMHooksDispatcher {
         point_xhandle: i32,
         point_yhandle: i32,
     ) {
-        panic!("Unavailable: double_ec");
+        self.use_gas(GasSection::CryptoApiCost, "DoubleECC");
+        self.handler.ec_double(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            point_xhandle,
+            point_yhandle,
+        );
     }
 
     fn is_on_curve_ec(&self, ec_handle: i32, point_xhandle: i32, point_yhandle: i32) -> i32 {
-        panic!("Unavailable: is_on_curve_ec")
+        self.use_gas(GasSection::CryptoApiCost, "IsOnCurveECC");
+        bool_to_i32(
+            self.handler
+                .ec_is_on_curve(ec_handle, point_xhandle, point_yhandle),
+        )
     }
 
     fn scalar_base_mult_ec(
@@ -1692,7 +2577,11 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: scalar_base_mult_ec")
+        self.use_gas(GasSection::CryptoApiCost, "ScalarMultECC");
+        let data_handle = self.mb_new(self.load_bytes(data_offset, length));
+        self.handler
+            .ec_scalar_base_mult(x_result_handle, y_result_handle, ec_handle, data_handle);
+        0
     }
 
     fn managed_scalar_base_mult_ec(
@@ -1702,7 +2591,10 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_scalar_base_mult_ec")
+        self.use_gas(GasSection::CryptoApiCost, "ScalarMultECC");
+        self.handler
+            .ec_scalar_base_mult(x_result_handle, y_result_handle, ec_handle, data_handle);
+        0
     }
 
     fn scalar_mult_ec(
@@ -1715,7 +2607,17 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: scalar_mult_ec")
+        self.use_gas(GasSection::CryptoApiCost, "ScalarMultECC");
+        let data_handle = self.mb_new(self.load_bytes(data_offset, length));
+        self.handler.ec_scalar_mult(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            point_xhandle,
+            point_yhandle,
+            data_handle,
+        );
+        0
     }
 
     fn managed_scalar_mult_ec(
@@ -1727,7 +2629,16 @@ impl VMHooks for VMHooksDispatcher {
         point_yhandle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_scalar_mult_ec")
+        self.use_gas(GasSection::CryptoApiCost, "ScalarMultECC");
+        self.handler.ec_scalar_mult(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            point_xhandle,
+            point_yhandle,
+            data_handle,
+        );
+        0
     }
 
     fn marshal_ec(
@@ -1737,7 +2648,11 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: marshal_ec")
+        self.use_gas(GasSection::CryptoApiCost, "MarshalECC");
+        let result_handle = self.handler.mb_new_empty();
+        self.handler
+            .ec_marshal(ec_handle, x_pair_handle, y_pair_handle, result_handle);
+        self.store_bytes(result_offset, &self.mb_get(result_handle))
     }
 
     fn managed_marshal_ec(
@@ -1747,7 +2662,10 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_marshal_ec")
+        self.use_gas(GasSection::CryptoApiCost, "MarshalECC");
+        self.handler
+            .ec_marshal(ec_handle, x_pair_handle, y_pair_handle, result_handle);
+        0
     }
 
     fn marshal_compressed_ec(
@@ -1757,7 +2675,11 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: marshal_compressed_ec")
+        self.use_gas(GasSection::CryptoApiCost, "MarshalCompressedECC");
+        let result_handle = self.handler.mb_new_empty();
+        self.handler
+            .ec_marshal_compressed(ec_handle, x_pair_handle, y_pair_handle, result_handle);
+        self.store_bytes(result_offset, &self.mb_get(result_handle))
     }
 
     fn managed_marshal_compressed_ec(
@@ -1767,7 +2689,10 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_marshal_compressed_ec")
+        self.use_gas(GasSection::CryptoApiCost, "MarshalCompressedECC");
+        self.handler
+            .ec_marshal_compressed(ec_handle, x_pair_handle, y_pair_handle, result_handle);
+        0
     }
 
     fn unmarshal_ec(
@@ -1778,7 +2703,11 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: unmarshal_ec")
+        self.use_gas(GasSection::CryptoApiCost, "UnmarshalECC");
+        let data_handle = self.mb_new(self.load_bytes(data_offset, length));
+        self.handler
+            .ec_unmarshal(x_result_handle, y_result_handle, ec_handle, data_handle);
+        0
     }
 
     fn managed_unmarshal_ec(
@@ -1788,7 +2717,10 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_unmarshal_ec")
+        self.use_gas(GasSection::CryptoApiCost, "UnmarshalECC");
+        self.handler
+            .ec_unmarshal(x_result_handle, y_result_handle, ec_handle, data_handle);
+        0
     }
 
     fn unmarshal_compressed_ec(
@@ -1799,7 +2731,15 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: unmarshal_compressed_ec")
+        self.use_gas(GasSection::CryptoApiCost, "UnmarshalCompressedECC");
+        let data_handle = self.mb_new(self.load_bytes(data_offset, length));
+        self.handler.ec_unmarshal_compressed(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            data_handle,
+        );
+        0
     }
 
     fn managed_unmarshal_compressed_ec(
@@ -1809,7 +2749,14 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_unmarshal_compressed_ec")
+        self.use_gas(GasSection::CryptoApiCost, "UnmarshalCompressedECC");
+        self.handler.ec_unmarshal_compressed(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            data_handle,
+        );
+        0
     }
 
     fn generate_key_ec(
@@ -1819,7 +2766,11 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: generate_key_ec")
+        self.use_gas(GasSection::CryptoApiCost, "GenerateKeyECC");
+        let result_handle = self.handler.mb_new_empty();
+        self.handler
+            .ec_generate_key(x_pub_key_handle, y_pub_key_handle, ec_handle, result_handle);
+        self.store_bytes(result_offset, &self.mb_get(result_handle))
     }
 
     fn managed_generate_key_ec(
@@ -1829,23 +2780,34 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_generate_key_ec")
+        self.use_gas(GasSection::CryptoApiCost, "GenerateKeyECC");
+        self.handler
+            .ec_generate_key(x_pub_key_handle, y_pub_key_handle, ec_handle, result_handle);
+        0
     }
 
     fn create_ec(&self, data_offset: MemPtr, data_length: MemLength) -> i32 {
-        panic!("Unavailable: create_ec")
+        self.use_gas(GasSection::CryptoApiCost, "EllipticCurveNew");
+        unsafe {
+            self.with_bytes(data_offset, data_length, |name| {
+                self.handler.ec_create_from_name_bytes(name)
+            })
+        }
     }
 
     fn managed_create_ec(&self, data_handle: i32) -> i32 {
-        panic!("Unavailable: managed_create_ec")
+        self.use_gas(GasSection::CryptoApiCost, "EllipticCurveNew");
+        self.handler.ec_create_from_name_mb(data_handle)
     }
 
     fn get_curve_length_ec(&self, ec_handle: i32) -> i32 {
-        panic!("Unavailable: get_curve_length_ec")
+        self.use_gas(GasSection::CryptoApiCost, "EllipticCurveNew");
+        self.handler.ec_curve_length(ec_handle) as i32
     }
 
     fn get_priv_key_byte_length_ec(&self, ec_handle: i32) -> i32 {
-        panic!("Unavailable: get_priv_key_byte_length_ec")
+        self.use_gas(GasSection::CryptoApiCost, "EllipticCurveNew");
+        self.handler.ec_private_key_byte_length(ec_handle) as i32
     }
 
     fn elliptic_curve_get_values(
@@ -1857,6 +2819,15 @@ impl VMHooks for VMHooksDispatcher {
         x_base_point_handle: i32,
         y_base_point_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: elliptic_curve_get_values")
+        self.use_gas(GasSection::CryptoApiCost, "EllipticCurveNew");
+        self.handler.ec_get_values(
+            ec_handle,
+            field_order_handle,
+            base_point_order_handle,
+            eq_constant_handle,
+            x_base_point_handle,
+            y_base_point_handle,
+        );
+        0
     }
 }
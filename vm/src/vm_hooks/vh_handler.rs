@@ -17,7 +17,8 @@ pub use vh_endpoint_finish::VMHooksEndpointFinish;
 pub use vh_error::{VMHooksError, VMHooksErrorManaged};
 pub use vh_log::VMHooksLog;
 pub use vh_managed_types::{
-    VMHooksBigFloat, VMHooksBigInt, VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes,
+    VMHooksBigFloat, VMHooksBigInt, VMHooksEllipticCurve, VMHooksManagedBuffer, VMHooksManagedMap,
+    VMHooksManagedTypes,
 };
 pub use vh_send::VMHooksSend;
 pub use vh_storage::{VMHooksStorageRead, VMHooksStorageWrite};
@@ -1,36 +1,59 @@
 use crate::{
     tx_execution::vm_builtin_function_names::*,
     types::{EsdtLocalRole, EsdtLocalRoleFlags, RawHandle, VMAddress},
+    vm_err_msg,
     vm_hooks::VMHooksHandlerSource,
-    world_mock::{EsdtData, EsdtInstance},
+    world_mock::{BlockInfo, EsdtData, EsdtInstance, BLOCK_HASH_LEN},
 };
 use num_bigint::BigInt;
 use num_traits::Zero;
 
 // The Go VM doesn't do it, but if we change that, we can enable it easily here too via this constant.
 const ESDT_TOKEN_DATA_FUNC_RESETS_VALUES: bool = false;
-const VM_BUILTIN_FUNCTION_NAMES: [&str; 16] = [
-    ESDT_LOCAL_MINT_FUNC_NAME,
-    ESDT_LOCAL_BURN_FUNC_NAME,
-    ESDT_MULTI_TRANSFER_FUNC_NAME,
-    ESDT_NFT_TRANSFER_FUNC_NAME,
-    ESDT_NFT_CREATE_FUNC_NAME,
-    ESDT_NFT_ADD_QUANTITY_FUNC_NAME,
-    ESDT_NFT_ADD_URI_FUNC_NAME,
-    ESDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME,
-    ESDT_NFT_BURN_FUNC_NAME,
-    ESDT_TRANSFER_FUNC_NAME,
-    CHANGE_OWNER_BUILTIN_FUNC_NAME,
-    CLAIM_DEVELOPER_REWARDS_FUNC_NAME,
-    SET_USERNAME_FUNC_NAME,
-    MIGRATE_USERNAME_FUNC_NAME,
-    DELETE_USERNAME_FUNC_NAME,
-    UPGRADE_CONTRACT_FUNC_NAME,
-];
+
+const TICKER_MIN_LENGTH: usize = 3;
+const TICKER_MAX_LENGTH: usize = 10;
+const ADDITIONAL_RANDOM_CHARS_LENGTH: usize = 6;
+// +1 because of the dash between ticker and random chars
+const IDENTIFIER_MIN_LENGTH: usize = TICKER_MIN_LENGTH + ADDITIONAL_RANDOM_CHARS_LENGTH + 1;
+const IDENTIFIER_MAX_LENGTH: usize = TICKER_MAX_LENGTH + ADDITIONAL_RANDOM_CHARS_LENGTH + 1;
+
+/// Same rules as the framework: an uppercase alphanumeric ticker of 3 to 10 characters,
+/// a dash and 6 lowercase alphanumeric random characters.
+fn is_valid_token_identifier(token_id: &[u8]) -> bool {
+    let length = token_id.len();
+    if !(IDENTIFIER_MIN_LENGTH..=IDENTIFIER_MAX_LENGTH).contains(&length) {
+        return false;
+    }
+
+    let ticker_len = length - ADDITIONAL_RANDOM_CHARS_LENGTH - 1;
+    let ticker = &token_id[..ticker_len];
+    let random_chars = &token_id[ticker_len + 1..];
+    ticker
+        .iter()
+        .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        && token_id[ticker_len] == b'-'
+        && random_chars
+            .iter()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+}
+
+const ADDRESS_LEN: usize = 32;
+
+/// Addresses coming from the contract can have any length, the ones that are not 32 bytes long end in a VM error.
+fn address_from_bytes<VH>(vh: &VH, address_bytes: &[u8]) -> VMAddress
+where
+    VH: VMHooksHandlerSource + ?Sized,
+{
+    if address_bytes.len() != ADDRESS_LEN {
+        vh.vm_error(vm_err_msg::INVALID_ADDRESS);
+    }
+    VMAddress::from_slice(address_bytes)
+}
 
 pub trait VMHooksBlockchain: VMHooksHandlerSource {
     fn is_contract_address(&self, address_bytes: &[u8]) -> bool {
-        let address = VMAddress::from_slice(address_bytes);
+        let address = address_from_bytes(self, address_bytes);
         &address == self.current_address()
     }
 
@@ -55,20 +78,25 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
     }
 
     fn get_shard_of_address(&self, address_bytes: &[u8]) -> i32 {
-        (address_bytes[address_bytes.len() - 1] % 3).into()
+        let address = address_from_bytes(self, address_bytes);
+        match self.shard_coordinator() {
+            Some(shard_coordinator) => shard_coordinator.compute_shard(&address) as i32,
+            None => (address.as_bytes()[ADDRESS_LEN - 1] % 3).into(),
+        }
     }
 
     fn is_smart_contract(&self, address_bytes: &[u8]) -> bool {
-        VMAddress::from_slice(address_bytes).is_smart_contract_address()
+        address_from_bytes(self, address_bytes).is_smart_contract_address()
     }
 
+    /// Missing accounts have a zero balance.
     fn load_balance(&self, address_bytes: &[u8], dest: RawHandle) {
-        assert!(
-            self.is_contract_address(address_bytes),
-            "get balance not yet implemented for accounts other than the contract itself"
-        );
-        self.m_types_lock()
-            .bi_overwrite(dest, self.current_account_data().egld_balance.into());
+        let address = address_from_bytes(self, address_bytes);
+        let balance = self
+            .account_data(&address)
+            .map(|account| account.egld_balance)
+            .unwrap_or_default();
+        self.m_types_lock().bi_overwrite(dest, balance.into());
     }
 
     fn get_tx_hash(&self, dest: RawHandle) {
@@ -77,7 +105,7 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
     }
 
     fn get_gas_left(&self) -> u64 {
-        self.input_ref().gas_limit
+        self.input_ref().gas_limit.saturating_sub(self.gas_used())
     }
 
     fn get_block_timestamp(&self) -> u64 {
@@ -103,6 +131,25 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
         );
     }
 
+    fn get_block_hash(&self, nonce: u64) -> Option<[u8; BLOCK_HASH_LEN]> {
+        let current_block_info = self.get_current_block_info();
+        let previous_block_info = self.get_previous_block_info();
+        if nonce > current_block_info.block_nonce {
+            None
+        } else if nonce == current_block_info.block_nonce {
+            Some(*current_block_info.block_hash)
+        } else if nonce == previous_block_info.block_nonce {
+            Some(*previous_block_info.block_hash)
+        } else {
+            Some(BlockInfo::simulated_block_hash(nonce))
+        }
+    }
+
+    fn get_state_root_hash(&self, dest: RawHandle) {
+        self.m_types_lock()
+            .mb_set(dest, self.get_current_block_info().state_root_hash.to_vec());
+    }
+
     fn get_prev_block_timestamp(&self) -> u64 {
         self.get_previous_block_info().block_timestamp
     }
@@ -145,20 +192,17 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
         nonce: u64,
         dest: RawHandle,
     ) {
-        assert!(
-            self.is_contract_address(address_bytes),
-            "get_esdt_balance not yet implemented for accounts other than the contract itself"
-        );
-
+        let address = address_from_bytes(self, address_bytes);
         let esdt_balance = self
-            .current_account_data()
-            .esdt
-            .get_esdt_balance(token_id_bytes, nonce);
+            .account_data(&address)
+            .map(|account| account.esdt.get_esdt_balance(token_id_bytes, nonce))
+            .unwrap_or_default();
         self.m_types_lock().bi_overwrite(dest, esdt_balance.into());
     }
 
     fn managed_get_code_metadata(&self, address_handle: i32, response_handle: i32) {
-        let address = VMAddress::from_slice(self.m_types_lock().mb_get(address_handle));
+        let address_bytes = self.m_types_lock().mb_get(address_handle).to_vec();
+        let address = address_from_bytes(self, &address_bytes);
         let Some(data) = self.account_data(&address) else {
             self.vm_error(&format!(
                 "account not found: {}",
@@ -170,6 +214,10 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
             .mb_set(response_handle, code_metadata_bytes.to_vec())
     }
 
+    fn validate_token_identifier(&self, token_id_handle: RawHandle) -> bool {
+        is_valid_token_identifier(self.m_types_lock().mb_get(token_id_handle))
+    }
+
     fn managed_is_builtin_function(&self, function_name_handle: i32) -> bool {
         VM_BUILTIN_FUNCTION_NAMES.contains(
             &self
@@ -194,7 +242,8 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
         royalties_handle: RawHandle,
         uris_handle: RawHandle,
     ) {
-        let address = VMAddress::from_slice(self.m_types_lock().mb_get(address_handle));
+        let address_bytes = self.m_types_lock().mb_get(address_handle).to_vec();
+        let address = address_from_bytes(self, &address_bytes);
         let token_id_bytes = self.m_types_lock().mb_get(token_id_handle).to_vec();
 
         if let Some(account) = self.account_data(&address) {
@@ -248,19 +297,24 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
         &self,
         address_handle: RawHandle,
         token_id_handle: RawHandle,
-        _nonce: u64,
+        nonce: u64,
     ) -> bool {
-        let address = VMAddress::from_slice(self.m_types_lock().mb_get(address_handle));
+        let address_bytes = self.m_types_lock().mb_get(address_handle).to_vec();
+        let address = address_from_bytes(self, &address_bytes);
         let token_id_bytes = self.m_types_lock().mb_get(token_id_handle).to_vec();
         if let Some(account) = self.account_data(&address) {
-            if let Some(esdt_data) = account.esdt.get_by_identifier(token_id_bytes.as_slice()) {
-                return esdt_data.frozen;
-            }
+            return account.esdt.is_frozen(token_id_bytes.as_slice(), nonce);
         }
 
         false
     }
 
+    fn check_esdt_paused(&self, token_id_handle: RawHandle) -> bool {
+        let token_id_bytes = self.m_types_lock().mb_get(token_id_handle).to_vec();
+        self.esdt_system_sc_data(token_id_bytes.as_slice())
+            .is_some_and(|token_data| token_data.is_paused)
+    }
+
     fn get_esdt_local_roles_bits(&self, token_id_handle: RawHandle) -> u64 {
         let token_id_bytes = self.m_types_lock().mb_get(token_id_handle).to_vec();
         let account = self.current_account_data();
@@ -289,7 +343,7 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
     ) {
         let mut m_types = self.m_types_lock();
         m_types.bi_overwrite(value_handle, instance.balance.clone().into());
-        if esdt_data.frozen {
+        if esdt_data.frozen || instance.frozen {
             m_types.mb_set(properties_handle, vec![1, 0]);
         } else {
             m_types.mb_set(properties_handle, vec![0, 0]);
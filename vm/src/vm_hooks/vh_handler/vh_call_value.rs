@@ -1,4 +1,6 @@
-use crate::{types::RawHandle, vm_err_msg, vm_hooks::VMHooksHandlerSource};
+use crate::{
+    tx_mock::TxTokenTransfer, types::RawHandle, vm_err_msg, vm_hooks::VMHooksHandlerSource,
+};
 use num_traits::Zero;
 
 use super::VMHooksManagedTypes;
@@ -27,4 +29,21 @@ pub trait VMHooksCallValue: VMHooksHandlerSource + VMHooksManagedTypes {
     fn esdt_num_transfers(&self) -> usize {
         self.input_ref().received_esdt().len()
     }
+
+    /// Used by the legacy hooks, which query the ESDT transfers one by one.
+    ///
+    /// Index 0 is allowed to be missing, any other index needs to exist.
+    fn esdt_transfer_by_index(&self, index: i32) -> Option<TxTokenTransfer> {
+        let transfers = self.input_ref().received_esdt();
+        if let Some(transfer) = usize::try_from(index)
+            .ok()
+            .and_then(|index| transfers.get(index))
+        {
+            return Some(transfer.clone());
+        }
+        if index != 0 {
+            self.vm_error(vm_err_msg::INVALID_TOKEN_INDEX);
+        }
+        None
+    }
 }
@@ -1,4 +1,8 @@
-use crate::{crypto_functions, types::RawHandle, vm_hooks::VMHooksHandlerSource};
+use crate::{
+    crypto_functions::{self, MessageHashType},
+    types::RawHandle,
+    vm_hooks::VMHooksHandlerSource,
+};
 
 pub trait VMHooksCrypto: VMHooksHandlerSource {
     fn sha256_managed(&self, dest: RawHandle, data_handle: RawHandle) {
@@ -19,6 +23,25 @@ pub trait VMHooksCrypto: VMHooksHandlerSource {
         types.mb_set(dest, result_bytes[..].to_vec());
     }
 
+    fn ripemd160_managed(&self, dest: RawHandle, data_handle: RawHandle) {
+        let mut types = self.m_types_lock();
+        let data = types.mb_get(data_handle);
+        let result_bytes = crypto_functions::ripemd160(data);
+        types.mb_set(dest, result_bytes[..].to_vec());
+    }
+
+    /// Should crash if the signature is invalid.
+    fn verify_bls_managed(&self, key: RawHandle, message: RawHandle, signature: RawHandle) {
+        let types = self.m_types_lock();
+        let key = types.mb_get(key);
+        let message = types.mb_get(message);
+        let signature = types.mb_get(signature);
+        let sig_valid = crypto_functions::verify_bls(key, message, signature);
+        if !sig_valid {
+            self.vm_error("invalid signature");
+        }
+    }
+
     /// Should crash if the signature is invalid.
     fn verify_ed25519_managed(&self, key: RawHandle, message: RawHandle, signature: RawHandle) {
         let types = self.m_types_lock();
@@ -30,4 +53,42 @@ pub trait VMHooksCrypto: VMHooksHandlerSource {
             self.vm_error("invalid signature");
         }
     }
+
+    /// Should crash if the signature is invalid.
+    ///
+    /// The message is hashed twice with SHA-256 before verification, like in Bitcoin.
+    fn verify_secp256k1_managed(&self, key: RawHandle, message: RawHandle, signature: RawHandle) {
+        self.verify_custom_secp256k1_managed(
+            key,
+            message,
+            signature,
+            MessageHashType::ECDSADoubleSha256,
+        );
+    }
+
+    /// Should crash if the signature is invalid.
+    fn verify_custom_secp256k1_managed(
+        &self,
+        key: RawHandle,
+        message: RawHandle,
+        signature: RawHandle,
+        hash_type: MessageHashType,
+    ) {
+        let types = self.m_types_lock();
+        let key = types.mb_get(key);
+        let message = types.mb_get(message);
+        let signature = types.mb_get(signature);
+        let sig_valid = crypto_functions::verify_secp256k1(key, message, signature, hash_type);
+        if !sig_valid {
+            self.vm_error("invalid signature");
+        }
+    }
+
+    fn encode_secp256k1_der_signature_managed(&self, r: RawHandle, s: RawHandle, dest: RawHandle) {
+        let mut types = self.m_types_lock();
+        let r = types.mb_get(r);
+        let s = types.mb_get(s);
+        let result_bytes = crypto_functions::encode_secp256k1_der_signature(r, s);
+        types.mb_set(dest, result_bytes);
+    }
 }
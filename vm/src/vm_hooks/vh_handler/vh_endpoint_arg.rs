@@ -1,7 +1,10 @@
 use num_bigint::{BigInt, BigUint};
 use num_traits::ToPrimitive;
 
-use crate::{vm_err_msg::ERROR_NO_CALLBACK_CLOSURE, vm_hooks::VMHooksHandlerSource};
+use crate::{
+    vm_err_msg::{self, ERROR_NO_CALLBACK_CLOSURE},
+    vm_hooks::VMHooksHandlerSource,
+};
 
 use crate::types::RawHandle;
 
@@ -31,7 +34,7 @@ pub trait VMHooksEndpointArgument: VMHooksHandlerSource + VMHooksManagedTypes {
         if let Some(v) = bi.to_i64() {
             v
         } else {
-            self.vm_error("argument out of range");
+            self.vm_error(vm_err_msg::ARGUMENT_OUT_OF_RANGE);
         }
     }
 
@@ -42,7 +45,7 @@ pub trait VMHooksEndpointArgument: VMHooksHandlerSource + VMHooksManagedTypes {
         if let Some(v) = bu.to_u64() {
             v
         } else {
-            self.vm_error("argument out of range");
+            self.vm_error(vm_err_msg::ARGUMENT_OUT_OF_RANGE);
         }
     }
 
@@ -4,6 +4,10 @@ pub trait VMHooksLog: VMHooksHandlerSource {
     fn managed_write_log(&self, topics_handle: RawHandle, data_handle: RawHandle) {
         let topics = self.m_types_lock().mb_get_vec_of_bytes(topics_handle);
         let single_data_field = self.m_types_lock().mb_get(data_handle).to_vec();
+        self.write_log_bytes(topics, single_data_field);
+    }
+
+    fn write_log_bytes(&self, topics: Vec<Vec<u8>>, single_data_field: Vec<u8>) {
         self.push_tx_log(TxLog {
             address: self.current_address().clone(),
             endpoint: self.input_ref().func_name.clone(),
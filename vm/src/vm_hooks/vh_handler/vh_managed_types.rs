@@ -1,24 +1,102 @@
 mod vh_big_float;
 mod vh_big_int;
+mod vh_elliptic_curve;
 mod vh_managed_buffer;
 mod vh_managed_map;
 
 pub use vh_big_float::VMHooksBigFloat;
 pub use vh_big_int::VMHooksBigInt;
+pub use vh_elliptic_curve::VMHooksEllipticCurve;
 pub use vh_managed_buffer::VMHooksManagedBuffer;
 pub use vh_managed_map::VMHooksManagedMap;
 
 use std::fmt::Debug;
 
-use crate::types::RawHandle;
+use crate::{types::RawHandle, vm_err_msg};
 
 use super::VMHooksError;
 
+/// Big floats are serialized the same way as Go's `big.Float.GobEncode`, for compatibility with the Go VM.
+const BIG_FLOAT_GOB_VERSION: u8 = 1;
+/// Precision of an `f64` mantissa, in bits.
+const BIG_FLOAT_PRECISION: u32 = 53;
+const BIG_FLOAT_FORM_ZERO: u8 = 0;
+const BIG_FLOAT_FORM_FINITE: u8 = 1;
+const BIG_FLOAT_FORM_INF: u8 = 2;
+/// Rounding mode "to nearest even" (0), accuracy "exact" (encoded as 1).
+const BIG_FLOAT_MODE_AND_ACCURACY: u8 = 1 << 3;
+
+/// Expects a finite value.
+fn big_float_gob_encode(value: f64) -> Vec<u8> {
+    let form = if value == 0.0 {
+        BIG_FLOAT_FORM_ZERO
+    } else {
+        BIG_FLOAT_FORM_FINITE
+    };
+    let mut flags = BIG_FLOAT_MODE_AND_ACCURACY | form << 1;
+    if value.is_sign_negative() {
+        flags |= 1;
+    }
+
+    let mut result = vec![BIG_FLOAT_GOB_VERSION, flags];
+    result.extend_from_slice(&BIG_FLOAT_PRECISION.to_be_bytes());
+    if form == BIG_FLOAT_FORM_FINITE {
+        // Go keeps the mantissa normalized, as 0.mantissa * 2^exponent, with the top bit set
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & ((1u64 << 52) - 1);
+        let (significand, exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1u64 << 52), biased_exponent - 1075)
+        };
+        let shift = significand.leading_zeros();
+        result.extend_from_slice(&(exponent + 64 - shift as i32).to_be_bytes());
+        result.extend_from_slice(&(significand << shift).to_be_bytes());
+    }
+    result
+}
+
+/// Only the first 64 bits of the mantissa are considered, which is more than an `f64` can hold.
+fn big_float_gob_decode(bytes: &[u8]) -> Option<f64> {
+    if bytes.len() < 6 || bytes[0] != BIG_FLOAT_GOB_VERSION {
+        return None;
+    }
+    let flags = bytes[1];
+    let abs_value = match (flags >> 1) & 3 {
+        BIG_FLOAT_FORM_ZERO => 0.0,
+        BIG_FLOAT_FORM_FINITE => {
+            if bytes.len() <= 10 {
+                return None;
+            }
+            let exponent = i32::from_be_bytes(bytes[6..10].try_into().unwrap());
+            let mut mantissa = [0u8; 8];
+            let mantissa_len = (bytes.len() - 10).min(8);
+            mantissa[..mantissa_len].copy_from_slice(&bytes[10..10 + mantissa_len]);
+            let fraction = u64::from_be_bytes(mantissa) as f64 / 2f64.powi(64);
+            fraction * 2f64.powi(exponent)
+        },
+        BIG_FLOAT_FORM_INF => f64::INFINITY,
+        _ => return None,
+    };
+    if flags & 1 == 1 {
+        Some(-abs_value)
+    } else {
+        Some(abs_value)
+    }
+}
+
 /// Provides VM hook implementations for methods that deal with more than one type of managed type.
 ///
 /// It is also the trait that unifies all managed type functionality.
 pub trait VMHooksManagedTypes:
-    VMHooksBigInt + VMHooksManagedBuffer + VMHooksManagedMap + VMHooksBigFloat + VMHooksError + Debug
+    VMHooksBigInt
+    + VMHooksManagedBuffer
+    + VMHooksManagedMap
+    + VMHooksBigFloat
+    + VMHooksEllipticCurve
+    + VMHooksError
+    + Debug
 {
     fn mb_to_big_int_unsigned(&self, buffer_handle: RawHandle, bi_handle: RawHandle) {
         let bytes = self.m_types_lock().mb_to_bytes(buffer_handle);
@@ -42,6 +120,29 @@ pub trait VMHooksManagedTypes:
         self.m_types_lock().mb_set(buffer_handle, bi_bytes);
     }
 
+    fn mb_to_big_float(&self, buffer_handle: RawHandle, bf_handle: RawHandle) {
+        let bytes = self.m_types_lock().mb_to_bytes(buffer_handle);
+        let Some(value) = big_float_gob_decode(&bytes) else {
+            self.vm_error(vm_err_msg::BIG_FLOAT_DECODE_ERROR)
+        };
+        if value.is_infinite() {
+            self.vm_error(vm_err_msg::INFINITY_FLOAT_OPERATION);
+        }
+        self.m_types_lock().bf_overwrite(bf_handle, value);
+    }
+
+    fn mb_from_big_float(&self, buffer_handle: RawHandle, bf_handle: RawHandle) {
+        let value = self.m_types_lock().bf_get_f64(bf_handle);
+        if value.is_infinite() {
+            self.vm_error(vm_err_msg::INFINITY_FLOAT_OPERATION);
+        }
+        if value.is_nan() {
+            self.vm_error(vm_err_msg::NUMBER_IS_NOT_NORMAL);
+        }
+        self.m_types_lock()
+            .mb_set(buffer_handle, big_float_gob_encode(value));
+    }
+
     fn bi_to_string(&self, bi_handle: RawHandle, str_handle: RawHandle) {
         let bi = self.m_types_lock().bi_get(bi_handle);
         let s = bi.to_string();
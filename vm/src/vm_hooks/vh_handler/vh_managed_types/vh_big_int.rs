@@ -143,6 +143,15 @@ pub trait VMHooksBigInt: VMHooksHandlerSource + VMHooksError {
     binary_bitwise_op_method! {bi_or, bitor}
     binary_bitwise_op_method! {bi_xor, bitxor}
 
+    fn bi_not(&self, dest: RawHandle, x: RawHandle) {
+        let bi_x = self.m_types_lock().bi_get(x);
+        if bi_x.sign() == num_bigint::Sign::Minus {
+            self.vm_error(vm_err_msg::BIG_INT_BITWISE_OPERATION_NEGATIVE);
+        }
+        let result = !bi_x;
+        self.m_types_lock().bi_overwrite(dest, result);
+    }
+
     fn bi_shr(&self, dest: RawHandle, x: RawHandle, bits: usize) {
         let bi_x = self.m_types_lock().bi_get(x);
         if bi_x.sign() == num_bigint::Sign::Minus {
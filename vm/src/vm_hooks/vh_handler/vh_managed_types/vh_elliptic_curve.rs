@@ -0,0 +1,240 @@
+use num_bigint::{BigInt, Sign};
+
+use crate::{
+    crypto_functions::EllipticCurve,
+    types::RawHandle,
+    vm_err_msg,
+    vm_hooks::{VMHooksError, VMHooksHandlerSource},
+};
+
+/// Elliptic curve hooks, covering the NIST P-224, P-256, P-384 and P-521 curves.
+///
+/// Points are passed around as pairs of big int handles, scalars and encoded points as managed buffers.
+pub trait VMHooksEllipticCurve: VMHooksHandlerSource + VMHooksError {
+    fn ec_create_from_name_bytes(&self, name: &[u8]) -> RawHandle {
+        let Some(curve) = EllipticCurve::from_name(name) else {
+            self.vm_error(vm_err_msg::EC_UNSUPPORTED_CURVE);
+        };
+        self.m_types_lock().ec_new(curve)
+    }
+
+    fn ec_create_from_name_mb(&self, name_handle: RawHandle) -> RawHandle {
+        let name = self.m_types_lock().mb_get(name_handle).to_vec();
+        self.ec_create_from_name_bytes(name.as_slice())
+    }
+
+    fn ec_get_values(
+        &self,
+        ec_handle: RawHandle,
+        field_order_handle: RawHandle,
+        base_point_order_handle: RawHandle,
+        eq_constant_handle: RawHandle,
+        x_base_point_handle: RawHandle,
+        y_base_point_handle: RawHandle,
+    ) {
+        let mut types = self.m_types_lock();
+        let curve = types.ec_get(ec_handle);
+        types.bi_overwrite(field_order_handle, curve.field_order);
+        types.bi_overwrite(base_point_order_handle, curve.base_point_order);
+        types.bi_overwrite(eq_constant_handle, curve.eq_constant);
+        types.bi_overwrite(x_base_point_handle, curve.x_base_point);
+        types.bi_overwrite(y_base_point_handle, curve.y_base_point);
+    }
+
+    fn ec_curve_length(&self, ec_handle: RawHandle) -> u32 {
+        self.m_types_lock().ec_get(ec_handle).size_in_bits
+    }
+
+    fn ec_private_key_byte_length(&self, ec_handle: RawHandle) -> u32 {
+        self.m_types_lock()
+            .ec_get(ec_handle)
+            .private_key_byte_length() as u32
+    }
+
+    fn ec_is_on_curve(
+        &self,
+        ec_handle: RawHandle,
+        x_handle: RawHandle,
+        y_handle: RawHandle,
+    ) -> bool {
+        let types = self.m_types_lock();
+        let curve = types.ec_get(ec_handle);
+        curve.is_on_curve(&types.bi_get(x_handle), &types.bi_get(y_handle))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn ec_add(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        x_first_point: RawHandle,
+        y_first_point: RawHandle,
+        x_second_point: RawHandle,
+        y_second_point: RawHandle,
+    ) {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        let (x1, y1) = self.ec_load_point(&curve, x_first_point, y_first_point);
+        let (x2, y2) = self.ec_load_point(&curve, x_second_point, y_second_point);
+        let result = curve.add(&x1, &y1, &x2, &y2);
+        self.ec_store_point(x_result_handle, y_result_handle, result);
+    }
+
+    fn ec_double(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        x_point_handle: RawHandle,
+        y_point_handle: RawHandle,
+    ) {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        let (x, y) = self.ec_load_point(&curve, x_point_handle, y_point_handle);
+        let result = curve.double(&x, &y);
+        self.ec_store_point(x_result_handle, y_result_handle, result);
+    }
+
+    fn ec_scalar_mult(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        x_point_handle: RawHandle,
+        y_point_handle: RawHandle,
+        data_handle: RawHandle,
+    ) {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        let (x, y) = self.ec_load_point(&curve, x_point_handle, y_point_handle);
+        let scalar = self.m_types_lock().mb_get(data_handle).to_vec();
+        let (x_result, y_result) = curve.scalar_mult(&x, &y, scalar.as_slice());
+        if !curve.is_on_curve(&x_result, &y_result) {
+            self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE);
+        }
+        self.ec_store_point(x_result_handle, y_result_handle, (x_result, y_result));
+    }
+
+    fn ec_scalar_base_mult(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data_handle: RawHandle,
+    ) {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        let scalar = self.m_types_lock().mb_get(data_handle).to_vec();
+        let (x_result, y_result) = curve.scalar_base_mult(scalar.as_slice());
+        if !curve.is_on_curve(&x_result, &y_result) {
+            self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE);
+        }
+        self.ec_store_point(x_result_handle, y_result_handle, (x_result, y_result));
+    }
+
+    fn ec_marshal(
+        &self,
+        ec_handle: RawHandle,
+        x_pair_handle: RawHandle,
+        y_pair_handle: RawHandle,
+        result_handle: RawHandle,
+    ) {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        let (x, y) = self.ec_load_point(&curve, x_pair_handle, y_pair_handle);
+        self.m_types_lock()
+            .mb_set(result_handle, curve.marshal(&x, &y));
+    }
+
+    fn ec_marshal_compressed(
+        &self,
+        ec_handle: RawHandle,
+        x_pair_handle: RawHandle,
+        y_pair_handle: RawHandle,
+        result_handle: RawHandle,
+    ) {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        let (x, y) = self.ec_load_point(&curve, x_pair_handle, y_pair_handle);
+        self.m_types_lock()
+            .mb_set(result_handle, curve.marshal_compressed(&x, &y));
+    }
+
+    fn ec_unmarshal(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data_handle: RawHandle,
+    ) {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        let data = self.m_types_lock().mb_get(data_handle).to_vec();
+        if data.len() != 1 + 2 * curve.byte_length() {
+            self.vm_error(vm_err_msg::EC_BUFFER_LENGTH_NOT_CORRECT);
+        }
+        let Some(point) = curve.unmarshal(data.as_slice()) else {
+            self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE);
+        };
+        self.ec_store_point(x_result_handle, y_result_handle, point);
+    }
+
+    fn ec_unmarshal_compressed(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data_handle: RawHandle,
+    ) {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        let data = self.m_types_lock().mb_get(data_handle).to_vec();
+        if data.len() != 1 + curve.byte_length() {
+            self.vm_error(vm_err_msg::EC_BUFFER_LENGTH_NOT_CORRECT);
+        }
+        let Some(point) = curve.unmarshal_compressed(data.as_slice()) else {
+            self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE);
+        };
+        self.ec_store_point(x_result_handle, y_result_handle, point);
+    }
+
+    /// Generates a private key using the VM random source,
+    /// then saves the corresponding public key in the given handles.
+    fn ec_generate_key(
+        &self,
+        x_pub_key_handle: RawHandle,
+        y_pub_key_handle: RawHandle,
+        ec_handle: RawHandle,
+        result_handle: RawHandle,
+    ) {
+        let curve = self.m_types_lock().ec_get(ec_handle);
+        let byte_len = curve.private_key_byte_length();
+        let excess_bits = byte_len * 8 - curve.base_point_order.bits() as usize;
+        let private_key = loop {
+            let mut candidate = self.random_next_bytes(byte_len);
+            candidate[0] &= 0xff >> excess_bits;
+            let value = BigInt::from_bytes_be(Sign::Plus, candidate.as_slice());
+            if value.sign() != Sign::NoSign && value < curve.base_point_order {
+                break candidate;
+            }
+        };
+
+        let public_key = curve.scalar_base_mult(private_key.as_slice());
+        self.ec_store_point(x_pub_key_handle, y_pub_key_handle, public_key);
+        self.m_types_lock().mb_set(result_handle, private_key);
+    }
+
+    /// Loads a point from big int handles, crashes if it is not on the curve.
+    fn ec_load_point(
+        &self,
+        curve: &EllipticCurve,
+        x_handle: RawHandle,
+        y_handle: RawHandle,
+    ) -> (BigInt, BigInt) {
+        let x = self.m_types_lock().bi_get(x_handle);
+        let y = self.m_types_lock().bi_get(y_handle);
+        if !curve.is_on_curve(&x, &y) {
+            self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE);
+        }
+        (x, y)
+    }
+
+    fn ec_store_point(&self, x_handle: RawHandle, y_handle: RawHandle, point: (BigInt, BigInt)) {
+        let mut types = self.m_types_lock();
+        types.bi_overwrite(x_handle, point.0);
+        types.bi_overwrite(y_handle, point.1);
+    }
+}
@@ -31,4 +31,14 @@ pub trait VMHooksManagedMap: VMHooksHandlerSource {
         let key = self.m_types_lock().mb_get(key_handle).to_vec();
         self.m_types_lock().mm_contains(map_handle, key.as_slice())
     }
+
+    fn mm_len(&self, map_handle: RawHandle) -> usize {
+        self.m_types_lock().mm_len(map_handle)
+    }
+
+    fn mm_keys(&self, map_handle: RawHandle, out_keys_handle: RawHandle) {
+        let mut m_types = self.m_types_lock();
+        let keys = m_types.mm_keys(map_handle);
+        m_types.mb_set_vec_of_bytes(out_keys_handle, keys);
+    }
 }
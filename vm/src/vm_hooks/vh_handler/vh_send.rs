@@ -1,10 +1,11 @@
 use crate::{
     tx_execution::builtin_function_names::{
-        ESDT_MULTI_TRANSFER_FUNC_NAME, ESDT_NFT_TRANSFER_FUNC_NAME, ESDT_TRANSFER_FUNC_NAME,
-        UPGRADE_CONTRACT_FUNC_NAME,
+        DELETE_CONTRACT_FUNC_NAME, ESDT_MULTI_TRANSFER_FUNC_NAME, ESDT_NFT_TRANSFER_FUNC_NAME,
+        ESDT_TRANSFER_FUNC_NAME, UPGRADE_CONTRACT_FUNC_NAME,
     },
     tx_mock::{AsyncCallTxData, Promise, TxFunctionName, TxTokenTransfer},
     types::{top_encode_big_uint, top_encode_u64, RawHandle, VMAddress, VMCodeMetadata},
+    vm_err_msg,
     vm_hooks::VMHooksHandlerSource,
 };
 use num_traits::Zero;
@@ -115,6 +116,16 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         self.perform_async_call(to, egld_value, UPGRADE_CONTRACT_FUNC_NAME.into(), arguments)
     }
 
+    /// Deletion is an async call, only the owner is allowed to perform it.
+    fn perform_delete_contract(&self, to: VMAddress, args: Vec<Vec<u8>>) -> ! {
+        self.perform_async_call(
+            to,
+            num_bigint::BigUint::zero(),
+            DELETE_CONTRACT_FUNC_NAME.into(),
+            args,
+        )
+    }
+
     fn transfer_value_execute(
         &self,
         to_handle: RawHandle,
@@ -152,6 +163,24 @@ pub trait VMHooksSend: VMHooksHandlerSource {
             .mb_to_function_name(endpoint_name_handle);
         let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
 
+        self.perform_multi_transfer_esdt_nft_execute(
+            to,
+            payments,
+            gas_limit,
+            endpoint_name,
+            arg_buffer,
+        );
+    }
+
+    /// A single payment is sent with the dedicated ESDT or NFT transfer.
+    fn perform_multi_transfer_esdt_nft_execute(
+        &self,
+        to: VMAddress,
+        payments: Vec<TxTokenTransfer>,
+        gas_limit: u64,
+        endpoint_name: TxFunctionName,
+        arg_buffer: Vec<Vec<u8>>,
+    ) {
         if payments.len() == 1 {
             let payment = payments[0].clone();
             if payment.nonce == 0 {
@@ -209,23 +238,48 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         _extra_gas_for_callback: u64,
         callback_closure_handle: RawHandle,
     ) {
-        let contract_address = self.current_address().clone();
         let to = self.m_types_lock().mb_to_address(to_handle);
         let egld_value = self.m_types_lock().bu_get(egld_value_handle);
         let endpoint_name = self
             .m_types_lock()
             .mb_to_function_name(endpoint_name_handle);
         let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
-        let tx_hash = self.tx_hash();
         let callback_closure_data = self.m_types_lock().mb_get(callback_closure_handle).to_vec();
 
+        self.register_promise(
+            to,
+            egld_value,
+            endpoint_name,
+            arg_buffer,
+            success_callback,
+            error_callback,
+            callback_closure_data,
+        );
+    }
+
+    /// The promise is only executed after the current transaction ends.
+    #[allow(clippy::too_many_arguments)]
+    fn register_promise(
+        &self,
+        to: VMAddress,
+        egld_value: num_bigint::BigUint,
+        endpoint_name: TxFunctionName,
+        arguments: Vec<Vec<u8>>,
+        success_callback: &[u8],
+        error_callback: &[u8],
+        callback_closure_data: Vec<u8>,
+    ) {
+        if self.input_ref().readonly {
+            self.vm_error(vm_err_msg::INVALID_CALL_ON_READ_ONLY_MODE);
+        }
+
         let call = AsyncCallTxData {
-            from: contract_address,
+            from: self.current_address().clone(),
             to,
             call_value: egld_value,
             endpoint_name,
-            arguments: arg_buffer,
-            tx_hash,
+            arguments,
+            tx_hash: self.tx_hash(),
         };
 
         let promise = Promise {
@@ -346,6 +400,18 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         self.perform_upgrade_contract(to, egld_value, code, code_metadata, arg_buffer)
     }
 
+    fn delete_contract(
+        &self,
+        sc_address_handle: RawHandle,
+        _gas: u64,
+        arg_buffer_handle: RawHandle,
+    ) {
+        let to = self.m_types_lock().mb_to_address(sc_address_handle);
+        let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
+
+        self.perform_delete_contract(to, arg_buffer)
+    }
+
     fn execute_on_dest_context_raw(
         &self,
         _gas: u64,
@@ -369,6 +435,49 @@ pub trait VMHooksSend: VMHooksHandlerSource {
             .mb_set_vec_of_bytes(result_handle, result);
     }
 
+    fn execute_on_same_context_raw(
+        &self,
+        _gas: u64,
+        to_handle: RawHandle,
+        egld_value_handle: RawHandle,
+        endpoint_name_handle: RawHandle,
+        arg_buffer_handle: RawHandle,
+        result_handle: RawHandle,
+    ) {
+        let to = self.m_types_lock().mb_to_address(to_handle);
+        let egld_value = self.m_types_lock().bu_get(egld_value_handle);
+        let endpoint_name = self
+            .m_types_lock()
+            .mb_to_function_name(endpoint_name_handle);
+        let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
+
+        let result =
+            self.perform_execute_on_same_context(to, egld_value, endpoint_name, arg_buffer);
+
+        self.m_types_lock()
+            .mb_set_vec_of_bytes(result_handle, result);
+    }
+
+    fn execute_read_only_raw(
+        &self,
+        _gas: u64,
+        to_handle: RawHandle,
+        endpoint_name_handle: RawHandle,
+        arg_buffer_handle: RawHandle,
+        result_handle: RawHandle,
+    ) {
+        let to = self.m_types_lock().mb_to_address(to_handle);
+        let endpoint_name = self
+            .m_types_lock()
+            .mb_to_function_name(endpoint_name_handle);
+        let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
+
+        let result = self.perform_execute_read_only(to, endpoint_name, arg_buffer);
+
+        self.m_types_lock()
+            .mb_set_vec_of_bytes(result_handle, result);
+    }
+
     fn clean_return_data(&self) {
         let mut tx_result = self.result_lock();
         tx_result.result_values.clear();
@@ -1,10 +1,27 @@
+use num_bigint::{BigInt, BigUint};
+use num_traits::{ToPrimitive, Zero};
+
 use crate::{
-    types::{RawHandle, VMAddress},
+    types::{top_encode_big_uint, RawHandle, VMAddress},
+    vm_err_msg,
     vm_hooks::VMHooksHandlerSource,
 };
 
 use super::VMHooksManagedTypes;
 
+/// Storage status codes, as returned by the legacy storage hooks of the Go VM.
+pub const STORAGE_UNCHANGED: i32 = 0;
+pub const STORAGE_MODIFIED: i32 = 1;
+pub const STORAGE_ADDED: i32 = 2;
+pub const STORAGE_DELETED: i32 = 3;
+
+/// Storage locks are kept under the original key, with this suffix.
+const TIME_LOCK_KEY_SUFFIX: &[u8] = b"timelock";
+
+fn time_lock_key(key: &[u8]) -> Vec<u8> {
+    [key, TIME_LOCK_KEY_SUFFIX].concat()
+}
+
 pub trait VMHooksStorageRead: VMHooksHandlerSource {
     fn storage_load_managed_buffer_raw(&self, key_handle: RawHandle, dest: RawHandle) {
         let value = self.storage_read(self.m_types_lock().mb_get(key_handle));
@@ -21,6 +38,37 @@ pub trait VMHooksStorageRead: VMHooksHandlerSource {
         let value = self.storage_read_any_address(&address, self.m_types_lock().mb_get(key_handle));
         self.m_types_lock().mb_set(dest, value);
     }
+
+    /// Halts if the stored value does not fit in a `u64`.
+    fn storage_load_u64(&self, key: &[u8]) -> u64 {
+        BigUint::from_bytes_be(&self.storage_read(key))
+            .to_u64()
+            .unwrap_or_else(|| self.vm_error(vm_err_msg::STORAGE_VALUE_OUT_OF_RANGE))
+    }
+
+    /// Halts if the stored value does not fit in an `i64`.
+    fn storage_load_i64(&self, key: &[u8]) -> i64 {
+        BigInt::from_signed_bytes_be(&self.storage_read(key))
+            .to_i64()
+            .unwrap_or_else(|| self.vm_error(vm_err_msg::STORAGE_VALUE_OUT_OF_RANGE))
+    }
+
+    fn get_storage_lock(&self, key: &[u8]) -> i64 {
+        let value = BigUint::from_bytes_be(&self.storage_read(&time_lock_key(key)));
+        value.iter_u64_digits().next().unwrap_or_default() as i64
+    }
+
+    /// Returns -1 for invalid locks, 1 if the lock is still active, 0 otherwise.
+    fn is_storage_locked(&self, key: &[u8]) -> i32 {
+        let time_lock = self.get_storage_lock(key);
+        if time_lock < 0 {
+            -1
+        } else if time_lock as u64 <= self.get_current_block_info().block_timestamp {
+            0
+        } else {
+            1
+        }
+    }
 }
 
 pub trait VMHooksStorageWrite: VMHooksHandlerSource + VMHooksManagedTypes {
@@ -30,4 +78,37 @@ pub trait VMHooksStorageWrite: VMHooksHandlerSource + VMHooksManagedTypes {
         let value_bytes = types.mb_get(value_handle);
         self.storage_write(key_bytes, value_bytes);
     }
+
+    /// Writes to storage and reports what happened to the stored value.
+    fn storage_store_with_status(&self, key: &[u8], value: &[u8]) -> i32 {
+        let old_value = self.storage_read(key);
+        self.storage_write(key, value);
+        if old_value == value {
+            STORAGE_UNCHANGED
+        } else if old_value.is_empty() {
+            STORAGE_ADDED
+        } else if value.is_empty() {
+            STORAGE_DELETED
+        } else {
+            STORAGE_MODIFIED
+        }
+    }
+
+    fn storage_store_u64(&self, key: &[u8], value: u64) -> i32 {
+        self.storage_store_with_status(key, &top_encode_big_uint(&BigUint::from(value)))
+    }
+
+    fn storage_store_i64(&self, key: &[u8], value: i64) -> i32 {
+        let bytes = if value.is_zero() {
+            Vec::new()
+        } else {
+            BigInt::from(value).to_signed_bytes_be()
+        };
+        self.storage_store_with_status(key, &bytes)
+    }
+
+    /// Only the magnitude of the timestamp is stored, like in the Go VM.
+    fn set_storage_lock(&self, key: &[u8], lock_timestamp: i64) -> i32 {
+        self.storage_store_u64(&time_lock_key(key), lock_timestamp.unsigned_abs())
+    }
 }
@@ -3,5 +3,7 @@ mod vh_single_tx_api;
 mod vh_static_api;
 
 pub use vh_debug_api::DebugApiVMHooksHandler;
-pub use vh_single_tx_api::{SingleTxApiData, SingleTxApiVMHooksHandler};
+pub use vh_single_tx_api::{
+    SingleTxApiData, SingleTxApiVMHooksHandler, StorageAccessCount, StorageAccessCounter,
+};
 pub use vh_static_api::StaticApiVMHooksHandler;
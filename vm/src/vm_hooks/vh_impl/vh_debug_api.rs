@@ -1,8 +1,10 @@
 use std::sync::{Arc, MutexGuard};
 
 use multiversx_chain_vm_executor::BreakpointValue;
+use num_traits::Zero;
 
 use crate::{
+    schedule::GasSchedule,
     tx_execution::execute_current_tx_context_input,
     tx_mock::{
         async_call_tx_input, AsyncCallTxData, BackTransfers, BlockchainUpdate, CallType, TxCache,
@@ -12,11 +14,15 @@ use crate::{
     vm_err_msg,
     vm_hooks::{
         VMHooksBigFloat, VMHooksBigInt, VMHooksBlockchain, VMHooksCallValue, VMHooksCrypto,
-        VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError, VMHooksErrorManaged,
-        VMHooksHandler, VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
-        VMHooksManagedTypes, VMHooksSend, VMHooksStorageRead, VMHooksStorageWrite,
+        VMHooksEllipticCurve, VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError,
+        VMHooksErrorManaged, VMHooksHandler, VMHooksHandlerSource, VMHooksLog,
+        VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes, VMHooksSend,
+        VMHooksStorageRead, VMHooksStorageWrite,
+    },
+    world_mock::{
+        reserved::STORAGE_RESERVED_PREFIX, AccountData, BlockInfo, EsdtSystemScData,
+        ShardCoordinator,
     },
-    world_mock::{reserved::STORAGE_RESERVED_PREFIX, AccountData, BlockInfo},
 };
 
 /// A simple wrapper around a managed type container RefCell.
@@ -49,6 +55,28 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         self.0.input_ref()
     }
 
+    fn gas_schedule(&self) -> Option<&GasSchedule> {
+        self.0.vm_ref.gas_schedule.as_ref()
+    }
+
+    fn use_gas(&self, gas: u64) {
+        if self.gas_schedule().is_none() {
+            return;
+        }
+
+        let gas_limit = self.input_ref().gas_limit;
+        let mut tx_result = self.result_lock();
+        tx_result.gas_used = tx_result.gas_used.saturating_add(gas);
+        if tx_result.gas_used > gas_limit {
+            drop(tx_result); // this avoid to poison the mutex
+            self.halt_with_error(5, vm_err_msg::OUT_OF_GAS);
+        }
+    }
+
+    fn gas_used(&self) -> u64 {
+        self.result_lock().gas_used
+    }
+
     fn random_next_bytes(&self, length: usize) -> Vec<u8> {
         self.0.rng_lock().next_bytes(length)
     }
@@ -64,6 +92,9 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
     }
 
     fn storage_write(&self, key: &[u8], value: &[u8]) {
+        if self.input_ref().readonly {
+            self.vm_error(vm_err_msg::CANNOT_WRITE_ON_READ_ONLY);
+        }
         self.check_reserved_key(key);
 
         self.0.with_contract_account_mut(|account| {
@@ -79,6 +110,10 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         &self.0.blockchain_ref().current_block_info
     }
 
+    fn shard_coordinator(&self) -> Option<ShardCoordinator> {
+        self.0.blockchain_ref().shard_coordinator
+    }
+
     fn back_transfers_lock(&self) -> MutexGuard<BackTransfers> {
         self.0.back_transfers_lock()
     }
@@ -95,6 +130,12 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             .unwrap_or_else(|| panic!("Account is not a smart contract, it has no code"))
     }
 
+    fn esdt_system_sc_data(&self, token_identifier: &[u8]) -> Option<EsdtSystemScData> {
+        self.0
+            .blockchain_cache()
+            .with_esdt_token(token_identifier, |token_data| token_data.cloned())
+    }
+
     fn perform_async_call(
         &self,
         to: VMAddress,
@@ -102,6 +143,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) -> ! {
+        self.check_not_readonly();
         let async_call_data = self.create_async_call_data(to, egld_value, func_name, arguments);
         // the cell is no longer needed, since we end in a panic
         let mut tx_result = self.result_lock();
@@ -118,21 +160,78 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>> {
+        if self.input_ref().readonly
+            && (egld_value > num_bigint::BigUint::zero()
+                || self
+                    .0
+                    .vm_ref
+                    .builtin_functions
+                    .is_builtin_function(func_name.as_str()))
+        {
+            self.vm_error(vm_err_msg::INVALID_CALL_ON_READ_ONLY_MODE);
+        }
+
         let async_call_data = self.create_async_call_data(to, egld_value, func_name, arguments);
-        let tx_input = async_call_tx_input(&async_call_data, CallType::ExecuteOnDestContext);
-        let tx_cache = TxCache::new(self.0.blockchain_cache_arc());
-        let (tx_result, blockchain_updates) = self.0.vm_ref.execute_builtin_function_or_default(
-            tx_input,
-            tx_cache,
-            execute_current_tx_context_input,
-        );
+        let mut tx_input = async_call_tx_input(&async_call_data, CallType::ExecuteOnDestContext);
+        tx_input.readonly = self.input_ref().readonly;
+        self.execute_sync_call(tx_input)
+    }
 
-        if tx_result.result_status == 0 {
-            self.sync_call_post_processing(tx_result, blockchain_updates)
-        } else {
-            // also kill current execution
-            self.halt_with_error(tx_result.result_status, &tx_result.result_message)
+    fn perform_execute_on_same_context(
+        &self,
+        to: VMAddress,
+        egld_value: num_bigint::BigUint,
+        func_name: TxFunctionName,
+        arguments: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        if self
+            .0
+            .vm_ref
+            .builtin_functions
+            .is_builtin_function(func_name.as_str())
+        {
+            self.vm_error(vm_err_msg::BUILTIN_CALL_ON_SAME_CONTEXT_DISALLOWED);
+        }
+        if self.input_ref().readonly && egld_value > num_bigint::BigUint::zero() {
+            self.vm_error(vm_err_msg::INVALID_CALL_ON_READ_ONLY_MODE);
+        }
+        let is_contract = self
+            .account_data(&to)
+            .is_some_and(|account| account.contract_path.is_some());
+        if !is_contract {
+            self.vm_error(vm_err_msg::CONTRACT_NOT_FOUND);
+        }
+
+        // the code of `to` runs on behalf of the current contract
+        let current_address = self.current_address().clone();
+        let async_call_data =
+            self.create_async_call_data(current_address, egld_value, func_name, arguments);
+        let mut tx_input = async_call_tx_input(&async_call_data, CallType::ExecuteOnSameContext);
+        tx_input.code_address = Some(to);
+        tx_input.readonly = self.input_ref().readonly;
+        self.execute_sync_call(tx_input)
+    }
+
+    fn perform_execute_read_only(
+        &self,
+        to: VMAddress,
+        func_name: TxFunctionName,
+        arguments: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        if self
+            .0
+            .vm_ref
+            .builtin_functions
+            .is_builtin_function(func_name.as_str())
+        {
+            self.vm_error(vm_err_msg::INVALID_CALL_ON_READ_ONLY_MODE);
         }
+
+        let async_call_data =
+            self.create_async_call_data(to, num_bigint::BigUint::zero(), func_name, arguments);
+        let mut tx_input = async_call_tx_input(&async_call_data, CallType::ExecuteOnDestContext);
+        tx_input.readonly = true;
+        self.execute_sync_call(tx_input)
     }
 
     fn perform_deploy(
@@ -142,9 +241,10 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         code_metadata: VMCodeMetadata,
         args: Vec<Vec<u8>>,
     ) -> (VMAddress, Vec<Vec<u8>>) {
+        self.check_not_readonly();
         let contract_address = self.current_address();
         let tx_hash = self.tx_hash();
-        let tx_input = TxInput {
+        let mut tx_input = TxInput {
             from: contract_address.clone(),
             to: VMAddress::zero(),
             egld_value,
@@ -156,6 +256,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             tx_hash,
             ..Default::default()
         };
+        self.forward_gas(&mut tx_input);
 
         let tx_cache = TxCache::new(self.0.blockchain_cache_arc());
         tx_cache.increase_acount_nonce(contract_address);
@@ -184,11 +285,13 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) {
+        self.check_not_readonly();
         let async_call_data = self.create_async_call_data(to, egld_value, func_name, arguments);
         let mut tx_input = async_call_tx_input(&async_call_data, CallType::TransferExecute);
         if self.is_back_transfer(&tx_input) {
             tx_input.call_type = CallType::BackTransfer;
         }
+        self.forward_gas(&mut tx_input);
 
         let tx_cache = TxCache::new(self.0.blockchain_cache_arc());
         let (tx_result, blockchain_updates) = self.0.vm_ref.execute_builtin_function_or_default(
@@ -229,11 +332,30 @@ impl DebugApiVMHooksHandler {
         }
     }
 
+    /// Executes a synchronous call, a failure also kills the current execution.
+    fn execute_sync_call(&self, mut tx_input: TxInput) -> Vec<Vec<u8>> {
+        self.forward_gas(&mut tx_input);
+        let tx_cache = TxCache::new(self.0.blockchain_cache_arc());
+        let (tx_result, blockchain_updates) = self.0.vm_ref.execute_builtin_function_or_default(
+            tx_input,
+            tx_cache,
+            execute_current_tx_context_input,
+        );
+
+        if tx_result.result_status == 0 {
+            self.sync_call_post_processing(tx_result, blockchain_updates)
+        } else {
+            self.halt_with_error(tx_result.result_status, &tx_result.result_message)
+        }
+    }
+
     fn sync_call_post_processing(
         &self,
         tx_result: TxResult,
         blockchain_updates: BlockchainUpdate,
     ) -> Vec<Vec<u8>> {
+        self.use_gas(tx_result.gas_used);
+
         self.0.blockchain_cache().commit_updates(blockchain_updates);
 
         self.0.result_lock().merge_after_sync_call(&tx_result);
@@ -246,6 +368,19 @@ impl DebugApiVMHooksHandler {
         tx_result.result_values
     }
 
+    /// With gas metering, synchronous calls get all the remaining gas.
+    fn forward_gas(&self, tx_input: &mut TxInput) {
+        if self.gas_schedule().is_some() {
+            tx_input.gas_limit = self.get_gas_left();
+        }
+    }
+
+    fn check_not_readonly(&self) {
+        if self.input_ref().readonly {
+            self.vm_error(vm_err_msg::INVALID_CALL_ON_READ_ONLY_MODE);
+        }
+    }
+
     fn check_reserved_key(&self, key: &[u8]) {
         if key.starts_with(STORAGE_RESERVED_PREFIX) {
             self.vm_error("cannot write to storage under reserved key");
@@ -268,6 +403,7 @@ impl VMHooksBigInt for DebugApiVMHooksHandler {}
 impl VMHooksManagedBuffer for DebugApiVMHooksHandler {}
 impl VMHooksManagedMap for DebugApiVMHooksHandler {}
 impl VMHooksBigFloat for DebugApiVMHooksHandler {}
+impl VMHooksEllipticCurve for DebugApiVMHooksHandler {}
 impl VMHooksManagedTypes for DebugApiVMHooksHandler {}
 
 impl VMHooksCallValue for DebugApiVMHooksHandler {}
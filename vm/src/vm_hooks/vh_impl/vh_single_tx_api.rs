@@ -1,6 +1,9 @@
 use std::{
     collections::HashMap,
-    sync::{Arc, Mutex, MutexGuard},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
 };
 
 use crate::{
@@ -8,11 +11,12 @@ use crate::{
     types::{VMAddress, VMCodeMetadata},
     vm_hooks::{
         VMHooksBigFloat, VMHooksBigInt, VMHooksBlockchain, VMHooksCallValue, VMHooksCrypto,
-        VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError, VMHooksErrorManaged,
-        VMHooksHandler, VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
-        VMHooksManagedTypes, VMHooksSend, VMHooksStorageRead, VMHooksStorageWrite,
+        VMHooksEllipticCurve, VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError,
+        VMHooksErrorManaged, VMHooksHandler, VMHooksHandlerSource, VMHooksLog,
+        VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes, VMHooksSend,
+        VMHooksStorageRead, VMHooksStorageWrite,
     },
-    world_mock::{AccountData, BlockInfo},
+    world_mock::{AccountData, BlockInfo, EsdtSystemScData},
 };
 
 #[derive(Default, Debug)]
@@ -23,6 +27,7 @@ pub struct SingleTxApiData {
     pub tx_result_cell: Mutex<TxResult>,
     pub previous_block_info: BlockInfo,
     pub current_block_info: BlockInfo,
+    pub storage_access_counter: StorageAccessCounter,
 }
 
 impl SingleTxApiData {
@@ -38,6 +43,42 @@ impl SingleTxApiData {
     }
 }
 
+/// Counts the storage reads and writes, so that tests can check the storage access pattern of their code.
+#[derive(Default, Debug)]
+pub struct StorageAccessCounter {
+    reads: AtomicUsize,
+    writes: AtomicUsize,
+}
+
+/// A snapshot of the storage access counts.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StorageAccessCount {
+    pub reads: usize,
+    pub writes: usize,
+}
+
+impl StorageAccessCounter {
+    pub fn count(&self) -> StorageAccessCount {
+        StorageAccessCount {
+            reads: self.reads.load(Ordering::Relaxed),
+            writes: self.writes.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn reset(&self) {
+        self.reads.store(0, Ordering::Relaxed);
+        self.writes.store(0, Ordering::Relaxed);
+    }
+
+    fn record_read(&self) {
+        self.reads.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_write(&self) {
+        self.writes.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct SingleTxApiVMHooksHandler(Arc<SingleTxApiData>);
 
@@ -74,12 +115,14 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
     }
 
     fn storage_read_any_address(&self, address: &VMAddress, key: &[u8]) -> Vec<u8> {
+        self.0.storage_access_counter.record_read();
         self.0.with_account_mut(address, |account| {
             account.storage.get(key).cloned().unwrap_or_default()
         })
     }
 
     fn storage_write(&self, key: &[u8], value: &[u8]) {
+        self.0.storage_access_counter.record_write();
         self.0.with_account_mut(&self.0.tx_input_box.to, |account| {
             account.storage.insert(key.to_vec(), value.to_vec());
         });
@@ -105,6 +148,10 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
         vec![]
     }
 
+    fn esdt_system_sc_data(&self, _token_identifier: &[u8]) -> Option<EsdtSystemScData> {
+        None
+    }
+
     fn perform_async_call(
         &self,
         _to: VMAddress,
@@ -125,6 +172,25 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
         panic!("cannot launch contract calls in the SingleTxApi")
     }
 
+    fn perform_execute_on_same_context(
+        &self,
+        _to: VMAddress,
+        _egld_value: num_bigint::BigUint,
+        _func_name: TxFunctionName,
+        _args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        panic!("cannot launch contract calls in the SingleTxApi")
+    }
+
+    fn perform_execute_read_only(
+        &self,
+        _to: VMAddress,
+        _func_name: TxFunctionName,
+        _args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        panic!("cannot launch contract calls in the SingleTxApi")
+    }
+
     fn perform_deploy(
         &self,
         _egld_value: num_bigint::BigUint,
@@ -150,6 +216,7 @@ impl VMHooksBigInt for SingleTxApiVMHooksHandler {}
 impl VMHooksManagedBuffer for SingleTxApiVMHooksHandler {}
 impl VMHooksManagedMap for SingleTxApiVMHooksHandler {}
 impl VMHooksBigFloat for SingleTxApiVMHooksHandler {}
+impl VMHooksEllipticCurve for SingleTxApiVMHooksHandler {}
 impl VMHooksManagedTypes for SingleTxApiVMHooksHandler {}
 
 impl VMHooksCallValue for SingleTxApiVMHooksHandler {}
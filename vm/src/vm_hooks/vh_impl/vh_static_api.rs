@@ -5,11 +5,12 @@ use crate::{
     types::{VMAddress, VMCodeMetadata},
     vm_hooks::{
         VMHooksBigFloat, VMHooksBigInt, VMHooksBlockchain, VMHooksCallValue, VMHooksCrypto,
-        VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError, VMHooksErrorManaged,
-        VMHooksHandler, VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
-        VMHooksManagedTypes, VMHooksSend, VMHooksStorageRead, VMHooksStorageWrite,
+        VMHooksEllipticCurve, VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError,
+        VMHooksErrorManaged, VMHooksHandler, VMHooksHandlerSource, VMHooksLog,
+        VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes, VMHooksSend,
+        VMHooksStorageRead, VMHooksStorageWrite,
     },
-    world_mock::{AccountData, BlockInfo},
+    world_mock::{AccountData, BlockInfo, EsdtSystemScData},
 };
 
 /// A simple wrapper around a managed type container Mutex.
@@ -80,6 +81,10 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
         panic!("cannot access account data in the StaticApi")
     }
 
+    fn esdt_system_sc_data(&self, _token_identifier: &[u8]) -> Option<EsdtSystemScData> {
+        panic!("cannot access the ESDT system SC in the StaticApi")
+    }
+
     fn perform_async_call(
         &self,
         _to: VMAddress,
@@ -100,6 +105,25 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
         panic!("cannot launch contract calls in the StaticApi")
     }
 
+    fn perform_execute_on_same_context(
+        &self,
+        _to: VMAddress,
+        _egld_value: num_bigint::BigUint,
+        _func_name: TxFunctionName,
+        _args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        panic!("cannot launch contract calls in the StaticApi")
+    }
+
+    fn perform_execute_read_only(
+        &self,
+        _to: VMAddress,
+        _func_name: TxFunctionName,
+        _args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        panic!("cannot launch contract calls in the StaticApi")
+    }
+
     fn perform_deploy(
         &self,
         _egld_value: num_bigint::BigUint,
@@ -125,6 +149,7 @@ impl VMHooksBigInt for StaticApiVMHooksHandler {}
 impl VMHooksManagedBuffer for StaticApiVMHooksHandler {}
 impl VMHooksManagedMap for StaticApiVMHooksHandler {}
 impl VMHooksBigFloat for StaticApiVMHooksHandler {}
+impl VMHooksEllipticCurve for StaticApiVMHooksHandler {}
 impl VMHooksManagedTypes for StaticApiVMHooksHandler {}
 
 impl VMHooksCallValue for StaticApiVMHooksHandler {}
@@ -1,9 +1,10 @@
 use std::{fmt::Debug, sync::MutexGuard};
 
 use crate::{
+    schedule::GasSchedule,
     tx_mock::{BackTransfers, TxFunctionName, TxInput, TxLog, TxManagedTypes, TxResult},
     types::{VMAddress, VMCodeMetadata, H256},
-    world_mock::{AccountData, BlockInfo},
+    world_mock::{AccountData, BlockInfo, EsdtSystemScData, ShardCoordinator},
 };
 
 /// Abstracts away the borrowing of a managed types structure.
@@ -18,6 +19,20 @@ pub trait VMHooksHandlerSource: Debug {
 
     fn input_ref(&self) -> &TxInput;
 
+    /// Only available when gas metering is enabled.
+    fn gas_schedule(&self) -> Option<&GasSchedule> {
+        None
+    }
+
+    /// Consumes gas, halts with "out of gas" when going over the gas limit.
+    ///
+    /// Does nothing when gas metering is disabled.
+    fn use_gas(&self, _gas: u64) {}
+
+    fn gas_used(&self) -> u64 {
+        0
+    }
+
     fn current_address(&self) -> &VMAddress {
         &self.input_ref().to
     }
@@ -47,6 +62,11 @@ pub trait VMHooksHandlerSource: Debug {
 
     fn get_current_block_info(&self) -> &BlockInfo;
 
+    /// Only available if sharding is enabled in the blockchain mock.
+    fn shard_coordinator(&self) -> Option<ShardCoordinator> {
+        None
+    }
+
     fn back_transfers_lock(&self) -> MutexGuard<BackTransfers>;
 
     /// For ownership reasons, needs to return a clone.
@@ -64,6 +84,9 @@ pub trait VMHooksHandlerSource: Debug {
 
     fn account_code(&self, address: &VMAddress) -> Vec<u8>;
 
+    /// Token-wide data kept by the ESDT system SC, `None` for tokens it did not issue.
+    fn esdt_system_sc_data(&self, token_identifier: &[u8]) -> Option<EsdtSystemScData>;
+
     fn perform_async_call(
         &self,
         to: VMAddress,
@@ -80,6 +103,23 @@ pub trait VMHooksHandlerSource: Debug {
         args: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>>;
 
+    /// Runs the code of `to` on the storage and balance of the current contract.
+    fn perform_execute_on_same_context(
+        &self,
+        to: VMAddress,
+        egld_value: num_bigint::BigUint,
+        func_name: TxFunctionName,
+        args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>>;
+
+    /// Synchronous call that is not allowed to modify the state.
+    fn perform_execute_read_only(
+        &self,
+        to: VMAddress,
+        func_name: TxFunctionName,
+        args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>>;
+
     fn perform_deploy(
         &self,
         egld_value: num_bigint::BigUint,
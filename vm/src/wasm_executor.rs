@@ -0,0 +1,7 @@
+mod wasmi_executor;
+mod wasmi_imports;
+mod wasmi_instance;
+mod wasmi_memory;
+
+pub use wasmi_executor::WasmiExecutor;
+pub use wasmi_instance::WasmiInstance;
@@ -0,0 +1,90 @@
+use multiversx_chain_vm_executor::{
+    CompilationOptions, Executor, ExecutorError, Instance, OpcodeCost,
+};
+use wasmi::{Config, Engine, Linker};
+
+use crate::{
+    tx_mock::TxContextStack,
+    vm_hooks::{DebugApiVMHooksHandler, VMHooksDispatcher},
+};
+
+use super::{
+    wasmi_imports::add_vm_hooks_imports,
+    wasmi_instance::{WasmiHostState, WasmiInstance},
+};
+
+/// All WebAssembly binaries start with these bytes.
+const WASM_MAGIC: &[u8] = b"\0asm";
+
+/// Runs compiled contract code (`.wasm`) in-process, using the wasmi interpreter.
+///
+/// Contract code that is not WebAssembly, e.g. the identifier of a contract registered in the debugger,
+/// is passed on to the fallback executor.
+///
+/// The VM hooks operate on the top of the tx context stack, just like the debugger contracts do.
+/// If a gas schedule is configured, the contract code is metered with wasmi fuel, limited to the gas left,
+/// and the fuel it consumes is charged as gas, on top of the gas used by the VM hooks.
+pub struct WasmiExecutor {
+    engine: Engine,
+    linker: Linker<WasmiHostState>,
+    fallback: Box<dyn Executor + Send + Sync>,
+}
+
+impl WasmiExecutor {
+    pub fn new(fallback: Box<dyn Executor + Send + Sync>) -> Self {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let mut linker = Linker::new(&engine);
+        add_vm_hooks_imports(&mut linker).expect("error registering the VM hooks");
+        WasmiExecutor {
+            engine,
+            linker,
+            fallback,
+        }
+    }
+}
+
+impl Executor for WasmiExecutor {
+    fn set_vm_hooks_ptr(
+        &mut self,
+        _vm_hooks_ptr: *mut std::ffi::c_void,
+    ) -> Result<(), ExecutorError> {
+        Ok(())
+    }
+
+    fn set_opcode_cost(&mut self, opcode_cost: &OpcodeCost) -> Result<(), ExecutorError> {
+        self.fallback.set_opcode_cost(opcode_cost)
+    }
+
+    fn new_instance(
+        &self,
+        wasm_bytes: &[u8],
+        compilation_options: &CompilationOptions,
+    ) -> Result<Box<dyn Instance>, ExecutorError> {
+        if !wasm_bytes.starts_with(WASM_MAGIC) {
+            return self.fallback.new_instance(wasm_bytes, compilation_options);
+        }
+
+        let tx_context = TxContextStack::static_peek();
+        let gas_left = tx_context.vm_ref.is_gas_metering_enabled().then(|| {
+            let gas_limit = tx_context.input_ref().gas_limit;
+            gas_limit.saturating_sub(tx_context.result_lock().gas_used)
+        });
+        let handler = DebugApiVMHooksHandler::new(tx_context);
+        let vm_hooks = VMHooksDispatcher::new(Box::new(handler));
+        let instance = WasmiInstance::new(&self.engine, &self.linker, wasm_bytes, vm_hooks)?;
+        if let Some(gas_left) = gas_left {
+            instance.set_points_limit(gas_left)?;
+        }
+        Ok(Box::new(instance))
+    }
+
+    fn new_instance_from_cache(
+        &self,
+        _cache_bytes: &[u8],
+        _compilation_options: &CompilationOptions,
+    ) -> Result<Box<dyn Instance>, ExecutorError> {
+        Err("WasmiExecutor new_instance_from_cache not supported".into())
+    }
+}
@@ -0,0 +1,3733 @@
+// Code generated by tools/wasmi-imports-generator. DO NOT EDIT.
+//
+// Contains the `VMHooks` trait of multiversx-chain-vm-executor, and the hooks that `VMHooksDispatcher` adds to it,
+// with the import names that contracts use for the VM hooks.
+
+use multiversx_chain_vm_executor::VMHooks;
+use wasmi::{Caller, Error, Linker};
+
+use crate::vm_hooks::VMHooksDispatcher;
+
+use super::{wasmi_instance::WasmiHostState, wasmi_memory::WasmiMemoryView};
+
+/// Gives a VM hook access to both the dispatcher and the instance memory.
+///
+/// The dispatcher gets the current memory bounds, to check the regions that the hook accesses.
+fn with_vm_hooks<R, F>(caller: &mut Caller<'_, WasmiHostState>, f: F) -> Result<R, Error>
+where
+    F: FnOnce(&VMHooksDispatcher, WasmiMemoryView) -> Result<R, Error>,
+{
+    let memory = caller
+        .data()
+        .memory
+        .ok_or_else(|| Error::new("contract does not export its memory"))?;
+    let (data, host_state) = memory.data_and_store_mut(caller);
+    let memory_view = WasmiMemoryView::new(data);
+    let (start, end) = memory_view.bounds();
+    host_state.vm_hooks.set_memory_bounds(start, end);
+    f(&host_state.vm_hooks, memory_view)
+}
+
+/// Registers all VM hooks as functions of the `env` module.
+pub(super) fn add_vm_hooks_imports(linker: &mut Linker<WasmiHostState>) -> Result<(), Error> {
+    linker.func_wrap(
+        "env",
+        "getGasLeft",
+        |caller: Caller<'_, WasmiHostState>| -> Result<i64, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_gas_left())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getSCAddress",
+        |mut caller: Caller<'_, WasmiHostState>, result_offset: i32| -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let result_offset = memory.ptr(result_offset)?;
+                vm_hooks.get_sc_address(result_offset);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getOwnerAddress",
+        |mut caller: Caller<'_, WasmiHostState>, result_offset: i32| -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let result_offset = memory.ptr(result_offset)?;
+                vm_hooks.get_owner_address(result_offset);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getShardOfAddress",
+        |mut caller: Caller<'_, WasmiHostState>, address_offset: i32| -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let address_offset = memory.ptr(address_offset)?;
+                Ok(vm_hooks.get_shard_of_address(address_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "isSmartContract",
+        |mut caller: Caller<'_, WasmiHostState>, address_offset: i32| -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let address_offset = memory.ptr(address_offset)?;
+                Ok(vm_hooks.is_smart_contract(address_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "signalError",
+        |mut caller: Caller<'_, WasmiHostState>,
+         message_offset: i32,
+         message_length: i32|
+         -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (message_offset, message_length) =
+                    memory.slice(message_offset, message_length)?;
+                vm_hooks.signal_error(message_offset, message_length);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getExternalBalance",
+        |mut caller: Caller<'_, WasmiHostState>,
+         address_offset: i32,
+         result_offset: i32|
+         -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let address_offset = memory.ptr(address_offset)?;
+                let result_offset = memory.ptr(result_offset)?;
+                vm_hooks.get_external_balance(address_offset, result_offset);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getBlockHash",
+        |mut caller: Caller<'_, WasmiHostState>,
+         nonce: i64,
+         result_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let result_offset = memory.ptr(result_offset)?;
+                Ok(vm_hooks.get_block_hash(nonce, result_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getESDTBalance",
+        |mut caller: Caller<'_, WasmiHostState>,
+         address_offset: i32,
+         token_id_offset: i32,
+         token_id_len: i32,
+         nonce: i64,
+         result_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let address_offset = memory.ptr(address_offset)?;
+                let (token_id_offset, token_id_len) =
+                    memory.slice(token_id_offset, token_id_len)?;
+                let result_offset = memory.ptr(result_offset)?;
+                Ok(vm_hooks.get_esdt_balance(
+                    address_offset,
+                    token_id_offset,
+                    token_id_len,
+                    nonce,
+                    result_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getESDTNFTNameLength",
+        |mut caller: Caller<'_, WasmiHostState>,
+         address_offset: i32,
+         token_id_offset: i32,
+         token_id_len: i32,
+         nonce: i64|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let address_offset = memory.ptr(address_offset)?;
+                let (token_id_offset, token_id_len) =
+                    memory.slice(token_id_offset, token_id_len)?;
+                Ok(vm_hooks.get_esdt_nft_name_length(
+                    address_offset,
+                    token_id_offset,
+                    token_id_len,
+                    nonce,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getESDTNFTAttributeLength",
+        |mut caller: Caller<'_, WasmiHostState>,
+         address_offset: i32,
+         token_id_offset: i32,
+         token_id_len: i32,
+         nonce: i64|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let address_offset = memory.ptr(address_offset)?;
+                let (token_id_offset, token_id_len) =
+                    memory.slice(token_id_offset, token_id_len)?;
+                Ok(vm_hooks.get_esdt_nft_attribute_length(
+                    address_offset,
+                    token_id_offset,
+                    token_id_len,
+                    nonce,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getESDTNFTURILength",
+        |mut caller: Caller<'_, WasmiHostState>,
+         address_offset: i32,
+         token_id_offset: i32,
+         token_id_len: i32,
+         nonce: i64|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let address_offset = memory.ptr(address_offset)?;
+                let (token_id_offset, token_id_len) =
+                    memory.slice(token_id_offset, token_id_len)?;
+                Ok(vm_hooks.get_esdt_nft_uri_length(
+                    address_offset,
+                    token_id_offset,
+                    token_id_len,
+                    nonce,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getESDTTokenData",
+        |mut caller: Caller<'_, WasmiHostState>,
+         address_offset: i32,
+         token_id_offset: i32,
+         token_id_len: i32,
+         nonce: i64,
+         value_handle: i32,
+         properties_offset: i32,
+         hash_offset: i32,
+         name_offset: i32,
+         attributes_offset: i32,
+         creator_offset: i32,
+         royalties_handle: i32,
+         uris_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let address_offset = memory.ptr(address_offset)?;
+                let (token_id_offset, token_id_len) =
+                    memory.slice(token_id_offset, token_id_len)?;
+                let properties_offset = memory.ptr(properties_offset)?;
+                let hash_offset = memory.ptr(hash_offset)?;
+                let name_offset = memory.ptr(name_offset)?;
+                let attributes_offset = memory.ptr(attributes_offset)?;
+                let creator_offset = memory.ptr(creator_offset)?;
+                let uris_offset = memory.ptr(uris_offset)?;
+                Ok(vm_hooks.get_esdt_token_data(
+                    address_offset,
+                    token_id_offset,
+                    token_id_len,
+                    nonce,
+                    value_handle,
+                    properties_offset,
+                    hash_offset,
+                    name_offset,
+                    attributes_offset,
+                    creator_offset,
+                    royalties_handle,
+                    uris_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getESDTLocalRoles",
+        |caller: Caller<'_, WasmiHostState>, token_id_handle: i32| -> Result<i64, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_esdt_local_roles(token_id_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "validateTokenIdentifier",
+        |caller: Caller<'_, WasmiHostState>, token_id_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.validate_token_identifier(token_id_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "transferValue",
+        |mut caller: Caller<'_, WasmiHostState>,
+         dest_offset: i32,
+         value_offset: i32,
+         data_offset: i32,
+         length: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let dest_offset = memory.ptr(dest_offset)?;
+                let value_offset = memory.ptr(value_offset)?;
+                let (data_offset, length) = memory.slice(data_offset, length)?;
+                Ok(vm_hooks.transfer_value(dest_offset, value_offset, data_offset, length))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "transferValueExecute",
+        |mut caller: Caller<'_, WasmiHostState>,
+         dest_offset: i32,
+         value_offset: i32,
+         gas_limit: i64,
+         function_offset: i32,
+         function_length: i32,
+         num_arguments: i32,
+         arguments_length_offset: i32,
+         data_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let dest_offset = memory.ptr(dest_offset)?;
+                let value_offset = memory.ptr(value_offset)?;
+                let (function_offset, function_length) =
+                    memory.slice(function_offset, function_length)?;
+                let arguments_length_offset = memory.ptr(arguments_length_offset)?;
+                let data_offset = memory.ptr(data_offset)?;
+                Ok(vm_hooks.transfer_value_execute(
+                    dest_offset,
+                    value_offset,
+                    gas_limit,
+                    function_offset,
+                    function_length,
+                    num_arguments,
+                    arguments_length_offset,
+                    data_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "transferESDTExecute",
+        |mut caller: Caller<'_, WasmiHostState>,
+         dest_offset: i32,
+         token_id_offset: i32,
+         token_id_len: i32,
+         value_offset: i32,
+         gas_limit: i64,
+         function_offset: i32,
+         function_length: i32,
+         num_arguments: i32,
+         arguments_length_offset: i32,
+         data_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let dest_offset = memory.ptr(dest_offset)?;
+                let (token_id_offset, token_id_len) =
+                    memory.slice(token_id_offset, token_id_len)?;
+                let value_offset = memory.ptr(value_offset)?;
+                let (function_offset, function_length) =
+                    memory.slice(function_offset, function_length)?;
+                let arguments_length_offset = memory.ptr(arguments_length_offset)?;
+                let data_offset = memory.ptr(data_offset)?;
+                Ok(vm_hooks.transfer_esdt_execute(
+                    dest_offset,
+                    token_id_offset,
+                    token_id_len,
+                    value_offset,
+                    gas_limit,
+                    function_offset,
+                    function_length,
+                    num_arguments,
+                    arguments_length_offset,
+                    data_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "transferESDTNFTExecute",
+        |mut caller: Caller<'_, WasmiHostState>,
+         dest_offset: i32,
+         token_id_offset: i32,
+         token_id_len: i32,
+         value_offset: i32,
+         nonce: i64,
+         gas_limit: i64,
+         function_offset: i32,
+         function_length: i32,
+         num_arguments: i32,
+         arguments_length_offset: i32,
+         data_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let dest_offset = memory.ptr(dest_offset)?;
+                let (token_id_offset, token_id_len) =
+                    memory.slice(token_id_offset, token_id_len)?;
+                let value_offset = memory.ptr(value_offset)?;
+                let (function_offset, function_length) =
+                    memory.slice(function_offset, function_length)?;
+                let arguments_length_offset = memory.ptr(arguments_length_offset)?;
+                let data_offset = memory.ptr(data_offset)?;
+                Ok(vm_hooks.transfer_esdt_nft_execute(
+                    dest_offset,
+                    token_id_offset,
+                    token_id_len,
+                    value_offset,
+                    nonce,
+                    gas_limit,
+                    function_offset,
+                    function_length,
+                    num_arguments,
+                    arguments_length_offset,
+                    data_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "multiTransferESDTNFTExecute",
+        |mut caller: Caller<'_, WasmiHostState>,
+         dest_offset: i32,
+         num_token_transfers: i32,
+         token_transfers_args_length_offset: i32,
+         token_transfer_data_offset: i32,
+         gas_limit: i64,
+         function_offset: i32,
+         function_length: i32,
+         num_arguments: i32,
+         arguments_length_offset: i32,
+         data_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let dest_offset = memory.ptr(dest_offset)?;
+                let token_transfers_args_length_offset =
+                    memory.ptr(token_transfers_args_length_offset)?;
+                let token_transfer_data_offset = memory.ptr(token_transfer_data_offset)?;
+                let (function_offset, function_length) =
+                    memory.slice(function_offset, function_length)?;
+                let arguments_length_offset = memory.ptr(arguments_length_offset)?;
+                let data_offset = memory.ptr(data_offset)?;
+                Ok(vm_hooks.multi_transfer_esdt_nft_execute(
+                    dest_offset,
+                    num_token_transfers,
+                    token_transfers_args_length_offset,
+                    token_transfer_data_offset,
+                    gas_limit,
+                    function_offset,
+                    function_length,
+                    num_arguments,
+                    arguments_length_offset,
+                    data_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "createAsyncCall",
+        |mut caller: Caller<'_, WasmiHostState>,
+         dest_offset: i32,
+         value_offset: i32,
+         data_offset: i32,
+         data_length: i32,
+         success_offset: i32,
+         success_length: i32,
+         error_offset: i32,
+         error_length: i32,
+         gas: i64,
+         extra_gas_for_callback: i64|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let dest_offset = memory.ptr(dest_offset)?;
+                let value_offset = memory.ptr(value_offset)?;
+                let (data_offset, data_length) = memory.slice(data_offset, data_length)?;
+                let (success_offset, success_length) =
+                    memory.slice(success_offset, success_length)?;
+                let (error_offset, error_length) = memory.slice(error_offset, error_length)?;
+                Ok(vm_hooks.create_async_call(
+                    dest_offset,
+                    value_offset,
+                    data_offset,
+                    data_length,
+                    success_offset,
+                    success_length,
+                    error_offset,
+                    error_length,
+                    gas,
+                    extra_gas_for_callback,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "setAsyncContextCallback",
+        |mut caller: Caller<'_, WasmiHostState>,
+         callback: i32,
+         callback_length: i32,
+         data: i32,
+         data_length: i32,
+         gas: i64|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (callback, callback_length) = memory.slice(callback, callback_length)?;
+                let (data, data_length) = memory.slice(data, data_length)?;
+                Ok(vm_hooks.set_async_context_callback(
+                    callback,
+                    callback_length,
+                    data,
+                    data_length,
+                    gas,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "upgradeContract",
+        |mut caller: Caller<'_, WasmiHostState>,
+         dest_offset: i32,
+         gas_limit: i64,
+         value_offset: i32,
+         code_offset: i32,
+         code_metadata_offset: i32,
+         length: i32,
+         num_arguments: i32,
+         arguments_length_offset: i32,
+         data_offset: i32|
+         -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let dest_offset = memory.ptr(dest_offset)?;
+                let value_offset = memory.ptr(value_offset)?;
+                let (code_offset, length) = memory.slice(code_offset, length)?;
+                let code_metadata_offset = memory.ptr(code_metadata_offset)?;
+                let arguments_length_offset = memory.ptr(arguments_length_offset)?;
+                let data_offset = memory.ptr(data_offset)?;
+                vm_hooks.upgrade_contract(
+                    dest_offset,
+                    gas_limit,
+                    value_offset,
+                    code_offset,
+                    code_metadata_offset,
+                    length,
+                    num_arguments,
+                    arguments_length_offset,
+                    data_offset,
+                );
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "upgradeFromSourceContract",
+        |mut caller: Caller<'_, WasmiHostState>,
+         dest_offset: i32,
+         gas_limit: i64,
+         value_offset: i32,
+         source_contract_address_offset: i32,
+         code_metadata_offset: i32,
+         num_arguments: i32,
+         arguments_length_offset: i32,
+         data_offset: i32|
+         -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let dest_offset = memory.ptr(dest_offset)?;
+                let value_offset = memory.ptr(value_offset)?;
+                let source_contract_address_offset = memory.ptr(source_contract_address_offset)?;
+                let code_metadata_offset = memory.ptr(code_metadata_offset)?;
+                let arguments_length_offset = memory.ptr(arguments_length_offset)?;
+                let data_offset = memory.ptr(data_offset)?;
+                vm_hooks.upgrade_from_source_contract(
+                    dest_offset,
+                    gas_limit,
+                    value_offset,
+                    source_contract_address_offset,
+                    code_metadata_offset,
+                    num_arguments,
+                    arguments_length_offset,
+                    data_offset,
+                );
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "deleteContract",
+        |mut caller: Caller<'_, WasmiHostState>,
+         dest_offset: i32,
+         gas_limit: i64,
+         num_arguments: i32,
+         arguments_length_offset: i32,
+         data_offset: i32|
+         -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let dest_offset = memory.ptr(dest_offset)?;
+                let arguments_length_offset = memory.ptr(arguments_length_offset)?;
+                let data_offset = memory.ptr(data_offset)?;
+                vm_hooks.delete_contract(
+                    dest_offset,
+                    gas_limit,
+                    num_arguments,
+                    arguments_length_offset,
+                    data_offset,
+                );
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "asyncCall",
+        |mut caller: Caller<'_, WasmiHostState>,
+         dest_offset: i32,
+         value_offset: i32,
+         data_offset: i32,
+         length: i32|
+         -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let dest_offset = memory.ptr(dest_offset)?;
+                let value_offset = memory.ptr(value_offset)?;
+                let (data_offset, length) = memory.slice(data_offset, length)?;
+                vm_hooks.async_call(dest_offset, value_offset, data_offset, length);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getArgumentLength",
+        |caller: Caller<'_, WasmiHostState>, id: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_argument_length(id))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getArgument",
+        |mut caller: Caller<'_, WasmiHostState>, id: i32, arg_offset: i32| -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let arg_offset = memory.ptr(arg_offset)?;
+                Ok(vm_hooks.get_argument(id, arg_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getFunction",
+        |mut caller: Caller<'_, WasmiHostState>, function_offset: i32| -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let function_offset = memory.ptr(function_offset)?;
+                Ok(vm_hooks.get_function(function_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getNumArguments",
+        |caller: Caller<'_, WasmiHostState>| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_num_arguments())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "storageStore",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32,
+         data_offset: i32,
+         data_length: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                let (data_offset, data_length) = memory.slice(data_offset, data_length)?;
+                Ok(vm_hooks.storage_store(key_offset, key_length, data_offset, data_length))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "storageLoadLength",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                Ok(vm_hooks.storage_load_length(key_offset, key_length))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "storageLoadFromAddress",
+        |mut caller: Caller<'_, WasmiHostState>,
+         address_offset: i32,
+         key_offset: i32,
+         key_length: i32,
+         data_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let address_offset = memory.ptr(address_offset)?;
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                let data_offset = memory.ptr(data_offset)?;
+                Ok(vm_hooks.storage_load_from_address(
+                    address_offset,
+                    key_offset,
+                    key_length,
+                    data_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "storageLoad",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32,
+         data_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                let data_offset = memory.ptr(data_offset)?;
+                Ok(vm_hooks.storage_load(key_offset, key_length, data_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "setStorageLock",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32,
+         lock_timestamp: i64|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                Ok(vm_hooks.set_storage_lock(key_offset, key_length, lock_timestamp))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getStorageLock",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32|
+         -> Result<i64, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                Ok(vm_hooks.get_storage_lock(key_offset, key_length))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "isStorageLocked",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                Ok(vm_hooks.is_storage_locked(key_offset, key_length))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "clearStorageLock",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                Ok(vm_hooks.clear_storage_lock(key_offset, key_length))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getCaller",
+        |mut caller: Caller<'_, WasmiHostState>, result_offset: i32| -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let result_offset = memory.ptr(result_offset)?;
+                vm_hooks.get_caller(result_offset);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "checkNoPayment",
+        |caller: Caller<'_, WasmiHostState>| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.check_no_payment();
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getCallValue",
+        |mut caller: Caller<'_, WasmiHostState>, result_offset: i32| -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let result_offset = memory.ptr(result_offset)?;
+                Ok(vm_hooks.get_call_value(result_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getESDTValue",
+        |mut caller: Caller<'_, WasmiHostState>, result_offset: i32| -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let result_offset = memory.ptr(result_offset)?;
+                Ok(vm_hooks.get_esdt_value(result_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getESDTValueByIndex",
+        |mut caller: Caller<'_, WasmiHostState>,
+         result_offset: i32,
+         index: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let result_offset = memory.ptr(result_offset)?;
+                Ok(vm_hooks.get_esdt_value_by_index(result_offset, index))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getESDTTokenName",
+        |mut caller: Caller<'_, WasmiHostState>, result_offset: i32| -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let result_offset = memory.ptr(result_offset)?;
+                Ok(vm_hooks.get_esdt_token_name(result_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getESDTTokenNameByIndex",
+        |mut caller: Caller<'_, WasmiHostState>,
+         result_offset: i32,
+         index: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let result_offset = memory.ptr(result_offset)?;
+                Ok(vm_hooks.get_esdt_token_name_by_index(result_offset, index))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getESDTTokenNonce",
+        |caller: Caller<'_, WasmiHostState>| -> Result<i64, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_esdt_token_nonce())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getESDTTokenNonceByIndex",
+        |caller: Caller<'_, WasmiHostState>, index: i32| -> Result<i64, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_esdt_token_nonce_by_index(index))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getCurrentESDTNFTNonce",
+        |mut caller: Caller<'_, WasmiHostState>,
+         address_offset: i32,
+         token_id_offset: i32,
+         token_id_len: i32|
+         -> Result<i64, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let address_offset = memory.ptr(address_offset)?;
+                let (token_id_offset, token_id_len) =
+                    memory.slice(token_id_offset, token_id_len)?;
+                Ok(vm_hooks.get_current_esdt_nft_nonce(
+                    address_offset,
+                    token_id_offset,
+                    token_id_len,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getESDTTokenType",
+        |caller: Caller<'_, WasmiHostState>| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_esdt_token_type())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getESDTTokenTypeByIndex",
+        |caller: Caller<'_, WasmiHostState>, index: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_esdt_token_type_by_index(index))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getNumESDTTransfers",
+        |caller: Caller<'_, WasmiHostState>| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_num_esdt_transfers())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getCallValueTokenName",
+        |mut caller: Caller<'_, WasmiHostState>,
+         call_value_offset: i32,
+         token_name_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let call_value_offset = memory.ptr(call_value_offset)?;
+                let token_name_offset = memory.ptr(token_name_offset)?;
+                Ok(vm_hooks.get_call_value_token_name(call_value_offset, token_name_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getCallValueTokenNameByIndex",
+        |mut caller: Caller<'_, WasmiHostState>,
+         call_value_offset: i32,
+         token_name_offset: i32,
+         index: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let call_value_offset = memory.ptr(call_value_offset)?;
+                let token_name_offset = memory.ptr(token_name_offset)?;
+                Ok(vm_hooks.get_call_value_token_name_by_index(
+                    call_value_offset,
+                    token_name_offset,
+                    index,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "writeLog",
+        |mut caller: Caller<'_, WasmiHostState>,
+         data_pointer: i32,
+         data_length: i32,
+         topic_ptr: i32,
+         num_topics: i32|
+         -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (data_pointer, data_length) = memory.slice(data_pointer, data_length)?;
+                let topic_ptr = memory.ptr(topic_ptr)?;
+                vm_hooks.write_log(data_pointer, data_length, topic_ptr, num_topics);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "writeEventLog",
+        |mut caller: Caller<'_, WasmiHostState>,
+         num_topics: i32,
+         topic_lengths_offset: i32,
+         topic_offset: i32,
+         data_offset: i32,
+         data_length: i32|
+         -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let topic_lengths_offset = memory.ptr(topic_lengths_offset)?;
+                let topic_offset = memory.ptr(topic_offset)?;
+                let (data_offset, data_length) = memory.slice(data_offset, data_length)?;
+                vm_hooks.write_event_log(
+                    num_topics,
+                    topic_lengths_offset,
+                    topic_offset,
+                    data_offset,
+                    data_length,
+                );
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getBlockTimestamp",
+        |caller: Caller<'_, WasmiHostState>| -> Result<i64, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_block_timestamp())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getBlockNonce",
+        |caller: Caller<'_, WasmiHostState>| -> Result<i64, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_block_nonce())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getBlockRound",
+        |caller: Caller<'_, WasmiHostState>| -> Result<i64, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_block_round())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getBlockEpoch",
+        |caller: Caller<'_, WasmiHostState>| -> Result<i64, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_block_epoch())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getBlockRandomSeed",
+        |mut caller: Caller<'_, WasmiHostState>, pointer: i32| -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let pointer = memory.ptr(pointer)?;
+                vm_hooks.get_block_random_seed(pointer);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getStateRootHash",
+        |mut caller: Caller<'_, WasmiHostState>, pointer: i32| -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let pointer = memory.ptr(pointer)?;
+                vm_hooks.get_state_root_hash(pointer);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getPrevBlockTimestamp",
+        |caller: Caller<'_, WasmiHostState>| -> Result<i64, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_prev_block_timestamp())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getPrevBlockNonce",
+        |caller: Caller<'_, WasmiHostState>| -> Result<i64, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_prev_block_nonce())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getPrevBlockRound",
+        |caller: Caller<'_, WasmiHostState>| -> Result<i64, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_prev_block_round())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getPrevBlockEpoch",
+        |caller: Caller<'_, WasmiHostState>| -> Result<i64, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_prev_block_epoch())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getPrevBlockRandomSeed",
+        |mut caller: Caller<'_, WasmiHostState>, pointer: i32| -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let pointer = memory.ptr(pointer)?;
+                vm_hooks.get_prev_block_random_seed(pointer);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "finish",
+        |mut caller: Caller<'_, WasmiHostState>, pointer: i32, length: i32| -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (pointer, length) = memory.slice(pointer, length)?;
+                vm_hooks.finish(pointer, length);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "executeOnSameContext",
+        |mut caller: Caller<'_, WasmiHostState>,
+         gas_limit: i64,
+         address_offset: i32,
+         value_offset: i32,
+         function_offset: i32,
+         function_length: i32,
+         num_arguments: i32,
+         arguments_length_offset: i32,
+         data_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let address_offset = memory.ptr(address_offset)?;
+                let value_offset = memory.ptr(value_offset)?;
+                let (function_offset, function_length) =
+                    memory.slice(function_offset, function_length)?;
+                let arguments_length_offset = memory.ptr(arguments_length_offset)?;
+                let data_offset = memory.ptr(data_offset)?;
+                Ok(vm_hooks.execute_on_same_context(
+                    gas_limit,
+                    address_offset,
+                    value_offset,
+                    function_offset,
+                    function_length,
+                    num_arguments,
+                    arguments_length_offset,
+                    data_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "executeOnDestContext",
+        |mut caller: Caller<'_, WasmiHostState>,
+         gas_limit: i64,
+         address_offset: i32,
+         value_offset: i32,
+         function_offset: i32,
+         function_length: i32,
+         num_arguments: i32,
+         arguments_length_offset: i32,
+         data_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let address_offset = memory.ptr(address_offset)?;
+                let value_offset = memory.ptr(value_offset)?;
+                let (function_offset, function_length) =
+                    memory.slice(function_offset, function_length)?;
+                let arguments_length_offset = memory.ptr(arguments_length_offset)?;
+                let data_offset = memory.ptr(data_offset)?;
+                Ok(vm_hooks.execute_on_dest_context(
+                    gas_limit,
+                    address_offset,
+                    value_offset,
+                    function_offset,
+                    function_length,
+                    num_arguments,
+                    arguments_length_offset,
+                    data_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "executeReadOnly",
+        |mut caller: Caller<'_, WasmiHostState>,
+         gas_limit: i64,
+         address_offset: i32,
+         function_offset: i32,
+         function_length: i32,
+         num_arguments: i32,
+         arguments_length_offset: i32,
+         data_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let address_offset = memory.ptr(address_offset)?;
+                let (function_offset, function_length) =
+                    memory.slice(function_offset, function_length)?;
+                let arguments_length_offset = memory.ptr(arguments_length_offset)?;
+                let data_offset = memory.ptr(data_offset)?;
+                Ok(vm_hooks.execute_read_only(
+                    gas_limit,
+                    address_offset,
+                    function_offset,
+                    function_length,
+                    num_arguments,
+                    arguments_length_offset,
+                    data_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "createContract",
+        |mut caller: Caller<'_, WasmiHostState>,
+         gas_limit: i64,
+         value_offset: i32,
+         code_offset: i32,
+         code_metadata_offset: i32,
+         length: i32,
+         result_offset: i32,
+         num_arguments: i32,
+         arguments_length_offset: i32,
+         data_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let value_offset = memory.ptr(value_offset)?;
+                let (code_offset, length) = memory.slice(code_offset, length)?;
+                let code_metadata_offset = memory.ptr(code_metadata_offset)?;
+                let result_offset = memory.ptr(result_offset)?;
+                let arguments_length_offset = memory.ptr(arguments_length_offset)?;
+                let data_offset = memory.ptr(data_offset)?;
+                Ok(vm_hooks.create_contract(
+                    gas_limit,
+                    value_offset,
+                    code_offset,
+                    code_metadata_offset,
+                    length,
+                    result_offset,
+                    num_arguments,
+                    arguments_length_offset,
+                    data_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "deployFromSourceContract",
+        |mut caller: Caller<'_, WasmiHostState>,
+         gas_limit: i64,
+         value_offset: i32,
+         source_contract_address_offset: i32,
+         code_metadata_offset: i32,
+         result_address_offset: i32,
+         num_arguments: i32,
+         arguments_length_offset: i32,
+         data_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let value_offset = memory.ptr(value_offset)?;
+                let source_contract_address_offset = memory.ptr(source_contract_address_offset)?;
+                let code_metadata_offset = memory.ptr(code_metadata_offset)?;
+                let result_address_offset = memory.ptr(result_address_offset)?;
+                let arguments_length_offset = memory.ptr(arguments_length_offset)?;
+                let data_offset = memory.ptr(data_offset)?;
+                Ok(vm_hooks.deploy_from_source_contract(
+                    gas_limit,
+                    value_offset,
+                    source_contract_address_offset,
+                    code_metadata_offset,
+                    result_address_offset,
+                    num_arguments,
+                    arguments_length_offset,
+                    data_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getNumReturnData",
+        |caller: Caller<'_, WasmiHostState>| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_num_return_data())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getReturnDataSize",
+        |caller: Caller<'_, WasmiHostState>, result_id: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_return_data_size(result_id))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getReturnData",
+        |mut caller: Caller<'_, WasmiHostState>,
+         result_id: i32,
+         data_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let data_offset = memory.ptr(data_offset)?;
+                Ok(vm_hooks.get_return_data(result_id, data_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "cleanReturnData",
+        |caller: Caller<'_, WasmiHostState>| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.clean_return_data();
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "deleteFromReturnData",
+        |caller: Caller<'_, WasmiHostState>, result_id: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.delete_from_return_data(result_id);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getOriginalTxHash",
+        |mut caller: Caller<'_, WasmiHostState>, data_offset: i32| -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let data_offset = memory.ptr(data_offset)?;
+                vm_hooks.get_original_tx_hash(data_offset);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getCurrentTxHash",
+        |mut caller: Caller<'_, WasmiHostState>, data_offset: i32| -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let data_offset = memory.ptr(data_offset)?;
+                vm_hooks.get_current_tx_hash(data_offset);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getPrevTxHash",
+        |mut caller: Caller<'_, WasmiHostState>, data_offset: i32| -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let data_offset = memory.ptr(data_offset)?;
+                vm_hooks.get_prev_tx_hash(data_offset);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedSCAddress",
+        |caller: Caller<'_, WasmiHostState>, destination_handle: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_sc_address(destination_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedOwnerAddress",
+        |caller: Caller<'_, WasmiHostState>, destination_handle: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_owner_address(destination_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedCaller",
+        |caller: Caller<'_, WasmiHostState>, destination_handle: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_caller(destination_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedSignalError",
+        |caller: Caller<'_, WasmiHostState>, err_handle: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_signal_error(err_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedWriteLog",
+        |caller: Caller<'_, WasmiHostState>,
+         topics_handle: i32,
+         data_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_write_log(topics_handle, data_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedGetOriginalTxHash",
+        |caller: Caller<'_, WasmiHostState>, result_handle: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_get_original_tx_hash(result_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedGetStateRootHash",
+        |caller: Caller<'_, WasmiHostState>, result_handle: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_get_state_root_hash(result_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedGetBlockRandomSeed",
+        |caller: Caller<'_, WasmiHostState>, result_handle: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_get_block_random_seed(result_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedGetPrevBlockRandomSeed",
+        |caller: Caller<'_, WasmiHostState>, result_handle: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_get_prev_block_random_seed(result_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedGetReturnData",
+        |caller: Caller<'_, WasmiHostState>,
+         result_id: i32,
+         result_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_get_return_data(result_id, result_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedGetMultiESDTCallValue",
+        |caller: Caller<'_, WasmiHostState>, multi_call_value_handle: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_get_multi_esdt_call_value(multi_call_value_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedGetBackTransfers",
+        |caller: Caller<'_, WasmiHostState>,
+         esdt_transfers_value_handle: i32,
+         call_value_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_get_back_transfers(esdt_transfers_value_handle, call_value_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedGetESDTBalance",
+        |caller: Caller<'_, WasmiHostState>,
+         address_handle: i32,
+         token_id_handle: i32,
+         nonce: i64,
+         value_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_get_esdt_balance(address_handle, token_id_handle, nonce, value_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedGetESDTTokenData",
+        |caller: Caller<'_, WasmiHostState>,
+         address_handle: i32,
+         token_id_handle: i32,
+         nonce: i64,
+         value_handle: i32,
+         properties_handle: i32,
+         hash_handle: i32,
+         name_handle: i32,
+         attributes_handle: i32,
+         creator_handle: i32,
+         royalties_handle: i32,
+         uris_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_get_esdt_token_data(
+                address_handle,
+                token_id_handle,
+                nonce,
+                value_handle,
+                properties_handle,
+                hash_handle,
+                name_handle,
+                attributes_handle,
+                creator_handle,
+                royalties_handle,
+                uris_handle,
+            );
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedAsyncCall",
+        |caller: Caller<'_, WasmiHostState>,
+         dest_handle: i32,
+         value_handle: i32,
+         function_handle: i32,
+         arguments_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_async_call(
+                dest_handle,
+                value_handle,
+                function_handle,
+                arguments_handle,
+            );
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedCreateAsyncCall",
+        |mut caller: Caller<'_, WasmiHostState>,
+         dest_handle: i32,
+         value_handle: i32,
+         function_handle: i32,
+         arguments_handle: i32,
+         success_offset: i32,
+         success_length: i32,
+         error_offset: i32,
+         error_length: i32,
+         gas: i64,
+         extra_gas_for_callback: i64,
+         callback_closure_handle: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (success_offset, success_length) =
+                    memory.slice(success_offset, success_length)?;
+                let (error_offset, error_length) = memory.slice(error_offset, error_length)?;
+                Ok(vm_hooks.managed_create_async_call(
+                    dest_handle,
+                    value_handle,
+                    function_handle,
+                    arguments_handle,
+                    success_offset,
+                    success_length,
+                    error_offset,
+                    error_length,
+                    gas,
+                    extra_gas_for_callback,
+                    callback_closure_handle,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedGetCallbackClosure",
+        |caller: Caller<'_, WasmiHostState>, callback_closure_handle: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_get_callback_closure(callback_closure_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedUpgradeFromSourceContract",
+        |caller: Caller<'_, WasmiHostState>,
+         dest_handle: i32,
+         gas: i64,
+         value_handle: i32,
+         address_handle: i32,
+         code_metadata_handle: i32,
+         arguments_handle: i32,
+         result_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_upgrade_from_source_contract(
+                dest_handle,
+                gas,
+                value_handle,
+                address_handle,
+                code_metadata_handle,
+                arguments_handle,
+                result_handle,
+            );
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedUpgradeContract",
+        |caller: Caller<'_, WasmiHostState>,
+         dest_handle: i32,
+         gas: i64,
+         value_handle: i32,
+         code_handle: i32,
+         code_metadata_handle: i32,
+         arguments_handle: i32,
+         result_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_upgrade_contract(
+                dest_handle,
+                gas,
+                value_handle,
+                code_handle,
+                code_metadata_handle,
+                arguments_handle,
+                result_handle,
+            );
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedDeleteContract",
+        |caller: Caller<'_, WasmiHostState>,
+         dest_handle: i32,
+         gas_limit: i64,
+         arguments_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_delete_contract(dest_handle, gas_limit, arguments_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedDeployFromSourceContract",
+        |caller: Caller<'_, WasmiHostState>,
+         gas: i64,
+         value_handle: i32,
+         address_handle: i32,
+         code_metadata_handle: i32,
+         arguments_handle: i32,
+         result_address_handle: i32,
+         result_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_deploy_from_source_contract(
+                gas,
+                value_handle,
+                address_handle,
+                code_metadata_handle,
+                arguments_handle,
+                result_address_handle,
+                result_handle,
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedCreateContract",
+        |caller: Caller<'_, WasmiHostState>,
+         gas: i64,
+         value_handle: i32,
+         code_handle: i32,
+         code_metadata_handle: i32,
+         arguments_handle: i32,
+         result_address_handle: i32,
+         result_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_create_contract(
+                gas,
+                value_handle,
+                code_handle,
+                code_metadata_handle,
+                arguments_handle,
+                result_address_handle,
+                result_handle,
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedExecuteReadOnly",
+        |caller: Caller<'_, WasmiHostState>,
+         gas: i64,
+         address_handle: i32,
+         function_handle: i32,
+         arguments_handle: i32,
+         result_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_execute_read_only(
+                gas,
+                address_handle,
+                function_handle,
+                arguments_handle,
+                result_handle,
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedExecuteOnSameContext",
+        |caller: Caller<'_, WasmiHostState>,
+         gas: i64,
+         address_handle: i32,
+         value_handle: i32,
+         function_handle: i32,
+         arguments_handle: i32,
+         result_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_execute_on_same_context(
+                gas,
+                address_handle,
+                value_handle,
+                function_handle,
+                arguments_handle,
+                result_handle,
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedExecuteOnDestContext",
+        |caller: Caller<'_, WasmiHostState>,
+         gas: i64,
+         address_handle: i32,
+         value_handle: i32,
+         function_handle: i32,
+         arguments_handle: i32,
+         result_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_execute_on_dest_context(
+                gas,
+                address_handle,
+                value_handle,
+                function_handle,
+                arguments_handle,
+                result_handle,
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedMultiTransferESDTNFTExecute",
+        |caller: Caller<'_, WasmiHostState>,
+         dst_handle: i32,
+         token_transfers_handle: i32,
+         gas_limit: i64,
+         function_handle: i32,
+         arguments_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_multi_transfer_esdt_nft_execute(
+                dst_handle,
+                token_transfers_handle,
+                gas_limit,
+                function_handle,
+                arguments_handle,
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedTransferValueExecute",
+        |caller: Caller<'_, WasmiHostState>,
+         dst_handle: i32,
+         value_handle: i32,
+         gas_limit: i64,
+         function_handle: i32,
+         arguments_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_transfer_value_execute(
+                dst_handle,
+                value_handle,
+                gas_limit,
+                function_handle,
+                arguments_handle,
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedIsESDTFrozen",
+        |caller: Caller<'_, WasmiHostState>,
+         address_handle: i32,
+         token_id_handle: i32,
+         nonce: i64|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_is_esdt_frozen(address_handle, token_id_handle, nonce))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedIsESDTLimitedTransfer",
+        |caller: Caller<'_, WasmiHostState>, token_id_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_is_esdt_limited_transfer(token_id_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedIsESDTPaused",
+        |caller: Caller<'_, WasmiHostState>, token_id_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_is_esdt_paused(token_id_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedBufferToHex",
+        |caller: Caller<'_, WasmiHostState>,
+         source_handle: i32,
+         dest_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_buffer_to_hex(source_handle, dest_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedGetCodeMetadata",
+        |caller: Caller<'_, WasmiHostState>,
+         address_handle: i32,
+         response_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.managed_get_code_metadata(address_handle, response_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedIsBuiltinFunction",
+        |caller: Caller<'_, WasmiHostState>, function_name_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_is_builtin_function(function_name_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatNewFromParts",
+        |caller: Caller<'_, WasmiHostState>,
+         integral_part: i32,
+         fractional_part: i32,
+         exponent: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.big_float_new_from_parts(integral_part, fractional_part, exponent))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatNewFromFrac",
+        |caller: Caller<'_, WasmiHostState>,
+         numerator: i64,
+         denominator: i64|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.big_float_new_from_frac(numerator, denominator))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatNewFromSci",
+        |caller: Caller<'_, WasmiHostState>,
+         significand: i64,
+         exponent: i64|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.big_float_new_from_sci(significand, exponent))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatAdd",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_float_add(destination_handle, op1_handle, op2_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatSub",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_float_sub(destination_handle, op1_handle, op2_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatMul",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_float_mul(destination_handle, op1_handle, op2_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatDiv",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_float_div(destination_handle, op1_handle, op2_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatNeg",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_float_neg(destination_handle, op_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatClone",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_float_clone(destination_handle, op_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatCmp",
+        |caller: Caller<'_, WasmiHostState>,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.big_float_cmp(op1_handle, op2_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatAbs",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_float_abs(destination_handle, op_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatSign",
+        |caller: Caller<'_, WasmiHostState>, op_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.big_float_sign(op_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatSqrt",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_float_sqrt(destination_handle, op_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatPow",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op_handle: i32,
+         exponent: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_float_pow(destination_handle, op_handle, exponent);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatFloor",
+        |caller: Caller<'_, WasmiHostState>,
+         dest_big_int_handle: i32,
+         op_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_float_floor(dest_big_int_handle, op_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatCeil",
+        |caller: Caller<'_, WasmiHostState>,
+         dest_big_int_handle: i32,
+         op_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_float_ceil(dest_big_int_handle, op_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatTruncate",
+        |caller: Caller<'_, WasmiHostState>,
+         dest_big_int_handle: i32,
+         op_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_float_truncate(dest_big_int_handle, op_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatSetInt64",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         value: i64|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_float_set_int64(destination_handle, value);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatIsInt",
+        |caller: Caller<'_, WasmiHostState>, op_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.big_float_is_int(op_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatSetBigInt",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         big_int_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_float_set_big_int(destination_handle, big_int_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatGetConstPi",
+        |caller: Caller<'_, WasmiHostState>, destination_handle: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_float_get_const_pi(destination_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigFloatGetConstE",
+        |caller: Caller<'_, WasmiHostState>, destination_handle: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_float_get_const_e(destination_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntGetUnsignedArgument",
+        |caller: Caller<'_, WasmiHostState>,
+         id: i32,
+         destination_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_get_unsigned_argument(id, destination_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntGetSignedArgument",
+        |caller: Caller<'_, WasmiHostState>,
+         id: i32,
+         destination_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_get_signed_argument(id, destination_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntStorageStoreUnsigned",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32,
+         source_handle: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                Ok(vm_hooks.big_int_storage_store_unsigned(key_offset, key_length, source_handle))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntStorageLoadUnsigned",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32,
+         destination_handle: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                Ok(vm_hooks.big_int_storage_load_unsigned(
+                    key_offset,
+                    key_length,
+                    destination_handle,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntGetCallValue",
+        |caller: Caller<'_, WasmiHostState>, destination_handle: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_get_call_value(destination_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntGetESDTCallValue",
+        |caller: Caller<'_, WasmiHostState>, destination: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_get_esdt_call_value(destination);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntGetESDTCallValueByIndex",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         index: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_get_esdt_call_value_by_index(destination_handle, index);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntGetExternalBalance",
+        |mut caller: Caller<'_, WasmiHostState>,
+         address_offset: i32,
+         result: i32|
+         -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let address_offset = memory.ptr(address_offset)?;
+                vm_hooks.big_int_get_external_balance(address_offset, result);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntGetESDTExternalBalance",
+        |mut caller: Caller<'_, WasmiHostState>,
+         address_offset: i32,
+         token_id_offset: i32,
+         token_id_len: i32,
+         nonce: i64,
+         result_handle: i32|
+         -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let address_offset = memory.ptr(address_offset)?;
+                let (token_id_offset, token_id_len) =
+                    memory.slice(token_id_offset, token_id_len)?;
+                vm_hooks.big_int_get_esdt_external_balance(
+                    address_offset,
+                    token_id_offset,
+                    token_id_len,
+                    nonce,
+                    result_handle,
+                );
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntNew",
+        |caller: Caller<'_, WasmiHostState>, small_value: i64| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.big_int_new(small_value))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntUnsignedByteLength",
+        |caller: Caller<'_, WasmiHostState>, reference_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.big_int_unsigned_byte_length(reference_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntSignedByteLength",
+        |caller: Caller<'_, WasmiHostState>, reference_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.big_int_signed_byte_length(reference_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntGetUnsignedBytes",
+        |mut caller: Caller<'_, WasmiHostState>,
+         reference_handle: i32,
+         byte_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let byte_offset = memory.ptr(byte_offset)?;
+                Ok(vm_hooks.big_int_get_unsigned_bytes(reference_handle, byte_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntGetSignedBytes",
+        |mut caller: Caller<'_, WasmiHostState>,
+         reference_handle: i32,
+         byte_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let byte_offset = memory.ptr(byte_offset)?;
+                Ok(vm_hooks.big_int_get_signed_bytes(reference_handle, byte_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntSetUnsignedBytes",
+        |mut caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         byte_offset: i32,
+         byte_length: i32|
+         -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (byte_offset, byte_length) = memory.slice(byte_offset, byte_length)?;
+                vm_hooks.big_int_set_unsigned_bytes(destination_handle, byte_offset, byte_length);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntSetSignedBytes",
+        |mut caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         byte_offset: i32,
+         byte_length: i32|
+         -> Result<(), Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (byte_offset, byte_length) = memory.slice(byte_offset, byte_length)?;
+                vm_hooks.big_int_set_signed_bytes(destination_handle, byte_offset, byte_length);
+                Ok(())
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntIsInt64",
+        |caller: Caller<'_, WasmiHostState>, destination_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.big_int_is_int64(destination_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntGetInt64",
+        |caller: Caller<'_, WasmiHostState>, destination_handle: i32| -> Result<i64, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.big_int_get_int64(destination_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntSetInt64",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         value: i64|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_set_int64(destination_handle, value);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntAdd",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_add(destination_handle, op1_handle, op2_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntSub",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_sub(destination_handle, op1_handle, op2_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntMul",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_mul(destination_handle, op1_handle, op2_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntTDiv",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_tdiv(destination_handle, op1_handle, op2_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntTMod",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_tmod(destination_handle, op1_handle, op2_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntEDiv",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_ediv(destination_handle, op1_handle, op2_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntEMod",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_emod(destination_handle, op1_handle, op2_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntSqrt",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_sqrt(destination_handle, op_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntPow",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_pow(destination_handle, op1_handle, op2_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntLog2",
+        |caller: Caller<'_, WasmiHostState>, op1_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.big_int_log2(op1_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntAbs",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_abs(destination_handle, op_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntNeg",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_neg(destination_handle, op_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntSign",
+        |caller: Caller<'_, WasmiHostState>, op_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.big_int_sign(op_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntCmp",
+        |caller: Caller<'_, WasmiHostState>,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.big_int_cmp(op1_handle, op2_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntNot",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_not(destination_handle, op_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntAnd",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_and(destination_handle, op1_handle, op2_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntOr",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_or(destination_handle, op1_handle, op2_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntXor",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op1_handle: i32,
+         op2_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_xor(destination_handle, op1_handle, op2_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntShr",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op_handle: i32,
+         bits: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_shr(destination_handle, op_handle, bits);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntShl",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         op_handle: i32,
+         bits: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_shl(destination_handle, op_handle, bits);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntFinishUnsigned",
+        |caller: Caller<'_, WasmiHostState>, reference_handle: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_finish_unsigned(reference_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntFinishSigned",
+        |caller: Caller<'_, WasmiHostState>, reference_handle: i32| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_finish_signed(reference_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "bigIntToString",
+        |caller: Caller<'_, WasmiHostState>,
+         big_int_handle: i32,
+         destination_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.big_int_to_string(big_int_handle, destination_handle);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferNew",
+        |caller: Caller<'_, WasmiHostState>| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.mbuffer_new())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferNewFromBytes",
+        |mut caller: Caller<'_, WasmiHostState>,
+         data_offset: i32,
+         data_length: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (data_offset, data_length) = memory.slice(data_offset, data_length)?;
+                Ok(vm_hooks.mbuffer_new_from_bytes(data_offset, data_length))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferGetLength",
+        |caller: Caller<'_, WasmiHostState>, m_buffer_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.mbuffer_get_length(m_buffer_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferGetBytes",
+        |mut caller: Caller<'_, WasmiHostState>,
+         m_buffer_handle: i32,
+         result_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let result_offset = memory.ptr(result_offset)?;
+                Ok(vm_hooks.mbuffer_get_bytes(m_buffer_handle, result_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferGetByteSlice",
+        |mut caller: Caller<'_, WasmiHostState>,
+         source_handle: i32,
+         starting_position: i32,
+         slice_length: i32,
+         result_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let result_offset = memory.ptr(result_offset)?;
+                Ok(vm_hooks.mbuffer_get_byte_slice(
+                    source_handle,
+                    starting_position,
+                    slice_length,
+                    result_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferCopyByteSlice",
+        |caller: Caller<'_, WasmiHostState>,
+         source_handle: i32,
+         starting_position: i32,
+         slice_length: i32,
+         destination_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.mbuffer_copy_byte_slice(
+                source_handle,
+                starting_position,
+                slice_length,
+                destination_handle,
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferEq",
+        |caller: Caller<'_, WasmiHostState>,
+         m_buffer_handle1: i32,
+         m_buffer_handle2: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.mbuffer_eq(m_buffer_handle1, m_buffer_handle2))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferSetBytes",
+        |mut caller: Caller<'_, WasmiHostState>,
+         m_buffer_handle: i32,
+         data_offset: i32,
+         data_length: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (data_offset, data_length) = memory.slice(data_offset, data_length)?;
+                Ok(vm_hooks.mbuffer_set_bytes(m_buffer_handle, data_offset, data_length))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferSetByteSlice",
+        |mut caller: Caller<'_, WasmiHostState>,
+         m_buffer_handle: i32,
+         starting_position: i32,
+         data_length: i32,
+         data_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (data_offset, data_length) = memory.slice(data_offset, data_length)?;
+                Ok(vm_hooks.mbuffer_set_byte_slice(
+                    m_buffer_handle,
+                    starting_position,
+                    data_length,
+                    data_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferAppend",
+        |caller: Caller<'_, WasmiHostState>,
+         accumulator_handle: i32,
+         data_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.mbuffer_append(accumulator_handle, data_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferAppendBytes",
+        |mut caller: Caller<'_, WasmiHostState>,
+         accumulator_handle: i32,
+         data_offset: i32,
+         data_length: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (data_offset, data_length) = memory.slice(data_offset, data_length)?;
+                Ok(vm_hooks.mbuffer_append_bytes(accumulator_handle, data_offset, data_length))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferToBigIntUnsigned",
+        |caller: Caller<'_, WasmiHostState>,
+         m_buffer_handle: i32,
+         big_int_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.mbuffer_to_big_int_unsigned(m_buffer_handle, big_int_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferToBigIntSigned",
+        |caller: Caller<'_, WasmiHostState>,
+         m_buffer_handle: i32,
+         big_int_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.mbuffer_to_big_int_signed(m_buffer_handle, big_int_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferFromBigIntUnsigned",
+        |caller: Caller<'_, WasmiHostState>,
+         m_buffer_handle: i32,
+         big_int_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.mbuffer_from_big_int_unsigned(m_buffer_handle, big_int_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferFromBigIntSigned",
+        |caller: Caller<'_, WasmiHostState>,
+         m_buffer_handle: i32,
+         big_int_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.mbuffer_from_big_int_signed(m_buffer_handle, big_int_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferToBigFloat",
+        |caller: Caller<'_, WasmiHostState>,
+         m_buffer_handle: i32,
+         big_float_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.mbuffer_to_big_float(m_buffer_handle, big_float_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferFromBigFloat",
+        |caller: Caller<'_, WasmiHostState>,
+         m_buffer_handle: i32,
+         big_float_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.mbuffer_from_big_float(m_buffer_handle, big_float_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferStorageStore",
+        |caller: Caller<'_, WasmiHostState>,
+         key_handle: i32,
+         source_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.mbuffer_storage_store(key_handle, source_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferStorageLoad",
+        |caller: Caller<'_, WasmiHostState>,
+         key_handle: i32,
+         destination_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.mbuffer_storage_load(key_handle, destination_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferStorageLoadFromAddress",
+        |caller: Caller<'_, WasmiHostState>,
+         address_handle: i32,
+         key_handle: i32,
+         destination_handle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.mbuffer_storage_load_from_address(
+                address_handle,
+                key_handle,
+                destination_handle,
+            );
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferGetArgument",
+        |caller: Caller<'_, WasmiHostState>,
+         id: i32,
+         destination_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.mbuffer_get_argument(id, destination_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferFinish",
+        |caller: Caller<'_, WasmiHostState>, source_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.mbuffer_finish(source_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "mBufferSetRandom",
+        |caller: Caller<'_, WasmiHostState>,
+         destination_handle: i32,
+         length: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.mbuffer_set_random(destination_handle, length))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedMapNew",
+        |caller: Caller<'_, WasmiHostState>| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_map_new())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedMapPut",
+        |caller: Caller<'_, WasmiHostState>,
+         m_map_handle: i32,
+         key_handle: i32,
+         value_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_map_put(m_map_handle, key_handle, value_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedMapGet",
+        |caller: Caller<'_, WasmiHostState>,
+         m_map_handle: i32,
+         key_handle: i32,
+         out_value_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_map_get(m_map_handle, key_handle, out_value_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedMapRemove",
+        |caller: Caller<'_, WasmiHostState>,
+         m_map_handle: i32,
+         key_handle: i32,
+         out_value_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_map_remove(m_map_handle, key_handle, out_value_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedMapContains",
+        |caller: Caller<'_, WasmiHostState>,
+         m_map_handle: i32,
+         key_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_map_contains(m_map_handle, key_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "smallIntGetUnsignedArgument",
+        |caller: Caller<'_, WasmiHostState>, id: i32| -> Result<i64, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.small_int_get_unsigned_argument(id))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "smallIntGetSignedArgument",
+        |caller: Caller<'_, WasmiHostState>, id: i32| -> Result<i64, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.small_int_get_signed_argument(id))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "smallIntFinishUnsigned",
+        |caller: Caller<'_, WasmiHostState>, value: i64| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.small_int_finish_unsigned(value);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "smallIntFinishSigned",
+        |caller: Caller<'_, WasmiHostState>, value: i64| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.small_int_finish_signed(value);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "smallIntStorageStoreUnsigned",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32,
+         value: i64|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                Ok(vm_hooks.small_int_storage_store_unsigned(key_offset, key_length, value))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "smallIntStorageStoreSigned",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32,
+         value: i64|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                Ok(vm_hooks.small_int_storage_store_signed(key_offset, key_length, value))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "smallIntStorageLoadUnsigned",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32|
+         -> Result<i64, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                Ok(vm_hooks.small_int_storage_load_unsigned(key_offset, key_length))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "smallIntStorageLoadSigned",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32|
+         -> Result<i64, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                Ok(vm_hooks.small_int_storage_load_signed(key_offset, key_length))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "int64getArgument",
+        |caller: Caller<'_, WasmiHostState>, id: i32| -> Result<i64, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.int64get_argument(id))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "int64finish",
+        |caller: Caller<'_, WasmiHostState>, value: i64| -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.int64finish(value);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "int64storageStore",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32,
+         value: i64|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                Ok(vm_hooks.int64storage_store(key_offset, key_length, value))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "int64storageLoad",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32|
+         -> Result<i64, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                Ok(vm_hooks.int64storage_load(key_offset, key_length))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "sha256",
+        |mut caller: Caller<'_, WasmiHostState>,
+         data_offset: i32,
+         length: i32,
+         result_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (data_offset, length) = memory.slice(data_offset, length)?;
+                let result_offset = memory.ptr(result_offset)?;
+                Ok(vm_hooks.sha256(data_offset, length, result_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedSha256",
+        |caller: Caller<'_, WasmiHostState>,
+         input_handle: i32,
+         output_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_sha256(input_handle, output_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "keccak256",
+        |mut caller: Caller<'_, WasmiHostState>,
+         data_offset: i32,
+         length: i32,
+         result_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (data_offset, length) = memory.slice(data_offset, length)?;
+                let result_offset = memory.ptr(result_offset)?;
+                Ok(vm_hooks.keccak256(data_offset, length, result_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedKeccak256",
+        |caller: Caller<'_, WasmiHostState>,
+         input_handle: i32,
+         output_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_keccak256(input_handle, output_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "ripemd160",
+        |mut caller: Caller<'_, WasmiHostState>,
+         data_offset: i32,
+         length: i32,
+         result_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (data_offset, length) = memory.slice(data_offset, length)?;
+                let result_offset = memory.ptr(result_offset)?;
+                Ok(vm_hooks.ripemd160(data_offset, length, result_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedRipemd160",
+        |caller: Caller<'_, WasmiHostState>,
+         input_handle: i32,
+         output_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_ripemd160(input_handle, output_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "verifyBLS",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         message_offset: i32,
+         message_length: i32,
+         sig_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let key_offset = memory.ptr(key_offset)?;
+                let (message_offset, message_length) =
+                    memory.slice(message_offset, message_length)?;
+                let sig_offset = memory.ptr(sig_offset)?;
+                Ok(vm_hooks.verify_bls(key_offset, message_offset, message_length, sig_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedVerifyBLS",
+        |caller: Caller<'_, WasmiHostState>,
+         key_handle: i32,
+         message_handle: i32,
+         sig_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_verify_bls(key_handle, message_handle, sig_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "verifyEd25519",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         message_offset: i32,
+         message_length: i32,
+         sig_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let key_offset = memory.ptr(key_offset)?;
+                let (message_offset, message_length) =
+                    memory.slice(message_offset, message_length)?;
+                let sig_offset = memory.ptr(sig_offset)?;
+                Ok(vm_hooks.verify_ed25519(key_offset, message_offset, message_length, sig_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedVerifyEd25519",
+        |caller: Caller<'_, WasmiHostState>,
+         key_handle: i32,
+         message_handle: i32,
+         sig_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_verify_ed25519(key_handle, message_handle, sig_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "verifyCustomSecp256k1",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32,
+         message_offset: i32,
+         message_length: i32,
+         sig_offset: i32,
+         hash_type: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                let (message_offset, message_length) =
+                    memory.slice(message_offset, message_length)?;
+                let sig_offset = memory.ptr(sig_offset)?;
+                Ok(vm_hooks.verify_custom_secp256k1(
+                    key_offset,
+                    key_length,
+                    message_offset,
+                    message_length,
+                    sig_offset,
+                    hash_type,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedVerifyCustomSecp256k1",
+        |caller: Caller<'_, WasmiHostState>,
+         key_handle: i32,
+         message_handle: i32,
+         sig_handle: i32,
+         hash_type: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_verify_custom_secp256k1(
+                key_handle,
+                message_handle,
+                sig_handle,
+                hash_type,
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "verifySecp256k1",
+        |mut caller: Caller<'_, WasmiHostState>,
+         key_offset: i32,
+         key_length: i32,
+         message_offset: i32,
+         message_length: i32,
+         sig_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (key_offset, key_length) = memory.slice(key_offset, key_length)?;
+                let (message_offset, message_length) =
+                    memory.slice(message_offset, message_length)?;
+                let sig_offset = memory.ptr(sig_offset)?;
+                Ok(vm_hooks.verify_secp256k1(
+                    key_offset,
+                    key_length,
+                    message_offset,
+                    message_length,
+                    sig_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedVerifySecp256k1",
+        |caller: Caller<'_, WasmiHostState>,
+         key_handle: i32,
+         message_handle: i32,
+         sig_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_verify_secp256k1(key_handle, message_handle, sig_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "encodeSecp256k1DerSignature",
+        |mut caller: Caller<'_, WasmiHostState>,
+         r_offset: i32,
+         r_length: i32,
+         s_offset: i32,
+         s_length: i32,
+         sig_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (r_offset, r_length) = memory.slice(r_offset, r_length)?;
+                let (s_offset, s_length) = memory.slice(s_offset, s_length)?;
+                let sig_offset = memory.ptr(sig_offset)?;
+                Ok(vm_hooks.encode_secp256k1_der_signature(
+                    r_offset, r_length, s_offset, s_length, sig_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedEncodeSecp256k1DerSignature",
+        |caller: Caller<'_, WasmiHostState>,
+         r_handle: i32,
+         s_handle: i32,
+         sig_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_encode_secp256k1_der_signature(r_handle, s_handle, sig_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "addEC",
+        |caller: Caller<'_, WasmiHostState>,
+         x_result_handle: i32,
+         y_result_handle: i32,
+         ec_handle: i32,
+         fst_point_xhandle: i32,
+         fst_point_yhandle: i32,
+         snd_point_xhandle: i32,
+         snd_point_yhandle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.add_ec(
+                x_result_handle,
+                y_result_handle,
+                ec_handle,
+                fst_point_xhandle,
+                fst_point_yhandle,
+                snd_point_xhandle,
+                snd_point_yhandle,
+            );
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "doubleEC",
+        |caller: Caller<'_, WasmiHostState>,
+         x_result_handle: i32,
+         y_result_handle: i32,
+         ec_handle: i32,
+         point_xhandle: i32,
+         point_yhandle: i32|
+         -> Result<(), Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            vm_hooks.double_ec(
+                x_result_handle,
+                y_result_handle,
+                ec_handle,
+                point_xhandle,
+                point_yhandle,
+            );
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "isOnCurveEC",
+        |caller: Caller<'_, WasmiHostState>,
+         ec_handle: i32,
+         point_xhandle: i32,
+         point_yhandle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.is_on_curve_ec(ec_handle, point_xhandle, point_yhandle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "scalarBaseMultEC",
+        |mut caller: Caller<'_, WasmiHostState>,
+         x_result_handle: i32,
+         y_result_handle: i32,
+         ec_handle: i32,
+         data_offset: i32,
+         length: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (data_offset, length) = memory.slice(data_offset, length)?;
+                Ok(vm_hooks.scalar_base_mult_ec(
+                    x_result_handle,
+                    y_result_handle,
+                    ec_handle,
+                    data_offset,
+                    length,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedScalarBaseMultEC",
+        |caller: Caller<'_, WasmiHostState>,
+         x_result_handle: i32,
+         y_result_handle: i32,
+         ec_handle: i32,
+         data_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_scalar_base_mult_ec(
+                x_result_handle,
+                y_result_handle,
+                ec_handle,
+                data_handle,
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "scalarMultEC",
+        |mut caller: Caller<'_, WasmiHostState>,
+         x_result_handle: i32,
+         y_result_handle: i32,
+         ec_handle: i32,
+         point_xhandle: i32,
+         point_yhandle: i32,
+         data_offset: i32,
+         length: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (data_offset, length) = memory.slice(data_offset, length)?;
+                Ok(vm_hooks.scalar_mult_ec(
+                    x_result_handle,
+                    y_result_handle,
+                    ec_handle,
+                    point_xhandle,
+                    point_yhandle,
+                    data_offset,
+                    length,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedScalarMultEC",
+        |caller: Caller<'_, WasmiHostState>,
+         x_result_handle: i32,
+         y_result_handle: i32,
+         ec_handle: i32,
+         point_xhandle: i32,
+         point_yhandle: i32,
+         data_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_scalar_mult_ec(
+                x_result_handle,
+                y_result_handle,
+                ec_handle,
+                point_xhandle,
+                point_yhandle,
+                data_handle,
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "marshalEC",
+        |mut caller: Caller<'_, WasmiHostState>,
+         x_pair_handle: i32,
+         y_pair_handle: i32,
+         ec_handle: i32,
+         result_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let result_offset = memory.ptr(result_offset)?;
+                Ok(vm_hooks.marshal_ec(x_pair_handle, y_pair_handle, ec_handle, result_offset))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedMarshalEC",
+        |caller: Caller<'_, WasmiHostState>,
+         x_pair_handle: i32,
+         y_pair_handle: i32,
+         ec_handle: i32,
+         result_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_marshal_ec(x_pair_handle, y_pair_handle, ec_handle, result_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "marshalCompressedEC",
+        |mut caller: Caller<'_, WasmiHostState>,
+         x_pair_handle: i32,
+         y_pair_handle: i32,
+         ec_handle: i32,
+         result_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let result_offset = memory.ptr(result_offset)?;
+                Ok(vm_hooks.marshal_compressed_ec(
+                    x_pair_handle,
+                    y_pair_handle,
+                    ec_handle,
+                    result_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedMarshalCompressedEC",
+        |caller: Caller<'_, WasmiHostState>,
+         x_pair_handle: i32,
+         y_pair_handle: i32,
+         ec_handle: i32,
+         result_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_marshal_compressed_ec(
+                x_pair_handle,
+                y_pair_handle,
+                ec_handle,
+                result_handle,
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "unmarshalEC",
+        |mut caller: Caller<'_, WasmiHostState>,
+         x_result_handle: i32,
+         y_result_handle: i32,
+         ec_handle: i32,
+         data_offset: i32,
+         length: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (data_offset, length) = memory.slice(data_offset, length)?;
+                Ok(vm_hooks.unmarshal_ec(
+                    x_result_handle,
+                    y_result_handle,
+                    ec_handle,
+                    data_offset,
+                    length,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedUnmarshalEC",
+        |caller: Caller<'_, WasmiHostState>,
+         x_result_handle: i32,
+         y_result_handle: i32,
+         ec_handle: i32,
+         data_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_unmarshal_ec(
+                x_result_handle,
+                y_result_handle,
+                ec_handle,
+                data_handle,
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "unmarshalCompressedEC",
+        |mut caller: Caller<'_, WasmiHostState>,
+         x_result_handle: i32,
+         y_result_handle: i32,
+         ec_handle: i32,
+         data_offset: i32,
+         length: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (data_offset, length) = memory.slice(data_offset, length)?;
+                Ok(vm_hooks.unmarshal_compressed_ec(
+                    x_result_handle,
+                    y_result_handle,
+                    ec_handle,
+                    data_offset,
+                    length,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedUnmarshalCompressedEC",
+        |caller: Caller<'_, WasmiHostState>,
+         x_result_handle: i32,
+         y_result_handle: i32,
+         ec_handle: i32,
+         data_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_unmarshal_compressed_ec(
+                x_result_handle,
+                y_result_handle,
+                ec_handle,
+                data_handle,
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "generateKeyEC",
+        |mut caller: Caller<'_, WasmiHostState>,
+         x_pub_key_handle: i32,
+         y_pub_key_handle: i32,
+         ec_handle: i32,
+         result_offset: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let result_offset = memory.ptr(result_offset)?;
+                Ok(vm_hooks.generate_key_ec(
+                    x_pub_key_handle,
+                    y_pub_key_handle,
+                    ec_handle,
+                    result_offset,
+                ))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedGenerateKeyEC",
+        |caller: Caller<'_, WasmiHostState>,
+         x_pub_key_handle: i32,
+         y_pub_key_handle: i32,
+         ec_handle: i32,
+         result_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_generate_key_ec(
+                x_pub_key_handle,
+                y_pub_key_handle,
+                ec_handle,
+                result_handle,
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "createEC",
+        |mut caller: Caller<'_, WasmiHostState>,
+         data_offset: i32,
+         data_length: i32|
+         -> Result<i32, Error> {
+            with_vm_hooks(&mut caller, |vm_hooks, memory| {
+                let (data_offset, data_length) = memory.slice(data_offset, data_length)?;
+                Ok(vm_hooks.create_ec(data_offset, data_length))
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedCreateEC",
+        |caller: Caller<'_, WasmiHostState>, data_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_create_ec(data_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getCurveLengthEC",
+        |caller: Caller<'_, WasmiHostState>, ec_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_curve_length_ec(ec_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "getPrivKeyByteLengthEC",
+        |caller: Caller<'_, WasmiHostState>, ec_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.get_priv_key_byte_length_ec(ec_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "ellipticCurveGetValues",
+        |caller: Caller<'_, WasmiHostState>,
+         ec_handle: i32,
+         field_order_handle: i32,
+         base_point_order_handle: i32,
+         eq_constant_handle: i32,
+         x_base_point_handle: i32,
+         y_base_point_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.elliptic_curve_get_values(
+                ec_handle,
+                field_order_handle,
+                base_point_order_handle,
+                eq_constant_handle,
+                x_base_point_handle,
+                y_base_point_handle,
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedMapLen",
+        |caller: Caller<'_, WasmiHostState>, map_handle: i32| -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_map_len(map_handle))
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "managedMapKeys",
+        |caller: Caller<'_, WasmiHostState>,
+         map_handle: i32,
+         out_keys_handle: i32|
+         -> Result<i32, Error> {
+            let vm_hooks = &caller.data().vm_hooks;
+            Ok(vm_hooks.managed_map_keys(map_handle, out_keys_handle))
+        },
+    )?;
+
+    Ok(())
+}
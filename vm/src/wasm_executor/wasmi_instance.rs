@@ -0,0 +1,253 @@
+use std::{
+    cell::{Cell, RefCell},
+    panic::AssertUnwindSafe,
+};
+
+use multiversx_chain_vm_executor::{BreakpointValue, ExecutorError, Instance, MemLength, MemPtr};
+use wasmi::{
+    core::{Pages, TrapCode},
+    Engine, Error, Extern, Linker, Memory, Module, Store,
+};
+
+use crate::{
+    tx_mock::{TxContextRef, TxPanic},
+    vm_err_msg,
+    vm_hooks::VMHooksDispatcher,
+};
+
+use super::wasmi_memory::WasmiMemoryError;
+
+const MEMORY_EXPORT_NAME: &str = "memory";
+
+/// The data attached to the wasmi store, available to the VM hooks.
+pub(super) struct WasmiHostState {
+    pub vm_hooks: VMHooksDispatcher,
+    pub memory: Option<Memory>,
+}
+
+/// A contract instantiated by the wasmi interpreter.
+///
+/// Created for each contract call, so it never needs to be reset.
+///
+/// Points are wasmi fuel. Without a points limit, the fuel is practically unlimited.
+pub struct WasmiInstance {
+    store: RefCell<Store<WasmiHostState>>,
+    instance: wasmi::Instance,
+    points_limit: Cell<Option<u64>>,
+}
+
+impl WasmiInstance {
+    pub(super) fn new(
+        engine: &Engine,
+        linker: &Linker<WasmiHostState>,
+        wasm_bytes: &[u8],
+        vm_hooks: VMHooksDispatcher,
+    ) -> Result<Self, ExecutorError> {
+        let module = Module::new(engine, wasm_bytes)?;
+        let mut store = Store::new(
+            engine,
+            WasmiHostState {
+                vm_hooks,
+                memory: None,
+            },
+        );
+        store.set_fuel(u64::MAX).map_err(wasmi::Error::from)?;
+        let instance = linker.instantiate(&mut store, &module)?.start(&mut store)?;
+        store.data_mut().memory = instance.get_memory(&store, MEMORY_EXPORT_NAME);
+        Ok(WasmiInstance {
+            store: RefCell::new(store),
+            instance,
+            points_limit: Cell::new(None),
+        })
+    }
+
+    /// With a points limit, the points used by the contract code are charged as gas, on top of the VM hooks gas.
+    fn charge_points_used(&self) -> Result<(), String> {
+        if self.points_limit.get().is_none() {
+            return Ok(());
+        }
+
+        let points_used = self.get_points_used()?;
+        let tx_context = TxContextRef::new_from_static();
+        let gas_limit = tx_context.input_ref().gas_limit;
+        let mut tx_result = tx_context.result_lock();
+        tx_result.gas_used = tx_result.gas_used.saturating_add(points_used);
+        if tx_result.gas_used > gas_limit {
+            drop(tx_result);
+            tx_context.replace_tx_result_with_error(TxPanic::new(5, vm_err_msg::OUT_OF_GAS));
+        }
+        Ok(())
+    }
+
+    fn set_fuel(&self, fuel: u64) -> Result<(), String> {
+        self.store
+            .borrow_mut()
+            .set_fuel(fuel)
+            .map_err(|err| err.to_string())
+    }
+
+    fn memory(&self) -> Result<Memory, String> {
+        self.store
+            .borrow()
+            .data()
+            .memory
+            .ok_or_else(|| "contract does not export its memory".to_string())
+    }
+
+    fn memory_bounds(&self, mem_ptr: MemPtr, mem_length: MemLength) -> Result<(), ExecutorError> {
+        let memory_length = self.memory_length()? as MemPtr;
+        if mem_ptr < 0 || mem_length < 0 || mem_ptr + mem_length > memory_length {
+            return Err("memory access out of bounds".into());
+        }
+        Ok(())
+    }
+}
+
+fn replace_tx_result_with_error(tx_panic: TxPanic) {
+    TxContextRef::new_from_static().replace_tx_result_with_error(tx_panic);
+}
+
+/// Running out of points and invalid memory accesses keep their own errors,
+/// any other trap is reported as a failed execution.
+fn trap_to_tx_panic(trap: &Error) -> TxPanic {
+    if let Some(memory_error) = trap.downcast_ref::<WasmiMemoryError>() {
+        return TxPanic::vm_error(memory_error.0);
+    }
+    match trap.as_trap_code() {
+        Some(TrapCode::OutOfFuel) => TxPanic::new(5, vm_err_msg::OUT_OF_GAS),
+        Some(TrapCode::MemoryOutOfBounds) => TxPanic::vm_error(vm_err_msg::BAD_BOUNDS_UPPER),
+        _ => TxPanic::vm_error(vm_err_msg::EXECUTION_FAILED),
+    }
+}
+
+impl Instance for WasmiInstance {
+    fn call(&self, func_name: &str) -> Result<(), String> {
+        let func = self
+            .instance
+            .get_typed_func::<(), ()>(&*self.store.borrow(), func_name);
+        let Ok(func) = func else {
+            replace_tx_result_with_error(TxPanic::new(1, vm_err_msg::FUNCTION_NOT_FOUND));
+            return Ok(());
+        };
+
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            func.call(&mut *self.store.borrow_mut(), ())
+        }));
+        match result {
+            Ok(Ok(())) => self.charge_points_used()?,
+            Ok(Err(trap)) => {
+                replace_tx_result_with_error(trap_to_tx_panic(&trap));
+            },
+            Err(panic_any) => {
+                // breakpoints are signalled by the VM hooks, and are considered to be already handled
+                if panic_any.downcast_ref::<BreakpointValue>().is_none() {
+                    std::panic::resume_unwind(panic_any);
+                }
+            },
+        }
+        Ok(())
+    }
+
+    fn check_signatures(&self) -> bool {
+        let store = self.store.borrow();
+        self.instance
+            .exports(&*store)
+            .all(|export| match export.into_func() {
+                Some(func) => {
+                    let func_type = func.ty(&*store);
+                    func_type.params().is_empty() && func_type.results().is_empty()
+                },
+                None => true,
+            })
+    }
+
+    fn has_function(&self, func_name: &str) -> bool {
+        self.instance
+            .get_func(&*self.store.borrow(), func_name)
+            .is_some()
+    }
+
+    fn get_exported_function_names(&self) -> Vec<String> {
+        let store = self.store.borrow();
+        self.instance
+            .exports(&*store)
+            .filter(|export| matches!(export.clone().into_extern(), Extern::Func(_)))
+            .map(|export| export.name().to_string())
+            .collect()
+    }
+
+    fn set_points_limit(&self, limit: u64) -> Result<(), String> {
+        self.set_fuel(limit)?;
+        self.points_limit.set(Some(limit));
+        Ok(())
+    }
+
+    fn set_points_used(&self, points: u64) -> Result<(), String> {
+        let limit = self.points_limit.get().unwrap_or(u64::MAX);
+        self.set_fuel(limit.saturating_sub(points))
+    }
+
+    fn get_points_used(&self) -> Result<u64, String> {
+        let limit = self.points_limit.get().unwrap_or(u64::MAX);
+        let fuel = self
+            .store
+            .borrow()
+            .get_fuel()
+            .map_err(|err| err.to_string())?;
+        Ok(limit.saturating_sub(fuel))
+    }
+
+    fn memory_length(&self) -> Result<u64, String> {
+        let memory = self.memory()?;
+        Ok(memory.data(&*self.store.borrow()).len() as u64)
+    }
+
+    fn memory_ptr(&self) -> Result<*mut u8, String> {
+        let memory = self.memory()?;
+        Ok(memory.data_mut(&mut *self.store.borrow_mut()).as_mut_ptr())
+    }
+
+    fn memory_load(&self, mem_ptr: MemPtr, mem_length: MemLength) -> Result<&[u8], ExecutorError> {
+        self.memory_bounds(mem_ptr, mem_length)?;
+        let data_ptr = self.memory_ptr()?;
+        unsafe {
+            Ok(std::slice::from_raw_parts(
+                data_ptr.offset(mem_ptr),
+                mem_length as usize,
+            ))
+        }
+    }
+
+    fn memory_store(&self, mem_ptr: MemPtr, data: &[u8]) -> Result<(), ExecutorError> {
+        self.memory_bounds(mem_ptr, data.len() as MemLength)?;
+        let memory = self.memory()?;
+        memory
+            .write(&mut *self.store.borrow_mut(), mem_ptr as usize, data)
+            .map_err(|err| err.to_string().into())
+    }
+
+    fn memory_grow(&self, by_num_pages: u32) -> Result<u32, ExecutorError> {
+        let memory = self.memory()?;
+        let by_num_pages = Pages::new(by_num_pages).ok_or("too many memory pages")?;
+        let previous_pages = memory
+            .grow(&mut *self.store.borrow_mut(), by_num_pages)
+            .map_err(|err| err.to_string())?;
+        Ok(previous_pages.into())
+    }
+
+    fn set_breakpoint_value(&self, _value: BreakpointValue) -> Result<(), String> {
+        Err("WasmiInstance signals breakpoints via panics".to_string())
+    }
+
+    fn get_breakpoint_value(&self) -> Result<BreakpointValue, String> {
+        Err("WasmiInstance signals breakpoints via panics".to_string())
+    }
+
+    fn reset(&self) -> Result<(), String> {
+        Err("WasmiInstance reset not supported".to_string())
+    }
+
+    fn cache(&self) -> Result<Vec<u8>, String> {
+        Err("WasmiInstance cache not supported".to_string())
+    }
+}
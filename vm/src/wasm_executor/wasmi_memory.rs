@@ -0,0 +1,74 @@
+use std::fmt;
+
+use multiversx_chain_vm_executor::{MemLength, MemPtr};
+use wasmi::{core::HostError, Error};
+
+use crate::vm_err_msg;
+
+/// Converts the memory offsets received from contract code
+/// into the absolute pointers expected by the VM hooks.
+///
+/// Offsets that fall outside the instance memory cause the contract to trap, with a `WasmiMemoryError`.
+#[derive(Clone, Copy)]
+pub(super) struct WasmiMemoryView {
+    base: *mut u8,
+    size: usize,
+}
+
+impl WasmiMemoryView {
+    pub fn new(data: &mut [u8]) -> Self {
+        WasmiMemoryView {
+            base: data.as_mut_ptr(),
+            size: data.len(),
+        }
+    }
+
+    /// The absolute pointers to the start and the end of the instance memory.
+    pub fn bounds(&self) -> (MemPtr, MemPtr) {
+        let start = self.base as MemPtr;
+        (start, start + self.size as MemPtr)
+    }
+
+    /// Pointer to a region whose length is only known to the VM hook.
+    ///
+    /// Only its start is checked here, the VM hooks check the whole region when accessing it,
+    /// against the bounds of the memory.
+    pub fn ptr(&self, offset: i32) -> Result<MemPtr, Error> {
+        self.slice(offset, 0).map(|(ptr, _)| ptr)
+    }
+
+    /// Pointer and length of a region that must lie entirely within the instance memory.
+    pub fn slice(&self, offset: i32, length: i32) -> Result<(MemPtr, MemLength), Error> {
+        let start = offset as u32 as usize;
+        let length = self.length(length)?;
+        match start.checked_add(length as usize) {
+            Some(end) if end <= self.size => Ok((self.base as MemPtr + start as MemPtr, length)),
+            _ => Err(WasmiMemoryError(vm_err_msg::BAD_BOUNDS_UPPER).into()),
+        }
+    }
+
+    pub fn length(&self, length: i32) -> Result<MemLength, Error> {
+        if length < 0 {
+            return Err(WasmiMemoryError(vm_err_msg::NEGATIVE_LENGTH).into());
+        }
+        Ok(length as MemLength)
+    }
+}
+
+/// Traps the contract on an invalid memory access, with the VM error message.
+#[derive(Debug)]
+pub(super) struct WasmiMemoryError(pub &'static str);
+
+impl fmt::Display for WasmiMemoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl HostError for WasmiMemoryError {}
+
+impl From<WasmiMemoryError> for Error {
+    fn from(err: WasmiMemoryError) -> Self {
+        Error::host(err)
+    }
+}
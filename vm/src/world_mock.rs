@@ -8,8 +8,10 @@ mod esdt_instance;
 mod esdt_instance_metadata;
 mod esdt_instances;
 mod esdt_roles;
+mod esdt_system_sc_data;
 mod failing_executor;
 pub mod reserved;
+mod shard_coordinator;
 
 pub use account_data::*;
 pub use block_info::*;
@@ -20,4 +22,6 @@ pub use esdt_instance::*;
 pub use esdt_instance_metadata::*;
 pub use esdt_instances::*;
 pub use esdt_roles::*;
+pub use esdt_system_sc_data::EsdtSystemScData;
 pub use failing_executor::FailingExecutor;
+pub use shard_coordinator::*;
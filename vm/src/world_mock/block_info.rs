@@ -1,3 +1,8 @@
+use crate::crypto_functions::sha256;
+
+pub const BLOCK_HASH_LEN: usize = 32;
+pub const STATE_ROOT_HASH_LEN: usize = 32;
+
 #[derive(Clone, Debug)]
 pub struct BlockInfo {
     pub block_timestamp: u64,
@@ -5,6 +10,8 @@ pub struct BlockInfo {
     pub block_round: u64,
     pub block_epoch: u64,
     pub block_random_seed: Box<[u8; 48]>,
+    pub block_hash: Box<[u8; BLOCK_HASH_LEN]>,
+    pub state_root_hash: Box<[u8; STATE_ROOT_HASH_LEN]>,
 }
 
 impl BlockInfo {
@@ -15,8 +22,35 @@ impl BlockInfo {
             block_round: 0,
             block_epoch: 0,
             block_random_seed: Box::from([0u8; 48]),
+            block_hash: Box::from([0u8; BLOCK_HASH_LEN]),
+            state_root_hash: Box::from([0u8; STATE_ROOT_HASH_LEN]),
         }
     }
+
+    /// The hash of a block that was not configured explicitly.
+    ///
+    /// Only depends on the nonce, so hashes of older blocks can be retrieved too.
+    pub fn simulated_block_hash(block_nonce: u64) -> [u8; BLOCK_HASH_LEN] {
+        sha256(&block_nonce.to_be_bytes())
+    }
+
+    /// Moves to another block.
+    ///
+    /// The block hash is replaced with the simulated one,
+    /// and the state root hash is chained from the previous one and the new block hash.
+    /// Nothing happens if the nonce does not change.
+    pub fn advance_to_nonce(&mut self, block_nonce: u64) {
+        if block_nonce == self.block_nonce {
+            return;
+        }
+
+        self.block_nonce = block_nonce;
+        *self.block_hash = Self::simulated_block_hash(block_nonce);
+
+        let mut state_root_input = self.state_root_hash.to_vec();
+        state_root_input.extend_from_slice(self.block_hash.as_slice());
+        *self.state_root_hash = sha256(&state_root_input);
+    }
 }
 
 impl Default for BlockInfo {
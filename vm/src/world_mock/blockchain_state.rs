@@ -3,9 +3,17 @@ use std::{collections::HashMap, fmt::Debug};
 use num_bigint::BigUint;
 use num_traits::Zero;
 
-use crate::{tx_mock::BlockchainUpdate, types::VMAddress};
+use crate::{
+    tx_mock::{BlockchainUpdate, CrossShardCall},
+    types::VMAddress,
+};
 
-use super::{reserved::STORAGE_REWARD_KEY, AccountData, BlockInfo};
+use super::{
+    reserved::STORAGE_REWARD_KEY, AccountData, BlockInfo, EsdtSystemScData, ShardCoordinator,
+};
+
+/// Duration of a round on mainnet.
+const ROUND_DURATION_SECONDS: u64 = 6;
 
 #[derive(Default, Clone)]
 pub struct BlockchainState {
@@ -14,6 +22,13 @@ pub struct BlockchainState {
     pub previous_block_info: BlockInfo,
     pub current_block_info: BlockInfo,
     pub new_token_identifiers: Vec<String>,
+    pub esdt_tokens: HashMap<Vec<u8>, EsdtSystemScData>,
+
+    /// Sharding is opt-in. Without it, all async calls complete within their transaction.
+    pub shard_coordinator: Option<ShardCoordinator>,
+
+    /// Async calls and callbacks that crossed shards, waiting for the next round.
+    pub cross_shard_calls: Vec<CrossShardCall>,
 }
 
 impl BlockchainState {
@@ -50,6 +65,17 @@ impl BlockchainState {
         account.egld_balance -= &gas_cost;
     }
 
+    /// Gives back the gas that was paid upfront, but not used.
+    pub fn refund_tx_gas(&mut self, address: &VMAddress, gas_left: u64, gas_price: u64) {
+        let account = self.accounts.get_mut(address).unwrap_or_else(|| {
+            panic!(
+                "Account not found: {}",
+                &std::str::from_utf8(address.as_ref()).unwrap()
+            )
+        });
+        account.egld_balance += BigUint::from(gas_left) * BigUint::from(gas_price);
+    }
+
     pub fn increase_validator_reward(&mut self, address: &VMAddress, amount: &BigUint) {
         let account = self.accounts.get_mut(address).unwrap_or_else(|| {
             panic!(
@@ -81,6 +107,19 @@ impl BlockchainState {
     pub fn update_new_token_identifiers(&mut self, token_identifiers: Vec<String>) {
         self.new_token_identifiers = token_identifiers;
     }
+
+    /// Moves on to the next block, one round later.
+    pub fn advance_round(&mut self) {
+        self.previous_block_info = self.current_block_info.clone();
+        let next_block_nonce = self.current_block_info.block_nonce + 1;
+        self.current_block_info.advance_to_nonce(next_block_nonce);
+        self.current_block_info.block_round += 1;
+        self.current_block_info.block_timestamp += ROUND_DURATION_SECONDS;
+    }
+
+    pub fn update_esdt_tokens(&mut self, esdt_tokens: HashMap<Vec<u8>, EsdtSystemScData>) {
+        self.esdt_tokens.extend(esdt_tokens);
+    }
 }
 
 impl Debug for BlockchainState {
@@ -89,6 +128,8 @@ impl Debug for BlockchainState {
             .field("accounts", &self.accounts)
             .field("new_addresses", &self.new_addresses)
             .field("current_block_info", &self.current_block_info)
+            .field("shard_coordinator", &self.shard_coordinator)
+            .field("cross_shard_calls", &self.cross_shard_calls)
             .finish()
     }
 }
@@ -152,15 +152,62 @@ impl AccountEsdt {
 
     pub fn set_special_role(&mut self, token_identifier: &[u8], role: &[u8]) {
         if let Some(esdt_data) = self.get_mut_by_identifier(token_identifier) {
-            let roles = esdt_data.roles.get();
-            if !roles.contains(role.to_vec().as_ref()) {
-                let mut new_roles = roles;
-                new_roles.push(role.to_vec());
-                esdt_data.roles = EsdtRoles::new(new_roles);
-            }
+            esdt_data.roles.add(role);
+        }
+    }
+
+    /// Unlike `set_special_role`, also creates the token entry if missing,
+    /// since roles can be given to accounts that do not hold the token yet.
+    pub fn add_special_role(&mut self, token_identifier: &[u8], role: &[u8]) {
+        self.0
+            .entry(token_identifier.to_vec())
+            .or_default()
+            .roles
+            .add(role);
+    }
+
+    pub fn unset_special_role(&mut self, token_identifier: &[u8], role: &[u8]) {
+        if let Some(esdt_data) = self.get_mut_by_identifier(token_identifier) {
+            esdt_data.roles.remove(role);
+        }
+    }
+
+    /// Nonce 0 freezes the token for the whole account, any other nonce only freezes that instance.
+    pub fn set_frozen(&mut self, token_identifier: &[u8], nonce: u64, frozen: bool) {
+        let esdt_data = self.0.entry(token_identifier.to_vec()).or_default();
+        if nonce == 0 {
+            esdt_data.frozen = frozen;
+        } else {
+            esdt_data.instances.get_or_insert_mut_by_nonce(nonce).frozen = frozen;
         }
     }
 
+    /// A token instance is frozen if either the whole token or the instance itself is frozen.
+    pub fn is_frozen(&self, token_identifier: &[u8], nonce: u64) -> bool {
+        let Some(esdt_data) = self.get_by_identifier(token_identifier) else {
+            return false;
+        };
+        esdt_data.frozen
+            || esdt_data
+                .instances
+                .get_by_nonce(nonce)
+                .is_some_and(|instance| instance.frozen)
+    }
+
+    /// Removes the balance of a frozen token instance, returns the wiped amount.
+    pub fn wipe(&mut self, token_identifier: &[u8], nonce: u64) -> Result<BigUint, &'static str> {
+        if !self.is_frozen(token_identifier, nonce) {
+            return Err("cannot wipe because the account is not frozen for this esdt token");
+        }
+        let esdt_data = self.get_mut_by_identifier(token_identifier).unwrap();
+        let wiped = esdt_data
+            .instances
+            .remove(nonce)
+            .map(|instance| instance.balance)
+            .unwrap_or_default();
+        Ok(wiped)
+    }
+
     pub fn register_and_set_roles(&mut self, token_identifier: &[u8], token_type: VMTokenType) {
         self.issue_token(token_identifier);
         self.set_roles(
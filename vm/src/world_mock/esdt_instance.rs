@@ -9,6 +9,8 @@ pub struct EsdtInstance {
     pub nonce: u64,
     pub balance: BigUint,
     pub metadata: EsdtInstanceMetadata,
+    /// Set by `freezeSingleNFT`, only relevant for tokens with nonce.
+    pub frozen: bool,
 }
 
 impl EsdtInstance {
@@ -17,6 +19,7 @@ impl EsdtInstance {
             nonce,
             balance: BigUint::zero(),
             metadata: EsdtInstanceMetadata::default(),
+            frozen: false,
         }
     }
 
@@ -25,6 +28,7 @@ impl EsdtInstance {
             nonce: 0,
             balance,
             metadata: EsdtInstanceMetadata::default(),
+            frozen: false,
         }
     }
 
@@ -44,6 +44,7 @@ impl EsdtInstances {
             nonce,
             balance: BigUint::zero(),
             metadata: metadata.clone(),
+            frozen: false,
         });
         if instance.balance.is_zero() {
             instance.metadata = metadata;
@@ -65,6 +66,7 @@ impl EsdtInstances {
                 nonce,
                 balance: value.clone(),
                 metadata,
+                frozen: false,
             });
     }
 
@@ -84,6 +86,16 @@ impl EsdtInstances {
         self.0.get_mut(&nonce)
     }
 
+    pub fn get_or_insert_mut_by_nonce(&mut self, nonce: u64) -> &mut EsdtInstance {
+        self.0
+            .entry(nonce)
+            .or_insert_with(|| EsdtInstance::default(nonce))
+    }
+
+    pub fn remove(&mut self, nonce: u64) -> Option<EsdtInstance> {
+        self.0.remove(&nonce)
+    }
+
     pub fn get_instances(&self) -> &BTreeMap<u64, EsdtInstance> {
         &self.0
     }
@@ -15,6 +15,20 @@ impl EsdtRoles {
     pub fn get(&self) -> Vec<Vec<u8>> {
         self.0.clone()
     }
+
+    pub fn contains(&self, role: &[u8]) -> bool {
+        self.0.iter().any(|r| r.as_slice() == role)
+    }
+
+    pub fn add(&mut self, role: &[u8]) {
+        if !self.contains(role) {
+            self.0.push(role.to_vec());
+        }
+    }
+
+    pub fn remove(&mut self, role: &[u8]) {
+        self.0.retain(|r| r.as_slice() != role);
+    }
 }
 
 impl fmt::Display for EsdtRoles {
@@ -0,0 +1,100 @@
+use num_bigint::BigUint;
+
+use crate::types::{VMAddress, VMTokenType};
+
+/// Token-wide data held by the ESDT system smart contract: owner, properties and global flags.
+///
+/// Only tokens issued through the system SC mock are registered here.
+/// Tokens that only appear in account state (e.g. set via `setState`) have no entry,
+/// and are treated permissively by the system SC functions.
+#[derive(Clone, Debug)]
+pub struct EsdtSystemScData {
+    pub owner: VMAddress,
+    pub token_name: Vec<u8>,
+    pub ticker: Vec<u8>,
+    pub token_type: VMTokenType,
+    pub num_decimals: u32,
+    pub minted_value: BigUint,
+    pub burnt_value: BigUint,
+    pub num_wiped: u64,
+    pub can_upgrade: bool,
+    pub can_mint: bool,
+    pub can_burn: bool,
+    pub can_change_owner: bool,
+    pub can_pause: bool,
+    pub can_freeze: bool,
+    pub can_wipe: bool,
+    pub can_add_special_roles: bool,
+    pub can_transfer_nft_create_role: bool,
+    pub can_create_multi_shard: bool,
+    pub nft_create_stopped: bool,
+    pub is_paused: bool,
+    pub burn_role_for_all: bool,
+}
+
+impl EsdtSystemScData {
+    pub fn new(
+        owner: VMAddress,
+        token_name: Vec<u8>,
+        ticker: Vec<u8>,
+        token_type: VMTokenType,
+        num_decimals: u32,
+    ) -> Self {
+        EsdtSystemScData {
+            owner,
+            token_name,
+            ticker,
+            token_type,
+            num_decimals,
+            minted_value: BigUint::default(),
+            burnt_value: BigUint::default(),
+            num_wiped: 0,
+            can_upgrade: true,
+            can_mint: false,
+            can_burn: false,
+            can_change_owner: false,
+            can_pause: false,
+            can_freeze: false,
+            can_wipe: false,
+            can_add_special_roles: true,
+            can_transfer_nft_create_role: false,
+            can_create_multi_shard: false,
+            nft_create_stopped: false,
+            is_paused: false,
+            burn_role_for_all: false,
+        }
+    }
+
+    /// Sets one of the upgradable properties, as passed to `issue*` and `controlChanges`.
+    pub fn set_property(&mut self, name: &[u8], value: &[u8]) -> Result<(), &'static str> {
+        let value = match value {
+            b"true" => true,
+            b"false" => false,
+            _ => return Err("invalid property value"),
+        };
+
+        match name {
+            b"canFreeze" => self.can_freeze = value,
+            b"canWipe" => self.can_wipe = value,
+            b"canPause" => self.can_pause = value,
+            b"canTransferNFTCreateRole" => self.can_transfer_nft_create_role = value,
+            b"canMint" => self.can_mint = value,
+            b"canBurn" => self.can_burn = value,
+            b"canChangeOwner" => self.can_change_owner = value,
+            b"canUpgrade" => self.can_upgrade = value,
+            b"canAddSpecialRoles" => self.can_add_special_roles = value,
+            b"canCreateMultiShard" => self.can_create_multi_shard = value,
+            _ => return Err("invalid property name"),
+        }
+        Ok(())
+    }
+
+    pub fn token_type_name(&self) -> &'static str {
+        match self.token_type {
+            VMTokenType::Fungible => "FungibleESDT",
+            VMTokenType::SemiFungible => "SemiFungibleESDT",
+            VMTokenType::Meta => "MetaESDT",
+            VMTokenType::NonFungible => "NonFungibleESDT",
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::types::VMAddress;
+
+/// Shard id of the metachain, where the system smart contracts live.
+pub const METACHAIN_SHARD_ID: u32 = u32::MAX;
+
+/// Smart contract addresses start with 8 zero bytes, followed by the 2-byte VM type.
+const SC_ADDRESS_PREFIX_LEN: usize = 10;
+
+/// Metachain smart contract addresses have 5 more zero bytes after the prefix.
+const METACHAIN_SC_ZEROS_LEN: usize = 5;
+
+/// Assigns accounts to shards based on the last bytes of their address, the same way the protocol does.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShardCoordinator {
+    num_shards: u32,
+    mask_high: u32,
+    mask_low: u32,
+}
+
+impl ShardCoordinator {
+    pub fn new(num_shards: u32) -> Self {
+        assert!(num_shards > 0, "there must be at least one shard");
+
+        let num_bits = u32::BITS - (num_shards - 1).leading_zeros();
+        let mask_high = (1u32 << num_bits) - 1;
+        let mask_low = (1u32 << num_bits.saturating_sub(1)) - 1;
+        ShardCoordinator {
+            num_shards,
+            mask_high,
+            mask_low,
+        }
+    }
+
+    pub fn num_shards(&self) -> u32 {
+        self.num_shards
+    }
+
+    pub fn compute_shard(&self, address: &VMAddress) -> u32 {
+        if is_metachain_sc_address(address) {
+            return METACHAIN_SHARD_ID;
+        }
+
+        let bytes_needed = (self.num_shards / 256) as usize + 1;
+        let address_bytes = address.as_bytes();
+        let last_bytes = &address_bytes[address_bytes.len() - bytes_needed..];
+        let address_suffix = last_bytes
+            .iter()
+            .fold(0u32, |acc, byte| (acc << 8) | *byte as u32);
+
+        let shard = address_suffix & self.mask_high;
+        if shard > self.num_shards - 1 {
+            address_suffix & self.mask_low
+        } else {
+            shard
+        }
+    }
+
+    pub fn same_shard(&self, address_1: &VMAddress, address_2: &VMAddress) -> bool {
+        self.compute_shard(address_1) == self.compute_shard(address_2)
+    }
+}
+
+fn is_metachain_sc_address(address: &VMAddress) -> bool {
+    address.is_smart_contract_address()
+        && address.as_bytes()[SC_ADDRESS_PREFIX_LEN..SC_ADDRESS_PREFIX_LEN + METACHAIN_SC_ZEROS_LEN]
+            .iter()
+            .all(|byte| *byte == 0)
+}
@@ -1,5 +1,6 @@
 use hex::FromHex;
-use multiversx_chain_vm::crypto_functions;
+use hex_literal::hex;
+use multiversx_chain_vm::crypto_functions::{self, MessageHashType};
 
 #[test]
 fn test_verify_ed25519_basic() {
@@ -42,3 +43,103 @@ fn test_verify_ed25519_invalid_args() {
     let success = crypto_functions::verify_ed25519(&pub_bytes, &msg_bytes, &sig_bytes);
     assert!(!success);
 }
+
+#[test]
+fn test_ripemd160() {
+    assert_eq!(
+        crypto_functions::ripemd160(&[1, 2, 3]),
+        hex!("79f901da2609f020adadbf2e5f68a16c8c3f7d57")
+    );
+    assert_eq!(
+        crypto_functions::ripemd160(&[1, 2, 3, 4, 5, 6, 7, 8]),
+        hex!("c9883eece7dca619b830dc9d87e82c38478111c0")
+    );
+}
+
+const BLS_KEY: [u8; 96] = hex!("b5823f6e564251cc03ce7bad3da83e72576e92795d3500bba1acb30ec9a94dce87bb8aa794d67b2d61d15c33f28f6c0c23ba1dfcbf21e8f8b46286ff871afabac925303ddcaddce6254fcff6d3155797db40b3d3b5865e8fc0bd770b3d79b381");
+const BLS_SIGNATURE: [u8; 48] = hex!("af32a2ddf341c08d1eb7232f05dc34e4454155e676b58c40fddf9a036562ac2c01533d2d557cb49d73aa9d7a89744696");
+
+#[test]
+fn test_verify_bls_basic() {
+    assert!(crypto_functions::verify_bls(
+        &BLS_KEY,
+        b"message to be signed",
+        &BLS_SIGNATURE
+    ));
+}
+
+#[test]
+fn test_verify_bls_bad_message() {
+    assert!(!crypto_functions::verify_bls(
+        &BLS_KEY,
+        b"message to be signeD",
+        &BLS_SIGNATURE
+    ));
+}
+
+#[test]
+fn test_verify_bls_invalid_args() {
+    assert!(!crypto_functions::verify_bls(
+        &BLS_KEY[..95],
+        b"message to be signed",
+        &BLS_SIGNATURE
+    ));
+    assert!(!crypto_functions::verify_bls(
+        &BLS_KEY,
+        b"message to be signed",
+        &BLS_KEY[..48]
+    ));
+}
+
+const SECP256K1_SIGNATURE: [u8; 71] = hex!("3045022100fab29dbcb623e7e96f44665f312b89ffdb9e4d2e2f53353236fbbcf57b2f49bf02203d6f9c63df8be43121042970a549a2d3baeca093fd37b726ddcc0924638409f5");
+
+#[test]
+fn test_verify_secp256k1_compressed_key() {
+    let key = hex!("02a673638cb9587cb68ea08dbef685c6f2d2a751a8b3c6f2a7e9a4999e6e4bfaf5");
+    assert!(crypto_functions::verify_secp256k1(
+        &key,
+        b"message to sign",
+        &SECP256K1_SIGNATURE,
+        MessageHashType::ECDSADoubleSha256,
+    ));
+}
+
+#[test]
+fn test_verify_secp256k1_uncompressed_key() {
+    let key = hex!("04a673638cb9587cb68ea08dbef685c6f2d2a751a8b3c6f2a7e9a4999e6e4bfaf5ca1d22fe57c6103dbaac10cf15d15c0791cab8bb9a04f800e4d215276cb3e008");
+    assert!(crypto_functions::verify_secp256k1(
+        &key,
+        b"message to sign",
+        &SECP256K1_SIGNATURE,
+        MessageHashType::ECDSADoubleSha256,
+    ));
+    assert!(!crypto_functions::verify_secp256k1(
+        &key,
+        b"message to sign",
+        &SECP256K1_SIGNATURE,
+        MessageHashType::ECDSASha256,
+    ));
+}
+
+#[test]
+fn test_verify_custom_secp256k1_plain_message() {
+    let key = hex!("04e32df42865e97135acfb65f3bae71bdc86f4d49150ad6a440b6f15878109880a0a2b2667f7e725ceea70c673093bf67663e0312623c8e091b13cf2c0f11ef652");
+    let message = hex!("ce0677bb30baa8cf067c88db9811f4333d131bf8bcf12fe7065d211dce971008");
+    let signature = hex!("304502210090f27b8b488db00b00606796d2987f6a5f59ae62ea05effe84fef5b8b0e5499802204a691139ad57a3f0b906637673aa2f63d1f55cb1a69199d4009eea23ceaddc93");
+    assert!(crypto_functions::verify_secp256k1(
+        &key,
+        &message,
+        &signature,
+        MessageHashType::ECDSAPlainMsg,
+    ));
+}
+
+#[test]
+fn test_encode_secp256k1_der_signature() {
+    let r = hex!("90f27b8b488db00b00606796d2987f6a5f59ae62ea05effe84fef5b8b0e54998");
+    let s = hex!("4a691139ad57a3f0b906637673aa2f63d1f55cb1a69199d4009eea23ceaddc93");
+    assert_eq!(
+        crypto_functions::encode_secp256k1_der_signature(&r, &s),
+        hex!("304502210090f27b8b488db00b00606796d2987f6a5f59ae62ea05effe84fef5b8b0e5499802204a691139ad57a3f0b906637673aa2f63d1f55cb1a69199d4009eea23ceaddc93")
+    );
+}
@@ -0,0 +1,132 @@
+use std::panic::AssertUnwindSafe;
+
+use multiversx_chain_vm::{
+    tx_execution::ESDT_SYSTEM_SC_ADDRESS_ARRAY,
+    tx_mock::{TxContextStack, TxFunctionName, TxInput, TxResult},
+    types::{VMAddress, VMCodeMetadata},
+    vm_hooks::{DebugApiVMHooksHandler, VMHooksHandlerSource, VMHooksSend},
+    world_mock::AccountData,
+    BlockchainMock,
+};
+use num_bigint::{BigInt, BigUint};
+
+const OWNER: [u8; 32] = [1u8; 32];
+
+fn new_blockchain() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::default();
+    for address in [OWNER, ESDT_SYSTEM_SC_ADDRESS_ARRAY] {
+        let address = VMAddress::new(address);
+        blockchain
+            .state
+            .accounts
+            .insert(address.clone(), AccountData::new_empty(address));
+    }
+    blockchain
+}
+
+/// Deploys a contract whose constructor is replaced by `f`.
+fn deploy<F>(blockchain: &mut BlockchainMock, f: F) -> (VMAddress, TxResult)
+where
+    F: FnOnce(&DebugApiVMHooksHandler),
+{
+    let tx_input = TxInput {
+        from: VMAddress::new(OWNER),
+        to: VMAddress::zero(),
+        func_name: TxFunctionName::INIT,
+        gas_limit: 1_000_000,
+        ..Default::default()
+    };
+    let vm = blockchain.vm.clone();
+    vm.sc_create_with_async_and_callback(
+        tx_input,
+        b"mock-code",
+        VMCodeMetadata::UPGRADEABLE,
+        &mut blockchain.state,
+        || {
+            let handler = DebugApiVMHooksHandler::new(TxContextStack::static_peek());
+            let _ = std::panic::catch_unwind(AssertUnwindSafe(|| f(&handler)));
+        },
+    )
+}
+
+/// Registers a promise, without callback, the same way a contract would.
+fn register_promise(
+    handler: &DebugApiVMHooksHandler,
+    to: VMAddress,
+    endpoint_name: &str,
+    args: Vec<Vec<u8>>,
+) {
+    let mut m_types = handler.m_types_lock();
+    let to_handle = m_types.mb_new(to.to_vec());
+    let egld_value_handle = m_types.bi_new_from_big_int(BigInt::default());
+    let endpoint_name_handle = m_types.mb_new(endpoint_name.as_bytes().to_vec());
+    let arg_buffer_handle = m_types.mb_new(Vec::new());
+    m_types.mb_set_vec_of_bytes(arg_buffer_handle, args);
+    let callback_closure_handle = m_types.mb_new(Vec::new());
+    drop(m_types);
+
+    handler.create_async_call_raw(
+        to_handle,
+        egld_value_handle,
+        endpoint_name_handle,
+        arg_buffer_handle,
+        b"",
+        b"",
+        0,
+        0,
+        callback_closure_handle,
+    );
+}
+
+#[test]
+fn test_deploy_promise() {
+    let mut blockchain = new_blockchain();
+
+    let (new_address, tx_result) = deploy(&mut blockchain, |handler| {
+        register_promise(
+            handler,
+            VMAddress::new(ESDT_SYSTEM_SC_ADDRESS_ARRAY),
+            "issue",
+            vec![
+                b"Test".to_vec(),
+                b"TEST".to_vec(),
+                BigUint::from(1000u32).to_bytes_be(),
+                vec![18],
+            ],
+        );
+    });
+    tx_result.assert_ok();
+    assert!(tx_result.pending_calls.no_calls());
+
+    // the issued token is credited to the new contract
+    let token_identifier = tx_result.result_values[0].clone();
+    assert!(token_identifier.starts_with(b"TEST-"));
+    assert_eq!(
+        blockchain.state.accounts[&new_address]
+            .esdt
+            .get_esdt_balance(&token_identifier, 0),
+        BigUint::from(1000u32)
+    );
+}
+
+#[test]
+fn test_deploy_failed_promise() {
+    let mut blockchain = new_blockchain();
+
+    let (new_address, tx_result) = deploy(&mut blockchain, |handler| {
+        handler.storage_write(b"key", b"value");
+        register_promise(
+            handler,
+            VMAddress::new(ESDT_SYSTEM_SC_ADDRESS_ARRAY),
+            "issue",
+            Vec::new(),
+        );
+    });
+    // the failed promise does not revert the deploy
+    assert_eq!(tx_result.result_status, 0);
+    assert!(blockchain.state.esdt_tokens.is_empty());
+    assert_eq!(
+        blockchain.state.accounts[&new_address].storage[b"key".as_slice()],
+        b"value".to_vec()
+    );
+}
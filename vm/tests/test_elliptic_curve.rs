@@ -0,0 +1,195 @@
+use hex_literal::hex;
+use multiversx_chain_vm::crypto_functions::EllipticCurve;
+use num_bigint::{BigInt, Sign};
+
+fn int(bytes: &[u8]) -> BigInt {
+    BigInt::from_bytes_be(Sign::Plus, bytes)
+}
+
+#[test]
+fn test_ec_from_name() {
+    assert_eq!(
+        EllipticCurve::from_name(b"p224"),
+        Some(EllipticCurve::p224())
+    );
+    assert_eq!(
+        EllipticCurve::from_name(b"p521"),
+        Some(EllipticCurve::p521())
+    );
+    assert_eq!(EllipticCurve::from_name(b"p22424"), None);
+    assert_eq!(EllipticCurve::from_name(b""), None);
+}
+
+#[test]
+fn test_ec_values() {
+    let p256 = EllipticCurve::p256();
+    assert_eq!(
+        p256.field_order.to_string(),
+        "115792089210356248762697446949407573530086143415290314195533631308867097853951"
+    );
+    assert_eq!(
+        p256.base_point_order.to_string(),
+        "115792089210356248762697446949407573529996955224135760342422259061068512044369"
+    );
+    assert_eq!(p256.private_key_byte_length(), 32);
+
+    let p521 = EllipticCurve::p521();
+    assert_eq!(p521.size_in_bits, 521);
+    assert_eq!(p521.private_key_byte_length(), 66);
+
+    for curve in [
+        EllipticCurve::p224(),
+        EllipticCurve::p256(),
+        EllipticCurve::p384(),
+        EllipticCurve::p521(),
+    ] {
+        assert!(curve.is_on_curve(&curve.x_base_point, &curve.y_base_point));
+    }
+}
+
+#[test]
+fn test_ec_is_on_curve() {
+    let p521 = EllipticCurve::p521();
+    assert!(!p521.is_on_curve(&BigInt::from(1), &BigInt::from(2)));
+    assert!(!p521.is_on_curve(&BigInt::from(0), &BigInt::from(0)));
+    assert!(!p521.is_on_curve(&-p521.x_base_point.clone(), &p521.y_base_point));
+}
+
+#[test]
+fn test_ec_add_double() {
+    let p256 = EllipticCurve::p256();
+    let (x, y) = p256.add(
+        &p256.x_base_point,
+        &p256.y_base_point,
+        &p256.x_base_point,
+        &p256.y_base_point,
+    );
+    assert_eq!(
+        x,
+        int(&hex!(
+            "7cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978"
+        ))
+    );
+    assert_eq!(
+        y,
+        int(&hex!(
+            "07775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1"
+        ))
+    );
+
+    let p521 = EllipticCurve::p521();
+    let (x, y) = p521.double(&p521.x_base_point, &p521.y_base_point);
+    assert_eq!(x, int(&hex!("433c219024277e7e682fcb288148c282747403279b1ccc06352c6e5505d769be97b3b204da6ef55507aa104a3a35c5af41cf2fa364d60fd967f43e3933ba6d783d")));
+    assert_eq!(y, int(&hex!("f4bb8cc7f86db26700a7f3eceeeed3f0b5c6b5107c4da97740ab21a29906c42dbbb3e377de9f251f6b93937fa99a3248f4eafcbe95edc0f4f71be356d661f41b02")));
+}
+
+#[test]
+fn test_ec_scalar_mult() {
+    let scalar = hex!("f93e4ae433cc12cf2a43fc0ef26400c0e125508224cdb649380f25479148a4ad");
+
+    let p224 = EllipticCurve::p224();
+    let (x, y) = p224.scalar_mult(
+        &int(&hex!(
+            "2507b2a1719f88dbe16518d8e719644b9fe2192f4b0e82c6dad005d2"
+        )),
+        &int(&hex!(
+            "a2534b3825a715e632b2629f158b9d9135de3a523c78814e5eef25c5"
+        )),
+        &scalar,
+    );
+    assert_eq!(
+        x,
+        int(&hex!(
+            "a71786c1e0b23c0812fd3feb626edfae5174f739823dd802eab38827"
+        ))
+    );
+    assert_eq!(
+        y,
+        int(&hex!(
+            "2ac7327527e4d1db0d8a610c09186fc5cad7806523ac3ae690c27229"
+        ))
+    );
+
+    let p384 = EllipticCurve::p384();
+    let (x, y) = p384.scalar_mult(
+        &int(&hex!("f705dc15013b3c76e0dc7f06c425cc9c30d9a216b70c3ba7ab2c42da32bcd16767466f2d5febd7615e04cc225312f565")),
+        &int(&hex!("953320d0d4e6dffe103f9d2a846fdf8c706d638f9101f04d1e00b2f51e050f7d35e49552fef6eac6c6e2868282636255")),
+        &scalar,
+    );
+    assert_eq!(x, int(&hex!("742bf14f0c2840dbdec06691f80dd7e291cc344cd49e5f5e036d9b5a398b4bf8be19ca7cfaf85da37e81cd34c4a2d886")));
+    assert_eq!(y, int(&hex!("d1c93b3c7c0afa8a8b4172ed58feeb1583212f45fa9b82af51ab0f22932cfe2dfd48d8d520869c28d9cb1f072003761f")));
+}
+
+#[test]
+fn test_ec_scalar_base_mult() {
+    let p224 = EllipticCurve::p224();
+    let scalar = "112233445566778899112233445566778899"
+        .parse::<BigInt>()
+        .unwrap()
+        .to_bytes_be()
+        .1;
+    let (x, y) = p224.scalar_base_mult(&scalar);
+    assert_eq!(
+        x,
+        int(&hex!(
+            "029895f0af496bfc62b6ef8d8a65c88c613949b03668aab4f0429e35"
+        ))
+    );
+    assert_eq!(
+        y,
+        int(&hex!(
+            "3ea6e53f9a841f2019ec24bde1a75677aa9b5902e61081c01064de93"
+        ))
+    );
+
+    let (x, y) = p224.scalar_base_mult(&[]);
+    assert!(!p224.is_on_curve(&x, &y));
+}
+
+#[test]
+fn test_ec_marshal_unmarshal() {
+    let p256 = EllipticCurve::p256();
+    let marshalled = p256.marshal(&p256.x_base_point, &p256.y_base_point);
+    assert_eq!(
+        marshalled,
+        hex!("046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c2964fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5")
+    );
+    assert_eq!(
+        p256.unmarshal(&marshalled),
+        Some((p256.x_base_point.clone(), p256.y_base_point.clone()))
+    );
+    assert_eq!(p256.unmarshal(&[4u8; 65]), None);
+    assert_eq!(p256.unmarshal(&marshalled[..64]), None);
+}
+
+#[test]
+fn test_ec_marshal_unmarshal_compressed() {
+    let p256 = EllipticCurve::p256();
+    let compressed = p256.marshal_compressed(&p256.x_base_point, &p256.y_base_point);
+    assert_eq!(
+        compressed,
+        hex!("036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296")
+    );
+    assert_eq!(
+        p256.unmarshal_compressed(&compressed),
+        Some((p256.x_base_point.clone(), p256.y_base_point.clone()))
+    );
+    assert_eq!(
+        p256.unmarshal_compressed(&hex!(
+            "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a1394000000000"
+        )),
+        None
+    );
+
+    // P-224 is the only supported curve where p = 1 mod 4
+    for curve in [
+        EllipticCurve::p224(),
+        EllipticCurve::p384(),
+        EllipticCurve::p521(),
+    ] {
+        let (x, y) = curve.scalar_base_mult(&[7]);
+        let compressed = curve.marshal_compressed(&x, &y);
+        assert_eq!(compressed.len(), 1 + curve.byte_length());
+        assert_eq!(curve.unmarshal_compressed(&compressed), Some((x, y)));
+    }
+}
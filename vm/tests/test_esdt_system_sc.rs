@@ -0,0 +1,355 @@
+use std::sync::Arc;
+
+use multiversx_chain_vm::{
+    tx_execution::{execute_system_sc, ESDT_SYSTEM_SC_ADDRESS_ARRAY},
+    tx_mock::{TxCache, TxInput, TxResult},
+    types::VMAddress,
+    world_mock::{AccountData, BlockchainState, EsdtInstanceMetadata},
+};
+use num_bigint::BigUint;
+
+const OWNER: [u8; 32] = [1u8; 32];
+const USER: [u8; 32] = [2u8; 32];
+
+fn new_state() -> BlockchainState {
+    let mut state = BlockchainState::default();
+    for address in [OWNER, USER] {
+        let address = VMAddress::new(address);
+        state
+            .accounts
+            .insert(address.clone(), AccountData::new_empty(address));
+    }
+    state
+}
+
+fn call_system_sc(
+    state: &mut BlockchainState,
+    from: [u8; 32],
+    func_name: &str,
+    args: Vec<Vec<u8>>,
+) -> TxResult {
+    let tx_input = TxInput {
+        from: VMAddress::new(from),
+        to: VMAddress::new(ESDT_SYSTEM_SC_ADDRESS_ARRAY),
+        func_name: func_name.into(),
+        args,
+        ..Default::default()
+    };
+    let tx_cache = TxCache::new(Arc::new(state.clone()));
+    let (tx_result, blockchain_update) = execute_system_sc(tx_input, tx_cache);
+    state.commit_updates(blockchain_update);
+    tx_result
+}
+
+/// Issues a fungible token with 1000 supply, returns its identifier.
+fn issue_fungible(state: &mut BlockchainState, properties: &[(&str, &str)]) -> Vec<u8> {
+    let mut args = vec![
+        b"Test".to_vec(),
+        b"TEST".to_vec(),
+        BigUint::from(1000u32).to_bytes_be(),
+        vec![18],
+    ];
+    for (name, value) in properties {
+        args.push(name.as_bytes().to_vec());
+        args.push(value.as_bytes().to_vec());
+    }
+    let tx_result = call_system_sc(state, OWNER, "issue", args);
+    tx_result.assert_ok();
+    tx_result.result_values[0].clone()
+}
+
+fn transfer(
+    state: &mut BlockchainState,
+    from: [u8; 32],
+    to: [u8; 32],
+    token: &[u8],
+    amount: u32,
+) -> TxResult {
+    let tx_cache = TxCache::new(Arc::new(state.clone()));
+    let result = tx_cache.transfer_esdt_balance(
+        &VMAddress::new(from),
+        &VMAddress::new(to),
+        token,
+        0,
+        &BigUint::from(amount),
+    );
+    match result {
+        Ok(()) => {
+            state.commit_updates(tx_cache.into_blockchain_updates());
+            TxResult::empty()
+        },
+        Err(panic_obj) => TxResult::from_panic_obj(&panic_obj),
+    }
+}
+
+fn balance(state: &BlockchainState, address: [u8; 32], token: &[u8], nonce: u64) -> BigUint {
+    state.accounts[&VMAddress::new(address)]
+        .esdt
+        .get_esdt_balance(token, nonce)
+}
+
+#[test]
+fn test_issue_mints_initial_supply() {
+    let mut state = new_state();
+    let token = issue_fungible(&mut state, &[]);
+
+    assert_eq!(balance(&state, OWNER, &token, 0), BigUint::from(1000u32));
+    assert_eq!(state.esdt_tokens[&token].owner, VMAddress::new(OWNER));
+}
+
+#[test]
+fn test_freeze_blocks_transfers() {
+    let mut state = new_state();
+    let token = issue_fungible(&mut state, &[("canFreeze", "true")]);
+    transfer(&mut state, OWNER, USER, &token, 100).assert_ok();
+
+    call_system_sc(
+        &mut state,
+        OWNER,
+        "freeze",
+        vec![token.clone(), USER.to_vec()],
+    )
+    .assert_ok();
+    transfer(&mut state, USER, OWNER, &token, 10)
+        .assert_error(10, "account is frozen for this esdt token");
+    transfer(&mut state, OWNER, USER, &token, 10)
+        .assert_error(10, "account is frozen for this esdt token");
+
+    call_system_sc(
+        &mut state,
+        OWNER,
+        "unFreeze",
+        vec![token.clone(), USER.to_vec()],
+    )
+    .assert_ok();
+    transfer(&mut state, USER, OWNER, &token, 10).assert_ok();
+}
+
+#[test]
+fn test_freeze_requires_owner_and_property() {
+    let mut state = new_state();
+    let token = issue_fungible(&mut state, &[]);
+
+    call_system_sc(
+        &mut state,
+        USER,
+        "freeze",
+        vec![token.clone(), USER.to_vec()],
+    )
+    .assert_error(10, "can be called by owner only");
+    call_system_sc(
+        &mut state,
+        OWNER,
+        "freeze",
+        vec![token.clone(), USER.to_vec()],
+    )
+    .assert_error(10, "cannot freeze");
+}
+
+#[test]
+fn test_wipe() {
+    let mut state = new_state();
+    let token = issue_fungible(&mut state, &[("canFreeze", "true"), ("canWipe", "true")]);
+    transfer(&mut state, OWNER, USER, &token, 100).assert_ok();
+
+    call_system_sc(
+        &mut state,
+        OWNER,
+        "wipe",
+        vec![token.clone(), USER.to_vec()],
+    )
+    .assert_error(
+        10,
+        "cannot wipe because the account is not frozen for this esdt token",
+    );
+
+    call_system_sc(
+        &mut state,
+        OWNER,
+        "freeze",
+        vec![token.clone(), USER.to_vec()],
+    )
+    .assert_ok();
+    call_system_sc(
+        &mut state,
+        OWNER,
+        "wipe",
+        vec![token.clone(), USER.to_vec()],
+    )
+    .assert_ok();
+
+    assert_eq!(balance(&state, USER, &token, 0), BigUint::from(0u32));
+    assert_eq!(state.esdt_tokens[&token].burnt_value, BigUint::from(100u32));
+    assert_eq!(state.esdt_tokens[&token].num_wiped, 1);
+}
+
+#[test]
+fn test_pause_blocks_transfers() {
+    let mut state = new_state();
+    let token = issue_fungible(&mut state, &[("canPause", "true")]);
+
+    call_system_sc(&mut state, OWNER, "pause", vec![token.clone()]).assert_ok();
+    call_system_sc(&mut state, OWNER, "pause", vec![token.clone()])
+        .assert_error(10, "cannot pause an already paused contract");
+    transfer(&mut state, OWNER, USER, &token, 10).assert_error(10, "esdt token is paused");
+
+    call_system_sc(&mut state, OWNER, "unPause", vec![token.clone()]).assert_ok();
+    transfer(&mut state, OWNER, USER, &token, 10).assert_ok();
+}
+
+#[test]
+fn test_freeze_single_nft() {
+    let mut state = new_state();
+    let tx_result = call_system_sc(
+        &mut state,
+        OWNER,
+        "issueNonFungible",
+        vec![
+            b"Nft".to_vec(),
+            b"NFT".to_vec(),
+            b"canFreeze".to_vec(),
+            b"true".to_vec(),
+        ],
+    );
+    tx_result.assert_ok();
+    let token = tx_result.result_values[0].clone();
+    for nonce in [1, 2] {
+        state
+            .accounts
+            .get_mut(&VMAddress::new(USER))
+            .unwrap()
+            .esdt
+            .increase_balance(
+                token.clone(),
+                nonce,
+                &BigUint::from(1u32),
+                EsdtInstanceMetadata::default(),
+            );
+    }
+
+    call_system_sc(
+        &mut state,
+        OWNER,
+        "freezeSingleNFT",
+        vec![token.clone(), vec![1], USER.to_vec()],
+    )
+    .assert_ok();
+
+    let user_esdt = &state.accounts[&VMAddress::new(USER)].esdt;
+    assert!(user_esdt.is_frozen(&token, 1));
+    assert!(!user_esdt.is_frozen(&token, 2));
+}
+
+#[test]
+fn test_transfer_ownership_and_mint() {
+    let mut state = new_state();
+    let token = issue_fungible(
+        &mut state,
+        &[("canChangeOwner", "true"), ("canMint", "true")],
+    );
+
+    call_system_sc(
+        &mut state,
+        OWNER,
+        "transferOwnership",
+        vec![token.clone(), USER.to_vec()],
+    )
+    .assert_ok();
+    call_system_sc(
+        &mut state,
+        OWNER,
+        "mint",
+        vec![token.clone(), BigUint::from(5u32).to_bytes_be()],
+    )
+    .assert_error(10, "can be called by owner only");
+    call_system_sc(
+        &mut state,
+        USER,
+        "mint",
+        vec![token.clone(), BigUint::from(5u32).to_bytes_be()],
+    )
+    .assert_ok();
+
+    assert_eq!(balance(&state, USER, &token, 0), BigUint::from(5u32));
+    assert_eq!(
+        state.esdt_tokens[&token].minted_value,
+        BigUint::from(1005u32)
+    );
+}
+
+#[test]
+fn test_get_token_properties() {
+    let mut state = new_state();
+    let token = issue_fungible(&mut state, &[("canPause", "true")]);
+
+    let tx_result = call_system_sc(&mut state, OWNER, "getTokenProperties", vec![token]);
+    tx_result.assert_ok();
+    assert_eq!(tx_result.result_values[0], b"Test");
+    assert_eq!(tx_result.result_values[1], b"FungibleESDT");
+    assert_eq!(tx_result.result_values[3], b"1000");
+    assert_eq!(tx_result.result_values[5], b"NumDecimals-18");
+    assert_eq!(tx_result.result_values[11], b"CanPause-true");
+}
+
+#[test]
+fn test_special_roles() {
+    let mut state = new_state();
+    let token = issue_fungible(&mut state, &[]);
+
+    call_system_sc(
+        &mut state,
+        OWNER,
+        "setSpecialRole",
+        vec![
+            token.clone(),
+            USER.to_vec(),
+            b"ESDTRoleLocalMint".to_vec(),
+            b"ESDTRoleLocalBurn".to_vec(),
+        ],
+    )
+    .assert_ok();
+    call_system_sc(
+        &mut state,
+        OWNER,
+        "unSetSpecialRole",
+        vec![token.clone(), USER.to_vec(), b"ESDTRoleLocalMint".to_vec()],
+    )
+    .assert_ok();
+
+    let tx_result = call_system_sc(&mut state, OWNER, "getAllAddressesAndRoles", vec![token]);
+    tx_result.assert_ok();
+    let user_index = tx_result
+        .result_values
+        .iter()
+        .position(|value| value.as_slice() == USER.as_slice())
+        .unwrap();
+    assert_eq!(
+        tx_result.result_values[user_index + 1],
+        b"ESDTRoleLocalBurn"
+    );
+}
+
+#[test]
+fn test_special_roles_invalid_address() {
+    let mut state = new_state();
+    let token = issue_fungible(&mut state, &[]);
+
+    call_system_sc(
+        &mut state,
+        OWNER,
+        "unSetSpecialRole",
+        vec![
+            token.clone(),
+            b"short".to_vec(),
+            b"ESDTRoleLocalMint".to_vec(),
+        ],
+    )
+    .assert_error(10, "invalid address argument");
+    call_system_sc(
+        &mut state,
+        OWNER,
+        "transferNFTCreateRole",
+        vec![token, USER.to_vec(), b"short".to_vec()],
+    )
+    .assert_error(10, "invalid address argument");
+}
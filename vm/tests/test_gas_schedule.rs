@@ -0,0 +1,238 @@
+use std::{cell::Cell, panic::AssertUnwindSafe};
+
+use multiversx_chain_vm::{
+    executor::VMHooks,
+    schedule::{GasSchedule, GasSection},
+    tx_mock::{TxContextStack, TxInput, TxResult},
+    types::VMAddress,
+    vm_hooks::{DebugApiVMHooksHandler, VMHooksDispatcher},
+    world_mock::{AccountData, EsdtInstanceMetadata},
+    BlockchainMock,
+};
+use num_bigint::BigUint;
+
+const USER: [u8; 32] = [2u8; 32];
+const CONTRACT: [u8; 32] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+];
+const TOKEN: &[u8] = b"TEST-123456";
+const GAS_PRICE: u64 = 2;
+
+const GAS_SCHEDULE_TOML: &str = r#"
+[BuiltInCost]
+    ESDTTransfer = 100
+    ESDTNFTMultiTransfer = 200
+
+[BigIntAPICost]
+    BigIntNew = 10
+    BigIntAdd = 20
+
+[MaxPerTransaction]
+    MaxBuiltInCallsPerTx = 100
+
+[SomethingElse]
+    NotAnInteger = "text"
+"#;
+
+fn new_blockchain() -> BlockchainMock {
+    let mut gas_schedule = GasSchedule::from_toml_str(GAS_SCHEDULE_TOML).unwrap();
+    gas_schedule.set_tx_base_cost(0, 0);
+    new_blockchain_with_gas_schedule(gas_schedule)
+}
+
+fn new_blockchain_with_gas_schedule(gas_schedule: GasSchedule) -> BlockchainMock {
+    let mut blockchain = BlockchainMock::default();
+    blockchain.vm.set_gas_schedule(Some(gas_schedule)).unwrap();
+    for address in [USER, CONTRACT] {
+        let address = VMAddress::new(address);
+        let mut account = AccountData::new_empty(address.clone());
+        account.egld_balance = BigUint::from(10_000u32);
+        account.esdt.increase_balance(
+            TOKEN.to_vec(),
+            0,
+            &BigUint::from(1000u32),
+            EsdtInstanceMetadata::default(),
+        );
+        blockchain.state.accounts.insert(address, account);
+    }
+    blockchain
+}
+
+fn egld_balance(blockchain: &BlockchainMock, address: [u8; 32]) -> BigUint {
+    blockchain.state.accounts[&VMAddress::new(address)]
+        .egld_balance
+        .clone()
+}
+
+fn esdt_transfer(blockchain: &mut BlockchainMock, gas_limit: u64) -> TxResult {
+    let tx_input = TxInput {
+        from: VMAddress::new(USER),
+        to: VMAddress::new(CONTRACT),
+        func_name: "ESDTTransfer".into(),
+        args: vec![TOKEN.to_vec(), BigUint::from(10u32).to_bytes_be()],
+        gas_limit,
+        gas_price: GAS_PRICE,
+        ..Default::default()
+    };
+    let vm = blockchain.vm.clone();
+    vm.execute_sc_call_lambda(tx_input, &mut blockchain.state, || {})
+}
+
+/// Calls `f` with the VM hooks of the current context, the same way a contract would.
+///
+/// Errors halt execution via a panic, which is caught here.
+fn call_contract<F>(blockchain: &mut BlockchainMock, gas_limit: u64, f: F) -> TxResult
+where
+    F: FnOnce(&dyn VMHooks),
+{
+    let tx_input = TxInput {
+        from: VMAddress::new(USER),
+        to: VMAddress::new(CONTRACT),
+        func_name: "endpoint".into(),
+        gas_limit,
+        gas_price: GAS_PRICE,
+        ..Default::default()
+    };
+    let vm = blockchain.vm.clone();
+    vm.execute_sc_call_lambda(tx_input, &mut blockchain.state, || {
+        let handler = DebugApiVMHooksHandler::new(TxContextStack::static_peek());
+        let dispatcher = VMHooksDispatcher::new(Box::new(handler));
+        let _ = std::panic::catch_unwind(AssertUnwindSafe(|| f(&dispatcher)));
+    })
+}
+
+#[test]
+fn test_gas_schedule_from_toml() {
+    let gas_schedule = GasSchedule::from_toml_str(GAS_SCHEDULE_TOML).unwrap();
+    assert_eq!(
+        gas_schedule.cost(GasSection::BuiltInCost, "ESDTTransfer"),
+        100
+    );
+    assert_eq!(
+        gas_schedule.cost(GasSection::BigIntApiCost, "BigIntAdd"),
+        20
+    );
+    assert_eq!(gas_schedule.cost(GasSection::BigIntApiCost, "BigIntSub"), 0);
+    assert_eq!(gas_schedule.cost(GasSection::CryptoApiCost, "SHA256"), 0);
+
+    assert!(GasSchedule::from_toml_str("[BuiltInCost").is_err());
+}
+
+#[test]
+fn test_builtin_function_gas_refund() {
+    let mut blockchain = new_blockchain();
+
+    let tx_result = esdt_transfer(&mut blockchain, 150);
+    tx_result.assert_ok();
+    assert_eq!(tx_result.gas_used, 100);
+    // only the gas used is paid for
+    assert_eq!(
+        egld_balance(&blockchain, USER),
+        BigUint::from(10_000u32 - 100 * GAS_PRICE as u32)
+    );
+}
+
+#[test]
+fn test_tx_base_cost() {
+    let mut gas_schedule = GasSchedule::from_toml_str(GAS_SCHEDULE_TOML).unwrap();
+    gas_schedule.set_tx_base_cost(50, 2);
+    let mut blockchain = new_blockchain_with_gas_schedule(gas_schedule);
+
+    // data: "ESDTTransfer@544553542d313233343536@0a", 38 bytes
+    let base_cost = 50 + 2 * 38;
+    assert_eq!(blockchain.vm.tx_base_gas_cost(38), base_cost);
+
+    let tx_result = esdt_transfer(&mut blockchain, 300);
+    tx_result.assert_ok();
+    assert_eq!(tx_result.gas_used, base_cost + 100);
+    assert_eq!(
+        egld_balance(&blockchain, USER),
+        BigUint::from(10_000 - (base_cost + 100) * GAS_PRICE)
+    );
+
+    // the base cost alone is over the gas limit, nothing gets paid
+    let tx_result = esdt_transfer(&mut blockchain, 100);
+    tx_result.assert_error(10, "insufficient gas limit in tx");
+    assert_eq!(
+        egld_balance(&blockchain, USER),
+        BigUint::from(10_000 - (base_cost + 100) * GAS_PRICE)
+    );
+}
+
+#[test]
+fn test_set_gas_schedule_while_in_use() {
+    let mut blockchain = new_blockchain();
+    let _vm_in_use = blockchain.vm.clone();
+    assert!(blockchain.vm.set_gas_schedule(None).is_err());
+}
+
+#[test]
+fn test_builtin_function_out_of_gas() {
+    let mut blockchain = new_blockchain();
+
+    let tx_result = esdt_transfer(&mut blockchain, 50);
+    tx_result.assert_error(5, "out of gas");
+    assert_eq!(tx_result.gas_used, 50);
+    assert_eq!(
+        egld_balance(&blockchain, USER),
+        BigUint::from(10_000u32 - 50 * GAS_PRICE as u32)
+    );
+    assert_eq!(
+        blockchain.state.accounts[&VMAddress::new(USER)]
+            .esdt
+            .get_esdt_balance(TOKEN, 0),
+        BigUint::from(1000u32)
+    );
+}
+
+#[test]
+fn test_vm_hook_gas() {
+    let mut blockchain = new_blockchain();
+    let gas_left = Cell::new(0);
+
+    let tx_result = call_contract(&mut blockchain, 1000, |vh| {
+        let x = vh.big_int_new(1);
+        let y = vh.big_int_new(2);
+        vh.big_int_add(x, x, y);
+        gas_left.set(vh.get_gas_left());
+    });
+    tx_result.assert_ok();
+    assert_eq!(tx_result.gas_used, 40);
+    assert_eq!(gas_left.get(), 1000 - 40);
+}
+
+#[test]
+fn test_vm_hook_out_of_gas() {
+    let mut blockchain = new_blockchain();
+
+    let tx_result = call_contract(&mut blockchain, 25, |vh| {
+        let x = vh.big_int_new(1);
+        let y = vh.big_int_new(2);
+        vh.big_int_add(x, x, y);
+    });
+    tx_result.assert_error(5, "out of gas");
+    assert_eq!(tx_result.gas_used, 25);
+    assert_eq!(
+        egld_balance(&blockchain, USER),
+        BigUint::from(10_000u32 - 25 * GAS_PRICE as u32)
+    );
+}
+
+#[test]
+fn test_no_gas_metering_by_default() {
+    let mut blockchain = new_blockchain();
+    blockchain.vm.set_gas_schedule(None).unwrap();
+
+    let tx_result = call_contract(&mut blockchain, 25, |vh| {
+        let x = vh.big_int_new(1);
+        let y = vh.big_int_new(2);
+        vh.big_int_add(x, x, y);
+    });
+    tx_result.assert_ok();
+    assert_eq!(tx_result.gas_used, 0);
+    // without gas metering, all gas is consumed
+    assert_eq!(
+        egld_balance(&blockchain, USER),
+        BigUint::from(10_000u32 - 25 * GAS_PRICE as u32)
+    );
+}
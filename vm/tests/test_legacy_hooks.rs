@@ -0,0 +1,383 @@
+use std::{cell::Cell, panic::AssertUnwindSafe};
+
+use multiversx_chain_vm::{
+    executor::VMHooks,
+    mem_conv,
+    tx_mock::{TxContextStack, TxInput, TxResult, TxTokenTransfer},
+    types::VMAddress,
+    vm_hooks::{DebugApiVMHooksHandler, VMHooksDispatcher},
+    world_mock::{AccountData, BlockInfo},
+    BlockchainMock,
+};
+use num_bigint::BigUint;
+
+const USER: [u8; 32] = [2u8; 32];
+const OTHER_USER: [u8; 32] = [3u8; 32];
+const CONTRACT: [u8; 32] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+];
+
+fn new_blockchain() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::default();
+    for (address, balance) in [(USER, 10_000u32), (OTHER_USER, 700), (CONTRACT, 0)] {
+        let address = VMAddress::new(address);
+        let mut account = AccountData::new_empty(address.clone());
+        account.egld_balance = BigUint::from(balance);
+        blockchain.state.accounts.insert(address, account);
+    }
+    blockchain
+}
+
+/// Calls `f` with the VM hooks of the current context, the same way a contract would.
+///
+/// Errors halt execution via a panic, which is caught here.
+fn call_contract<F>(blockchain: &mut BlockchainMock, egld_value: u32, f: F) -> TxResult
+where
+    F: FnOnce(&dyn VMHooks),
+{
+    let tx_input = TxInput {
+        from: VMAddress::new(USER),
+        to: VMAddress::new(CONTRACT),
+        egld_value: BigUint::from(egld_value),
+        func_name: "endpoint".into(),
+        ..Default::default()
+    };
+    let vm = blockchain.vm.clone();
+    vm.execute_sc_call_lambda(tx_input, &mut blockchain.state, || {
+        let handler = DebugApiVMHooksHandler::new(TxContextStack::static_peek());
+        let dispatcher = VMHooksDispatcher::new(Box::new(handler));
+        let _ = std::panic::catch_unwind(AssertUnwindSafe(|| f(&dispatcher)));
+    })
+}
+
+fn storage_store(vh: &dyn VMHooks, key: &[u8], value: &[u8]) -> i32 {
+    mem_conv::with_mem_ptr(key, |key_offset, key_length| {
+        mem_conv::with_mem_ptr(value, |value_offset, value_length| {
+            vh.storage_store(key_offset, key_length, value_offset, value_length)
+        })
+    })
+}
+
+fn storage_load(vh: &dyn VMHooks, key: &[u8]) -> Vec<u8> {
+    mem_conv::with_mem_ptr(key, |key_offset, key_length| {
+        let mut value = vec![0u8; vh.storage_load_length(key_offset, key_length) as usize];
+        mem_conv::with_mem_ptr_mut(&mut value, |value_offset, _| {
+            vh.storage_load(key_offset, key_length, value_offset)
+        });
+        value
+    })
+}
+
+#[test]
+fn test_storage_store_status() {
+    let mut blockchain = new_blockchain();
+    let statuses = Cell::new([0i32; 4]);
+    let loaded = Cell::new(Vec::new());
+
+    let tx_result = call_contract(&mut blockchain, 0, |vh| {
+        statuses.set([
+            storage_store(vh, b"key", b"value"),
+            storage_store(vh, b"key", b"value"),
+            storage_store(vh, b"key", b"other"),
+            storage_store(vh, b"deleted", b""),
+        ]);
+        loaded.set(storage_load(vh, b"key"));
+    });
+    tx_result.assert_ok();
+    // added, unchanged, modified, unchanged (deleting a missing key)
+    assert_eq!(statuses.get(), [2, 0, 1, 0]);
+    assert_eq!(loaded.take(), b"other".to_vec());
+
+    let tx_result = call_contract(&mut blockchain, 0, |vh| {
+        statuses.set([storage_store(vh, b"key", b""), 0, 0, 0]);
+    });
+    tx_result.assert_ok();
+    assert_eq!(statuses.get()[0], 3);
+    assert!(blockchain.state.accounts[&VMAddress::new(CONTRACT)]
+        .storage
+        .get(b"key".as_slice())
+        .cloned()
+        .unwrap_or_default()
+        .is_empty());
+}
+
+#[test]
+fn test_storage_integers() {
+    let mut blockchain = new_blockchain();
+    let loaded = Cell::new((0i64, 0i64));
+
+    let tx_result = call_contract(&mut blockchain, 0, |vh| {
+        mem_conv::with_mem_ptr(b"signed", |key_offset, key_length| {
+            vh.small_int_storage_store_signed(key_offset, key_length, -5);
+        });
+        mem_conv::with_mem_ptr(b"unsigned", |key_offset, key_length| {
+            vh.small_int_storage_store_unsigned(key_offset, key_length, 300);
+        });
+        loaded.set((
+            mem_conv::with_mem_ptr(b"signed", |key_offset, key_length| {
+                vh.small_int_storage_load_signed(key_offset, key_length)
+            }),
+            mem_conv::with_mem_ptr(b"unsigned", |key_offset, key_length| {
+                vh.small_int_storage_load_unsigned(key_offset, key_length)
+            }),
+        ));
+    });
+    tx_result.assert_ok();
+    assert_eq!(loaded.get(), (-5, 300));
+    assert_eq!(
+        blockchain.state.accounts[&VMAddress::new(CONTRACT)].storage[b"unsigned".as_slice()],
+        vec![1, 44]
+    );
+}
+
+#[test]
+fn test_storage_lock() {
+    let mut blockchain = new_blockchain();
+    blockchain.state.current_block_info.block_timestamp = 100;
+    let locks = Cell::new((0i64, 0i32, 0i32));
+
+    let tx_result = call_contract(&mut blockchain, 0, |vh| {
+        mem_conv::with_mem_ptr(b"key", |key_offset, key_length| {
+            vh.set_storage_lock(key_offset, key_length, 200);
+            let locked = vh.is_storage_locked(key_offset, key_length);
+            let lock = vh.get_storage_lock(key_offset, key_length);
+            vh.clear_storage_lock(key_offset, key_length);
+            locks.set((lock, locked, vh.is_storage_locked(key_offset, key_length)));
+        });
+    });
+    tx_result.assert_ok();
+    assert_eq!(locks.get(), (200, 1, 0));
+}
+
+#[test]
+fn test_caller_and_call_value() {
+    let mut blockchain = new_blockchain();
+    let caller = Cell::new([0u8; 32]);
+    let call_value = Cell::new([0u8; 32]);
+
+    let tx_result = call_contract(&mut blockchain, 1234, |vh| {
+        let mut buffer = [0u8; 32];
+        mem_conv::with_mem_ptr_mut(&mut buffer, |offset, _| vh.get_caller(offset));
+        caller.set(buffer);
+        mem_conv::with_mem_ptr_mut(&mut buffer, |offset, _| vh.get_call_value(offset));
+        call_value.set(buffer);
+    });
+    tx_result.assert_ok();
+    assert_eq!(caller.get(), USER);
+    let mut expected_value = [0u8; 32];
+    expected_value[30..].copy_from_slice(&1234u16.to_be_bytes());
+    assert_eq!(call_value.get(), expected_value);
+}
+
+#[test]
+fn test_external_balance() {
+    let mut blockchain = new_blockchain();
+    let balances = Cell::new(([0u8; 32], [0u8; 32]));
+
+    let tx_result = call_contract(&mut blockchain, 0, |vh| {
+        let mut other_balance = [0u8; 32];
+        let mut missing_balance = [0u8; 32];
+        mem_conv::with_mem_ptr(&OTHER_USER, |address_offset, _| {
+            mem_conv::with_mem_ptr_mut(&mut other_balance, |result_offset, _| {
+                vh.get_external_balance(address_offset, result_offset)
+            })
+        });
+        mem_conv::with_mem_ptr(&[9u8; 32], |address_offset, _| {
+            mem_conv::with_mem_ptr_mut(&mut missing_balance, |result_offset, _| {
+                vh.get_external_balance(address_offset, result_offset)
+            })
+        });
+        balances.set((other_balance, missing_balance));
+    });
+    tx_result.assert_ok();
+    let mut expected_balance = [0u8; 32];
+    expected_balance[30..].copy_from_slice(&700u16.to_be_bytes());
+    assert_eq!(balances.get(), (expected_balance, [0u8; 32]));
+}
+
+#[test]
+fn test_validate_token_identifier() {
+    let mut blockchain = new_blockchain();
+    let results = Cell::new(Vec::new());
+
+    let tx_result = call_contract(&mut blockchain, 0, |vh| {
+        let identifiers: [&[u8]; 5] = [
+            b"TEST-123456",
+            b"AB12-abcdef",
+            b"test-123456",
+            b"TEST123456",
+            b"TEST-1234567",
+        ];
+        let validated = identifiers
+            .iter()
+            .map(|identifier| {
+                let handle = mem_conv::with_mem_ptr(identifier, |offset, length| {
+                    vh.mbuffer_new_from_bytes(offset, length)
+                });
+                vh.validate_token_identifier(handle)
+            })
+            .collect();
+        results.set(validated);
+    });
+    tx_result.assert_ok();
+    assert_eq!(results.take(), vec![1, 1, 0, 0, 0]);
+}
+
+#[test]
+fn test_big_float_buffer_round_trip() {
+    let mut blockchain = new_blockchain();
+    let compared = Cell::new(-2);
+
+    let tx_result = call_contract(&mut blockchain, 0, |vh| {
+        let original = vh.big_float_new_from_frac(-7, 4);
+        let buffer = vh.mbuffer_new();
+        vh.mbuffer_from_big_float(buffer, original);
+        let decoded = vh.big_float_new_from_frac(0, 1);
+        vh.mbuffer_to_big_float(buffer, decoded);
+        compared.set(vh.big_float_cmp(original, decoded));
+    });
+    tx_result.assert_ok();
+    assert_eq!(compared.get(), 0);
+}
+
+#[test]
+fn test_big_float_buffer_invalid() {
+    let mut blockchain = new_blockchain();
+
+    let tx_result = call_contract(&mut blockchain, 0, |vh| {
+        let buffer = mem_conv::with_mem_ptr(&[1, 2, 3], |offset, length| {
+            vh.mbuffer_new_from_bytes(offset, length)
+        });
+        let decoded = vh.big_float_new_from_frac(0, 1);
+        vh.mbuffer_to_big_float(buffer, decoded);
+    });
+    tx_result.assert_error(10, "cannot decode big float");
+}
+
+#[test]
+fn test_write_log() {
+    let mut blockchain = new_blockchain();
+    let topics = [[1u8; 32], [2u8; 32]].concat();
+
+    let tx_result = call_contract(&mut blockchain, 0, |vh| {
+        mem_conv::with_mem_ptr(b"data", |data_offset, data_length| {
+            mem_conv::with_mem_ptr(&topics, |topic_offset, _| {
+                vh.write_log(data_offset, data_length, topic_offset, 2)
+            })
+        });
+    });
+    tx_result.assert_ok();
+    assert_eq!(tx_result.result_logs.len(), 1);
+    let log = &tx_result.result_logs[0];
+    assert_eq!(log.topics, vec![vec![1u8; 32], vec![2u8; 32]]);
+    assert_eq!(log.data, vec![b"data".to_vec()]);
+}
+
+#[test]
+fn test_esdt_transfer_by_index() {
+    let esdt_transfer = TxTokenTransfer {
+        token_identifier: b"TEST-123456".to_vec(),
+        nonce: 0,
+        value: BigUint::from(5u32),
+    };
+    let mut blockchain = new_blockchain();
+    let value = Cell::new([0u8; 32]);
+
+    let tx_input = TxInput {
+        from: VMAddress::new(USER),
+        to: VMAddress::new(CONTRACT),
+        esdt_values: vec![esdt_transfer],
+        func_name: "endpoint".into(),
+        ..Default::default()
+    };
+    blockchain
+        .state
+        .accounts
+        .get_mut(&VMAddress::new(USER))
+        .unwrap()
+        .esdt
+        .increase_balance(
+            b"TEST-123456".to_vec(),
+            0,
+            &BigUint::from(5u32),
+            Default::default(),
+        );
+    let vm = blockchain.vm.clone();
+    let tx_result = vm.execute_sc_call_lambda(tx_input, &mut blockchain.state, || {
+        let handler = DebugApiVMHooksHandler::new(TxContextStack::static_peek());
+        let vh = VMHooksDispatcher::new(Box::new(handler));
+        let mut buffer = [0u8; 32];
+        mem_conv::with_mem_ptr_mut(&mut buffer, |offset, _| {
+            vh.get_esdt_value_by_index(offset, 0)
+        });
+        value.set(buffer);
+        let _ = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            mem_conv::with_mem_ptr_mut(&mut buffer, |offset, _| {
+                vh.get_esdt_value_by_index(offset, 1)
+            });
+        }));
+    });
+    tx_result.assert_error(10, "invalid token index");
+    assert_eq!(value.get()[31], 5);
+}
+
+fn get_block_hash(vh: &dyn VMHooks, nonce: u64) -> (i32, [u8; 32]) {
+    let mut block_hash = [0u8; 32];
+    let result = mem_conv::with_mem_ptr_mut(&mut block_hash, |offset, _| {
+        vh.get_block_hash(nonce as i64, offset)
+    });
+    (result, block_hash)
+}
+
+#[test]
+fn test_block_hash() {
+    let mut blockchain = new_blockchain();
+    blockchain.state.previous_block_info.advance_to_nonce(4);
+    *blockchain.state.previous_block_info.block_hash = [7u8; 32];
+    blockchain.state.current_block_info.advance_to_nonce(5);
+    let block_hashes = Cell::new([(0, [0u8; 32]); 4]);
+
+    let tx_result = call_contract(&mut blockchain, 0, |vh| {
+        block_hashes.set([
+            get_block_hash(vh, 5),
+            get_block_hash(vh, 4),
+            get_block_hash(vh, 2),
+            get_block_hash(vh, 6),
+        ]);
+    });
+    tx_result.assert_ok();
+    assert_eq!(
+        block_hashes.get(),
+        [
+            (0, BlockInfo::simulated_block_hash(5)),
+            (0, [7u8; 32]),
+            (0, BlockInfo::simulated_block_hash(2)),
+            (1, [0u8; 32]),
+        ]
+    );
+}
+
+#[test]
+fn test_state_root_hash() {
+    let mut blockchain = new_blockchain();
+    blockchain.state.current_block_info.advance_to_nonce(1);
+    let first_state_root_hash = *blockchain.state.current_block_info.state_root_hash;
+    assert_ne!(first_state_root_hash, [0u8; 32]);
+
+    // advancing is deterministic, and does nothing if the nonce stays the same
+    let mut block_info = BlockInfo::new();
+    block_info.advance_to_nonce(1);
+    block_info.advance_to_nonce(1);
+    assert_eq!(*block_info.state_root_hash, first_state_root_hash);
+    block_info.advance_to_nonce(2);
+    assert_ne!(*block_info.state_root_hash, first_state_root_hash);
+
+    let state_root_hash = Cell::new([0u8; 32]);
+    let tx_result = call_contract(&mut blockchain, 0, |vh| {
+        let mut buffer = [0u8; 32];
+        mem_conv::with_mem_ptr_mut(&mut buffer, |offset, _| vh.get_state_root_hash(offset));
+        state_root_hash.set(buffer);
+    });
+    tx_result.assert_ok();
+    assert_eq!(state_root_hash.get(), first_state_root_hash);
+}
@@ -0,0 +1,89 @@
+use std::panic::AssertUnwindSafe;
+
+use multiversx_chain_vm::{
+    tx_execution::ESDT_SYSTEM_SC_ADDRESS_ARRAY,
+    tx_mock::{TxContextStack, TxInput},
+    types::VMAddress,
+    vm_hooks::{DebugApiVMHooksHandler, VMHooksBlockchain},
+    world_mock::{AccountData, ShardCoordinator, METACHAIN_SHARD_ID},
+    BlockchainMock,
+};
+
+fn address_ending_in(last_byte: u8) -> VMAddress {
+    let mut address = [1u8; 32];
+    address[31] = last_byte;
+    VMAddress::new(address)
+}
+
+#[test]
+fn test_three_shards() {
+    let shard_coordinator = ShardCoordinator::new(3);
+    assert_eq!(shard_coordinator.compute_shard(&address_ending_in(0)), 0);
+    assert_eq!(shard_coordinator.compute_shard(&address_ending_in(1)), 1);
+    assert_eq!(shard_coordinator.compute_shard(&address_ending_in(2)), 2);
+    // 3 is not a valid shard, so the last bit is dropped
+    assert_eq!(shard_coordinator.compute_shard(&address_ending_in(3)), 1);
+    assert_eq!(shard_coordinator.compute_shard(&address_ending_in(4)), 0);
+    assert_eq!(shard_coordinator.compute_shard(&address_ending_in(0xff)), 1);
+
+    assert!(shard_coordinator.same_shard(&address_ending_in(1), &address_ending_in(3)));
+    assert!(!shard_coordinator.same_shard(&address_ending_in(1), &address_ending_in(2)));
+}
+
+#[test]
+fn test_single_shard() {
+    let shard_coordinator = ShardCoordinator::new(1);
+    for last_byte in [0, 1, 2, 3, 0xff] {
+        assert_eq!(
+            shard_coordinator.compute_shard(&address_ending_in(last_byte)),
+            0
+        );
+    }
+}
+
+#[test]
+fn test_metachain() {
+    let shard_coordinator = ShardCoordinator::new(3);
+    assert_eq!(
+        shard_coordinator.compute_shard(&VMAddress::new(ESDT_SYSTEM_SC_ADDRESS_ARRAY)),
+        METACHAIN_SHARD_ID
+    );
+
+    // regular contracts live in the shards
+    let mut contract_address = [1u8; 32];
+    contract_address[..8].copy_from_slice(&[0u8; 8]);
+    contract_address[31] = 2;
+    assert_eq!(
+        shard_coordinator.compute_shard(&VMAddress::new(contract_address)),
+        2
+    );
+}
+
+#[test]
+fn test_get_shard_of_invalid_address() {
+    let mut contract_address = [1u8; 32];
+    contract_address[..8].copy_from_slice(&[0u8; 8]);
+    let contract_address = VMAddress::new(contract_address);
+
+    let mut blockchain = BlockchainMock::default();
+    blockchain.state.shard_coordinator = Some(ShardCoordinator::new(3));
+    for address in [address_ending_in(1), contract_address.clone()] {
+        let account = AccountData::new_empty(address.clone());
+        blockchain.state.accounts.insert(address, account);
+    }
+
+    let tx_input = TxInput {
+        from: address_ending_in(1),
+        to: contract_address,
+        func_name: "endpoint".into(),
+        ..Default::default()
+    };
+    let vm = blockchain.vm.clone();
+    let tx_result = vm.execute_sc_call_lambda(tx_input, &mut blockchain.state, || {
+        let handler = DebugApiVMHooksHandler::new(TxContextStack::static_peek());
+        let _ = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            handler.get_shard_of_address(&[1u8, 2, 3]);
+        }));
+    });
+    tx_result.assert_error(10, "invalid address");
+}
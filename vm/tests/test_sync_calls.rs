@@ -0,0 +1,141 @@
+use std::panic::AssertUnwindSafe;
+
+use multiversx_chain_vm::{
+    tx_mock::{TxContextStack, TxInput, TxResult},
+    types::VMAddress,
+    vm_hooks::{DebugApiVMHooksHandler, VMHooksHandlerSource},
+    world_mock::AccountData,
+    BlockchainMock,
+};
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+const USER: [u8; 32] = [2u8; 32];
+const CONTRACT: [u8; 32] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+];
+
+fn new_blockchain() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::default();
+    for address in [USER, CONTRACT] {
+        let address = VMAddress::new(address);
+        let mut account = AccountData::new_empty(address.clone());
+        account.egld_balance = BigUint::from(1000u32);
+        blockchain.state.accounts.insert(address, account);
+    }
+    blockchain
+}
+
+/// Calls `f` with the VM hooks handler of the current context, the same way a contract would.
+///
+/// Errors halt execution via a panic, which is caught here.
+fn call_contract<F>(blockchain: &mut BlockchainMock, readonly: bool, f: F) -> TxResult
+where
+    F: FnOnce(&DebugApiVMHooksHandler),
+{
+    let tx_input = TxInput {
+        from: VMAddress::new(USER),
+        to: VMAddress::new(CONTRACT),
+        func_name: "endpoint".into(),
+        readonly,
+        ..Default::default()
+    };
+    let vm = blockchain.vm.clone();
+    vm.execute_sc_call_lambda(tx_input, &mut blockchain.state, || {
+        let handler = DebugApiVMHooksHandler::new(TxContextStack::static_peek());
+        let _ = std::panic::catch_unwind(AssertUnwindSafe(|| f(&handler)));
+    })
+}
+
+#[test]
+fn test_storage_write() {
+    let mut blockchain = new_blockchain();
+
+    let tx_result = call_contract(&mut blockchain, false, |handler| {
+        handler.storage_write(b"key", b"value");
+    });
+    tx_result.assert_ok();
+    assert_eq!(
+        blockchain.state.accounts[&VMAddress::new(CONTRACT)].storage[b"key".as_slice()],
+        b"value".to_vec()
+    );
+}
+
+#[test]
+fn test_readonly_storage_write() {
+    let mut blockchain = new_blockchain();
+
+    let tx_result = call_contract(&mut blockchain, true, |handler| {
+        handler.storage_write(b"key", b"value");
+    });
+    tx_result.assert_error(10, "cannot write on read only mode");
+    assert!(blockchain.state.accounts[&VMAddress::new(CONTRACT)]
+        .storage
+        .is_empty());
+}
+
+#[test]
+fn test_readonly_transfer() {
+    let mut blockchain = new_blockchain();
+
+    let tx_result = call_contract(&mut blockchain, true, |handler| {
+        handler.perform_transfer_execute(
+            VMAddress::new(USER),
+            BigUint::from(10u32),
+            "".into(),
+            Vec::new(),
+        );
+    });
+    tx_result.assert_error(10, "operation not permitted in read only mode");
+    assert_eq!(
+        blockchain.state.accounts[&VMAddress::new(USER)].egld_balance,
+        BigUint::from(1000u32)
+    );
+}
+
+#[test]
+fn test_readonly_builtin_function() {
+    let mut blockchain = new_blockchain();
+
+    let tx_result = call_contract(&mut blockchain, false, |handler| {
+        handler.perform_execute_read_only(
+            VMAddress::new(USER),
+            "ESDTTransfer".into(),
+            vec![b"TEST-123456".to_vec(), vec![10]],
+        );
+    });
+    tx_result.assert_error(10, "operation not permitted in read only mode");
+}
+
+#[test]
+fn test_same_context_builtin_function() {
+    let mut blockchain = new_blockchain();
+
+    let tx_result = call_contract(&mut blockchain, false, |handler| {
+        handler.perform_execute_on_same_context(
+            VMAddress::new(USER),
+            BigUint::zero(),
+            "ESDTTransfer".into(),
+            vec![b"TEST-123456".to_vec(), vec![10]],
+        );
+    });
+    tx_result.assert_error(
+        10,
+        "calling built-in function on the same context is disallowed",
+    );
+}
+
+#[test]
+fn test_same_context_not_a_contract() {
+    let mut blockchain = new_blockchain();
+
+    let tx_result = call_contract(&mut blockchain, false, |handler| {
+        handler.perform_execute_on_same_context(
+            VMAddress::new(USER),
+            BigUint::zero(),
+            "endpoint".into(),
+            Vec::new(),
+        );
+    });
+    tx_result.assert_error(10, "contract not found");
+}